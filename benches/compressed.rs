@@ -0,0 +1,73 @@
+// Compares the graph representations on the same query over a large grid:
+// the default Graph, built from one HashMap per node, the CsrGraph and the
+// CompressedGraph. The memory taken by the edges of the last two is printed as
+// well.
+//
+// Run with `cargo bench --bench compressed`.
+
+// Used to keep the compiler from optimizing the measured code away.
+use std::hint::black_box;
+// Used to time the measures.
+use std::time::{Duration, Instant};
+
+// The graphs and the algorithm being measured.
+use dijkstra::{dijkstra, CompressedGraph, CsrGraph, Graph};
+
+// The side of the grid used for the queries.
+const SIDE: u32 = 300;
+// How many times every measure is repeated, the best time being kept.
+const RUNS: usize = 10;
+
+// Runs the measure several times and returns the best time.
+fn best_of<F: FnMut()>(mut measure: F) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            measure();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn main() {
+    // A grid where every node is connected to its four neighbors, row by row
+    // so that the neighbors get close indices.
+    let mut edges = Vec::new();
+    for row in 0..SIDE {
+        for column in 0..SIDE {
+            let cost = 1 + (row * column) % 7;
+            if column + 1 < SIDE {
+                edges.push(((row, column), (row, column + 1), cost));
+                edges.push(((row, column + 1), (row, column), cost));
+            }
+            if row + 1 < SIDE {
+                edges.push(((row, column), (row + 1, column), cost));
+                edges.push(((row + 1, column), (row, column), cost));
+            }
+        }
+    }
+    let graph: Graph<(u32, u32)> = edges.iter().copied().collect();
+    let csr: CsrGraph<(u32, u32)> = edges.iter().copied().collect();
+    let compressed = CompressedGraph::from_graph(&csr);
+
+    let source = (0, 0);
+    let destination = (SIDE - 1, SIDE - 1);
+    let hash_map = best_of(|| {
+        black_box(dijkstra(&graph, &source, &destination).ok());
+    });
+    let arrays = best_of(|| {
+        black_box(dijkstra(&csr, &source, &destination).ok());
+    });
+    let varints = best_of(|| {
+        black_box(dijkstra(&compressed, &source, &destination).ok());
+    });
+    println!("Graph query: {:?}", hash_map);
+    println!("CsrGraph query: {:?}", arrays);
+    println!("CompressedGraph query: {:?}", varints);
+
+    // The CsrGraph stores a target and a cost per edge, and an offset per node.
+    let csr_bytes = csr.edge_count() * 8 + (csr.node_count() + 1) * std::mem::size_of::<usize>();
+    println!("CsrGraph edges: {} bytes", csr_bytes);
+    println!("CompressedGraph edges: {} bytes", compressed.edge_bytes());
+}
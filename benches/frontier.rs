@@ -0,0 +1,75 @@
+// Compares the priority queues the search can be run with: the default binary
+// heap, the bucket queue and the pairing heap, on the same query over a large
+// grid with small integer costs.
+//
+// Run with `cargo bench --bench frontier`.
+
+// Used to keep the compiler from optimizing the measured code away.
+use std::hint::black_box;
+// Used to time the measures.
+use std::time::{Duration, Instant};
+
+// The graph and the priority queues being measured.
+use dijkstra::{
+    dijkstra_with_frontier, BinaryHeapFrontier, BucketFrontier, Graph, PairingHeapFrontier,
+};
+
+// The side of the grid used for the queries.
+const SIDE: u32 = 300;
+// The largest cost of the edges of the grid.
+const LARGEST: u32 = 3;
+// How many times every measure is repeated, the best time being kept.
+const RUNS: usize = 10;
+
+// Runs the measure several times and returns the best time.
+fn best_of<F: FnMut()>(mut measure: F) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            measure();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn main() {
+    // A grid where every node is connected to its right and bottom neighbors.
+    let mut grid: Graph<(u32, u32)> = Graph::new();
+    for row in 0..SIDE {
+        for column in 0..SIDE {
+            if column + 1 < SIDE {
+                grid.add_edge(
+                    (row, column),
+                    (row, column + 1),
+                    1 + (row + column) % LARGEST,
+                );
+            }
+            if row + 1 < SIDE {
+                grid.add_edge(
+                    (row, column),
+                    (row + 1, column),
+                    1 + (row * column) % LARGEST,
+                );
+            }
+        }
+    }
+    let source = (0, 0);
+    let destination = (SIDE - 1, SIDE - 1);
+
+    let binary = best_of(|| {
+        let frontier = BinaryHeapFrontier::new();
+        black_box(dijkstra_with_frontier(&grid, &source, &destination, frontier).ok());
+    });
+    let bucket = best_of(|| {
+        let frontier = BucketFrontier::new(LARGEST as usize);
+        black_box(dijkstra_with_frontier(&grid, &source, &destination, frontier).ok());
+    });
+    let pairing = best_of(|| {
+        let frontier = PairingHeapFrontier::new();
+        black_box(dijkstra_with_frontier(&grid, &source, &destination, frontier).ok());
+    });
+    println!("binary heap: {:?}", binary);
+    println!("bucket queue: {:?}", bucket);
+    println!("pairing heap: {:?}", pairing);
+}
@@ -0,0 +1,98 @@
+// Compares the two ways of looking up the neighbors of a node: building an
+// empty HashMap as a fallback for every lookup, as the search used to do, and
+// iterating over the Option returned by the lookup, as it does now. The time
+// of a whole query on a large grid is measured as well.
+//
+// Run with `cargo bench --bench neighbors`.
+
+// Used for the raw adjacency lists of the first measures.
+use std::collections::HashMap;
+// Used to keep the compiler from optimizing the measured code away.
+use std::hint::black_box;
+// Used to time the measures.
+use std::time::{Duration, Instant};
+
+// The graph and the algorithm being measured.
+use dijkstra::{dijkstra, Graph};
+
+// The number of nodes of the raw adjacency lists.
+const NODES: u32 = 100_000;
+// The side of the grid used for the whole queries.
+const SIDE: u32 = 300;
+// How many times every measure is repeated, the best time being kept.
+const RUNS: usize = 10;
+
+// Runs the measure several times and returns the best time.
+fn best_of<F: FnMut()>(mut measure: F) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            measure();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+// Sums the costs of the edges leaving every node, building an empty HashMap
+// for every lookup whether the node has neighbors or not.
+fn eager_fallback(adjacency: &HashMap<u32, HashMap<u32, u32>>) -> u64 {
+    let mut total = 0;
+    // Half of the nodes are looked up without being in the adjacency lists.
+    for node in 0..2 * NODES {
+        let empty = HashMap::new();
+        let neighbors = adjacency.get(&node).unwrap_or(&empty);
+        total += neighbors.values().map(|&cost| u64::from(cost)).sum::<u64>();
+    }
+    total
+}
+
+// Sums the costs of the edges leaving every node, treating a missing node as
+// an empty iterator.
+fn lazy_fallback(adjacency: &HashMap<u32, HashMap<u32, u32>>) -> u64 {
+    let mut total = 0;
+    // Half of the nodes are looked up without being in the adjacency lists.
+    for node in 0..2 * NODES {
+        let neighbors = adjacency.get(&node).into_iter().flatten();
+        total += neighbors.map(|(_, &cost)| u64::from(cost)).sum::<u64>();
+    }
+    total
+}
+
+fn main() {
+    // A sparse graph where every node is connected to a few of the next ones.
+    let mut adjacency: HashMap<u32, HashMap<u32, u32>> = HashMap::new();
+    for node in 0..NODES {
+        let adjacent = adjacency.entry(node).or_default();
+        for step in 1..4 {
+            adjacent.insert((node + step) % NODES, step);
+        }
+    }
+
+    let eager = best_of(|| {
+        black_box(eager_fallback(black_box(&adjacency)));
+    });
+    let lazy = best_of(|| {
+        black_box(lazy_fallback(black_box(&adjacency)));
+    });
+    println!("empty HashMap fallback: {:?}", eager);
+    println!("empty iterator fallback: {:?}", lazy);
+
+    // A grid where every node is connected to its right and bottom neighbors.
+    let mut grid: Graph<(u32, u32)> = Graph::new();
+    for row in 0..SIDE {
+        for column in 0..SIDE {
+            if column + 1 < SIDE {
+                grid.add_edge((row, column), (row, column + 1), 1 + (row + column) % 3);
+            }
+            if row + 1 < SIDE {
+                grid.add_edge((row, column), (row + 1, column), 1 + (row * column) % 3);
+            }
+        }
+    }
+
+    let query = best_of(|| {
+        black_box(dijkstra(&grid, &(0, 0), &(SIDE - 1, SIDE - 1)).ok());
+    });
+    println!("{}x{} grid query: {:?}", SIDE, SIDE, query);
+}
@@ -0,0 +1,250 @@
+// Used to order the nodes by cost.
+use std::cmp::Reverse;
+// Used as priority queue by the searches.
+use std::collections::BinaryHeap;
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to find the index of the nodes, and to keep track of the search.
+use crate::hash::HashMap;
+// The graph being preprocessed, and the paths found in it.
+use crate::{
+    GraphLike, OverflowPolicy, PathError, PathResult, SearchStats, ShortestPathResult, Weight,
+};
+
+/// A graph prepared for fast shortest path queries with arc-flags, following
+/// Lauther, and Möhring et al.
+///
+/// The nodes are split into regions, and every edge is flagged with the
+/// regions it leads to along a shortest path, i.e. with the regions of the
+/// nodes which have a shortest path starting with the edge. A query towards
+/// a destination then only follows the edges flagged with its region: the
+/// search still spreads around the source like Dijkstra's algorithm, but
+/// soon narrows down to the edges heading towards the destination, and
+/// settles only a fraction of the nodes once it is far from it.
+///
+/// The flags are computed once with [`ArcFlags::new`], after which
+/// [`ArcFlags::query`] finds any path. The edges of the graph are copied, so
+/// the arc-flags don't borrow the graph, and they have to be computed again
+/// if the graph changes. One bit is stored for every edge and every region.
+#[derive(Debug, Clone)]
+pub struct ArcFlags<N, W> {
+    // The nodes of the graph, by position.
+    nodes: Vec<N>,
+    // The position of every node.
+    index: HashMap<N, usize>,
+    // The region of every node.
+    regions: Vec<usize>,
+    // The position of the first edge leaving every node, followed by the
+    // number of edges.
+    offsets: Vec<usize>,
+    // The destination and the cost of every edge, grouped by source.
+    edges: Vec<(usize, W)>,
+    // The flags of every edge, as words of bits indexed by region.
+    flags: Vec<u64>,
+    // The number of words of flags of every edge.
+    words: usize,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> ArcFlags<N, W> {
+    /// Splits the nodes of `graph` into the regions given by `partition`, and
+    /// flags its edges with the regions they lead to.
+    ///
+    /// `partition` returns the region of every node, regions being numbered
+    /// from zero. The regions should be compact, of similar sizes, and cut
+    /// few edges: a grid laid over the coordinates of a road network does
+    /// well, for instance, with a few dozen to a few hundred regions. The
+    /// edges between two nodes of the same region are flagged with it, and
+    /// a search backward from every node entered from another region flags
+    /// the edges of its shortest paths, so this takes O(B (V + E) log V)
+    /// time where B is the number of such boundary nodes. The costs of the
+    /// edges leading out of the graph are ignored.
+    ///
+    /// Fails with [`PathError::Overflow`] if the cost of a path overflows.
+    pub fn new<G, P>(graph: &G, mut partition: P) -> Result<Self, PathError>
+    where
+        G: GraphLike<Node = N, Weight = W>,
+        P: FnMut(&N) -> usize,
+    {
+        let policy = OverflowPolicy::default();
+        let nodes: Vec<N> = graph.nodes().cloned().collect();
+        let index: HashMap<N, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(position, node)| (node.clone(), position))
+            .collect();
+        let regions: Vec<usize> = nodes.iter().map(&mut partition).collect();
+        let words = regions
+            .iter()
+            .max()
+            .map_or(0, |&last| last + 1)
+            .div_ceil(64);
+
+        // The edges are grouped by source, and also by destination for the
+        // searches going backward.
+        let mut offsets = Vec::with_capacity(nodes.len() + 1);
+        let mut edges = Vec::new();
+        let mut entering = vec![Vec::new(); nodes.len()];
+        for (from, node) in nodes.iter().enumerate() {
+            offsets.push(edges.len());
+            for (to, cost) in graph.neighbors(node) {
+                if let Some(&to) = index.get(to) {
+                    entering[to].push((from, edges.len()));
+                    edges.push((to, cost));
+                }
+            }
+        }
+        offsets.push(edges.len());
+        let mut flags = vec![0; edges.len() * words];
+
+        // Every edge within a region leads to it.
+        for from in 0..nodes.len() {
+            for edge in offsets[from]..offsets[from + 1] {
+                if regions[edges[edge].0] == regions[from] {
+                    flag(&mut flags, words, edge, regions[from]);
+                }
+            }
+        }
+
+        // The shortest paths into a region from elsewhere enter it through
+        // its boundary, so the edges on the shortest paths to the boundary
+        // lead to the region.
+        let mut costs = vec![None; nodes.len()];
+        let mut queue = BinaryHeap::new();
+        for boundary in 0..nodes.len() {
+            let region = regions[boundary];
+            if entering[boundary]
+                .iter()
+                .all(|&(from, _)| regions[from] == region)
+            {
+                continue;
+            }
+            costs.iter_mut().for_each(|cost| *cost = None);
+            costs[boundary] = Some(W::zero());
+            queue.push(Reverse((W::zero(), boundary)));
+            while let Some(Reverse((cost, node))) = queue.pop() {
+                // The outdated entries are skipped.
+                if costs[node].is_some_and(|best| best < cost) {
+                    continue;
+                }
+                for &(from, edge) in &entering[node] {
+                    let new_cost = policy.add(cost, edges[edge].1).ok_or(PathError::Overflow)?;
+                    if costs[from].is_none_or(|old| new_cost < old) {
+                        costs[from] = Some(new_cost);
+                        queue.push(Reverse((new_cost, from)));
+                    }
+                }
+            }
+            // An edge is on a shortest path to the boundary whenever it is
+            // tight, i.e. its cost makes up the difference between the costs
+            // of its ends.
+            for to in 0..nodes.len() {
+                let to_cost = match costs[to] {
+                    Some(to_cost) => to_cost,
+                    None => continue,
+                };
+                for &(from, edge) in &entering[to] {
+                    let through = policy
+                        .add(to_cost, edges[edge].1)
+                        .ok_or(PathError::Overflow)?;
+                    if costs[from] == Some(through) {
+                        flag(&mut flags, words, edge, region);
+                    }
+                }
+            }
+        }
+
+        Ok(ArcFlags {
+            nodes,
+            index,
+            regions,
+            offsets,
+            edges,
+            flags,
+            words,
+        })
+    }
+
+    /// Finds the shortest path from `source` to `destination` in the graph
+    /// of the arc-flags.
+    ///
+    /// The search is Dijkstra's algorithm, except that it only follows the
+    /// edges flagged with the region of the destination. The returned path
+    /// and errors are the same as with [`dijkstra`](crate::dijkstra).
+    pub fn query(&self, source: &N, destination: &N) -> PathResult<N, W> {
+        let policy = OverflowPolicy::default();
+        let source = *self.index.get(source).ok_or(PathError::UnknownSource)?;
+        let destination = *self
+            .index
+            .get(destination)
+            .ok_or(PathError::UnknownDestination)?;
+        let region = self.regions[destination];
+
+        // The best known cost of every node reached so far, along with the
+        // node it was reached from.
+        let mut reached: HashMap<usize, (W, Option<usize>)> = HashMap::default();
+        reached.insert(source, (W::zero(), None));
+        let mut queue = BinaryHeap::new();
+        queue.push(Reverse((W::zero(), source)));
+        let mut stats = SearchStats {
+            pushes: 1,
+            ..SearchStats::default()
+        };
+
+        while let Some(Reverse((cost, node))) = queue.pop() {
+            // The outdated entries are skipped.
+            if reached[&node].0 < cost {
+                continue;
+            }
+            stats.settled += 1;
+            if node == destination {
+                let mut path = vec![node];
+                let mut previous = reached[&node].1;
+                while let Some(node) = previous {
+                    path.push(node);
+                    previous = reached[&node].1;
+                }
+                return Ok(ShortestPathResult {
+                    nodes: path
+                        .into_iter()
+                        .rev()
+                        .map(|node| self.nodes[node].clone())
+                        .collect(),
+                    cost,
+                    settled: Some(stats.settled),
+                    stats: Some(stats),
+                });
+            }
+
+            for edge in self.offsets[node]..self.offsets[node + 1] {
+                if !flagged(&self.flags, self.words, edge, region) {
+                    continue;
+                }
+                stats.relaxations += 1;
+                let (neighbor, edge_cost) = self.edges[edge];
+                let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+                if reached
+                    .get(&neighbor)
+                    .is_some_and(|&(old, _)| old <= new_cost)
+                {
+                    continue;
+                }
+                reached.insert(neighbor, (new_cost, Some(node)));
+                queue.push(Reverse((new_cost, neighbor)));
+                stats.pushes += 1;
+            }
+        }
+
+        Err(PathError::NoPath)
+    }
+}
+
+// Flags the edge with the region.
+fn flag(flags: &mut [u64], words: usize, edge: usize, region: usize) {
+    flags[edge * words + region / 64] |= 1 << (region % 64);
+}
+
+// Whether the edge is flagged with the region.
+fn flagged(flags: &[u64], words: usize, edge: usize, region: usize) -> bool {
+    flags[edge * words + region / 64] & (1 << (region % 64)) != 0
+}
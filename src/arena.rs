@@ -0,0 +1,148 @@
+// Used to number the names.
+use std::convert::TryFrom;
+// Used to print the names.
+use std::fmt;
+// Used to hash the names.
+use std::hash::BuildHasher as _;
+
+// Used to find the names already stored.
+use crate::hash::{BuildHasher, HashMap};
+
+/// A cheap identifier for a name stored in a [`NameArena`].
+///
+/// The identifiers are numbered from zero, in the order the names were first
+/// interned. Since they are plain integers, they make much lighter nodes than
+/// the names themselves, for instance in a `Graph<NameId>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NameId(u32);
+
+impl NameId {
+    /// The position of the name in the arena, starting from zero.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Stores every distinct node name once, and hands out [`NameId`]s for them.
+///
+/// Storing the names of a large graph as separate `String`s costs one
+/// allocation and 24 bytes of bookkeeping per occurrence of every name. The
+/// arena instead appends every new name to a single buffer, and only keeps the
+/// position where it ends along with its hash. Interning a name which is
+/// already stored returns the same identifier without copying it again.
+///
+/// The names are borrowed back with [`NameArena::resolve`], for as long as
+/// the arena lives.
+#[derive(Clone, Default)]
+pub struct NameArena {
+    // All the names, one after the other.
+    text: String,
+    // Where every name ends in the text, the name i starting where the name
+    // i - 1 ends.
+    ends: Vec<usize>,
+    // The last name interned with every hash.
+    buckets: HashMap<u64, u32>,
+    // The name interned before every name with the same hash, if any. Two
+    // names rarely share the same hash, so these chains are very short.
+    previous: Vec<Option<u32>>,
+    // Hashes the names.
+    hasher: BuildHasher,
+}
+
+impl NameArena {
+    /// Creates an empty arena.
+    pub fn new() -> Self {
+        NameArena::default()
+    }
+
+    /// Creates an empty arena with room for `names` names totalling `bytes`
+    /// bytes.
+    pub fn with_capacity(names: usize, bytes: usize) -> Self {
+        NameArena {
+            text: String::with_capacity(bytes),
+            ends: Vec::with_capacity(names),
+            buckets: HashMap::with_capacity_and_hasher(names, BuildHasher::default()),
+            previous: Vec::with_capacity(names),
+            hasher: BuildHasher::default(),
+        }
+    }
+
+    /// Returns the identifier of `name`, storing it first if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena would hold more than `u32::MAX` names.
+    pub fn intern(&mut self, name: &str) -> NameId {
+        let hash = self.hasher.hash_one(name);
+        if let Some(id) = self.find(hash, name) {
+            return id;
+        }
+
+        // We store the new name, and chain it to the names with the same hash.
+        let id = u32::try_from(self.ends.len()).expect("too many names for a NameArena");
+        self.text.push_str(name);
+        self.ends.push(self.text.len());
+        self.previous.push(self.buckets.insert(hash, id));
+        NameId(id)
+    }
+
+    /// Returns the identifier of `name`, if it was interned.
+    pub fn get(&self, name: &str) -> Option<NameId> {
+        self.find(self.hasher.hash_one(name), name)
+    }
+
+    /// Returns the name with the given identifier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the identifier was handed out by another arena, holding more
+    /// names than this one.
+    pub fn resolve(&self, id: NameId) -> &str {
+        let start = match id.index() {
+            0 => 0,
+            index => self.ends[index - 1],
+        };
+        &self.text[start..self.ends[id.index()]]
+    }
+
+    /// The number of distinct names in the arena.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Whether the arena holds no name.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Iterates over the names with their identifiers, in the order they were
+    /// first interned.
+    pub fn iter(&self) -> impl Iterator<Item = (NameId, &str)> + '_ {
+        (0..self.ends.len()).map(move |index| {
+            let id = NameId(index as u32);
+            (id, self.resolve(id))
+        })
+    }
+
+    // Walks the chain of the names with the given hash, looking for `name`.
+    fn find(&self, hash: u64, name: &str) -> Option<NameId> {
+        let mut candidate = self.buckets.get(&hash).copied();
+        while let Some(id) = candidate {
+            if self.resolve(NameId(id)) == name {
+                return Some(NameId(id));
+            }
+            candidate = self.previous[id as usize];
+        }
+        None
+    }
+}
+
+// The hasher of the "fast-hash" feature cannot be printed, so the names are
+// printed alone.
+impl fmt::Debug for NameArena {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(|(_, name)| name))
+            .finish()
+    }
+}
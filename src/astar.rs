@@ -0,0 +1,37 @@
+// The graph being explored, and the search itself.
+use crate::{GraphLike, PathResult, SearchWorkspace};
+
+/// Finds the shortest path from `source` to `destination` in `graph` with the
+/// A* algorithm, guided by `heuristic`.
+///
+/// The heuristic estimates the cost of the path from every node to the
+/// destination, for instance the straight-line distance between two points
+/// of a road network. The nodes are settled in the order of the cost of the
+/// path leading to them plus this estimate, rather than of the cost alone, so
+/// that the search heads towards the destination instead of spreading evenly
+/// around the source. On spatial graphs, this settles a small fraction of the
+/// nodes settled by [`dijkstra`](crate::dijkstra).
+///
+/// The returned path is a shortest one as long as the heuristic is
+/// admissible, i.e. never overestimates the cost of reaching the destination.
+/// It is fastest when the heuristic is also consistent, i.e. when the
+/// estimate of a node never exceeds the cost of an edge leaving it plus the
+/// estimate of the other end: nodes are otherwise settled again every time a
+/// better path to them is found. A heuristic always returning zero turns A*
+/// back into Dijkstra's algorithm.
+///
+/// The returned path and errors are the same as with
+/// [`dijkstra`](crate::dijkstra).
+pub fn astar<G, H>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    heuristic: H,
+) -> PathResult<G::Node, G::Weight>
+where
+    G: GraphLike,
+    H: FnMut(&G::Node) -> G::Weight,
+{
+    // A fresh workspace is only used for this query.
+    SearchWorkspace::new().astar(graph, source, destination, heuristic)
+}
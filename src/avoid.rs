@@ -0,0 +1,138 @@
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to store the forbidden nodes and edges.
+use crate::hash::{HashMap, HashSet};
+// The graph being explored, and the search run on it.
+use crate::{dijkstra, GraphLike, PathResult};
+
+/// The nodes and edges a query must not go through.
+///
+/// A closed road or a failed link is forbidden for a single query, without
+/// rebuilding the graph: the list is applied to a graph with
+/// [`Avoid::apply`], which gives a view of the graph without the forbidden
+/// nodes and edges. Any algorithm of the crate can then run on this view, or
+/// [`dijkstra_avoiding`] can be used directly.
+#[derive(Debug, Clone)]
+pub struct Avoid<N> {
+    // The forbidden nodes.
+    nodes: HashSet<N>,
+    // The destinations of the forbidden edges leaving every node.
+    edges: HashMap<N, HashSet<N>>,
+}
+
+impl<N: Eq + Hash + Clone> Avoid<N> {
+    /// Creates a list which forbids nothing.
+    pub fn new() -> Self {
+        Avoid {
+            nodes: HashSet::default(),
+            edges: HashMap::default(),
+        }
+    }
+
+    /// Forbids `node`, and therefore all the edges leading to it or leaving
+    /// it.
+    pub fn node(mut self, node: N) -> Self {
+        self.nodes.insert(node);
+        self
+    }
+
+    /// Forbids the edge going from `source` to `destination`. In an
+    /// undirected graph, the edge going the other way has to be forbidden as
+    /// well.
+    pub fn edge(mut self, source: N, destination: N) -> Self {
+        self.edges.entry(source).or_default().insert(destination);
+        self
+    }
+
+    /// Whether `node` is forbidden.
+    pub fn avoids_node(&self, node: &N) -> bool {
+        self.nodes.contains(node)
+    }
+
+    /// Whether the edge going from `source` to `destination` is forbidden,
+    /// including when one of its ends is.
+    pub fn avoids_edge(&self, source: &N, destination: &N) -> bool {
+        self.avoids_node(source)
+            || self.avoids_node(destination)
+            || self
+                .edges
+                .get(source)
+                .is_some_and(|destinations| destinations.contains(destination))
+    }
+
+    /// Returns a view of `graph` without the forbidden nodes and edges.
+    pub fn apply<'a, G: GraphLike<Node = N>>(&'a self, graph: &'a G) -> Avoiding<'a, G> {
+        Avoiding { graph, avoid: self }
+    }
+}
+
+impl<N: Eq + Hash + Clone> Default for Avoid<N> {
+    fn default() -> Self {
+        Avoid::new()
+    }
+}
+
+/// A view of a graph without the nodes and edges of an [`Avoid`] list, as
+/// returned by [`Avoid::apply`].
+///
+/// The forbidden nodes are not part of the view, and the forbidden edges are
+/// skipped while iterating over the neighbors of their source. The graph
+/// itself is left untouched.
+#[derive(Debug, Clone, Copy)]
+pub struct Avoiding<'a, G: GraphLike> {
+    // The graph being viewed.
+    graph: &'a G,
+    // What the view leaves out of the graph.
+    avoid: &'a Avoid<G::Node>,
+}
+
+impl<G: GraphLike> GraphLike for Avoiding<'_, G> {
+    type Node = G::Node;
+    type Weight = G::Weight;
+
+    fn get_node(&self, node: &G::Node) -> Option<&G::Node> {
+        self.graph
+            .get_node(node)
+            .filter(|node| !self.avoid.avoids_node(node))
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        node: &G::Node,
+    ) -> impl Iterator<Item = (&'a G::Node, G::Weight)> + 'a {
+        // A forbidden node has no neighbors, and the forbidden edges leaving
+        // the node are looked up once.
+        let avoided = self.avoid.avoids_node(node);
+        let forbidden = self.avoid.edges.get(node);
+        self.graph.neighbors(node).filter(move |(neighbor, _)| {
+            !avoided
+                && !self.avoid.avoids_node(neighbor)
+                && forbidden.is_none_or(|forbidden| !forbidden.contains(*neighbor))
+        })
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a G::Node> + 'a {
+        self.graph
+            .nodes()
+            .filter(move |node| !self.avoid.avoids_node(node))
+    }
+}
+
+/// Finds the shortest path from `source` to `destination` in `graph` which
+/// doesn't go through any of the nodes and edges of `avoid`.
+///
+/// This runs [`dijkstra`] on the view returned by [`Avoid::apply`], so the
+/// returned path and errors are the same. The forbidden nodes are not part of
+/// the view, so the query fails with
+/// [`PathError::UnknownSource`](crate::PathError::UnknownSource) or
+/// [`PathError::UnknownDestination`](crate::PathError::UnknownDestination) if
+/// the source or the destination is forbidden.
+pub fn dijkstra_avoiding<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    avoid: &Avoid<G::Node>,
+) -> PathResult<G::Node, G::Weight> {
+    dijkstra(&avoid.apply(graph), source, destination)
+}
@@ -0,0 +1,145 @@
+// Used to keep track of the progression of the algorithm.
+use crate::hash::HashMap;
+// The graph being explored, and the shortest paths found in it.
+use crate::{GraphLike, OverflowPolicy, PathError, ShortestPathTree, Weight};
+
+/// Finds the shortest paths from `source` to all the nodes it can reach in
+/// `graph` with the Bellman-Ford algorithm, which supports negative costs.
+///
+/// Dijkstra's algorithm settles every node once and for all, which is only
+/// correct if no edge has a negative cost. Bellman-Ford instead relaxes all
+/// the edges again and again until no path improves, which takes at most one
+/// round per node of the graph, i.e. O(V·E) time. The rounds stop as soon as
+/// one of them didn't improve any path, so the algorithm is much faster in
+/// practice.
+///
+/// The distances and the predecessors of all the reached nodes are returned
+/// as a [`ShortestPathTree`], from which the paths are extracted just like
+/// [`dijkstra`](crate::dijkstra) would have returned them. The query fails
+/// with [`PathError::NegativeCycle`] if the source can reach a cycle whose
+/// total cost is negative, since the paths through it can be made as cheap as
+/// wanted.
+pub fn bellman_ford<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+) -> Result<ShortestPathTree<G::Node, G::Weight>, PathError> {
+    let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+
+    // The best known cost of every node reached so far.
+    let mut distances = HashMap::default();
+    // The node every node was reached from on its best known path.
+    let mut predecessors = HashMap::default();
+    distances.insert(source, G::Weight::zero());
+
+    if relax(graph, &mut distances, &mut predecessors)?.is_some() {
+        return Err(PathError::NegativeCycle);
+    }
+
+    Ok(ShortestPathTree::new(
+        source.clone(),
+        distances
+            .into_iter()
+            .map(|(node, cost)| (node.clone(), cost))
+            .collect(),
+        predecessors
+            .into_iter()
+            .map(|(node, predecessor)| (node.clone(), predecessor.clone()))
+            .collect(),
+    ))
+}
+
+/// Looks for a cycle whose total cost is negative anywhere in `graph`, and
+/// returns its nodes if there is one.
+///
+/// Such a cycle makes the shortest paths through it meaningless, and breaks
+/// [`dijkstra`](crate::dijkstra) as well as [`bellman_ford`]. This lets a
+/// cost model be checked before running any query on it. The cycle is
+/// returned as a path, i.e. its first node is repeated at its end, and
+/// following its edges in order gives a negative total cost. `None` means
+/// that the graph has no negative cycle at all, whatever the source.
+///
+/// The search runs Bellman-Ford from all the nodes at once, which takes
+/// O(V·E) time. It fails with [`PathError::Overflow`] if the cost of a path
+/// overflows before the cycle is found.
+pub fn find_negative_cycle<G: GraphLike>(graph: &G) -> Result<Option<Vec<G::Node>>, PathError> {
+    // Starting all the nodes at zero is the same as adding a source linked
+    // to all of them for free, which reaches every cycle of the graph.
+    let mut distances: HashMap<_, _> = graph
+        .nodes()
+        .map(|node| (node, G::Weight::zero()))
+        .collect();
+    let mut predecessors = HashMap::default();
+
+    let improved = match relax(graph, &mut distances, &mut predecessors)? {
+        Some(node) => node,
+        None => return Ok(None),
+    };
+
+    // The node improved in the last round is reached through the cycle, but
+    // may not be part of it. Going back as many steps as there are nodes is
+    // enough to be on the cycle.
+    let mut start = improved;
+    for _ in 0..graph.node_count() {
+        start = predecessors[start];
+    }
+
+    // We then go back around the cycle until we are back to where we started.
+    let mut cycle = vec![start.clone()];
+    let mut location = predecessors[start];
+    while location != start {
+        cycle.push(location.clone());
+        location = predecessors[location];
+    }
+    cycle.push(start.clone());
+    cycle.reverse();
+
+    Ok(Some(cycle))
+}
+
+// Relaxes the edges leaving the reached nodes, round after round, until no
+// path improves anymore. Returns a node improved by the last round if the
+// paths still improve after as many rounds as there are nodes, which means
+// that they go through a negative cycle. The nodes which are not part of the
+// graph are given a cost, but are not counted as improved: no edge leaves
+// them, and the shortest path to one of them may have as many edges as there
+// are nodes in the graph.
+pub(crate) fn relax<'g, G: GraphLike>(
+    graph: &'g G,
+    distances: &mut HashMap<&'g G::Node, G::Weight>,
+    predecessors: &mut HashMap<&'g G::Node, &'g G::Node>,
+) -> Result<Option<&'g G::Node>, PathError> {
+    let policy = OverflowPolicy::default();
+
+    // A shortest path has at most as many edges as there are nodes, minus one,
+    // and every round finds the shortest paths with one more edge. A path
+    // still improving after that many rounds goes through a negative cycle.
+    let mut improved = None;
+    for _ in 0..graph.node_count() {
+        improved = None;
+
+        for node in graph.nodes() {
+            // Only the reached nodes can improve the paths of their neighbors.
+            let cost = match distances.get(node) {
+                Some(&cost) => cost,
+                None => continue,
+            };
+
+            for (neighbor, edge_cost) in graph.neighbors(node) {
+                let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+                if distances.get(neighbor).is_none_or(|old| *old > new_cost) {
+                    distances.insert(neighbor, new_cost);
+                    predecessors.insert(neighbor, node);
+                    if graph.contains_node(neighbor) {
+                        improved = Some(neighbor);
+                    }
+                }
+            }
+        }
+
+        // The paths are final once a whole round didn't improve any of them.
+        if improved.is_none() {
+            break;
+        }
+    }
+    Ok(improved)
+}
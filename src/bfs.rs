@@ -0,0 +1,100 @@
+// Used as queue by the search.
+use std::collections::VecDeque;
+
+// Used to keep track of the progression of the search.
+use crate::hash::HashMap;
+// The graph being explored, and the path found in it.
+use crate::{
+    dijkstra, dijkstra_backtrack, GraphLike, OverflowPolicy, PathError, PathResult, SearchStats,
+    ShortestPathResult, Weight,
+};
+
+/// Finds the path from `source` to `destination` with the fewest edges, with
+/// a breadth-first search.
+///
+/// The nodes are explored in the order they are reached, from a plain queue,
+/// which is much cheaper than the priority queue of [`dijkstra`]. The costs
+/// of the edges are ignored while searching, so the path is the shortest one
+/// only if all the edges cost the same, see [`shortest_path`]. Its cost is
+/// the sum of the costs of its edges all the same.
+///
+/// The returned path and errors are the same as with [`dijkstra`].
+pub fn bfs_shortest_path<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+) -> PathResult<G::Node, G::Weight> {
+    // We work with the copies of the nodes stored in the graph, so that all
+    // the nodes we handle share the lifetime of the graph.
+    let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+    let destination = graph
+        .get_node(destination)
+        .ok_or(PathError::UnknownDestination)?;
+    let policy = OverflowPolicy::default();
+
+    // The cost of the path to every node reached so far, which never changes
+    // once the node is reached.
+    let mut progression = HashMap::default();
+    // The node every node was reached from, used to backtrack the path.
+    let mut origin = HashMap::default();
+    let mut queue = VecDeque::new();
+    let mut stats = SearchStats {
+        pushes: 1,
+        ..SearchStats::default()
+    };
+    progression.insert(source, G::Weight::zero());
+    queue.push_back(source);
+
+    while let Some(node) = queue.pop_front() {
+        stats.settled += 1;
+        let cost = progression[node];
+
+        if node == destination {
+            // We backtrack to find the path used to reach the destination.
+            let path = dijkstra_backtrack(&origin, source, destination).ok_or(PathError::NoPath)?;
+            return Ok(ShortestPathResult {
+                nodes: path.into_iter().cloned().collect(),
+                cost,
+                settled: Some(stats.settled),
+                stats: Some(stats),
+            });
+        }
+
+        for (neighbor, edge_cost) in graph.neighbors(node) {
+            stats.relaxations += 1;
+            // The first path reaching a node has the fewest edges.
+            if progression.contains_key(neighbor) {
+                continue;
+            }
+            let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+            progression.insert(neighbor, new_cost);
+            origin.insert(neighbor, node);
+            queue.push_back(neighbor);
+            stats.pushes += 1;
+        }
+    }
+
+    Err(PathError::NoPath)
+}
+
+/// Finds the shortest path from `source` to `destination`, with a
+/// breadth-first search if all the edges of `graph` cost the same.
+///
+/// The path with the fewest edges is then the shortest one, so
+/// [`bfs_shortest_path`] finds it without the overhead of a priority queue.
+/// The search falls back to [`dijkstra`] otherwise. The graph is asked
+/// whether its edges all cost the same with [`GraphLike::uniform_cost`],
+/// which goes through all the edges by default.
+///
+/// The returned path and errors are the same as with [`dijkstra`].
+pub fn shortest_path<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+) -> PathResult<G::Node, G::Weight> {
+    // A negative cost would make the longer paths cheaper.
+    match graph.uniform_cost() {
+        Some(cost) if cost >= G::Weight::zero() => bfs_shortest_path(graph, source, destination),
+        _ => dijkstra(graph, source, destination),
+    }
+}
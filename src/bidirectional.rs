@@ -0,0 +1,303 @@
+// Used as priority queue by both searches.
+use std::collections::BinaryHeap;
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to keep track of the progression of both searches.
+use crate::hash::{HashMap, HashSet};
+// The graph being explored, and the costs of its edges.
+use crate::queue::HeapEntry;
+use crate::{
+    dijkstra_backtrack, GraphLike, OverflowPolicy, PathError, PathResult, SearchStats,
+    ShortestPathResult, Weight,
+};
+
+/// Finds the shortest path from `source` to `destination` with two searches,
+/// one going forward from the source and the other backward from the
+/// destination, which stop once they meet in the middle.
+///
+/// `backward` has to be the transpose of `forward`, i.e. the same graph with
+/// every edge flipped, as returned by
+/// [`Graph::reversed`](crate::Graph::reversed) for instance. An undirected
+/// graph is its own transpose, so it can be given twice. Both searches settle
+/// roughly the nodes within half the cost of the path, which is far fewer
+/// nodes than a single search on road-like graphs.
+///
+/// The returned path and errors are the same as with
+/// [`dijkstra`](crate::dijkstra), the settled nodes of both searches being
+/// counted.
+pub fn bidirectional_dijkstra<G: GraphLike>(
+    forward: &G,
+    backward: &G,
+    source: &G::Node,
+    destination: &G::Node,
+) -> PathResult<G::Node, G::Weight> {
+    let policy = OverflowPolicy::default();
+    let zero = |_: &G::Node| G::Weight::zero();
+
+    // Both queues give a lower bound of the cost of the paths which are not
+    // known yet. We stop once the best path is cheaper than their sum.
+    meet_in_the_middle(
+        forward,
+        backward,
+        source,
+        destination,
+        (zero, zero),
+        |forward_cost, backward_cost, best_cost| {
+            policy
+                .add(forward_cost, backward_cost)
+                .is_none_or(|bound| bound >= best_cost)
+        },
+    )
+}
+
+/// Finds the shortest path from `source` to `destination` with two A*
+/// searches, one going forward from the source and the other backward from
+/// the destination, which stop once they meet in the middle.
+///
+/// `backward` has to be the transpose of `forward`, just like with
+/// [`bidirectional_dijkstra`]. The forward search is guided by
+/// `to_destination`, which estimates the cost of the path from every node to
+/// the destination, and the backward search by `from_source`, which
+/// estimates the cost of the path from the source to every node. Both
+/// heuristics must be consistent, see [`astar`](crate::astar): a node is
+/// never settled twice by the same search. The search stops as soon as
+/// either of the queues only holds nodes whose estimate is not cheaper than
+/// the best path found, since the estimates are then lower bounds of the
+/// cost of the paths not found yet.
+///
+/// The returned path and errors are the same as with
+/// [`dijkstra`](crate::dijkstra), the settled nodes of both searches being
+/// counted.
+pub fn bidirectional_astar<G, H, K>(
+    forward: &G,
+    backward: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    to_destination: H,
+    from_source: K,
+) -> PathResult<G::Node, G::Weight>
+where
+    G: GraphLike,
+    H: FnMut(&G::Node) -> G::Weight,
+    K: FnMut(&G::Node) -> G::Weight,
+{
+    meet_in_the_middle(
+        forward,
+        backward,
+        source,
+        destination,
+        (to_destination, from_source),
+        |forward_estimate, backward_estimate, best_cost| {
+            forward_estimate >= best_cost || backward_estimate >= best_cost
+        },
+    )
+}
+
+// Runs both searches, each one being guided by its heuristic, until done
+// returns true for the lowest estimates of both queues and the cost of the
+// best path found so far.
+fn meet_in_the_middle<G, H, K, D>(
+    forward: &G,
+    backward: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    (mut to_destination, mut from_source): (H, K),
+    done: D,
+) -> PathResult<G::Node, G::Weight>
+where
+    G: GraphLike,
+    H: FnMut(&G::Node) -> G::Weight,
+    K: FnMut(&G::Node) -> G::Weight,
+    D: Fn(G::Weight, G::Weight, G::Weight) -> bool,
+{
+    // We work with the copies of the nodes stored in the graphs, so that all
+    // the nodes we handle share the lifetime of the graphs.
+    let source = forward.get_node(source).ok_or(PathError::UnknownSource)?;
+    let destination = backward
+        .get_node(destination)
+        .ok_or(PathError::UnknownDestination)?;
+    let policy = OverflowPolicy::default();
+
+    let mut forward_search = Side::new(source);
+    let mut backward_search = Side::new(destination);
+    // The cost of the best path found so far, along with the node where both
+    // searches met on this path.
+    let mut best = None;
+
+    while let (Some(forward_estimate), Some(backward_estimate)) =
+        (forward_search.lowest(), backward_search.lowest())
+    {
+        if let Some((best_cost, _)) = best {
+            if done(forward_estimate, backward_estimate, best_cost) {
+                break;
+            }
+        }
+
+        // We extend the search with the fewest nodes in its queue, which
+        // keeps the work balanced between both searches.
+        if forward_search.queue.len() <= backward_search.queue.len() {
+            forward_search.step(
+                forward,
+                &backward_search,
+                policy,
+                &mut to_destination,
+                &mut best,
+            )?;
+        } else {
+            backward_search.step(
+                backward,
+                &forward_search,
+                policy,
+                &mut from_source,
+                &mut best,
+            )?;
+        }
+    }
+
+    let (cost, middle) = best.ok_or(PathError::NoPath)?;
+
+    // The path is made of the path from the source to the middle, followed by
+    // the path from the middle to the destination found by the backward
+    // search, which has to be reversed.
+    let mut path =
+        dijkstra_backtrack(&forward_search.origin, source, middle).ok_or(PathError::NoPath)?;
+    let second_half = dijkstra_backtrack(&backward_search.origin, destination, middle)
+        .ok_or(PathError::NoPath)?;
+    path.extend(second_half.into_iter().rev().skip(1));
+
+    Ok(ShortestPathResult {
+        nodes: path.into_iter().cloned().collect(),
+        cost,
+        settled: Some(forward_search.visited.len() + backward_search.visited.len()),
+        stats: Some(forward_search.stats + backward_search.stats),
+    })
+}
+
+// One of the two searches of a bidirectional query.
+struct Side<'a, N, W> {
+    // The best known cost of every node reached so far.
+    progression: HashMap<&'a N, W>,
+    // The node every node was reached from, used to backtrack the path.
+    origin: HashMap<&'a N, &'a N>,
+    // The nodes already settled.
+    visited: HashSet<&'a N>,
+    // The nodes reached so far along with their estimate, i.e. the cost of
+    // their path plus the estimate of the heuristic, the cheapest one first.
+    queue: BinaryHeap<HeapEntry<'a, N, W>>,
+    // What the search did so far.
+    stats: SearchStats,
+}
+
+impl<'a, N: Eq + Hash, W: Weight> Side<'a, N, W> {
+    // Creates a search starting from the given node.
+    fn new(start: &'a N) -> Self {
+        let mut side = Side {
+            progression: HashMap::default(),
+            origin: HashMap::default(),
+            visited: HashSet::default(),
+            queue: BinaryHeap::new(),
+            stats: SearchStats {
+                pushes: 1,
+                ..SearchStats::default()
+            },
+        };
+        side.progression.insert(start, W::zero());
+        side.queue.push(HeapEntry {
+            cost: W::zero(),
+            node: start,
+        });
+        side
+    }
+
+    // The lowest estimate in the queue, or None if the search is over.
+    fn lowest(&self) -> Option<W> {
+        self.queue.peek().map(|entry| entry.cost)
+    }
+
+    // Settles the next node of the search, guided by the heuristic, and
+    // updates the best path with the paths going through the nodes reached by
+    // the other search.
+    fn step<G, H>(
+        &mut self,
+        graph: &'a G,
+        other: &Side<'a, N, W>,
+        policy: OverflowPolicy,
+        heuristic: &mut H,
+        best: &mut Option<(W, &'a N)>,
+    ) -> Result<(), PathError>
+    where
+        G: GraphLike<Node = N, Weight = W>,
+        H: FnMut(&N) -> W,
+    {
+        let node = match self.queue.pop() {
+            Some(entry) => entry.node,
+            None => return Ok(()),
+        };
+        // The outdated entries are skipped.
+        if !self.visited.insert(node) {
+            return Ok(());
+        }
+        // The queue holds the estimates, the cost of the node being its
+        // progression.
+        let cost = self.progression[node];
+        self.stats.settled += 1;
+
+        // We keep track of the paths going through the nodes known by both
+        // searches.
+        other.meet(node, cost, policy, best)?;
+
+        // The paths through a node settled by the other search are already
+        // known, so there is no point in going further.
+        if other.visited.contains(node) {
+            return Ok(());
+        }
+
+        for (neighbor, edge_cost) in graph.neighbors(node) {
+            self.stats.relaxations += 1;
+            let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+            if self
+                .progression
+                .get(neighbor)
+                .is_some_and(|old| *old <= new_cost)
+            {
+                continue;
+            }
+            self.progression.insert(neighbor, new_cost);
+            self.origin.insert(neighbor, node);
+            other.meet(neighbor, new_cost, policy, best)?;
+
+            // The estimate only orders the nodes, so it simply saturates. The
+            // nodes whose estimate is not cheaper than the best path cannot
+            // lead to a better one.
+            let estimate = new_cost.saturating_add(heuristic(neighbor));
+            if best.is_some_and(|(best_cost, _)| estimate >= best_cost) {
+                continue;
+            }
+            self.queue.push(HeapEntry {
+                cost: estimate,
+                node: neighbor,
+            });
+            self.stats.pushes += 1;
+        }
+        Ok(())
+    }
+
+    // Updates the best path with the path through `node`, reached for `cost`
+    // by the other search, if this search reached it as well.
+    fn meet(
+        &self,
+        node: &'a N,
+        cost: W,
+        policy: OverflowPolicy,
+        best: &mut Option<(W, &'a N)>,
+    ) -> Result<(), PathError> {
+        if let Some(&own_cost) = self.progression.get(node) {
+            let total = policy.add(cost, own_cost).ok_or(PathError::Overflow)?;
+            if best.is_none_or(|(best_cost, _)| total < best_cost) {
+                *best = Some((total, node));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+// Used to color the nodes layer after layer, and to find the layers of the
+// matchings.
+use std::collections::VecDeque;
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to find the index of the nodes, and to return their colors.
+use crate::hash::HashMap;
+// The graph being split.
+use crate::GraphLike;
+
+/// Colors the nodes of `graph` with two colors so that every edge joins two
+/// nodes of different colors, if possible.
+///
+/// A graph which can be colored so is bipartite: its nodes split into two
+/// sides, with every edge going from one side to the other, such as the
+/// workers and the tasks they can take on. The direction of the edges is
+/// ignored. The nodes are colored layer after layer from the first node of
+/// every component, in the order of [`GraphLike::nodes`], which gets
+/// `false`, in O(V + E) time.
+///
+/// Returns the color of every node, or `None` if the graph has a cycle with
+/// an odd number of edges, which cannot be colored, a self-loop included.
+pub fn is_bipartite<G: GraphLike>(graph: &G) -> Option<HashMap<G::Node, bool>> {
+    let sides = Sides::new(graph)?;
+    Some(
+        sides
+            .nodes
+            .iter()
+            .zip(&sides.colors)
+            .map(|(&node, &color)| (node.clone(), color))
+            .collect(),
+    )
+}
+
+/// Finds a maximum matching of the bipartite graph `graph`, with the
+/// Hopcroft-Karp algorithm.
+///
+/// A matching is a set of edges without any node in common, such as the
+/// assignment of tasks to workers which can take them on, each worker taking
+/// a single task. The sides of the graph are the colors found by
+/// [`is_bipartite`], and the direction of the edges is ignored. The matching
+/// grows along many shortest augmenting paths at once, alternating between
+/// the edges out of the matching and the edges in it, which takes
+/// O(E √V) time.
+///
+/// Returns the matched pairs, the node colored `false` coming first, in no
+/// particular order, or `None` if the graph is not bipartite.
+pub fn maximum_matching<G: GraphLike>(graph: &G) -> Option<Vec<(G::Node, G::Node)>> {
+    let sides = Sides::new(graph)?;
+    let count = sides.nodes.len();
+
+    // The node every node is matched with, if any.
+    let mut mates: Vec<Option<usize>> = vec![None; count];
+    let left: Vec<usize> = (0..count).filter(|&node| !sides.colors[node]).collect();
+    loop {
+        // We number the layers of the alternating paths from the unmatched
+        // nodes on the left side, which are only found on the left side.
+        let mut layers = vec![usize::MAX; count];
+        let mut queue: VecDeque<usize> = left
+            .iter()
+            .copied()
+            .filter(|&node| mates[node].is_none())
+            .collect();
+        for &node in &queue {
+            layers[node] = 0;
+        }
+        let mut found = false;
+        while let Some(node) = queue.pop_front() {
+            for &neighbor in &sides.adjacency[node] {
+                match mates[neighbor] {
+                    None => found = true,
+                    Some(mate) if layers[mate] == usize::MAX => {
+                        layers[mate] = layers[node] + 1;
+                        queue.push_back(mate);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        // The matching is maximum once there is no augmenting path left.
+        if !found {
+            break;
+        }
+
+        // We follow the augmenting paths along the layers from every
+        // unmatched node, the nodes leading nowhere being dropped.
+        let mut next = vec![0; count];
+        for &root in &left {
+            if mates[root].is_some() {
+                continue;
+            }
+            // The nodes of the path on the left side, and the nodes on the
+            // right side they lead to.
+            let mut path = vec![root];
+            let mut through: Vec<usize> = Vec::new();
+            while let Some(&node) = path.last() {
+                let neighbor = match sides.adjacency[node].get(next[node]) {
+                    Some(&neighbor) => neighbor,
+                    None => {
+                        layers[node] = usize::MAX;
+                        path.pop();
+                        through.pop();
+                        continue;
+                    }
+                };
+                next[node] += 1;
+                match mates[neighbor] {
+                    // The path is augmenting, so every node of the left side
+                    // is matched with the next node of the right side.
+                    None => {
+                        through.push(neighbor);
+                        for (&left, &right) in path.iter().zip(&through) {
+                            mates[left] = Some(right);
+                            mates[right] = Some(left);
+                        }
+                        break;
+                    }
+                    Some(mate) if layers[mate] == layers[node] + 1 => {
+                        through.push(neighbor);
+                        path.push(mate);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    Some(
+        left.into_iter()
+            .filter_map(|node| {
+                let mate = mates[node]?;
+                Some((sides.nodes[node].clone(), sides.nodes[mate].clone()))
+            })
+            .collect(),
+    )
+}
+
+// The nodes of a bipartite graph, numbered, along with their colors.
+struct Sides<'g, N> {
+    // The node associated with each index, as stored in the graph.
+    nodes: Vec<&'g N>,
+    // The color of every node.
+    colors: Vec<bool>,
+    // The nodes joined to every node by an edge, whatever its direction.
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl<'g, N: Eq + Hash> Sides<'g, N> {
+    // Colors the nodes of the graph, or returns None if it is not bipartite.
+    fn new<G: GraphLike<Node = N>>(graph: &'g G) -> Option<Self> {
+        let nodes: Vec<&'g N> = graph.nodes().collect();
+        let index: HashMap<&'g N, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(position, &node)| (node, position))
+            .collect();
+        // The edges leading out of the graph don't join anything.
+        let mut adjacency = vec![Vec::new(); nodes.len()];
+        for (from, to, _) in graph.edges() {
+            if let Some(&to) = index.get(to) {
+                let from = index[from];
+                adjacency[from].push(to);
+                adjacency[to].push(from);
+            }
+        }
+
+        // Every node gets the other color than the node it was reached from.
+        let mut colors: Vec<Option<bool>> = vec![None; nodes.len()];
+        let mut queue = VecDeque::new();
+        for root in 0..nodes.len() {
+            if colors[root].is_some() {
+                continue;
+            }
+            colors[root] = Some(false);
+            queue.push_back(root);
+            while let Some(node) = queue.pop_front() {
+                let color = colors[node];
+                for &neighbor in &adjacency[node] {
+                    match colors[neighbor] {
+                        None => {
+                            colors[neighbor] = color.map(|color| !color);
+                            queue.push_back(neighbor);
+                        }
+                        Some(other) if Some(other) == color => return None,
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+
+        Some(Sides {
+            nodes,
+            colors: colors.into_iter().flatten().collect(),
+            adjacency,
+        })
+    }
+}
@@ -0,0 +1,143 @@
+// Used to settle the cheapest nodes first.
+use std::cmp::Reverse;
+// Used as priority queue by the search.
+use std::collections::BinaryHeap;
+
+// Used to keep track of the progression of the search.
+use crate::hash::{HashMap, HashSet};
+// The graph being explored, and the path found in it.
+use crate::{
+    dijkstra_backtrack, GraphLike, PathError, PathResult, SearchStats, ShortestPathResult, Weight,
+};
+
+/// Finds the path from `source` to `destination` in `graph` whose cheapest
+/// edge costs as much as possible, i.e. the widest path.
+///
+/// The costs are read as capacities here: a path can carry as much as its
+/// narrowest edge, so the path with the largest capacity is the one whose
+/// narrowest edge is the widest. The search is the one of Dijkstra's
+/// algorithm, the paths being extended by taking the minimum of their width
+/// and of the edge rather than the sum, and the widest node being settled
+/// first. It takes O((V + E) log V) time, and works with negative costs as
+/// well since a path never gets wider as it grows.
+///
+/// The cost of the returned path is its width, the cost of its narrowest
+/// edge, and a path from a node to itself, which has no edge, has a width of
+/// zero. The errors are the same as with [`dijkstra`](crate::dijkstra).
+pub fn widest_path<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+) -> PathResult<G::Node, G::Weight> {
+    bottleneck_path(graph, source, destination, Ord::min, |width| width)
+}
+
+/// Finds the path from `source` to `destination` in `graph` whose most
+/// expensive edge costs as little as possible, i.e. the minimax path.
+///
+/// This is the counterpart of [`widest_path`]: rather than the total cost,
+/// the path minimizes its worst segment, which matters when a single bad
+/// edge cannot be made up for by cheap ones, such as the steepest climb of a
+/// route or the highest link latency a real-time stream can bear. The
+/// search is the same, the paths being extended by taking the maximum of
+/// their cost and of the edge, and the cheapest node being settled first.
+///
+/// The cost of the returned path is the cost of its most expensive edge, and
+/// a path from a node to itself, which has no edge, costs zero. The errors
+/// are the same as with [`dijkstra`](crate::dijkstra).
+pub fn minimax_path<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+) -> PathResult<G::Node, G::Weight> {
+    bottleneck_path(graph, source, destination, Ord::max, Reverse)
+}
+
+// Finds the best path from source to destination, where extending a path
+// with an edge combines their costs into the cost of the new path, and where
+// the path whose cost has the largest order is the best. The cost of a path
+// only ever gets worse as it grows, so that the best node can be settled
+// first just like with Dijkstra's algorithm.
+fn bottleneck_path<G, C, O, K>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    combine: C,
+    order: O,
+) -> PathResult<G::Node, G::Weight>
+where
+    G: GraphLike,
+    C: Fn(G::Weight, G::Weight) -> G::Weight,
+    O: Fn(G::Weight) -> K,
+    K: Ord,
+{
+    // We work with the copies of the nodes stored in the graph, so that all
+    // the nodes we handle share the lifetime of the graph.
+    let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+    let destination = graph
+        .get_node(destination)
+        .ok_or(PathError::UnknownDestination)?;
+
+    // The cost of the best path to every node reached so far, and the node it
+    // was reached from on this path.
+    let mut progression: HashMap<&G::Node, G::Weight> = HashMap::default();
+    let mut origin = HashMap::default();
+    let mut visited = HashSet::default();
+    // The nodes are not ordered, so the queue refers to them by their
+    // position in the list of the nodes reached.
+    let mut reached = Vec::new();
+    let mut queue = BinaryHeap::new();
+    let mut stats = SearchStats::default();
+
+    // The source is settled first, with the empty path which has no cost:
+    // the paths leaving it are made of their first edge.
+    let mut next = Some((source, None));
+    while let Some((node, cost)) = next {
+        visited.insert(node);
+        stats.settled += 1;
+
+        if node == destination {
+            let path = dijkstra_backtrack(&origin, source, destination).ok_or(PathError::NoPath)?;
+            return Ok(ShortestPathResult {
+                nodes: path.into_iter().cloned().collect(),
+                cost: cost.unwrap_or_else(G::Weight::zero),
+                settled: Some(stats.settled),
+                stats: Some(stats),
+            });
+        }
+
+        for (neighbor, edge_cost) in graph.neighbors(node) {
+            stats.relaxations += 1;
+            if visited.contains(neighbor) {
+                continue;
+            }
+            let new_cost = match cost {
+                Some(cost) => combine(cost, edge_cost),
+                None => edge_cost,
+            };
+            if progression
+                .get(neighbor)
+                .is_none_or(|&old_cost| order(new_cost) > order(old_cost))
+            {
+                progression.insert(neighbor, new_cost);
+                origin.insert(neighbor, node);
+                // The old entry of the node is skipped once it is popped.
+                reached.push(neighbor);
+                queue.push((order(new_cost), reached.len() - 1));
+                stats.pushes += 1;
+            }
+        }
+
+        // We settle the best node which was not settled yet, if any.
+        next = None;
+        while let Some((_, position)) = queue.pop() {
+            let node = reached[position];
+            if !visited.contains(node) {
+                next = Some((node, Some(progression[node])));
+                break;
+            }
+        }
+    }
+
+    Err(PathError::NoPath)
+}
@@ -0,0 +1,52 @@
+// Required by the nodes of the graph.
+use std::hash::Hash;
+
+// The graph being explored, and the search itself.
+use crate::{GraphLike, PathError, SearchWorkspace, ShortestPathResult, Weight};
+
+/// Finds all the nodes which can be reached from `source` in `graph` for at
+/// most `max_cost`, along with the cost of the shortest path leading to them.
+///
+/// The nodes are returned by increasing cost, starting with the source itself.
+/// Only the part of the graph within the budget is explored, so this is cheap
+/// on huge graphs as long as the budget is small.
+pub fn dijkstra_within<N, W, G>(
+    graph: &G,
+    source: &N,
+    max_cost: W,
+) -> Result<Vec<(N, W)>, PathError>
+where
+    N: Eq + Hash + Clone,
+    W: Weight,
+    G: GraphLike<Node = N, Weight = W>,
+{
+    let mut workspace = SearchWorkspace::new();
+    workspace.start(graph, source)?;
+
+    // We settle every node within the budget, the others never being queued.
+    workspace.progress(graph, Default::default(), Some(max_cost), |_| false)?;
+
+    let mut reached: Vec<(N, W)> = workspace
+        .progression()
+        .iter()
+        .map(|(node, cost)| ((*node).clone(), *cost))
+        .collect();
+    reached.sort_by_key(|(_, cost)| *cost);
+    Ok(reached)
+}
+
+/// Finds the shortest path from `source` to `destination` in `graph`, provided
+/// that it costs at most `max_cost`.
+///
+/// The nodes further than `max_cost` from the source are abandoned, so a short
+/// route is found without exploring the whole graph. If the destination is
+/// too far away, the query fails with [`PathError::NoPath`]. See
+/// [`dijkstra`](crate::dijkstra) for the details of the query.
+pub fn dijkstra_bounded<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    max_cost: G::Weight,
+) -> Result<ShortestPathResult<G::Node, G::Weight>, PathError> {
+    SearchWorkspace::new().dijkstra_bounded(graph, source, destination, max_cost)
+}
@@ -0,0 +1,38 @@
+// The graph being explored, and the costs of its edges.
+use crate::{dijkstra, dijkstra_with_frontier, BucketFrontier, GraphLike, PathResult, Weight};
+
+/// The largest edge cost for which [`dijkstra_bucket`] uses a bucket queue.
+pub const BUCKET_THRESHOLD: usize = 256;
+
+/// Finds the shortest path from `source` to `destination` in `graph`, using
+/// Dial's bucket queue when the costs of the edges are small integers.
+///
+/// The nodes are kept in one bucket per distance instead of a binary heap (see
+/// [`BucketFrontier`]), which settles them in O(E + C·V) where C is the
+/// largest edge cost. This beats [`dijkstra`] on graphs whose costs are
+/// hop-like, i.e. bounded by a small constant. The bucket queue is only used
+/// if all the costs can be converted with [`Weight::to_usize`] and the largest
+/// one is at most [`BUCKET_THRESHOLD`], the query being handed over to
+/// [`dijkstra`] otherwise. Checking the costs takes a scan of all the edges.
+///
+/// The returned path and errors are the same as with [`dijkstra`].
+pub fn dijkstra_bucket<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+) -> PathResult<G::Node, G::Weight> {
+    // Every cost has to be converted, not only the largest one, since the
+    // negative costs cannot be. A graph without edges has a single useful
+    // bucket.
+    let largest = graph.edges().try_fold(0, |largest, (_, _, cost)| {
+        Some(largest.max(cost.to_usize()?))
+    });
+
+    match largest {
+        Some(largest) if largest <= BUCKET_THRESHOLD => {
+            dijkstra_with_frontier(graph, source, destination, BucketFrontier::new(largest))
+        }
+        // The buckets would not pay off, or cannot be indexed by the costs.
+        _ => dijkstra(graph, source, destination),
+    }
+}
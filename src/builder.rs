@@ -0,0 +1,98 @@
+// Required by the keys of the graph.
+use std::hash::Hash;
+
+// The graph being built.
+use crate::{DuplicateEdgePolicy, Graph, GraphKind, Weight};
+
+/// A fluent interface to build a [`Graph`].
+///
+/// The edges are added one by one or in bulk, and the graph is returned by
+/// [`GraphBuilder::build`] once it is complete. When the same edge is added
+/// several times, the builder follows its [`DuplicateEdgePolicy`], which
+/// overwrites the old cost by default.
+#[derive(Debug, Clone)]
+pub struct GraphBuilder<N, W = u32, D = (), E = ()> {
+    // The graph built so far.
+    graph: Graph<N, W, D, E>,
+    // What to do with duplicate edges.
+    policy: DuplicateEdgePolicy,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight, D, E> GraphBuilder<N, W, D, E> {
+    /// Creates a builder for an empty directed graph.
+    pub fn new() -> Self {
+        GraphBuilder::with_kind(GraphKind::Directed)
+    }
+
+    /// Creates a builder for an empty undirected graph.
+    pub fn new_undirected() -> Self {
+        GraphBuilder::with_kind(GraphKind::Undirected)
+    }
+
+    /// Creates a builder for an empty graph of the given kind.
+    pub fn with_kind(kind: GraphKind) -> Self {
+        GraphBuilder {
+            graph: Graph::with_kind(kind),
+            policy: DuplicateEdgePolicy::default(),
+        }
+    }
+
+    /// Selects what to do with the edges which are added several times.
+    ///
+    /// The policy only applies to the edges added after it was selected.
+    pub fn duplicates(mut self, policy: DuplicateEdgePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Adds a node without any edge to the graph.
+    pub fn node(mut self, node: N) -> Self {
+        self.graph.add_node(node);
+        self
+    }
+
+    /// Attaches `data` to `node`, adding the node to the graph if needed.
+    pub fn node_data(mut self, node: N, data: D) -> Self {
+        self.graph.set_node_data(node, data);
+        self
+    }
+
+    /// Adds an edge going from `source` to `destination`, in both directions
+    /// if the graph is undirected.
+    pub fn edge(mut self, source: N, destination: N, cost: W) -> Self {
+        self.graph
+            .add_edge_with_policy(source, destination, cost, self.policy);
+        self
+    }
+
+    /// Adds an edge going from `source` to `destination` with some data
+    /// attached to it, in both directions if the graph is undirected.
+    ///
+    /// The data always replaces the data previously attached to the edge,
+    /// while the cost follows the duplicate edge policy.
+    pub fn edge_with_data(mut self, source: N, destination: N, cost: W, data: E) -> Self {
+        self.graph
+            .attach_edge_data(source.clone(), destination.clone(), data);
+        self.edge(source, destination, cost)
+    }
+
+    /// Adds all the `(source, destination, cost)` edges of `edges`.
+    pub fn edges<I: IntoIterator<Item = (N, N, W)>>(self, edges: I) -> Self {
+        edges
+            .into_iter()
+            .fold(self, |builder, (source, destination, cost)| {
+                builder.edge(source, destination, cost)
+            })
+    }
+
+    /// Returns the graph which was built.
+    pub fn build(self) -> Graph<N, W, D, E> {
+        self.graph
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Weight, D, E> Default for GraphBuilder<N, W, D, E> {
+    fn default() -> Self {
+        GraphBuilder::new()
+    }
+}
@@ -0,0 +1,168 @@
+// Used to accumulate the distances, and to tell the sources apart.
+use crate::hash::{HashMap, HashSet};
+// Used to pick the sources of the sampled measures.
+use crate::random::Random;
+// The graph being measured, and the searches measuring it.
+use crate::{GraphLike, OverflowPolicy, PathError, SearchWorkspace, Weight};
+
+/// Computes the closeness centrality of every node of `graph`.
+///
+/// A node is central if the other nodes can reach it cheaply, so the
+/// closeness of a node is the inverse of the average cost of the shortest
+/// paths leading to it. Since a node may not be reached from every other
+/// node, the average only counts the nodes which reach it, and is then
+/// scaled by the share of the other nodes which do, following Wasserman and
+/// Faust. A node which no other node reaches has a closeness of zero.
+///
+/// A search is run from every node, so this takes O(V (V + E) log V) time;
+/// [`closeness_centrality_sampled`] estimates the closeness of the large
+/// graphs instead. The costs are averaged as `f64`, see [`Weight::to_f64`],
+/// and the query fails with [`PathError::Overflow`] if the cost of a path
+/// overflows or cannot be converted.
+pub fn closeness_centrality<G: GraphLike>(graph: &G) -> Result<HashMap<G::Node, f64>, PathError> {
+    let sources = graph.nodes().collect();
+    closeness(graph, sources)
+}
+
+/// Estimates the closeness centrality of every node of `graph` from
+/// `samples` searches, starting from nodes picked at random.
+///
+/// This is the closeness computed by [`closeness_centrality`], except that
+/// the average cost of the paths leading to a node and the share of the
+/// nodes reaching it are only measured from the sampled sources, following
+/// Eppstein and Wang. This takes O(samples (V + E) log V) time, and a few
+/// hundred samples are usually enough for a good estimate on a large graph.
+/// The same `seed` always picks the same sources, and every node is used if
+/// there are fewer than `samples`.
+///
+/// The errors are the same as with [`closeness_centrality`].
+pub fn closeness_centrality_sampled<G: GraphLike>(
+    graph: &G,
+    samples: usize,
+    seed: u64,
+) -> Result<HashMap<G::Node, f64>, PathError> {
+    let mut sources = graph.nodes().collect();
+    Random::new(seed).sample(&mut sources, samples);
+    closeness(graph, sources)
+}
+
+/// Ranks the nodes of `graph` with PageRank, the most important node first.
+///
+/// The rank of a node is the probability that a surfer who follows the edges
+/// at random is on it: at every step, the surfer follows one of the edges
+/// leaving its node with probability `damping`, and jumps to any node
+/// otherwise. The surfer always jumps from the nodes without any edge. The
+/// costs of the edges are ignored, every edge leaving a node being followed
+/// with the same probability. The ranks sum to one.
+///
+/// The ranks are computed by power iteration, starting from the same rank for
+/// every node, until the sum of the changes of the ranks over an iteration
+/// drops below `tolerance`. Every iteration takes O(V + E) time, and their
+/// number grows as `damping` gets closer to one, 0.85 being the usual value.
+///
+/// # Panics
+///
+/// Panics if `damping` is not between zero included and one excluded, or if
+/// `tolerance` is not positive.
+pub fn pagerank<G: GraphLike>(graph: &G, damping: f64, tolerance: f64) -> Vec<(G::Node, f64)> {
+    assert!(
+        (0.0..1.0).contains(&damping),
+        "damping must be between zero and one"
+    );
+    assert!(tolerance > 0.0, "tolerance must be positive");
+
+    // We number the nodes, and list the edges leaving every one of them.
+    // The edges leading out of the graph are ignored.
+    let nodes: Vec<&G::Node> = graph.nodes().collect();
+    let index: HashMap<&G::Node, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(position, &node)| (node, position))
+        .collect();
+    let successors: Vec<Vec<usize>> = nodes
+        .iter()
+        .map(|&node| {
+            graph
+                .neighbors(node)
+                .filter_map(|(neighbor, _)| index.get(neighbor).copied())
+                .collect()
+        })
+        .collect();
+
+    let count = nodes.len() as f64;
+    let mut ranks = vec![1.0 / count; nodes.len()];
+    let mut next = vec![0.0; nodes.len()];
+    loop {
+        // The rank of the nodes without edges is spread over every node,
+        // just like the jumps.
+        let stranded: f64 = successors
+            .iter()
+            .zip(&ranks)
+            .filter(|(successors, _)| successors.is_empty())
+            .map(|(_, rank)| rank)
+            .sum();
+        let base = (1.0 - damping) / count + damping * stranded / count;
+        next.iter_mut().for_each(|rank| *rank = base);
+        for (successors, rank) in successors.iter().zip(&ranks) {
+            let share = damping * rank / successors.len() as f64;
+            for &successor in successors {
+                next[successor] += share;
+            }
+        }
+
+        let change: f64 = ranks
+            .iter()
+            .zip(&next)
+            .map(|(old, new)| (old - new).abs())
+            .sum();
+        std::mem::swap(&mut ranks, &mut next);
+        if change < tolerance {
+            break;
+        }
+    }
+
+    let mut ranked: Vec<(G::Node, f64)> = nodes.into_iter().cloned().zip(ranks).collect();
+    ranked.sort_by(|(_, first), (_, second)| second.total_cmp(first));
+    ranked
+}
+
+// Computes the closeness of every node from the shortest paths leading to it
+// from the given sources, which are all different.
+fn closeness<'g, G: GraphLike>(
+    graph: &'g G,
+    sources: Vec<&'g G::Node>,
+) -> Result<HashMap<G::Node, f64>, PathError> {
+    // The number of sources reaching every node, and the sum of the costs of
+    // their shortest paths to it. The nodes don't reach themselves.
+    let mut reached: HashMap<&G::Node, (usize, f64)> = HashMap::default();
+    let mut workspace = SearchWorkspace::new();
+    for &source in &sources {
+        workspace.start(graph, source)?;
+        workspace.progress(graph, OverflowPolicy::default(), None, |_| false)?;
+        for (&node, &cost) in workspace.progression() {
+            if node != source {
+                let cost = cost.to_f64().ok_or(PathError::Overflow)?;
+                let (count, total) = reached.entry(node).or_insert((0, 0.0));
+                *count += 1;
+                *total += cost;
+            }
+        }
+    }
+
+    let is_source: HashSet<&G::Node> = sources.iter().copied().collect();
+    Ok(graph
+        .nodes()
+        .map(|node| {
+            // A node which is a source cannot reach itself.
+            let others = sources.len() - usize::from(is_source.contains(node));
+            let closeness = match reached.get(node) {
+                Some(&(count, total)) if total > 0.0 => {
+                    let count = count as f64;
+                    (count / total) * (count / others as f64)
+                }
+                _ => 0.0,
+            };
+            (node.clone(), closeness)
+        })
+        .collect())
+}
@@ -0,0 +1,460 @@
+// Used to order the nodes and the states by cost.
+use std::cmp::Reverse;
+// Used as priority queue by the contraction and by the searches.
+use std::collections::BinaryHeap;
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to keep track of the nodes, the edges and the searches.
+use crate::hash::HashMap;
+// The graph being preprocessed, and the paths found in it.
+use crate::{
+    GraphLike, OverflowPolicy, PathError, PathResult, SearchStats, ShortestPathResult, Weight,
+};
+
+// The largest number of nodes settled by a witness search, i.e. a search for
+// a path making a shortcut useless. Stopping earlier only adds shortcuts
+// which are not needed, so the limit trades the time of the preprocessing
+// for the size of the hierarchy.
+const WITNESS_SETTLED_LIMIT: usize = 500;
+
+/// A graph prepared for fast shortest path queries with a contraction
+/// hierarchy, following Geisberger et al.
+///
+/// The nodes are contracted one by one, from the least important to the
+/// most important: contracting a node removes it from the graph, and adds a
+/// shortcut between two of its neighbors whenever the path through the node
+/// was the only shortest path between them. The next node contracted is the
+/// one adding the fewest shortcuts compared to the edges it removes, among
+/// the nodes whose neighbors were contracted the least. On road networks the
+/// shortcuts then make up about as many edges as the graph itself, and every
+/// shortest path becomes a path going up the hierarchy of the nodes followed
+/// by a path going down it.
+///
+/// The hierarchy is built once with [`ContractionHierarchy::new`], after
+/// which [`ContractionHierarchy::query`] finds any path by only following
+/// the edges going up the hierarchy, from both ends, which settles a few
+/// hundred nodes even on country-scale road networks. The hierarchy clones
+/// the nodes, so it doesn't borrow the graph, and it has to be built again
+/// if the graph changes.
+#[derive(Debug, Clone)]
+pub struct ContractionHierarchy<N, W> {
+    // The nodes of the graph, by position.
+    pub(crate) nodes: Vec<N>,
+    // The position of every node.
+    pub(crate) index: HashMap<N, usize>,
+    // The positions of the nodes, in the order they were contracted.
+    pub(crate) order: Vec<usize>,
+    // The edges and shortcuts leaving every node towards the nodes contracted
+    // after it, along with their cost.
+    pub(crate) upward: Vec<Vec<(usize, W)>>,
+    // The edges and shortcuts entering every node from the nodes contracted
+    // after it, along with their cost.
+    pub(crate) downward: Vec<Vec<(usize, W)>>,
+    // The node bypassed by the shortcut between every pair of nodes joined by
+    // one.
+    middles: HashMap<(usize, usize), usize>,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> ContractionHierarchy<N, W> {
+    /// Builds the contraction hierarchy of `graph`.
+    ///
+    /// The nodes are ordered by the edge difference, i.e. the number of
+    /// shortcuts their contraction adds minus the number of edges it
+    /// removes, plus the number of their neighbors already contracted,
+    /// which spreads the contraction evenly over the graph. The priorities
+    /// are updated lazily, a node being contracted only if its priority is
+    /// still the lowest one once computed again. The searches proving that a
+    /// shortcut is not needed are limited to a few hundred nodes, so that
+    /// a few more shortcuts than needed may be added. The costs of the edges
+    /// leading out of the graph are ignored, just like the edges from a node
+    /// to itself.
+    ///
+    /// Fails with [`PathError::Overflow`] if the cost of a shortcut
+    /// overflows.
+    pub fn new<G>(graph: &G) -> Result<Self, PathError>
+    where
+        G: GraphLike<Node = N, Weight = W>,
+    {
+        let policy = OverflowPolicy::default();
+        let nodes: Vec<N> = graph.nodes().cloned().collect();
+        let index: HashMap<N, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(position, node)| (node.clone(), position))
+            .collect();
+
+        // The edges between the nodes not contracted yet, both ways, the
+        // cheapest one of the parallel edges being kept.
+        let mut outgoing: Vec<HashMap<usize, W>> = vec![HashMap::default(); nodes.len()];
+        let mut incoming: Vec<HashMap<usize, W>> = vec![HashMap::default(); nodes.len()];
+        for (from, to, cost) in graph.edges() {
+            if let (Some(&from), Some(&to)) = (index.get(from), index.get(to)) {
+                if from != to && outgoing[from].get(&to).is_none_or(|&old| cost < old) {
+                    outgoing[from].insert(to, cost);
+                    incoming[to].insert(from, cost);
+                }
+            }
+        }
+
+        let mut witnesses = Witnesses::new(nodes.len());
+        let mut contracted_neighbors = vec![0; nodes.len()];
+        let mut queue = BinaryHeap::with_capacity(nodes.len());
+        for node in 0..nodes.len() {
+            let shortcuts = shortcuts(&outgoing, &incoming, &mut witnesses, node, policy)?;
+            let priority = priority(
+                &outgoing,
+                &incoming,
+                &contracted_neighbors,
+                node,
+                &shortcuts,
+            );
+            queue.push(Reverse((priority, node)));
+        }
+
+        let mut order = Vec::with_capacity(nodes.len());
+        let mut upward = vec![Vec::new(); nodes.len()];
+        let mut downward = vec![Vec::new(); nodes.len()];
+        let mut middles = HashMap::default();
+        while let Some(Reverse((_, node))) = queue.pop() {
+            // The priority of the node may have grown since it was pushed, in
+            // which case another node may now come first.
+            let shortcuts = shortcuts(&outgoing, &incoming, &mut witnesses, node, policy)?;
+            let priority = priority(
+                &outgoing,
+                &incoming,
+                &contracted_neighbors,
+                node,
+                &shortcuts,
+            );
+            if queue
+                .peek()
+                .is_some_and(|&Reverse((lowest, _))| priority > lowest)
+            {
+                queue.push(Reverse((priority, node)));
+                continue;
+            }
+
+            // The remaining edges of the node lead up the hierarchy.
+            order.push(node);
+            for (to, cost) in std::mem::take(&mut outgoing[node]) {
+                incoming[to].remove(&node);
+                contracted_neighbors[to] += 1;
+                upward[node].push((to, cost));
+            }
+            for (from, cost) in std::mem::take(&mut incoming[node]) {
+                outgoing[from].remove(&node);
+                contracted_neighbors[from] += 1;
+                downward[node].push((from, cost));
+            }
+            for (from, to, cost) in shortcuts {
+                if outgoing[from].get(&to).is_none_or(|&old| cost < old) {
+                    outgoing[from].insert(to, cost);
+                    incoming[to].insert(from, cost);
+                    middles.insert((from, to), node);
+                }
+            }
+        }
+
+        Ok(ContractionHierarchy {
+            nodes,
+            index,
+            order,
+            upward,
+            downward,
+            middles,
+        })
+    }
+
+    /// Finds the shortest path from `source` to `destination` in the graph
+    /// of the hierarchy.
+    ///
+    /// Two searches run on the hierarchy, one going forward from the source
+    /// and the other backward from the destination, both only following
+    /// edges towards nodes contracted later. Each one stops once its queue
+    /// only holds nodes not cheaper than the best path found through a node
+    /// reached by both. The shortcuts of this path are then unpacked into
+    /// the edges of the graph they stand for.
+    ///
+    /// The returned path and errors are the same as with
+    /// [`dijkstra`](crate::dijkstra), the settled nodes of both searches being
+    /// counted.
+    pub fn query(&self, source: &N, destination: &N) -> PathResult<N, W> {
+        let policy = OverflowPolicy::default();
+        let source = *self.index.get(source).ok_or(PathError::UnknownSource)?;
+        let destination = *self
+            .index
+            .get(destination)
+            .ok_or(PathError::UnknownDestination)?;
+
+        let mut forward = Side::new(source);
+        let mut backward = Side::new(destination);
+        // The cost of the best path found so far, along with the node where
+        // both searches met on this path.
+        let mut best: Option<(W, usize)> = None;
+        loop {
+            // Every search is over once it cannot find a cheaper path.
+            for side in [&mut forward, &mut backward] {
+                if side
+                    .lowest()
+                    .is_some_and(|lowest| best.is_some_and(|(cost, _)| lowest >= cost))
+                {
+                    side.queue.clear();
+                }
+            }
+            let (side, other, edges) = match (forward.lowest(), backward.lowest()) {
+                (None, None) => break,
+                (Some(ahead), Some(behind)) if ahead > behind => {
+                    (&mut backward, &forward, &self.downward)
+                }
+                (Some(_), _) => (&mut forward, &backward, &self.upward),
+                (None, Some(_)) => (&mut backward, &forward, &self.downward),
+            };
+            side.step(edges, other, policy, &mut best)?;
+        }
+
+        let (cost, middle) = best.ok_or(PathError::NoPath)?;
+        // The forward search leads from the source to the middle and the
+        // backward search from the middle to the destination, once reversed.
+        let mut hops = side_path(&forward.origin, middle);
+        hops.reverse();
+        let mut node = middle;
+        while let Some(&next) = backward.origin.get(&node) {
+            hops.push((node, next));
+            node = next;
+        }
+        let mut path = vec![source];
+        for (from, to) in hops {
+            self.unpack(from, to, &mut path);
+        }
+
+        Ok(ShortestPathResult {
+            nodes: path
+                .into_iter()
+                .map(|node| self.nodes[node].clone())
+                .collect(),
+            cost,
+            settled: Some(forward.stats.settled + backward.stats.settled),
+            stats: Some(forward.stats + backward.stats),
+        })
+    }
+
+    // Appends to the path the nodes of the edges of the graph the shortcut
+    // or edge between the given nodes stands for, the first node excepted.
+    fn unpack(&self, from: usize, to: usize, path: &mut Vec<usize>) {
+        let mut pending = vec![(from, to)];
+        while let Some((from, to)) = pending.pop() {
+            match self.middles.get(&(from, to)) {
+                Some(&middle) => {
+                    pending.push((middle, to));
+                    pending.push((from, middle));
+                }
+                None => path.push(to),
+            }
+        }
+    }
+}
+
+// Lists the shortcuts needed to contract the node, as (from, to, cost)
+// triplets: a shortcut is needed between two neighbors unless a path avoiding
+// the node is as cheap as the path through it.
+fn shortcuts<W: Weight>(
+    outgoing: &[HashMap<usize, W>],
+    incoming: &[HashMap<usize, W>],
+    witnesses: &mut Witnesses<W>,
+    node: usize,
+    policy: OverflowPolicy,
+) -> Result<Vec<(usize, usize, W)>, PathError> {
+    let mut shortcuts = Vec::new();
+    let farthest = match outgoing[node].values().max() {
+        Some(&farthest) => farthest,
+        None => return Ok(shortcuts),
+    };
+    for (&from, &to_node) in &incoming[node] {
+        let limit = policy.add(to_node, farthest).ok_or(PathError::Overflow)?;
+        witnesses.search(outgoing, from, node, limit, policy)?;
+        for (&to, &from_node) in &outgoing[node] {
+            if to == from {
+                continue;
+            }
+            let cost = policy.add(to_node, from_node).ok_or(PathError::Overflow)?;
+            if witnesses.costs[to].is_none_or(|witness| witness > cost) {
+                shortcuts.push((from, to, cost));
+            }
+        }
+    }
+    Ok(shortcuts)
+}
+
+// The searches for the paths avoiding a node, whose memory is reused from a
+// search to the next.
+struct Witnesses<W> {
+    // The cost of the path found to every node by the last search, if any.
+    costs: Vec<Option<W>>,
+    // The nodes reached by the last search, whose cost has to be cleared.
+    reached: Vec<usize>,
+    // The nodes reached so far along with their cost, the cheapest one
+    // first.
+    queue: BinaryHeap<Reverse<(W, usize)>>,
+}
+
+impl<W: Weight> Witnesses<W> {
+    // Creates the searches of a graph with the given number of nodes.
+    fn new(node_count: usize) -> Self {
+        Witnesses {
+            costs: vec![None; node_count],
+            reached: Vec::new(),
+            queue: BinaryHeap::new(),
+        }
+    }
+
+    // Finds the costs of the paths from the source which avoid the given node
+    // and cost at most the limit. The search settles at most
+    // WITNESS_SETTLED_LIMIT nodes, so some of the costs may not be the
+    // cheapest ones, and some paths may be missing.
+    fn search(
+        &mut self,
+        outgoing: &[HashMap<usize, W>],
+        source: usize,
+        avoided: usize,
+        limit: W,
+        policy: OverflowPolicy,
+    ) -> Result<(), PathError> {
+        for node in self.reached.drain(..) {
+            self.costs[node] = None;
+        }
+        self.queue.clear();
+        self.costs[source] = Some(W::zero());
+        self.reached.push(source);
+        self.queue.push(Reverse((W::zero(), source)));
+        let mut settled = 0;
+        while let Some(Reverse((cost, node))) = self.queue.pop() {
+            if cost > limit || settled == WITNESS_SETTLED_LIMIT {
+                break;
+            }
+            // The outdated entries are skipped.
+            if self.costs[node].is_some_and(|best| best < cost) {
+                continue;
+            }
+            settled += 1;
+            for (&neighbor, &edge_cost) in &outgoing[node] {
+                if neighbor == avoided {
+                    continue;
+                }
+                let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+                match self.costs[neighbor] {
+                    Some(old) if old <= new_cost => continue,
+                    Some(_) => {}
+                    None => self.reached.push(neighbor),
+                }
+                self.costs[neighbor] = Some(new_cost);
+                self.queue.push(Reverse((new_cost, neighbor)));
+            }
+        }
+        Ok(())
+    }
+}
+
+// The priority of the node in the contraction order, the lowest one being
+// contracted first: its edge difference plus its contracted neighbors.
+fn priority<W>(
+    outgoing: &[HashMap<usize, W>],
+    incoming: &[HashMap<usize, W>],
+    contracted_neighbors: &[usize],
+    node: usize,
+    shortcuts: &[(usize, usize, W)],
+) -> isize {
+    let removed = outgoing[node].len() + incoming[node].len();
+    shortcuts.len() as isize - removed as isize + contracted_neighbors[node] as isize
+}
+
+// The (from, to) edges of the hierarchy followed by a search to reach the
+// node, from the node back to the start of the search.
+fn side_path(origin: &HashMap<usize, usize>, mut node: usize) -> Vec<(usize, usize)> {
+    let mut hops = Vec::new();
+    while let Some(&previous) = origin.get(&node) {
+        hops.push((previous, node));
+        node = previous;
+    }
+    hops
+}
+
+// One of the two searches of a query, going up the hierarchy.
+struct Side<W> {
+    // The best known cost of every node reached so far.
+    costs: HashMap<usize, W>,
+    // The node every node was reached from, used to backtrack the path.
+    origin: HashMap<usize, usize>,
+    // The nodes reached so far along with their cost, the cheapest one
+    // first.
+    queue: BinaryHeap<Reverse<(W, usize)>>,
+    // What the search did so far.
+    stats: SearchStats,
+}
+
+impl<W: Weight> Side<W> {
+    // Creates a search starting from the given node.
+    fn new(start: usize) -> Self {
+        let mut costs = HashMap::default();
+        costs.insert(start, W::zero());
+        let mut queue = BinaryHeap::new();
+        queue.push(Reverse((W::zero(), start)));
+        Side {
+            costs,
+            origin: HashMap::default(),
+            queue,
+            stats: SearchStats {
+                pushes: 1,
+                ..SearchStats::default()
+            },
+        }
+    }
+
+    // The lowest cost in the queue, or None if the search is over.
+    fn lowest(&self) -> Option<W> {
+        self.queue.peek().map(|&Reverse((cost, _))| cost)
+    }
+
+    // Settles the next node of the search, following the given edges, and
+    // updates the best path if the other search reached the node as well.
+    fn step(
+        &mut self,
+        edges: &[Vec<(usize, W)>],
+        other: &Side<W>,
+        policy: OverflowPolicy,
+        best: &mut Option<(W, usize)>,
+    ) -> Result<(), PathError> {
+        let (cost, node) = match self.queue.pop() {
+            Some(Reverse(entry)) => entry,
+            None => return Ok(()),
+        };
+        // The outdated entries are skipped.
+        if self.costs[&node] < cost {
+            return Ok(());
+        }
+        self.stats.settled += 1;
+        if let Some(&other_cost) = other.costs.get(&node) {
+            let total = policy.add(cost, other_cost).ok_or(PathError::Overflow)?;
+            if best.is_none_or(|(best_cost, _)| total < best_cost) {
+                *best = Some((total, node));
+            }
+        }
+
+        for &(neighbor, edge_cost) in &edges[node] {
+            self.stats.relaxations += 1;
+            let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+            if self
+                .costs
+                .get(&neighbor)
+                .is_some_and(|&old| old <= new_cost)
+            {
+                continue;
+            }
+            self.costs.insert(neighbor, new_cost);
+            self.origin.insert(neighbor, node);
+            self.queue.push(Reverse((new_cost, neighbor)));
+            self.stats.pushes += 1;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to number the nodes and their components.
+use crate::hash::{HashMap, HashSet};
+// The graph being split into components.
+use crate::GraphLike;
+
+/// Splits `graph` into its connected components, the edges being followed in
+/// both directions.
+///
+/// Two nodes are in the same component if there is a path between them when
+/// the direction of the edges is ignored. A query between two nodes of
+/// different components can therefore never find a path, whatever the
+/// direction of the edges. The components are found by merging the ends of
+/// every edge in a disjoint set forest, which takes O(V + E) time in
+/// practice.
+pub fn connected_components<G: GraphLike>(graph: &G) -> Components<G::Node> {
+    let nodes: Vec<&G::Node> = graph.nodes().collect();
+    let index: HashMap<&G::Node, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(position, &node)| (node, position))
+        .collect();
+
+    // Every node starts in its own set, and the ends of every edge are then
+    // merged, the smallest set joining the largest one.
+    let mut parents: Vec<usize> = (0..nodes.len()).collect();
+    let mut sizes = vec![1; nodes.len()];
+    for (source, destination, _) in graph.edges() {
+        // The edges leading out of the graph don't connect anything.
+        let destination = match index.get(destination) {
+            Some(&destination) => destination,
+            None => continue,
+        };
+        let first = root(&mut parents, index[source]);
+        let second = root(&mut parents, destination);
+        if first != second {
+            let (small, large) = if sizes[first] < sizes[second] {
+                (first, second)
+            } else {
+                (second, first)
+            };
+            parents[small] = large;
+            sizes[large] += sizes[small];
+        }
+    }
+
+    // The components are numbered in the order of their first node.
+    let mut numbers = HashMap::default();
+    let mut components = Components {
+        component: HashMap::default(),
+        sizes: Vec::new(),
+    };
+    for (position, node) in nodes.into_iter().enumerate() {
+        let set = root(&mut parents, position);
+        let number = *numbers.entry(set).or_insert_with(|| {
+            components.sizes.push(sizes[set]);
+            components.sizes.len() - 1
+        });
+        components.component.insert(node.clone(), number);
+    }
+    components
+}
+
+/// Splits `graph` into its strongly connected components, with Tarjan's
+/// algorithm.
+///
+/// Two nodes are in the same strongly connected component if each of them
+/// can reach the other along the edges. Every node belongs to exactly one
+/// component, and the components are returned in reverse topological order:
+/// no edge leads from a component to an earlier one, so the components
+/// which cannot be left come first. Merging every component into a single
+/// node therefore gives a directed acyclic graph, which can be routed
+/// through with [`dag_shortest_path`](crate::dag_shortest_path).
+///
+/// The graph is explored once with an explicit stack rather than recursion,
+/// which takes O(V + E) time and doesn't overflow the call stack on long
+/// paths.
+pub fn strongly_connected_components<G: GraphLike>(graph: &G) -> Vec<Vec<G::Node>> {
+    // The order in which every node was first reached, and the earliest
+    // node it was found to reach back to.
+    let mut discovery: HashMap<&G::Node, usize> = HashMap::default();
+    let mut lowest: HashMap<&G::Node, usize> = HashMap::default();
+    // The nodes reached whose component is not known yet, and whether a
+    // node is still among them.
+    let mut pending = Vec::new();
+    let mut on_pending = HashSet::default();
+    let mut components = Vec::new();
+
+    for root in graph.nodes() {
+        if discovery.contains_key(root) {
+            continue;
+        }
+
+        // Every node being explored along with its neighbors left to visit,
+        // standing in for the recursive calls.
+        let mut exploring = Vec::new();
+        visit(root, &mut discovery, &mut lowest, &mut pending);
+        on_pending.insert(root);
+        exploring.push((root, graph.neighbors(root)));
+
+        while let Some((node, neighbors)) = exploring.last_mut() {
+            let node = *node;
+            match neighbors.next() {
+                // The edges leading out of the graph are ignored.
+                Some((neighbor, _)) if !graph.contains_node(neighbor) => {}
+                Some((neighbor, _)) => {
+                    if !discovery.contains_key(neighbor) {
+                        visit(neighbor, &mut discovery, &mut lowest, &mut pending);
+                        on_pending.insert(neighbor);
+                        exploring.push((neighbor, graph.neighbors(neighbor)));
+                    } else if on_pending.contains(neighbor) {
+                        // The neighbor is an ancestor of the node or in the
+                        // same component as one.
+                        let reached = discovery[neighbor];
+                        let low = lowest.get_mut(node).expect("the node was visited");
+                        *low = (*low).min(reached);
+                    }
+                }
+                None => {
+                    exploring.pop();
+                    let low = lowest[node];
+                    // The node reaches back to its parent through its own
+                    // descendants, as well as the parent does.
+                    if let Some((parent, _)) = exploring.last() {
+                        let parent_low = lowest.get_mut(*parent).expect("the node was visited");
+                        *parent_low = (*parent_low).min(low);
+                    }
+
+                    // The node cannot reach anything before itself, so it is
+                    // the first node of its component, whose nodes are all
+                    // pending after it.
+                    if low == discovery[node] {
+                        let mut component = Vec::new();
+                        loop {
+                            let member = pending.pop().expect("the node is pending");
+                            on_pending.remove(member);
+                            component.push(member.clone());
+                            if member == node {
+                                break;
+                            }
+                        }
+                        components.push(component);
+                    }
+                }
+            }
+        }
+    }
+    components
+}
+
+// Gives the next discovery number to a node reached for the first time, and
+// marks it as pending.
+fn visit<'g, N: Eq + Hash>(
+    node: &'g N,
+    discovery: &mut HashMap<&'g N, usize>,
+    lowest: &mut HashMap<&'g N, usize>,
+    pending: &mut Vec<&'g N>,
+) {
+    let number = lowest.len();
+    discovery.insert(node, number);
+    lowest.insert(node, number);
+    pending.push(node);
+}
+
+// Finds the root of the set of a node, halving the path to it on the way so
+// that the next lookups are faster.
+fn root(parents: &mut [usize], mut node: usize) -> usize {
+    while parents[node] != node {
+        parents[node] = parents[parents[node]];
+        node = parents[node];
+    }
+    node
+}
+
+/// A partition of the nodes of a graph into components, as returned by
+/// [`connected_components`].
+///
+/// The components are numbered from 0, and every node of the graph belongs
+/// to exactly one of them. The nodes are cloned from the graph, so the
+/// partition doesn't borrow it.
+#[derive(Debug, Clone)]
+pub struct Components<N> {
+    // The number of the component of every node.
+    component: HashMap<N, usize>,
+    // The number of nodes in every component.
+    sizes: Vec<usize>,
+}
+
+impl<N: Eq + Hash> Components<N> {
+    /// The number of components.
+    pub fn count(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// The number of the component of `node`, or `None` if the node is not
+    /// part of the graph.
+    pub fn component_of(&self, node: &N) -> Option<usize> {
+        self.component.get(node).copied()
+    }
+
+    /// Whether both nodes are part of the graph and belong to the same
+    /// component.
+    pub fn same_component(&self, first: &N, second: &N) -> bool {
+        match (self.component_of(first), self.component_of(second)) {
+            (Some(first), Some(second)) => first == second,
+            _ => false,
+        }
+    }
+
+    /// The number of nodes in every component, indexed by the number of the
+    /// components.
+    pub fn sizes(&self) -> &[usize] {
+        &self.sizes
+    }
+
+    /// Iterates over the nodes of the graph along with the number of their
+    /// component, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, usize)> + '_ {
+        self.component
+            .iter()
+            .map(|(node, component)| (node, *component))
+    }
+}
@@ -0,0 +1,266 @@
+// Required by the keys of the index.
+use std::hash::Hash;
+// Used to collect graphs from edge lists.
+use std::iter::FromIterator;
+
+// Used to find the index of the nodes.
+use crate::hash::HashMap;
+// The graph being compressed, and the operations needed by the algorithms.
+use crate::{CsrGraph, GraphLike};
+
+/// A static directed graph with `u32` costs, whose edges are compressed with
+/// delta and varint encoding.
+///
+/// The graph is laid out like a [`CsrGraph`], except that the edges leaving
+/// every node are sorted by destination and packed into a single array of
+/// bytes. The index of every destination is stored as the difference with the
+/// previous one, the first one being stored as the difference with the index
+/// of the source, and the costs are stored as they are. All these integers are
+/// encoded as varints, i.e. with 7 bits per byte, so that small numbers only
+/// take a single byte. Since the nodes close to each other tend to have close
+/// indices, for instance when they were read from a road network, this
+/// roughly halves the memory taken by the edges.
+///
+/// The edges are decoded on the fly while iterating over the neighbors of a
+/// node, which is slower than reading the plain arrays of the [`CsrGraph`] but
+/// lets much larger graphs fit in memory. The graph is built with
+/// [`CompressedGraph::from_edges`], [`CompressedGraph::from_graph`] or from a
+/// [`CsrGraph`], and cannot be modified afterward. Parallel edges are all
+/// kept.
+#[derive(Debug, Clone)]
+pub struct CompressedGraph<N> {
+    // The node associated with each index.
+    nodes: Vec<N>,
+    // The index associated with each node.
+    index: HashMap<N, u32>,
+    // Where the edges of each node start in the encoded bytes, with one more
+    // offset than there are nodes, like those of the CsrGraph.
+    offsets: Vec<usize>,
+    // The encoded edges of all the nodes.
+    bytes: Vec<u8>,
+    // The number of edges in the graph.
+    edge_count: usize,
+}
+
+impl<N: Eq + Hash + Clone> CompressedGraph<N> {
+    /// Builds the graph from a list of `(source, destination, cost)` edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edges involve more than `u32::MAX` distinct nodes.
+    pub fn from_edges<I: IntoIterator<Item = (N, N, u32)>>(edges: I) -> Self {
+        CompressedGraph::from(CsrGraph::from_edges(edges))
+    }
+
+    /// Builds a compressed copy of `graph`, its isolated nodes included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph has more than `u32::MAX` nodes.
+    pub fn from_graph<G: GraphLike<Node = N, Weight = u32>>(graph: &G) -> Self {
+        CompressedGraph::from(CsrGraph::from_graph(graph))
+    }
+
+    /// The number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// The number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// The number of bytes taken by the encoded edges, their offsets
+    /// included. The same edges take 8 bytes each in a [`CsrGraph`], plus 8
+    /// bytes per node for the offsets.
+    pub fn edge_bytes(&self) -> usize {
+        self.bytes.len() + self.offsets.len() * std::mem::size_of::<usize>()
+    }
+
+    /// Iterates over all the nodes of the graph, in the order of their
+    /// indices.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> + '_ {
+        self.nodes.iter()
+    }
+
+    /// Iterates over the nodes adjacent to `node`, along with the cost of the
+    /// edge leading to them, sorted by the index of the adjacent nodes.
+    ///
+    /// A node which is not part of the graph has no neighbors.
+    pub fn neighbors<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, u32)> + 'a {
+        // A missing node is treated just like a node without edges.
+        let (source, bytes) = match self.index.get(node) {
+            Some(&index) => {
+                let index = index as usize;
+                (
+                    index,
+                    &self.bytes[self.offsets[index]..self.offsets[index + 1]],
+                )
+            }
+            None => (0, &self.bytes[..0]),
+        };
+
+        Neighbors {
+            graph: self,
+            previous: source as u64,
+            first: true,
+            bytes,
+        }
+    }
+
+    /// Returns the copy of `node` which is stored in the graph, if any.
+    pub fn get_node(&self, node: &N) -> Option<&N> {
+        self.index
+            .get(node)
+            .map(|&index| &self.nodes[index as usize])
+    }
+}
+
+/// Compresses the edges of `graph`, keeping the indices of its nodes.
+impl<N: Eq + Hash + Clone> From<CsrGraph<N, u32>> for CompressedGraph<N> {
+    fn from(graph: CsrGraph<N, u32>) -> Self {
+        let mut offsets = Vec::with_capacity(graph.offsets.len());
+        let mut bytes = Vec::new();
+        let mut edges = Vec::new();
+
+        for source in 0..graph.nodes.len() {
+            offsets.push(bytes.len());
+
+            // We sort the edges of the node by destination, so that the
+            // differences between them are small and positive.
+            let range = graph.offsets[source]..graph.offsets[source + 1];
+            edges.clear();
+            edges.extend(range.map(|edge| (graph.targets[edge], graph.costs[edge])));
+            edges.sort_unstable();
+
+            // The first destination may come before the source, its
+            // difference with it is therefore zigzag encoded.
+            let mut previous = source as u64;
+            for (position, &(target, cost)) in edges.iter().enumerate() {
+                let target = u64::from(target);
+                let delta = if position == 0 {
+                    zigzag(target as i64 - previous as i64)
+                } else {
+                    target - previous
+                };
+                encode(&mut bytes, delta);
+                encode(&mut bytes, u64::from(cost));
+                previous = target;
+            }
+        }
+        offsets.push(bytes.len());
+        bytes.shrink_to_fit();
+
+        CompressedGraph {
+            nodes: graph.nodes,
+            index: graph.index,
+            offsets,
+            bytes,
+            edge_count: graph.targets.len(),
+        }
+    }
+}
+
+impl<N: Eq + Hash + Clone> GraphLike for CompressedGraph<N> {
+    type Node = N;
+    type Weight = u32;
+
+    fn get_node(&self, node: &N) -> Option<&N> {
+        CompressedGraph::get_node(self, node)
+    }
+
+    fn neighbors<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, u32)> + 'a {
+        CompressedGraph::neighbors(self, node)
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a N> + 'a {
+        CompressedGraph::nodes(self)
+    }
+
+    fn node_count(&self) -> usize {
+        CompressedGraph::node_count(self)
+    }
+
+    fn edge_count(&self) -> usize {
+        CompressedGraph::edge_count(self)
+    }
+}
+
+/// Builds the graph from `(source, destination, cost)` edges, just like
+/// [`CompressedGraph::from_edges`] would.
+impl<N: Eq + Hash + Clone> FromIterator<(N, N, u32)> for CompressedGraph<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N, u32)>>(edges: I) -> Self {
+        CompressedGraph::from_edges(edges)
+    }
+}
+
+// Decodes the edges leaving a node on the fly.
+struct Neighbors<'a, N> {
+    // The graph the edges belong to.
+    graph: &'a CompressedGraph<N>,
+    // The index of the last decoded destination, or of the source before the
+    // first one.
+    previous: u64,
+    // Whether the next destination is the first one.
+    first: bool,
+    // The encoded edges not decoded yet.
+    bytes: &'a [u8],
+}
+
+impl<'a, N> Iterator for Neighbors<'a, N> {
+    type Item = (&'a N, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let delta = decode(&mut self.bytes);
+        let cost = decode(&mut self.bytes) as u32;
+        self.previous = if self.first {
+            (self.previous as i64 + unzigzag(delta)) as u64
+        } else {
+            self.previous + delta
+        };
+        self.first = false;
+
+        Some((&self.graph.nodes[self.previous as usize], cost))
+    }
+}
+
+// Appends `value` to `bytes` as a varint, the lowest 7 bits first. The highest
+// bit of every byte tells whether more bytes follow.
+fn encode(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+// Reads the varint at the start of `bytes`, and moves past it.
+fn decode(bytes: &mut &[u8]) -> u64 {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = bytes[0];
+        *bytes = &bytes[1..];
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte < 0x80 {
+            return value;
+        }
+        shift += 7;
+    }
+}
+
+// Maps the signed integers to unsigned ones, the small negative integers
+// becoming small odd integers, so that they are encoded in few bytes.
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+// The reverse of zigzag.
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
@@ -0,0 +1,116 @@
+// Used to order the states by cost.
+use std::cmp::Reverse;
+// Used as priority queue by the search.
+use std::collections::BinaryHeap;
+
+// Used to keep track of the settled states.
+use crate::hash::HashMap;
+// The graph being explored, and the path found in it.
+use crate::{
+    GraphLike, OverflowPolicy, PathError, PathResult, SearchStats, ShortestPathResult, Weight,
+};
+
+/// Finds the cheapest path from `source` to `destination` in `graph` whose
+/// total usage of a second resource stays within `budget`.
+///
+/// Every edge uses some of a resource as well as costing its cost, as given
+/// by `resource` from the source, the destination and the cost of the edge:
+/// an electric car going for the fastest route, for instance, must not use
+/// more than the charge of its battery. The cheapest path may use too much,
+/// and the cheapest path to a node may not be the start of the best path
+/// through it, so the search settles labels, i.e. the paths reaching a node
+/// with a given cost and usage, rather than the plain nodes. A label is
+/// dominated if another label of the same node was as cheap and used as
+/// little, and is then dropped, since the paths extending it are not better
+/// than the paths extending the other label. The labels are settled by
+/// increasing cost, so a label is only worth settling if it uses less than
+/// every label of its node settled before it. The problem is NP-hard, and a
+/// node may be settled as many times as there are resource usages.
+///
+/// The returned path and errors are the same as with
+/// [`dijkstra`](crate::dijkstra). The query fails with [`PathError::NoPath`]
+/// if every path to the destination uses more than the budget, and with
+/// [`PathError::Overflow`] if the cost or the usage of a path overflows.
+pub fn constrained_shortest_path<G, R, U>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    mut resource: U,
+    budget: R,
+) -> PathResult<G::Node, G::Weight>
+where
+    G: GraphLike,
+    R: Weight,
+    U: FnMut(&G::Node, &G::Node, G::Weight) -> R,
+{
+    // We work with the copies of the nodes stored in the graph, so that all
+    // the nodes we handle share the lifetime of the graph.
+    let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+    let destination = graph
+        .get_node(destination)
+        .ok_or(PathError::UnknownDestination)?;
+    let policy = OverflowPolicy::default();
+
+    // Every label reached so far, as its node along with the label it was
+    // reached from.
+    let mut labels: Vec<(&G::Node, Option<usize>)> = vec![(source, None)];
+    // The labels reached so far along with their cost and usage, the
+    // cheapest one first, and the one using the least among them.
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((G::Weight::zero(), R::zero(), 0)));
+    // The least usage of a settled label of every node.
+    let mut least_usage: HashMap<&G::Node, R> = HashMap::default();
+    let mut stats = SearchStats {
+        pushes: 1,
+        ..SearchStats::default()
+    };
+
+    while let Some(Reverse((cost, usage, label))) = queue.pop() {
+        let (node, _) = labels[label];
+        // A label is dominated by the cheaper labels of the node which used
+        // as little.
+        if least_usage.get(node).is_some_and(|&least| least <= usage) {
+            continue;
+        }
+        least_usage.insert(node, usage);
+        stats.settled += 1;
+
+        if node == destination {
+            let mut path = Vec::new();
+            let mut location = Some(label);
+            while let Some(position) = location {
+                path.push(labels[position].0.clone());
+                location = labels[position].1;
+            }
+            path.reverse();
+            return Ok(ShortestPathResult {
+                nodes: path,
+                cost,
+                settled: Some(stats.settled),
+                stats: Some(stats),
+            });
+        }
+
+        for (neighbor, edge_cost) in graph.neighbors(node) {
+            stats.relaxations += 1;
+            let new_usage = policy
+                .add(usage, resource(node, neighbor, edge_cost))
+                .ok_or(PathError::Overflow)?;
+            // The labels over the budget cannot lead anywhere, and neither
+            // can the dominated ones.
+            if new_usage > budget
+                || least_usage
+                    .get(neighbor)
+                    .is_some_and(|&least| least <= new_usage)
+            {
+                continue;
+            }
+            let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+            labels.push((neighbor, Some(label)));
+            queue.push(Reverse((new_cost, new_usage, labels.len() - 1)));
+            stats.pushes += 1;
+        }
+    }
+
+    Err(PathError::NoPath)
+}
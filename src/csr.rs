@@ -0,0 +1,378 @@
+// Used to order the nodes by distance in the indexed search.
+use std::cmp::Reverse;
+// Used to number the nodes.
+use std::convert::TryFrom;
+// Used as priority queue by the indexed search.
+use std::collections::BinaryHeap;
+// Required by the keys of the index.
+use std::hash::Hash;
+// Used to collect graphs from edge lists.
+use std::iter::FromIterator;
+
+// Used to find the index of the nodes.
+use crate::hash::HashMap;
+// The operations needed by the algorithms, and the costs of the edges.
+use crate::{GraphLike, OverflowPolicy, PathError, SearchStats, ShortestPathResult, Weight};
+
+/// A static directed graph stored in compressed sparse row format.
+///
+/// The nodes are numbered once and for all when the graph is built, and the
+/// edges are stored in a few flat arrays sorted by source node: the edges
+/// leaving the node with index `i` are found between `offsets[i]` and
+/// `offsets[i + 1]` in the `targets` and `costs` arrays. This takes much less
+/// memory than nested `HashMap`s and keeps the neighbors of a node next to each
+/// other, which is what the algorithms need for large graphs which don't
+/// change once loaded.
+///
+/// The graph is built from an edge list with [`CsrGraph::from_edges`], or
+/// from any other graph with [`CsrGraph::from_graph`], and cannot be modified
+/// afterward. It can hold at most `u32::MAX` nodes.
+///
+/// Since the nodes are numbered, [`CsrGraph::shortest_path`] can run the
+/// search over plain arrays indexed by the nodes, without hashing a single
+/// node once the ends of the query are found.
+#[derive(Debug, Clone)]
+pub struct CsrGraph<N, W = u32> {
+    // The node associated with each index.
+    pub(crate) nodes: Vec<N>,
+    // The index associated with each node.
+    pub(crate) index: HashMap<N, u32>,
+    // Where the edges of each node start in the targets and costs arrays. There
+    // is one more offset than there are nodes, so that the edges of the node i
+    // always end at offsets[i + 1].
+    pub(crate) offsets: Vec<usize>,
+    // The index of the destination of each edge.
+    pub(crate) targets: Vec<u32>,
+    // The cost of each edge.
+    pub(crate) costs: Vec<W>,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> CsrGraph<N, W> {
+    /// Builds the graph from a list of `(source, destination, cost)` edges.
+    ///
+    /// Parallel edges are all kept, the algorithms will simply favor the
+    /// cheapest one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edges involve more than `u32::MAX` distinct nodes.
+    pub fn from_edges<I: IntoIterator<Item = (N, N, W)>>(edges: I) -> Self {
+        CsrGraph::from_nodes_and_edges(Vec::new(), edges)
+    }
+
+    /// Builds a copy of `graph`, its isolated nodes included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph has more than `u32::MAX` nodes.
+    pub fn from_graph<G: GraphLike<Node = N, Weight = W>>(graph: &G) -> Self {
+        CsrGraph::from_nodes_and_edges(
+            graph.nodes().cloned(),
+            graph
+                .edges()
+                .map(|(source, destination, cost)| (source.clone(), destination.clone(), cost)),
+        )
+    }
+
+    // Builds the graph from a list of nodes, which may not appear in any edge,
+    // and a list of edges. The nodes are numbered in the order they are given,
+    // then the missing ends of the edges in the order they appear.
+    fn from_nodes_and_edges<I, J>(given: I, edges: J) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        J: IntoIterator<Item = (N, N, W)>,
+    {
+        let mut nodes = Vec::new();
+        let mut index = HashMap::default();
+
+        // We first number the given nodes.
+        for node in given {
+            intern(&mut nodes, &mut index, node);
+        }
+
+        // We then number the ends of the edges, keeping the edges around with
+        // the indices of their ends.
+        let edges: Vec<(u32, u32, W)> = edges
+            .into_iter()
+            .map(|(source, destination, cost)| {
+                let source = intern(&mut nodes, &mut index, source);
+                let destination = intern(&mut nodes, &mut index, destination);
+                (source, destination, cost)
+            })
+            .collect();
+
+        CsrGraph::from_numbered_edges(nodes, index, edges)
+    }
+
+    // Builds the graph from its numbered nodes and from the edges between the
+    // indices of their ends, in any order.
+    pub(crate) fn from_numbered_edges(
+        nodes: Vec<N>,
+        index: HashMap<N, u32>,
+        edges: Vec<(u32, u32, W)>,
+    ) -> Self {
+        // We first count the edges leaving every node, which gives us the
+        // offsets once accumulated.
+        let mut offsets = vec![0; nodes.len() + 1];
+        for (source, _, _) in &edges {
+            offsets[*source as usize + 1] += 1;
+        }
+        for i in 1..offsets.len() {
+            offsets[i] += offsets[i - 1];
+        }
+
+        // Finally we place every edge in the slot of its source. The position
+        // of the next free slot of every node starts at its offset.
+        let mut next_slot = offsets.clone();
+        let mut targets = vec![0; edges.len()];
+        let mut costs = vec![W::zero(); edges.len()];
+        for (source, destination, cost) in edges {
+            let slot = &mut next_slot[source as usize];
+            targets[*slot] = destination;
+            costs[*slot] = cost;
+            *slot += 1;
+        }
+
+        CsrGraph {
+            nodes,
+            index,
+            offsets,
+            targets,
+            costs,
+        }
+    }
+
+    /// The number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// The number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Iterates over all the nodes of the graph, in the order of their
+    /// indices.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> + '_ {
+        self.nodes.iter()
+    }
+
+    /// Iterates over all the edges of the graph as `(source, destination,
+    /// cost)` tuples, sorted by the index of their source.
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N, W)> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, source)| {
+                (self.offsets[index]..self.offsets[index + 1]).map(move |edge| {
+                    (
+                        source,
+                        &self.nodes[self.targets[edge] as usize],
+                        self.costs[edge],
+                    )
+                })
+            })
+    }
+
+    /// Returns a copy of the graph with the direction of every edge flipped.
+    pub fn reversed(&self) -> Self {
+        // The isolated nodes are kept, with the same indices.
+        CsrGraph::from_nodes_and_edges(
+            self.nodes.iter().cloned(),
+            self.edges()
+                .map(|(source, destination, cost)| (destination.clone(), source.clone(), cost)),
+        )
+    }
+
+    /// Iterates over the nodes adjacent to `node`, along with the cost of the
+    /// edge leading to them.
+    ///
+    /// A node which is not part of the graph has no neighbors.
+    pub fn neighbors<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, W)> + 'a {
+        // A missing node is treated just like a node without edges.
+        let edges = match self.index.get(node) {
+            Some(&index) => self.offsets[index as usize]..self.offsets[index as usize + 1],
+            None => 0..0,
+        };
+
+        edges.map(move |edge| (&self.nodes[self.targets[edge] as usize], self.costs[edge]))
+    }
+
+    /// Returns the copy of `node` which is stored in the graph, if any.
+    pub fn get_node(&self, node: &N) -> Option<&N> {
+        self.index
+            .get(node)
+            .map(|&index| &self.nodes[index as usize])
+    }
+
+    /// Finds the shortest path from `source` to `destination`, just like
+    /// [`dijkstra`](crate::dijkstra) would.
+    ///
+    /// The ends of the query are translated to their indices once, then the
+    /// search keeps the progression of every node in arrays indexed by the
+    /// nodes. The nodes are translated back when building the path. This is
+    /// much faster than the generic search when hashing the nodes is
+    /// expensive, for instance with string nodes.
+    pub fn shortest_path(
+        &self,
+        source: &N,
+        destination: &N,
+    ) -> Result<ShortestPathResult<N, W>, PathError> {
+        let source = *self.index.get(source).ok_or(PathError::UnknownSource)?;
+        let destination = *self
+            .index
+            .get(destination)
+            .ok_or(PathError::UnknownDestination)?;
+
+        // The search itself only works with the indices.
+        let (path, cost, stats) = indexed_search(self.nodes.len(), source, destination, |node| {
+            (self.offsets[node as usize]..self.offsets[node as usize + 1])
+                .map(move |edge| (self.targets[edge], self.costs[edge]))
+        })?;
+
+        // We translate the indices back to the nodes.
+        Ok(ShortestPathResult {
+            nodes: path
+                .into_iter()
+                .map(|index| self.nodes[index as usize].clone())
+                .collect(),
+            cost,
+            settled: Some(stats.settled),
+            stats: Some(stats),
+        })
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> GraphLike for CsrGraph<N, W> {
+    type Node = N;
+    type Weight = W;
+
+    fn get_node(&self, node: &N) -> Option<&N> {
+        CsrGraph::get_node(self, node)
+    }
+
+    fn neighbors<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, W)> + 'a {
+        CsrGraph::neighbors(self, node)
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a N> + 'a {
+        CsrGraph::nodes(self)
+    }
+
+    fn edges<'a>(&'a self) -> impl Iterator<Item = (&'a N, &'a N, W)> + 'a {
+        CsrGraph::edges(self)
+    }
+
+    fn uniform_cost(&self) -> Option<W> {
+        // The costs of all the edges are stored in a single array.
+        let (&first, rest) = self.costs.split_first()?;
+        rest.iter().all(|&cost| cost == first).then_some(first)
+    }
+
+    fn node_count(&self) -> usize {
+        CsrGraph::node_count(self)
+    }
+
+    fn edge_count(&self) -> usize {
+        CsrGraph::edge_count(self)
+    }
+}
+
+/// Builds the graph from `(source, destination, cost)` edges, just like
+/// [`CsrGraph::from_edges`] would.
+impl<N: Eq + Hash + Clone, W: Weight> FromIterator<(N, N, W)> for CsrGraph<N, W> {
+    fn from_iter<I: IntoIterator<Item = (N, N, W)>>(edges: I) -> Self {
+        CsrGraph::from_edges(edges)
+    }
+}
+
+// Returns the index of `node`, numbering it if it had never been seen before.
+pub(crate) fn intern<N: Eq + Hash + Clone>(
+    nodes: &mut Vec<N>,
+    index: &mut HashMap<N, u32>,
+    node: N,
+) -> u32 {
+    *index.entry(node).or_insert_with_key(|node| {
+        // The new node gets the next free index.
+        let next = u32::try_from(nodes.len()).expect("too many nodes for a CsrGraph");
+        nodes.push(node.clone());
+        next
+    })
+}
+
+// Runs Dijkstra's algorithm over a graph whose `node_count` nodes are numbered,
+// `edges` giving the index and cost of the destinations of the edges leaving a
+// node. Returns the indices of the nodes of the path in order, its cost, and
+// what the search did to find it.
+pub(crate) fn indexed_search<W, E, I>(
+    node_count: usize,
+    source: u32,
+    destination: u32,
+    edges: E,
+) -> Result<(Vec<u32>, W, SearchStats), PathError>
+where
+    W: Weight,
+    E: Fn(u32) -> I,
+    I: Iterator<Item = (u32, W)>,
+{
+    let policy = OverflowPolicy::default();
+
+    // The best known cost of every node, None for the nodes never reached.
+    let mut progression = vec![None; node_count];
+    // The node every node was reached from, used to backtrack the path.
+    let mut origin = vec![source; node_count];
+    // Whether every node has been settled.
+    let mut visited = vec![false; node_count];
+    // The nodes reached so far, the cheapest one first. A node is pushed again
+    // every time a better path to it is found, the outdated entries being
+    // skipped.
+    let mut queue = BinaryHeap::new();
+    // What the search did so far.
+    let mut stats = SearchStats {
+        pushes: 1,
+        ..SearchStats::default()
+    };
+
+    progression[source as usize] = Some(W::zero());
+    queue.push(Reverse((W::zero(), source)));
+
+    let cost = loop {
+        // If the queue is empty, both nodes are simply not connected.
+        let Reverse((cost, node)) = queue.pop().ok_or(PathError::NoPath)?;
+        if visited[node as usize] {
+            continue;
+        }
+        visited[node as usize] = true;
+        stats.settled += 1;
+
+        if node == destination {
+            break cost;
+        }
+
+        for (neighbor, edge_cost) in edges(node) {
+            stats.relaxations += 1;
+            let new_progression = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+
+            // We only queue the neighbor again if we found a better path to it.
+            let old_progression = &mut progression[neighbor as usize];
+            if old_progression.is_none_or(|old| old > new_progression) {
+                *old_progression = Some(new_progression);
+                origin[neighbor as usize] = node;
+                queue.push(Reverse((new_progression, neighbor)));
+                stats.pushes += 1;
+            }
+        }
+    };
+
+    // We backtrack from the destination, and return the path in the right
+    // order.
+    let mut path = vec![destination];
+    let mut location = destination;
+    while location != source {
+        location = origin[location as usize];
+        path.push(location);
+    }
+    path.reverse();
+    Ok((path, cost, stats))
+}
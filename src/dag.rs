@@ -0,0 +1,206 @@
+// Used to queue the nodes whose predecessors are all sorted.
+use std::collections::VecDeque;
+
+// Used to count the predecessors of the nodes.
+use crate::hash::HashMap;
+// The graph being explored, the cycle found if it is not acyclic, and the
+// paths found in it.
+use crate::{
+    dijkstra_backtrack, CycleError, GraphLike, OverflowPolicy, PathError, PathResult,
+    ShortestPathResult, Weight,
+};
+
+/// Sorts the nodes of `graph` so that every edge goes from a node to a later
+/// one, with Kahn's algorithm.
+///
+/// The nodes without any incoming edge come first, in the order of
+/// [`GraphLike::nodes`], and every node follows all its predecessors. This
+/// takes O(V + E) time, and is the building block of the algorithms which
+/// only work on directed acyclic graphs.
+///
+/// Such an order only exists if the graph has no cycle, otherwise one of
+/// its cycles is returned in a [`CycleError`]. A self-loop is a cycle as
+/// well.
+pub fn topological_sort<G: GraphLike>(graph: &G) -> Result<Vec<G::Node>, CycleError<G::Node>> {
+    let order = topological_order(graph)?;
+    Ok(order.into_iter().cloned().collect())
+}
+
+/// Finds a directed cycle of `graph`, or returns `None` if the graph is
+/// acyclic.
+///
+/// The cycle is returned as its nodes in the order of its edges, the first
+/// node being repeated at the end, so that a self-loop on `a` gives `[a, a]`.
+/// This is the cycle [`topological_sort`] would fail with, found in O(V + E)
+/// time, and makes a good witness when a graph expected to be acyclic, such
+/// as a graph of dependencies, is not.
+pub fn find_cycle<G: GraphLike>(graph: &G) -> Option<Vec<G::Node>> {
+    topological_order(graph).err().map(|error| error.cycle)
+}
+
+/// Finds the shortest path from `source` to `destination` in the directed
+/// acyclic graph `graph`, in O(V + E) time.
+///
+/// Going through the nodes in topological order, every node is reached after
+/// all the paths leading to it, so that its cost is final once it is reached
+/// without any priority queue. Negative costs are supported as well.
+///
+/// The returned path and errors are the same as with
+/// [`dijkstra`](crate::dijkstra), and the query fails with
+/// [`PathError::NotAcyclic`] if the graph has a cycle, even one the source
+/// cannot reach.
+pub fn dag_shortest_path<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+) -> PathResult<G::Node, G::Weight> {
+    dag_path(graph, source, destination, |new, old| new < old)
+}
+
+/// Finds the longest path from `source` to `destination` in the directed
+/// acyclic graph `graph`, in O(V + E) time.
+///
+/// The longest path is the critical path of a schedule whose edges are
+/// tasks, since every task has to wait for all the tasks leading to it. It
+/// is found just like [`dag_shortest_path`], keeping the most expensive path
+/// to every node instead of the cheapest one, which is only possible because
+/// the graph has no cycle.
+///
+/// The returned path and errors are the same as with [`dag_shortest_path`].
+pub fn dag_longest_path<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+) -> PathResult<G::Node, G::Weight> {
+    dag_path(graph, source, destination, |new, old| new > old)
+}
+
+// Finds the best path from source to destination, a path being better than
+// another if better returns true for their costs, in that order.
+fn dag_path<G, B>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    better: B,
+) -> PathResult<G::Node, G::Weight>
+where
+    G: GraphLike,
+    B: Fn(G::Weight, G::Weight) -> bool,
+{
+    let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+    let destination = graph
+        .get_node(destination)
+        .ok_or(PathError::UnknownDestination)?;
+    let order = topological_order(graph).map_err(|_| PathError::NotAcyclic)?;
+    let policy = OverflowPolicy::default();
+
+    // The best cost of every node reached so far, and the node it was
+    // reached from on this path.
+    let mut progression = HashMap::default();
+    let mut origin = HashMap::default();
+    progression.insert(source, G::Weight::zero());
+
+    // The nodes before the source cannot be reached, and neither can the
+    // nodes after the destination lead to it.
+    let start = order
+        .iter()
+        .position(|&node| node == source)
+        .expect("the source is sorted");
+    for &node in &order[start..] {
+        if node == destination {
+            break;
+        }
+        // Every path to the node was already followed.
+        let cost = match progression.get(node) {
+            Some(&cost) => cost,
+            None => continue,
+        };
+
+        for (neighbor, edge_cost) in graph.neighbors(node) {
+            let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+            if progression
+                .get(neighbor)
+                .is_none_or(|&old| better(new_cost, old))
+            {
+                progression.insert(neighbor, new_cost);
+                origin.insert(neighbor, node);
+            }
+        }
+    }
+
+    let cost = *progression.get(destination).ok_or(PathError::NoPath)?;
+    let path = dijkstra_backtrack(&origin, source, destination).ok_or(PathError::NoPath)?;
+    Ok(ShortestPathResult {
+        nodes: path.into_iter().cloned().collect(),
+        cost,
+        settled: None,
+        stats: None,
+    })
+}
+
+// Sorts the nodes of the graph in topological order, as stored in the graph.
+fn topological_order<G: GraphLike>(graph: &G) -> Result<Vec<&G::Node>, CycleError<G::Node>> {
+    // We count the incoming edges of every node, which are removed as the
+    // nodes they come from are sorted.
+    let mut incoming: HashMap<&G::Node, usize> = graph.nodes().map(|node| (node, 0)).collect();
+    for (_, destination, _) in graph.edges() {
+        *incoming.entry(destination).or_default() += 1;
+    }
+
+    // The nodes without any incoming edge left can be sorted.
+    let mut ready: VecDeque<&G::Node> = graph.nodes().filter(|node| incoming[node] == 0).collect();
+    let mut order = Vec::with_capacity(incoming.len());
+    while let Some(node) = ready.pop_front() {
+        order.push(node);
+        for (neighbor, _) in graph.neighbors(node) {
+            let count = incoming.get_mut(neighbor).expect("every node is counted");
+            *count -= 1;
+            if *count == 0 {
+                ready.push_back(neighbor);
+            }
+        }
+    }
+
+    if order.len() == incoming.len() {
+        Ok(order)
+    } else {
+        Err(CycleError {
+            cycle: leftover_cycle(graph, &incoming),
+        })
+    }
+}
+
+// Finds a cycle among the nodes which kept some incoming edges, i.e. which
+// could not be sorted, returning it with its first node repeated at its end.
+fn leftover_cycle<G: GraphLike>(graph: &G, incoming: &HashMap<&G::Node, usize>) -> Vec<G::Node> {
+    // Every node left is reached from another node left, so going back from
+    // predecessor to predecessor eventually loops.
+    let mut predecessors = HashMap::default();
+    for (node, _) in incoming.iter().filter(|(_, count)| **count > 0) {
+        for (neighbor, _) in graph.neighbors(node) {
+            if incoming[neighbor] > 0 {
+                predecessors.insert(neighbor, *node);
+            }
+        }
+    }
+
+    // We walk back until a node comes up twice, the nodes walked since its
+    // first visit forming the cycle.
+    let mut walked: Vec<&G::Node> = Vec::new();
+    let mut positions = HashMap::default();
+    let mut location = *predecessors.keys().next().expect("a node is left");
+    while !positions.contains_key(location) {
+        positions.insert(location, walked.len());
+        walked.push(location);
+        location = predecessors[location];
+    }
+
+    // The walk went against the edges, so the cycle has to be reversed.
+    let mut cycle: Vec<G::Node> = walked[positions[location]..]
+        .iter()
+        .rev()
+        .map(|&node| node.clone())
+        .collect();
+    cycle.push(cycle[0].clone());
+    cycle
+}
@@ -0,0 +1,204 @@
+// Used to find the next bucket holding some nodes.
+use std::collections::BTreeMap;
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to keep track of the progression of the algorithm.
+use crate::hash::{HashMap, HashSet};
+// The graph being explored, and the costs of its edges.
+use crate::{
+    dijkstra, dijkstra_backtrack, GraphLike, OverflowPolicy, PathError, PathResult,
+    ShortestPathResult, Weight,
+};
+
+// The tentative cost of a node, along with the node it was reached from.
+pub(crate) type Request<'a, N, W> = (&'a N, W, &'a N);
+
+// The requests of a phase, or the error which prevented computing them.
+pub(crate) type Requests<'a, G> =
+    Result<Vec<Request<'a, <G as GraphLike>::Node, <G as GraphLike>::Weight>>, PathError>;
+
+/// Finds the shortest path from `source` to `destination` in `graph` with the
+/// delta-stepping algorithm.
+///
+/// The nodes are kept in buckets covering `delta` units of cost each, and all
+/// the nodes of a bucket are relaxed together: first along the light edges,
+/// which cost at most `delta` and may refill the bucket, then along the heavy
+/// edges once the bucket is settled. This does more work than
+/// [`dijkstra`], but every phase can be spread over several threads, see
+/// `par_delta_stepping` with the "rayon" feature. A `delta` close to the
+/// average edge cost usually works well.
+///
+/// The buckets are indexed with [`Weight::to_usize`], so the query is handed
+/// over to [`dijkstra`] if the costs cannot be converted. The returned path
+/// and errors are the same as with [`dijkstra`].
+///
+/// # Panics
+///
+/// Panics if `delta` is zero.
+pub fn delta_stepping<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    delta: G::Weight,
+) -> PathResult<G::Node, G::Weight> {
+    delta_stepping_with(graph, source, destination, delta, requests)
+}
+
+// Delta-stepping, the requests of every phase being computed by the given
+// function from the nodes to relax and whether the light or the heavy edges
+// are followed.
+pub(crate) fn delta_stepping_with<'a, G, R>(
+    graph: &'a G,
+    source: &G::Node,
+    destination: &G::Node,
+    delta: G::Weight,
+    requests: R,
+) -> PathResult<G::Node, G::Weight>
+where
+    G: GraphLike,
+    R: Fn(&'a G, &[(&'a G::Node, G::Weight)], G::Weight, bool) -> Requests<'a, G>,
+{
+    assert!(delta > G::Weight::zero(), "delta must not be zero");
+
+    // The costs have to be converted to find their bucket.
+    let width = match delta.to_usize() {
+        Some(width) if graph.edges().all(|(_, _, cost)| cost.to_usize().is_some()) => width,
+        _ => return dijkstra(graph, source, destination),
+    };
+
+    // We work with the copies of the nodes stored in the graph, so that all
+    // the nodes we handle share the lifetime of the graph.
+    let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+    let destination = graph
+        .get_node(destination)
+        .ok_or(PathError::UnknownDestination)?;
+
+    let mut search = DeltaStepping {
+        progression: HashMap::default(),
+        origin: HashMap::default(),
+        buckets: BTreeMap::new(),
+        width,
+    };
+    // The nodes whose cost is final.
+    let mut settled = HashSet::default();
+    search.relax(vec![(source, G::Weight::zero(), source)])?;
+    search.origin.remove(source);
+
+    // We go straight to the next bucket holding some nodes, the buckets in
+    // between being empty.
+    while let Some(&current) = search.buckets.keys().next() {
+        // The nodes of the bucket, which are settled once it is empty.
+        let mut bucket = Vec::new();
+
+        // The light edges may bring new nodes into the bucket, so we relax
+        // them until it stays empty.
+        while search.buckets.contains_key(&current) {
+            let frontier = search.frontier(current);
+            let light = requests(graph, &frontier, delta, true)?;
+            bucket.extend(frontier);
+            search.relax(light)?;
+        }
+
+        // The heavy edges always lead to later buckets.
+        let heavy = requests(graph, &bucket, delta, false)?;
+        search.relax(heavy)?;
+        settled.extend(bucket.into_iter().map(|(node, _)| node));
+
+        // The destination is final once its bucket is settled.
+        if settled.contains(destination) {
+            let path =
+                dijkstra_backtrack(&search.origin, source, destination).ok_or(PathError::NoPath)?;
+            return Ok(ShortestPathResult {
+                nodes: path.into_iter().cloned().collect(),
+                cost: search.progression[destination],
+                settled: Some(settled.len()),
+                stats: None,
+            });
+        }
+    }
+
+    // All the buckets are empty, both nodes are simply not connected.
+    Err(PathError::NoPath)
+}
+
+// Computes sequentially the requests of a phase, i.e. the tentative costs of
+// the nodes reached from the given ones by a light or a heavy edge.
+fn requests<'a, G: GraphLike>(
+    graph: &'a G,
+    nodes: &[(&'a G::Node, G::Weight)],
+    delta: G::Weight,
+    light: bool,
+) -> Requests<'a, G> {
+    nodes
+        .iter()
+        .flat_map(|&(node, cost)| node_requests(graph, node, cost, delta, light))
+        .collect()
+}
+
+// Computes the requests of a single node for a phase.
+pub(crate) fn node_requests<'a, G: GraphLike>(
+    graph: &'a G,
+    node: &'a G::Node,
+    cost: G::Weight,
+    delta: G::Weight,
+    light: bool,
+) -> impl Iterator<Item = Result<Request<'a, G::Node, G::Weight>, PathError>> + 'a {
+    graph
+        .neighbors(node)
+        .filter(move |(_, edge_cost)| (*edge_cost <= delta) == light)
+        .map(move |(neighbor, edge_cost)| {
+            let new_cost = OverflowPolicy::default()
+                .add(cost, edge_cost)
+                .ok_or(PathError::Overflow)?;
+            Ok((neighbor, new_cost, node))
+        })
+}
+
+// The state of a delta-stepping search.
+struct DeltaStepping<'a, N, W> {
+    // The best known cost of every node reached so far.
+    progression: HashMap<&'a N, W>,
+    // The node every node was reached from, used to backtrack the path.
+    origin: HashMap<&'a N, &'a N>,
+    // The nodes of every bucket holding some, indexed by the position of the
+    // bucket, a node being pushed again every time its cost improves. The
+    // outdated entries are skipped. Only the buckets holding some nodes are
+    // stored, so that a few expensive edges don't require allocating all the
+    // buckets before them.
+    buckets: BTreeMap<usize, Vec<&'a N>>,
+    // The range of costs covered by every bucket.
+    width: usize,
+}
+
+impl<'a, N: Eq + Hash, W: Weight> DeltaStepping<'a, N, W> {
+    // Applies the requests which improve the cost of their node.
+    fn relax(&mut self, requests: Vec<Request<'a, N, W>>) -> Result<(), PathError> {
+        for (node, cost, from) in requests {
+            if self.progression.get(node).is_some_and(|old| *old <= cost) {
+                continue;
+            }
+            let bucket = cost.to_usize().ok_or(PathError::Overflow)? / self.width;
+            self.buckets.entry(bucket).or_default().push(node);
+            self.progression.insert(node, cost);
+            self.origin.insert(node, from);
+        }
+        Ok(())
+    }
+
+    // Empties a bucket, returning its nodes along with their cost. The
+    // outdated entries and the duplicates are dropped.
+    fn frontier(&mut self, bucket: usize) -> Vec<(&'a N, W)> {
+        let mut seen = HashSet::default();
+        self.buckets
+            .remove(&bucket)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|node| {
+                let cost = self.progression[node];
+                cost.to_usize().map(|cost| cost / self.width) == Some(bucket) && seen.insert(*node)
+            })
+            .map(|node| (node, self.progression[node]))
+            .collect()
+    }
+}
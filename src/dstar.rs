@@ -0,0 +1,374 @@
+// Used to order the nodes by key.
+use std::cmp::Reverse;
+// Used as priority queue by the search, and to walk the plan.
+use std::collections::{BinaryHeap, VecDeque};
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to find the index of the nodes, and to store the edges.
+use crate::hash::HashMap;
+// The graph being explored, and the paths found in it.
+use crate::{
+    GraphLike, OverflowPolicy, PathError, PathResult, SearchStats, ShortestPathResult, Weight,
+};
+
+/// The plan of an agent heading to a goal through a graph whose edges change
+/// as it moves, kept up to date with D* Lite, following Koenig and
+/// Likhachev.
+///
+/// A robot discovering obstacles, or a unit of a game whose map changes, has
+/// to plan its path again every time it learns about a change, usually from
+/// the node it has moved to since. D* Lite searches backward from the goal,
+/// guided by a heuristic like [`astar`](crate::astar), and keeps the state of
+/// its search between the plans: once the edges changed, only the nodes
+/// whose cost to the goal changed are searched again, which is usually a
+/// small part of the nodes of the first search. It is Lifelong Planning A*
+/// searching backward, with the keys of the nodes corrected as the start of
+/// the plan moves.
+///
+/// The graph is copied by [`DStarLite::new`], after which
+/// [`DStarLite::plan`] returns the path from the current start to the goal.
+/// The agent moves with [`DStarLite::move_to`], and the edges change with
+/// [`DStarLite::update_edge`] and [`DStarLite::remove_edge`], in any order,
+/// the next plan taking all of them into account.
+pub struct DStarLite<N, W, H> {
+    // The nodes of the graph, by position.
+    nodes: Vec<N>,
+    // The position of every node.
+    index: HashMap<N, usize>,
+    // The cost of the edges leaving every node.
+    outgoing: Vec<HashMap<usize, W>>,
+    // The cost of the edges entering every node.
+    incoming: Vec<HashMap<usize, W>>,
+    // The position of the node the agent is on.
+    start: usize,
+    // The position of the node the agent heads to.
+    goal: usize,
+    // The estimate of the cost between two nodes.
+    heuristic: H,
+    // The cost of the path from every node to the goal found by the search,
+    // None while it found none.
+    costs: Vec<Option<W>>,
+    // The cost of the path from every node to the goal through the best of
+    // its successors, None if none of them has a path. The node has to be
+    // searched again whenever it differs from its cost.
+    lookahead: Vec<Option<W>>,
+    // The nodes to search again, along with their key, the lowest one first.
+    // A node is pushed again whenever its key changes.
+    queue: BinaryHeap<Reverse<((W, W), usize)>>,
+    // The current key of every node to search again.
+    keys: Vec<Option<(W, W)>>,
+    // The sum of the estimates of the moves of the start since the search
+    // began, which corrects the keys of the nodes pushed before them.
+    correction: W,
+    // The node the start was on when the edges last changed.
+    last_start: usize,
+    // The number of nodes pushed since the search began.
+    pushes: usize,
+}
+
+impl<N, W, H> DStarLite<N, W, H>
+where
+    N: Eq + Hash + Clone,
+    W: Weight,
+    H: FnMut(&N, &N) -> W,
+{
+    /// Copies `graph`, and prepares the plan of an agent going from `start`
+    /// to `goal`.
+    ///
+    /// `heuristic` estimates the cost of the path between any two nodes. It
+    /// must be consistent, i.e. the estimate between two nodes must never
+    /// exceed the cost of an edge leaving the first one plus the estimate
+    /// from the other end, see [`astar`](crate::astar). A heuristic always
+    /// returning zero gives the right paths, but searches more nodes.
+    ///
+    /// The edges leading out of the graph are copied as well, along with the
+    /// nodes they lead to. Fails with [`PathError::UnknownSource`] if the
+    /// start is not part of the graph, and with
+    /// [`PathError::UnknownDestination`] if the goal is not.
+    pub fn new<G>(graph: &G, start: &N, goal: &N, heuristic: H) -> Result<Self, PathError>
+    where
+        G: GraphLike<Node = N, Weight = W>,
+    {
+        let start_node = graph.get_node(start).ok_or(PathError::UnknownSource)?;
+        let goal_node = graph.get_node(goal).ok_or(PathError::UnknownDestination)?;
+        let mut planner = DStarLite {
+            nodes: Vec::new(),
+            index: HashMap::default(),
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+            start: 0,
+            goal: 0,
+            heuristic,
+            costs: Vec::new(),
+            lookahead: Vec::new(),
+            queue: BinaryHeap::new(),
+            keys: Vec::new(),
+            correction: W::zero(),
+            last_start: 0,
+            pushes: 0,
+        };
+        for node in graph.nodes() {
+            planner.position(node);
+        }
+        for (from, to, cost) in graph.edges() {
+            let (from, to) = (planner.position(from), planner.position(to));
+            planner.outgoing[from].insert(to, cost);
+            planner.incoming[to].insert(from, cost);
+        }
+        planner.start = planner.index[start_node];
+        planner.last_start = planner.start;
+        planner.goal = planner.index[goal_node];
+
+        // The search starts from the goal.
+        let goal = planner.goal;
+        planner.lookahead[goal] = Some(W::zero());
+        planner.push(goal, (W::zero(), W::zero()));
+        Ok(planner)
+    }
+
+    /// The node the agent is on.
+    pub fn start(&self) -> &N {
+        &self.nodes[self.start]
+    }
+
+    /// The node the agent heads to.
+    pub fn goal(&self) -> &N {
+        &self.nodes[self.goal]
+    }
+
+    /// Finds the shortest path from the start to the goal, searching again
+    /// the nodes affected by the changes since the last plan.
+    ///
+    /// The query fails with [`PathError::NoPath`] if the goal cannot be
+    /// reached from the start, and with [`PathError::Overflow`] if the cost
+    /// of a path overflows. The statistics count the nodes searched by this
+    /// plan only.
+    pub fn plan(&mut self) -> PathResult<N, W> {
+        let stats = self.search()?;
+        let cost = self.costs[self.start].ok_or(PathError::NoPath)?;
+
+        // We follow the best successors, which lead to the goal along a
+        // shortest path. A node may have several of them, some of which lead
+        // back to the nodes already walked through edges of zero cost, so we
+        // walk all of them breadth first, reaching every node once, and keep
+        // the node we reached every node from.
+        let policy = OverflowPolicy::default();
+        let mut previous = vec![None; self.nodes.len()];
+        previous[self.start] = Some(self.start);
+        let mut walk = VecDeque::new();
+        walk.push_back(self.start);
+        while previous[self.goal].is_none() {
+            let node = walk.pop_front().ok_or(PathError::NoPath)?;
+            let mut best = None;
+            let mut successors = Vec::new();
+            for (&next, &edge_cost) in &self.outgoing[node] {
+                if let Some(next_cost) = self.costs[next] {
+                    let total = policy
+                        .add(edge_cost, next_cost)
+                        .ok_or(PathError::Overflow)?;
+                    if best.is_none_or(|best| total < best) {
+                        best = Some(total);
+                        successors.clear();
+                    }
+                    if best == Some(total) {
+                        successors.push(next);
+                    }
+                }
+            }
+            for next in successors {
+                if previous[next].is_none() {
+                    previous[next] = Some(node);
+                    walk.push_back(next);
+                }
+            }
+        }
+        let mut path = vec![self.goal];
+        let mut node = self.goal;
+        while node != self.start {
+            node = previous[node].expect("every reached node has a previous one");
+            path.push(node);
+        }
+        path.reverse();
+
+        Ok(ShortestPathResult {
+            nodes: path
+                .into_iter()
+                .map(|node| self.nodes[node].clone())
+                .collect(),
+            cost,
+            settled: Some(stats.settled),
+            stats: Some(stats),
+        })
+    }
+
+    /// Moves the agent to `node`, which becomes the start of the next plans.
+    ///
+    /// The agent usually moves to the second node of the last plan, but it
+    /// may move anywhere. Fails with [`PathError::UnknownSource`] if the
+    /// node is not part of the graph.
+    pub fn move_to(&mut self, node: &N) -> Result<(), PathError> {
+        self.start = *self.index.get(node).ok_or(PathError::UnknownSource)?;
+        Ok(())
+    }
+
+    /// Sets the cost of the edge from `from` to `to`, which is added if the
+    /// graph didn't have it, along with its nodes.
+    ///
+    /// Fails with [`PathError::Overflow`] if the cost of a path overflows.
+    pub fn update_edge(&mut self, from: &N, to: &N, cost: W) -> Result<(), PathError> {
+        self.correct();
+        let (from, to) = (self.position(from), self.position(to));
+        self.outgoing[from].insert(to, cost);
+        self.incoming[to].insert(from, cost);
+        self.update(from)
+    }
+
+    /// Removes the edge from `from` to `to`, if the graph has it.
+    ///
+    /// The errors are the same as with [`DStarLite::update_edge`].
+    pub fn remove_edge(&mut self, from: &N, to: &N) -> Result<(), PathError> {
+        let (from, to) = match (self.index.get(from), self.index.get(to)) {
+            (Some(&from), Some(&to)) => (from, to),
+            _ => return Ok(()),
+        };
+        self.correct();
+        if self.outgoing[from].remove(&to).is_none() {
+            return Ok(());
+        }
+        self.incoming[to].remove(&from);
+        self.update(from)
+    }
+
+    // The position of the node, which is added if it was not known yet.
+    fn position(&mut self, node: &N) -> usize {
+        if let Some(&position) = self.index.get(node) {
+            return position;
+        }
+        let position = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.index.insert(node.clone(), position);
+        self.outgoing.push(HashMap::default());
+        self.incoming.push(HashMap::default());
+        self.costs.push(None);
+        self.lookahead.push(None);
+        self.keys.push(None);
+        position
+    }
+
+    // Accounts for the moves of the start since the edges last changed: the
+    // keys of the nodes already pushed got lower than they would be now by at
+    // most the estimate of the move.
+    fn correct(&mut self) {
+        if self.last_start != self.start {
+            let (last_start, start) = (&self.nodes[self.last_start], &self.nodes[self.start]);
+            let estimate = (self.heuristic)(last_start, start);
+            self.correction = self.correction.saturating_add(estimate);
+            self.last_start = self.start;
+        }
+    }
+
+    // The key of the node, which orders the nodes to search again, or None
+    // for the nodes without any path, whose key is infinite. These nodes are
+    // never pushed, since their cost is their lookahead.
+    fn key(&mut self, node: usize) -> Option<(W, W)> {
+        let best = match (self.costs[node], self.lookahead[node]) {
+            (Some(cost), Some(lookahead)) => cost.min(lookahead),
+            (Some(best), None) | (None, Some(best)) => best,
+            (None, None) => return None,
+        };
+        let estimate = (self.heuristic)(&self.nodes[self.start], &self.nodes[node]);
+        let estimate = best
+            .saturating_add(estimate)
+            .saturating_add(self.correction);
+        Some((estimate, best))
+    }
+
+    // Pushes the node with the given key, replacing its previous key if any.
+    fn push(&mut self, node: usize, key: (W, W)) {
+        self.keys[node] = Some(key);
+        self.queue.push(Reverse((key, node)));
+        self.pushes += 1;
+    }
+
+    // Computes again the lookahead of the node, which is pushed if it
+    // differs from its cost.
+    fn update(&mut self, node: usize) -> Result<(), PathError> {
+        let policy = OverflowPolicy::default();
+        if node != self.goal {
+            let mut best = None;
+            for (&next, &edge_cost) in &self.outgoing[node] {
+                if let Some(next_cost) = self.costs[next] {
+                    let total = policy
+                        .add(edge_cost, next_cost)
+                        .ok_or(PathError::Overflow)?;
+                    best = Some(best.map_or(total, |best: W| best.min(total)));
+                }
+            }
+            self.lookahead[node] = best;
+        }
+        self.keys[node] = None;
+        if self.costs[node] != self.lookahead[node] {
+            if let Some(key) = self.key(node) {
+                self.push(node, key);
+            }
+        }
+        Ok(())
+    }
+
+    // The lowest key of the nodes to search again, skipping their outdated
+    // entries.
+    fn lowest(&mut self) -> Option<((W, W), usize)> {
+        while let Some(&Reverse((key, node))) = self.queue.peek() {
+            if self.keys[node] == Some(key) {
+                return Some((key, node));
+            }
+            self.queue.pop();
+        }
+        None
+    }
+
+    // Searches the nodes again until the cost of the start is known, and
+    // returns what the search did.
+    fn search(&mut self) -> Result<SearchStats, PathError> {
+        let mut stats = SearchStats::default();
+        let pushes = self.pushes;
+        while let Some((key, node)) = self.lowest() {
+            let start_key = self.key(self.start);
+            let start_consistent = self.costs[self.start] == self.lookahead[self.start];
+            if start_key.is_some_and(|start_key| key >= start_key) && start_consistent {
+                break;
+            }
+            self.queue.pop();
+            self.keys[node] = None;
+            stats.settled += 1;
+
+            // The key may have been lowered by a move of the start.
+            if let Some(new_key) = self.key(node).filter(|&new_key| key < new_key) {
+                self.push(node, new_key);
+                continue;
+            }
+            // The node has a cheaper path than before, which its
+            // predecessors may use, or its path got more expensive, in which
+            // case the node itself is searched again as well.
+            let cheaper = match (self.lookahead[node], self.costs[node]) {
+                (Some(lookahead), Some(cost)) => lookahead < cost,
+                (Some(_), None) => true,
+                (None, _) => false,
+            };
+            if cheaper {
+                self.costs[node] = self.lookahead[node];
+            } else {
+                self.costs[node] = None;
+                self.update(node)?;
+            }
+            let predecessors: Vec<usize> = self.incoming[node].keys().copied().collect();
+            for previous in predecessors {
+                stats.relaxations += 1;
+                self.update(previous)?;
+            }
+        }
+        stats.pushes = self.pushes - pushes;
+        Ok(stats)
+    }
+}
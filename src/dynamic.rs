@@ -0,0 +1,291 @@
+// Used to order the nodes by cost.
+use std::cmp::Reverse;
+// Used as priority queue by the repairs.
+use std::collections::BinaryHeap;
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to find the index of the nodes, and to store the edges.
+use crate::hash::HashMap;
+// The graph being explored, and the shortest paths found in it.
+use crate::{
+    GraphLike, OverflowPolicy, PathError, PathResult, SearchWorkspace, ShortestPathResult,
+    ShortestPathTree, Weight,
+};
+
+/// The shortest paths from a source to all the nodes of a graph whose edges
+/// change, kept up to date as they change, following Ramalingam and Reps.
+///
+/// The graph is copied, after which its edges are changed with
+/// [`DynamicShortestPaths::update_edge`] and
+/// [`DynamicShortestPaths::remove_edge`]. Rather than running the search
+/// again, every change only repairs the paths it affects: an edge getting
+/// cheaper improves the paths of the nodes it leads to, which are settled
+/// again from there, while an edge of a shortest path getting more expensive
+/// only affects the nodes reached through it, whose paths are searched again
+/// from the nodes not affected. A change of the cost of a road in live
+/// traffic usually affects a few nodes, which makes the repairs much faster
+/// than a new search. The costs must not be negative, just like with
+/// [`dijkstra`](crate::dijkstra).
+#[derive(Debug, Clone)]
+pub struct DynamicShortestPaths<N, W> {
+    // The position of the source.
+    source: usize,
+    // The nodes of the graph, by position.
+    nodes: Vec<N>,
+    // The position of every node.
+    index: HashMap<N, usize>,
+    // The cost of the edges leaving every node.
+    outgoing: Vec<HashMap<usize, W>>,
+    // The cost of the edges entering every node.
+    incoming: Vec<HashMap<usize, W>>,
+    // The cost of the shortest path to every node, None if it is not reached.
+    distances: Vec<Option<W>>,
+    // The node every node is reached from, None for the source and for the
+    // nodes which are not reached.
+    predecessors: Vec<Option<usize>>,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> DynamicShortestPaths<N, W> {
+    /// Copies `graph`, and finds the shortest paths from `source` to all the
+    /// nodes it can reach in it.
+    ///
+    /// The edges leading out of the graph are copied as well, along with the
+    /// nodes they lead to. The query fails with [`PathError::UnknownSource`]
+    /// if the source is not part of the graph, and with
+    /// [`PathError::Overflow`] if the cost of a path overflows.
+    pub fn new<G>(graph: &G, source: &N) -> Result<Self, PathError>
+    where
+        G: GraphLike<Node = N, Weight = W>,
+    {
+        let mut workspace = SearchWorkspace::new();
+        workspace.start(graph, source)?;
+        workspace.progress(graph, OverflowPolicy::default(), None, |_| false)?;
+
+        let mut paths = DynamicShortestPaths {
+            source: 0,
+            nodes: Vec::new(),
+            index: HashMap::default(),
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+            distances: Vec::new(),
+            predecessors: Vec::new(),
+        };
+        for node in graph.nodes() {
+            paths.position(node);
+        }
+        for (from, to, cost) in graph.edges() {
+            let (from, to) = (paths.position(from), paths.position(to));
+            paths.outgoing[from].insert(to, cost);
+            paths.incoming[to].insert(from, cost);
+        }
+        paths.source = paths.index[source];
+        for (&node, &cost) in workspace.progression() {
+            let position = paths.index[node];
+            paths.distances[position] = Some(cost);
+        }
+        for (&node, &origin) in workspace.origin() {
+            let position = paths.index[node];
+            paths.predecessors[position] = Some(paths.index[origin]);
+        }
+        Ok(paths)
+    }
+
+    /// The node all the paths start from.
+    pub fn source(&self) -> &N {
+        &self.nodes[self.source]
+    }
+
+    /// The cost of the shortest path from the source to `node`, or `None` if
+    /// the node is not reached.
+    pub fn distance(&self, node: &N) -> Option<W> {
+        self.distances[*self.index.get(node)?]
+    }
+
+    /// The node preceding `node` on its shortest path from the source, or
+    /// `None` for the source and for the nodes which are not reached.
+    pub fn predecessor(&self, node: &N) -> Option<&N> {
+        let predecessor = self.predecessors[*self.index.get(node)?]?;
+        Some(&self.nodes[predecessor])
+    }
+
+    /// Extracts the shortest path from the source to `destination`.
+    ///
+    /// The query fails with [`PathError::NoPath`] if the destination is not
+    /// reached. The returned path carries no statistics, since no search is
+    /// run to find it.
+    pub fn path_to(&self, destination: &N) -> PathResult<N, W> {
+        let destination = *self.index.get(destination).ok_or(PathError::NoPath)?;
+        let cost = self.distances[destination].ok_or(PathError::NoPath)?;
+        let mut path = vec![self.nodes[destination].clone()];
+        let mut location = destination;
+        while let Some(predecessor) = self.predecessors[location] {
+            path.push(self.nodes[predecessor].clone());
+            location = predecessor;
+        }
+        path.reverse();
+        Ok(ShortestPathResult {
+            nodes: path,
+            cost,
+            settled: None,
+            stats: None,
+        })
+    }
+
+    /// The current shortest paths, as a [`ShortestPathTree`] which no longer
+    /// changes with the edges.
+    pub fn tree(&self) -> ShortestPathTree<N, W> {
+        let reached = || (0..self.nodes.len()).filter(|&node| self.distances[node].is_some());
+        ShortestPathTree::new(
+            self.nodes[self.source].clone(),
+            reached()
+                .filter_map(|node| Some((self.nodes[node].clone(), self.distances[node]?)))
+                .collect(),
+            reached()
+                .filter_map(|node| {
+                    let predecessor = self.predecessors[node]?;
+                    Some((self.nodes[node].clone(), self.nodes[predecessor].clone()))
+                })
+                .collect(),
+        )
+    }
+
+    /// Sets the cost of the edge from `from` to `to`, which is added if the
+    /// graph didn't have it, along with its nodes, and repairs the paths it
+    /// affects.
+    ///
+    /// Fails with [`PathError::Overflow`] if the cost of a path overflows,
+    /// in which case the repair stops halfway and some of the paths may be
+    /// outdated.
+    pub fn update_edge(&mut self, from: &N, to: &N, cost: W) -> Result<(), PathError> {
+        let (from, to) = (self.position(from), self.position(to));
+        let old = self.outgoing[from].insert(to, cost);
+        self.incoming[to].insert(from, cost);
+        match old {
+            Some(old) if old < cost => self.lengthen(from, to),
+            _ => self.shorten(from, to, cost),
+        }
+    }
+
+    /// Removes the edge from `from` to `to`, if the graph has it, and
+    /// repairs the paths it affects.
+    ///
+    /// The errors are the same as with
+    /// [`DynamicShortestPaths::update_edge`].
+    pub fn remove_edge(&mut self, from: &N, to: &N) -> Result<(), PathError> {
+        let (from, to) = match (self.index.get(from), self.index.get(to)) {
+            (Some(&from), Some(&to)) => (from, to),
+            _ => return Ok(()),
+        };
+        if self.outgoing[from].remove(&to).is_none() {
+            return Ok(());
+        }
+        self.incoming[to].remove(&from);
+        self.lengthen(from, to)
+    }
+
+    // The position of the node, which is added if it was not known yet.
+    fn position(&mut self, node: &N) -> usize {
+        if let Some(&position) = self.index.get(node) {
+            return position;
+        }
+        let position = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.index.insert(node.clone(), position);
+        self.outgoing.push(HashMap::default());
+        self.incoming.push(HashMap::default());
+        self.distances.push(None);
+        self.predecessors.push(None);
+        position
+    }
+
+    // Repairs the paths after the edge between the given nodes got cheaper,
+    // or was added.
+    fn shorten(&mut self, from: usize, to: usize, cost: W) -> Result<(), PathError> {
+        let policy = OverflowPolicy::default();
+        let start = match self.distances[from] {
+            Some(start) => start,
+            None => return Ok(()),
+        };
+        let new_cost = policy.add(start, cost).ok_or(PathError::Overflow)?;
+        if self.distances[to].is_some_and(|old| old <= new_cost) || to == self.source {
+            return Ok(());
+        }
+        self.distances[to] = Some(new_cost);
+        self.predecessors[to] = Some(from);
+        let mut queue = BinaryHeap::new();
+        queue.push(Reverse((new_cost, to)));
+        self.settle(queue)
+    }
+
+    // Repairs the paths after the edge between the given nodes got more
+    // expensive, or was removed.
+    fn lengthen(&mut self, from: usize, to: usize) -> Result<(), PathError> {
+        let policy = OverflowPolicy::default();
+        if self.predecessors[to] != Some(from) {
+            return Ok(());
+        }
+
+        // The nodes reached through the edge are the nodes of the subtree it
+        // leads to, whose paths are forgotten.
+        let mut affected = vec![to];
+        let mut next = 0;
+        while let Some(&node) = affected.get(next) {
+            next += 1;
+            for &neighbor in self.outgoing[node].keys() {
+                if self.predecessors[neighbor] == Some(node) {
+                    affected.push(neighbor);
+                }
+            }
+        }
+        for &node in &affected {
+            self.distances[node] = None;
+            self.predecessors[node] = None;
+        }
+
+        // Their new paths start from the nodes which were not affected, whose
+        // paths didn't change.
+        let mut queue = BinaryHeap::new();
+        for &node in &affected {
+            let mut best = None;
+            for (&previous, &edge_cost) in &self.incoming[node] {
+                if let Some(start) = self.distances[previous] {
+                    let cost = policy.add(start, edge_cost).ok_or(PathError::Overflow)?;
+                    if best.is_none_or(|(best, _)| cost < best) {
+                        best = Some((cost, previous));
+                    }
+                }
+            }
+            if let Some((cost, previous)) = best {
+                self.distances[node] = Some(cost);
+                self.predecessors[node] = Some(previous);
+                queue.push(Reverse((cost, node)));
+            }
+        }
+        self.settle(queue)
+    }
+
+    // Settles the nodes of the queue, whose path improved, and improves the
+    // paths of their neighbors in turn.
+    fn settle(&mut self, mut queue: BinaryHeap<Reverse<(W, usize)>>) -> Result<(), PathError> {
+        let policy = OverflowPolicy::default();
+        while let Some(Reverse((cost, node))) = queue.pop() {
+            // The outdated entries are skipped.
+            if self.distances[node].is_some_and(|best| best < cost) {
+                continue;
+            }
+            for (&neighbor, &edge_cost) in &self.outgoing[node] {
+                let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+                if neighbor == self.source
+                    || self.distances[neighbor].is_some_and(|old| old <= new_cost)
+                {
+                    continue;
+                }
+                self.distances[neighbor] = Some(new_cost);
+                self.predecessors[neighbor] = Some(node);
+                queue.push(Reverse((new_cost, neighbor)));
+            }
+        }
+        Ok(())
+    }
+}
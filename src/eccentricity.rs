@@ -0,0 +1,134 @@
+// Used to pick the nodes of the sampled measures.
+use crate::random::Random;
+// The graph being measured, and the searches measuring it.
+use crate::{GraphLike, OverflowPolicy, PathError, SearchWorkspace, Weight};
+
+/// Computes the eccentricity of `node` in `graph`, i.e. the cost of the
+/// shortest path from it to the farthest node.
+///
+/// A single search is run from the node, in O((V + E) log V) time. The query
+/// fails with [`PathError::UnknownSource`] if the node is not part of the
+/// graph, with [`PathError::NoPath`] if some node cannot be reached from it,
+/// its eccentricity being infinite, and with [`PathError::Overflow`] if the
+/// cost of a path overflows.
+pub fn eccentricity<G: GraphLike>(graph: &G, node: &G::Node) -> Result<G::Weight, PathError> {
+    let node_count = graph.node_count();
+    let mut workspace = SearchWorkspace::new();
+    farthest(graph, &mut workspace, node, node_count)?.ok_or(PathError::NoPath)
+}
+
+/// Computes the diameter of `graph`, i.e. the largest eccentricity of its
+/// nodes, which is the cost of the longest of all the shortest paths.
+///
+/// A search is run from every node, so this takes O(V (V + E) log V) time;
+/// [`diameter_sampled`] bounds the diameter of the large graphs instead. A
+/// graph without any node has a diameter of zero. The query fails with
+/// [`PathError::NoPath`] if some node cannot reach another one, the
+/// diameter being infinite, and with [`PathError::Overflow`] if the cost of
+/// a path overflows.
+pub fn diameter<G: GraphLike>(graph: &G) -> Result<G::Weight, PathError> {
+    largest(graph, graph.nodes().collect())
+}
+
+/// Computes the radius of `graph`, i.e. the smallest eccentricity of its
+/// nodes, the cost from the most central node to the farthest node.
+///
+/// Only the nodes which reach every other node have a finite eccentricity,
+/// and the query fails with [`PathError::NoPath`] if there is none. The cost
+/// and the other errors are the same as with [`diameter`], and
+/// [`radius_sampled`] bounds the radius of the large graphs.
+pub fn radius<G: GraphLike>(graph: &G) -> Result<G::Weight, PathError> {
+    smallest(graph, graph.nodes().collect())
+}
+
+/// Bounds the diameter of `graph` from below with the eccentricities of
+/// `samples` nodes picked at random.
+///
+/// This runs `samples` searches instead of one per node, and the largest
+/// eccentricity found is often the diameter itself, since the farthest
+/// nodes of most nodes are the ends of the longest shortest paths. The same
+/// `seed` always picks the same nodes, and every node is used if there are
+/// fewer than `samples`, which gives the exact diameter.
+///
+/// The errors are the same as with [`diameter`], except that a node which
+/// cannot reach another one is only found if it is sampled.
+pub fn diameter_sampled<G: GraphLike>(
+    graph: &G,
+    samples: usize,
+    seed: u64,
+) -> Result<G::Weight, PathError> {
+    let mut nodes = graph.nodes().collect();
+    Random::new(seed).sample(&mut nodes, samples);
+    largest(graph, nodes)
+}
+
+/// Bounds the radius of `graph` from above with the eccentricities of
+/// `samples` nodes picked at random.
+///
+/// This is the counterpart of [`diameter_sampled`], the smallest
+/// eccentricity found being the radius as soon as a most central node is
+/// sampled. The query fails with [`PathError::NoPath`] if none of the
+/// sampled nodes reaches every other node, the other errors being the same as
+/// with [`radius`].
+pub fn radius_sampled<G: GraphLike>(
+    graph: &G,
+    samples: usize,
+    seed: u64,
+) -> Result<G::Weight, PathError> {
+    let mut nodes = graph.nodes().collect();
+    Random::new(seed).sample(&mut nodes, samples);
+    smallest(graph, nodes)
+}
+
+// The largest eccentricity of the given nodes, zero if there is none.
+fn largest<G: GraphLike>(graph: &G, nodes: Vec<&G::Node>) -> Result<G::Weight, PathError> {
+    let node_count = graph.node_count();
+    let mut workspace = SearchWorkspace::new();
+    let mut largest = G::Weight::zero();
+    for node in nodes {
+        let eccentricity =
+            farthest(graph, &mut workspace, node, node_count)?.ok_or(PathError::NoPath)?;
+        largest = largest.max(eccentricity);
+    }
+    Ok(largest)
+}
+
+// The smallest finite eccentricity of the given nodes, zero if there is no
+// node at all.
+fn smallest<G: GraphLike>(graph: &G, nodes: Vec<&G::Node>) -> Result<G::Weight, PathError> {
+    if nodes.is_empty() {
+        return Ok(G::Weight::zero());
+    }
+    let node_count = graph.node_count();
+    let mut workspace = SearchWorkspace::new();
+    let mut smallest = None;
+    for node in nodes {
+        if let Some(eccentricity) = farthest(graph, &mut workspace, node, node_count)? {
+            smallest = Some(smallest.map_or(eccentricity, |old: G::Weight| old.min(eccentricity)));
+        }
+    }
+    smallest.ok_or(PathError::NoPath)
+}
+
+// The eccentricity of the node in the graph, which has node_count nodes, or
+// None if some node cannot be reached from it.
+fn farthest<'g, G: GraphLike>(
+    graph: &'g G,
+    workspace: &mut SearchWorkspace<'g, G>,
+    node: &G::Node,
+    node_count: usize,
+) -> Result<Option<G::Weight>, PathError> {
+    workspace.start(graph, node)?;
+    workspace.progress(graph, OverflowPolicy::default(), None, |_| false)?;
+    // The nodes reached through the edges leading out of the graph don't
+    // count.
+    let mut reached = 0;
+    let mut farthest = G::Weight::zero();
+    for (node, &cost) in workspace.progression() {
+        if graph.contains_node(node) {
+            reached += 1;
+            farthest = farthest.max(cost);
+        }
+    }
+    Ok((reached == node_count).then_some(farthest))
+}
@@ -0,0 +1,109 @@
+// Used to read the lines of the edge lists.
+use std::io::BufRead;
+// Used to remember the type of the costs.
+use std::marker::PhantomData;
+// Used to parse the costs of the edges.
+use std::str::FromStr;
+
+// The errors found while reading.
+use crate::EdgeListError;
+
+/// Reads the edges of a graph from a text edge list, one at a time.
+///
+/// Every line holds one edge as its source, its destination and its cost,
+/// separated by whitespace, and nothing else. The blank lines and the lines
+/// starting with `#` are skipped. The nodes are returned as `String`s, and the
+/// costs are parsed with their `FromStr` implementation:
+///
+/// ```text
+/// # source destination cost
+/// Paris Lyon 465
+/// Lyon Marseille 315
+/// ```
+///
+/// The lines are read lazily from `reader`, which is typically a
+/// `BufReader` over a file, so that the whole file never has to be held in
+/// memory. The returned iterator is meant to be fed to a
+/// [`StreamingBuilder`](crate::StreamingBuilder).
+pub fn read_edge_list<R: BufRead, W: FromStr>(reader: R) -> EdgeListReader<R, W> {
+    EdgeListReader {
+        reader,
+        line: String::new(),
+        line_number: 0,
+        cost: PhantomData,
+    }
+}
+
+/// The iterator over the edges of a text edge list, see [`read_edge_list`].
+///
+/// Every item is either an edge, or the error found on the line it was
+/// expected. The iteration may go on after an error.
+#[derive(Debug)]
+pub struct EdgeListReader<R, W> {
+    // Where the lines are read from.
+    reader: R,
+    // The last line read, kept to reuse its memory.
+    line: String,
+    // The number of the last line read, starting from 1.
+    line_number: usize,
+    // The type of the costs being parsed.
+    cost: PhantomData<W>,
+}
+
+impl<R: BufRead, W: FromStr> Iterator for EdgeListReader<R, W> {
+    type Item = Result<(String, String, W), EdgeListError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => self.line_number += 1,
+                Err(error) => return Some(Err(EdgeListError::Io(error))),
+            }
+
+            if let Some(edge) = parse_line(&self.line, self.line_number) {
+                return Some(edge);
+            }
+        }
+    }
+}
+
+// Parses the edge held by the line with the given number, if any, the blank
+// lines and the comments holding no edge.
+pub(crate) fn parse_line<W: FromStr>(
+    line: &str,
+    line_number: usize,
+) -> Option<Result<(String, String, W), EdgeListError>> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        None
+    } else {
+        Some(parse_edge(line, line_number))
+    }
+}
+
+// Parses the edge held by the line with the given number.
+fn parse_edge<W: FromStr>(
+    line: &str,
+    line_number: usize,
+) -> Result<(String, String, W), EdgeListError> {
+    let mut fields = line.split_whitespace();
+    let mut field = || {
+        fields
+            .next()
+            .ok_or(EdgeListError::MissingField { line: line_number })
+    };
+
+    let source = field()?.to_string();
+    let destination = field()?.to_string();
+    let cost = field()?
+        .parse()
+        .map_err(|_| EdgeListError::InvalidCost { line: line_number })?;
+
+    // A line with more fields doesn't follow the format.
+    if fields.next().is_some() {
+        return Err(EdgeListError::TrailingField { line: line_number });
+    }
+    Ok((source, destination, cost))
+}
@@ -0,0 +1,287 @@
+// Used to describe the errors to the user.
+use std::error::Error;
+use std::fmt;
+
+/// The reasons why no shortest path could be returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathError {
+    /// The source of the query is not part of the graph.
+    UnknownSource,
+    /// The destination of the query is not part of the graph.
+    UnknownDestination,
+    /// Both nodes are in the graph, but the destination cannot be reached from
+    /// the source.
+    NoPath,
+    /// The cost of a path overflowed its type, see
+    /// [`OverflowPolicy::Checked`](crate::OverflowPolicy::Checked).
+    Overflow,
+    /// The source can reach a cycle whose total cost is negative, so that
+    /// the paths through it can be made as cheap as wanted, see
+    /// [`bellman_ford`](crate::bellman_ford).
+    NegativeCycle,
+    /// The graph has a cycle, while the algorithm only works on directed
+    /// acyclic graphs, see [`topological_sort`](crate::topological_sort).
+    NotAcyclic,
+    /// A cycle whose total cost is zero lies on the shortest paths, so that
+    /// there are infinitely many of them, see
+    /// [`shortest_path_count`](crate::shortest_path_count).
+    ZeroCostCycle,
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::UnknownSource => write!(f, "the source is not part of the graph"),
+            PathError::UnknownDestination => {
+                write!(f, "the destination is not part of the graph")
+            }
+            PathError::NoPath => write!(f, "the destination cannot be reached from the source"),
+            PathError::Overflow => write!(f, "the cost of a path overflowed"),
+            PathError::NegativeCycle => {
+                write!(f, "the source can reach a cycle of negative cost")
+            }
+            PathError::NotAcyclic => write!(f, "the graph has a cycle"),
+            PathError::ZeroCostCycle => {
+                write!(f, "a cycle of zero cost lies on the shortest paths")
+            }
+        }
+    }
+}
+
+impl Error for PathError {}
+
+/// An edge which two merged graphs both contain with different costs, see
+/// [`MergePolicy::Error`](crate::MergePolicy::Error).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict<N, W> {
+    /// The source of the conflicting edge.
+    pub source: N,
+    /// The destination of the conflicting edge.
+    pub destination: N,
+    /// The cost of the edge in the graph being merged into.
+    pub existing: W,
+    /// The cost of the edge in the graph being merged.
+    pub incoming: W,
+}
+
+impl<N: fmt::Debug, W: fmt::Debug> fmt::Display for MergeConflict<N, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the edge from {:?} to {:?} costs {:?} in one graph and {:?} in the other",
+            self.source, self.destination, self.existing, self.incoming
+        )
+    }
+}
+
+impl<N: fmt::Debug, W: fmt::Debug> Error for MergeConflict<N, W> {}
+
+/// The cycle which prevents a graph from being sorted, see
+/// [`topological_sort`](crate::topological_sort).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<N> {
+    /// The nodes of the cycle in the order of its edges, the first node
+    /// being repeated at the end.
+    pub cycle: Vec<N>,
+}
+
+impl<N: fmt::Debug> fmt::Display for CycleError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the graph has a cycle going through {:?}", self.cycle)
+    }
+}
+
+impl<N: fmt::Debug> Error for CycleError<N> {}
+
+/// The reasons why a graph has no Eulerian path or circuit, see
+/// [`eulerian_path`](crate::eulerian_path) and
+/// [`eulerian_circuit`](crate::eulerian_circuit).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EulerError<N> {
+    /// The node has too many incoming or outgoing edges: a walk leaves every
+    /// node as many times as it enters it, but for its ends.
+    Unbalanced {
+        /// The node, the first one of the graph found unbalanced.
+        node: N,
+        /// The number of edges entering the node.
+        incoming: usize,
+        /// The number of edges leaving the node.
+        outgoing: usize,
+    },
+    /// Some edges cannot be reached from the others, so that a single walk
+    /// cannot follow all of them.
+    Disconnected {
+        /// The node the walk started from.
+        first: N,
+        /// A node with edges the walk could not reach.
+        second: N,
+    },
+}
+
+impl<N: fmt::Debug> fmt::Display for EulerError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EulerError::Unbalanced {
+                node,
+                incoming,
+                outgoing,
+            } => write!(
+                f,
+                "{:?} has {} incoming and {} outgoing edges",
+                node, incoming, outgoing
+            ),
+            EulerError::Disconnected { first, second } => write!(
+                f,
+                "the edges of {:?} cannot be reached from {:?}",
+                second, first
+            ),
+        }
+    }
+}
+
+impl<N: fmt::Debug> Error for EulerError<N> {}
+
+/// The reasons why no Hamiltonian path or cycle was returned, see
+/// [`hamiltonian_path`](crate::hamiltonian_path) and
+/// [`hamiltonian_cycle`](crate::hamiltonian_cycle).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HamiltonianError {
+    /// The graph has more nodes than the search was allowed to handle.
+    TooManyNodes {
+        /// The number of nodes of the graph.
+        count: usize,
+        /// The largest number of nodes allowed.
+        max_nodes: usize,
+    },
+    /// The graph has no Hamiltonian path or cycle.
+    NotFound,
+}
+
+impl fmt::Display for HamiltonianError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HamiltonianError::TooManyNodes { count, max_nodes } => write!(
+                f,
+                "the graph has {} nodes, more than the {} allowed",
+                count, max_nodes
+            ),
+            HamiltonianError::NotFound => {
+                write!(f, "no path goes through every node once")
+            }
+        }
+    }
+}
+
+impl Error for HamiltonianError {}
+
+/// The reasons why a floating point cost was rejected, see
+/// [`FloatWeight::new`](crate::FloatWeight::new).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidWeight {
+    /// The cost is NaN, which cannot be compared to the other costs.
+    NaN,
+    /// The cost is negative, which Dijkstra's algorithm does not support.
+    Negative,
+}
+
+impl fmt::Display for InvalidWeight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidWeight::NaN => write!(f, "the cost is NaN"),
+            InvalidWeight::Negative => write!(f, "the cost is negative"),
+        }
+    }
+}
+
+impl Error for InvalidWeight {}
+
+/// The reasons why bytes could not be read as a saved graph, see
+/// [`MappedGraph::new`](crate::MappedGraph::new).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryFormatError {
+    /// The bytes are too short for the header, or for the arrays it
+    /// announces.
+    Truncated,
+    /// The bytes don't start with the magic bytes of a saved graph.
+    BadMagic,
+    /// The graph was saved with an unknown version of the layout.
+    UnsupportedVersion(u32),
+    /// The names of the nodes are not valid UTF-8.
+    InvalidUtf8,
+    /// The sizes found in the header don't match the arrays.
+    Inconsistent,
+}
+
+impl fmt::Display for BinaryFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryFormatError::Truncated => write!(f, "the saved graph is truncated"),
+            BinaryFormatError::BadMagic => write!(f, "the bytes are not a saved graph"),
+            BinaryFormatError::UnsupportedVersion(version) => {
+                write!(f, "the version {} of the layout is not supported", version)
+            }
+            BinaryFormatError::InvalidUtf8 => write!(f, "the names of the nodes are not UTF-8"),
+            BinaryFormatError::Inconsistent => {
+                write!(f, "the header doesn't match the arrays of the saved graph")
+            }
+        }
+    }
+}
+
+impl Error for BinaryFormatError {}
+
+/// The errors found while reading a text edge list, see
+/// [`read_edge_list`](crate::read_edge_list).
+#[derive(Debug)]
+pub enum EdgeListError {
+    /// The edge list could not be read.
+    Io(std::io::Error),
+    /// The line with the given number, starting from 1, lacks the destination
+    /// or the cost of its edge.
+    MissingField {
+        /// The number of the line.
+        line: usize,
+    },
+    /// The cost on the line with the given number cannot be parsed.
+    InvalidCost {
+        /// The number of the line.
+        line: usize,
+    },
+    /// The line with the given number holds more than an edge.
+    TrailingField {
+        /// The number of the line.
+        line: usize,
+    },
+}
+
+impl fmt::Display for EdgeListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EdgeListError::Io(error) => write!(f, "the edge list could not be read: {}", error),
+            EdgeListError::MissingField { line } => {
+                write!(f, "the edge on line {} is incomplete", line)
+            }
+            EdgeListError::InvalidCost { line } => {
+                write!(f, "the cost on line {} is invalid", line)
+            }
+            EdgeListError::TrailingField { line } => {
+                write!(f, "line {} holds more than an edge", line)
+            }
+        }
+    }
+}
+
+impl Error for EdgeListError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EdgeListError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for EdgeListError {
+    fn from(error: std::io::Error) -> Self {
+        EdgeListError::Io(error)
+    }
+}
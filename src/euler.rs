@@ -0,0 +1,139 @@
+// Used to find the index of the nodes.
+use crate::hash::HashMap;
+// The graph being walked, and the reasons why it cannot be.
+use crate::{EulerError, GraphLike};
+
+/// Finds an Eulerian circuit of `graph`, i.e. a closed walk following every
+/// edge exactly once, with Hierholzer's algorithm.
+///
+/// Such a circuit exists if and only if every node has as many incoming
+/// edges as outgoing ones, and all the edges are connected to each other.
+/// The walk starts from the first node of [`GraphLike::nodes`] with an
+/// outgoing edge, follows the edges as long as possible, and splices in the
+/// circuits left from the nodes it went through, in O(V + E) time.
+///
+/// Returns the `(source, destination, cost)` edges in the order they are
+/// followed, every edge starting where the previous one ended and the last
+/// one ending where the first one started. The parallel edges are all
+/// followed, and a graph without any edge has an empty circuit. The reason
+/// why no circuit exists is returned otherwise, see [`EulerError`].
+pub fn eulerian_circuit<G: GraphLike>(graph: &G) -> Result<EulerWalk<G>, EulerError<G::Node>> {
+    eulerian_walk(graph, true)
+}
+
+/// Finds an Eulerian path of `graph`, i.e. a walk following every edge
+/// exactly once, with Hierholzer's algorithm.
+///
+/// Such a path exists if and only if all the edges are connected to each
+/// other, and every node has as many incoming edges as outgoing ones except
+/// for at most two nodes: the start of the path, with one more outgoing
+/// edge, and its end, with one more incoming edge. Without such nodes, the
+/// path is a circuit, found just like with [`eulerian_circuit`].
+///
+/// The returned edges and errors are the same as with [`eulerian_circuit`],
+/// except that the last edge may end elsewhere than where the first one
+/// started.
+pub fn eulerian_path<G: GraphLike>(graph: &G) -> Result<EulerWalk<G>, EulerError<G::Node>> {
+    eulerian_walk(graph, false)
+}
+
+/// The edges of an Eulerian path or circuit of a graph, in the order they
+/// are followed, see [`eulerian_path`] and [`eulerian_circuit`].
+pub type EulerWalk<G> = Vec<(
+    <G as GraphLike>::Node,
+    <G as GraphLike>::Node,
+    <G as GraphLike>::Weight,
+)>;
+
+// Finds an Eulerian circuit of the graph if closed is true, and an Eulerian
+// path otherwise.
+fn eulerian_walk<G: GraphLike>(
+    graph: &G,
+    closed: bool,
+) -> Result<EulerWalk<G>, EulerError<G::Node>> {
+    let nodes: Vec<&G::Node> = graph.nodes().collect();
+    let index: HashMap<&G::Node, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(position, &node)| (node, position))
+        .collect();
+
+    // We number the edges, those leading out of the graph being ignored, and
+    // count the edges entering every node.
+    let mut edges = Vec::new();
+    let mut outgoing = vec![Vec::new(); nodes.len()];
+    let mut incoming = vec![0; nodes.len()];
+    for (from, to, cost) in graph.edges() {
+        if let Some(&to) = index.get(to) {
+            let from = index[from];
+            outgoing[from].push(edges.len());
+            incoming[to] += 1;
+            edges.push((from, to, cost));
+        }
+    }
+
+    // Every node must be balanced, but for the two ends of an open path. The
+    // walk starts from the node with an extra outgoing edge if there is one.
+    let mut start = None;
+    let mut end = None;
+    for node in 0..nodes.len() {
+        let (entering, leaving) = (incoming[node], outgoing[node].len());
+        if entering == leaving {
+            continue;
+        }
+        if !closed && leaving == entering + 1 && start.is_none() {
+            start = Some(node);
+        } else if !closed && entering == leaving + 1 && end.is_none() {
+            end = Some(node);
+        } else {
+            return Err(EulerError::Unbalanced {
+                node: nodes[node].clone(),
+                incoming: entering,
+                outgoing: leaving,
+            });
+        }
+    }
+    let start = match start.or_else(|| (0..nodes.len()).find(|&node| !outgoing[node].is_empty())) {
+        Some(start) => start,
+        None => return Ok(Vec::new()),
+    };
+
+    // We walk along the edges not followed yet until getting stuck, which can
+    // only happen at the end of the walk. The edges are then taken back one
+    // by one, those taken back forming the walk in reverse order, until a
+    // node with edges left is found, from which the walk goes on.
+    let mut next = vec![0; nodes.len()];
+    let mut stack: Vec<(usize, Option<usize>)> = vec![(start, None)];
+    let mut walk = Vec::with_capacity(edges.len());
+    while let Some(&(node, edge)) = stack.last() {
+        match outgoing[node].get(next[node]) {
+            Some(&following) => {
+                next[node] += 1;
+                stack.push((edges[following].1, Some(following)));
+            }
+            None => {
+                stack.pop();
+                walk.extend(edge);
+            }
+        }
+    }
+
+    // The edges which were not followed cannot be reached from the others.
+    if walk.len() < edges.len() {
+        let missed = (0..nodes.len())
+            .find(|&node| next[node] < outgoing[node].len())
+            .expect("an edge was not followed");
+        return Err(EulerError::Disconnected {
+            first: nodes[start].clone(),
+            second: nodes[missed].clone(),
+        });
+    }
+    Ok(walk
+        .into_iter()
+        .rev()
+        .map(|edge| {
+            let (from, to, cost) = edges[edge];
+            (nodes[from].clone(), nodes[to].clone(), cost)
+        })
+        .collect())
+}
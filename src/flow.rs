@@ -0,0 +1,619 @@
+// Used to order the nodes by cost in the searches for the cheapest paths.
+use std::cmp::Reverse;
+// Used as priority queue by the searches for the cheapest paths.
+use std::collections::BinaryHeap;
+// Used to compute the capacities left on the edges, and the total cost of
+// the flows.
+use std::ops::{Mul, Sub};
+// Used to find the levels of the nodes.
+use std::collections::VecDeque;
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to find the index of the nodes.
+use crate::hash::HashMap;
+// The graph carrying the flow, the costs of its edges being capacities.
+use crate::{GraphLike, OverflowPolicy, PathError, Weight};
+
+/// Finds a maximum flow from `source` to `sink` in `graph`, with Dinic's
+/// algorithm.
+///
+/// The costs of the edges are read as capacities here: a flow sends some
+/// amount along every edge, at most its capacity, and every node but the
+/// source and the sink lets out as much as it receives. The flow is pushed
+/// along the shortest paths of the residual graph first, the one made of the
+/// capacities left on the edges and of the flows which can be sent back. A
+/// whole blocking flow is pushed along the paths of the same length at once,
+/// which takes O(V² E) time overall, and much less on most graphs.
+///
+/// The parallel edges carry their own flows, and the edges whose capacity is
+/// negative are left empty. The capacities have to support subtraction to
+/// compute what is left of them, which all the primitive integer types do.
+/// The query fails if one of the nodes is not part of the graph, or with
+/// [`PathError::Overflow`] if the value of the flow overflows. A node sends
+/// no flow to itself.
+pub fn max_flow<G>(
+    graph: &G,
+    source: &G::Node,
+    sink: &G::Node,
+) -> Result<MaxFlow<G::Node, G::Weight>, PathError>
+where
+    G: GraphLike,
+    G::Weight: Sub<Output = G::Weight>,
+{
+    let mut network = Network::new(graph, |_, _, capacity| capacity);
+    let source = *network.index.get(source).ok_or(PathError::UnknownSource)?;
+    let sink = *network
+        .index
+        .get(sink)
+        .ok_or(PathError::UnknownDestination)?;
+    let value = network.saturate(source, sink)?;
+    Ok(MaxFlow {
+        value,
+        flows: network.flows(),
+    })
+}
+
+/// A maximum flow, as returned by [`max_flow`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxFlow<N, W> {
+    /// The value of the flow, i.e. the total amount going from the source to
+    /// the sink.
+    pub value: W,
+    /// The `(source, destination, flow)` edges carrying some flow, in the
+    /// order of [`GraphLike::edges`].
+    pub flows: Vec<(N, N, W)>,
+}
+
+/// Finds a minimum cut between `source` and `sink` in `graph`, i.e. the
+/// cheapest set of edges to remove so that the sink cannot be reached from
+/// the source anymore.
+///
+/// By the max-flow min-cut theorem, the capacity of a minimum cut is the
+/// value of a maximum flow, which saturates every edge of the cut: these
+/// edges are the bottlenecks of the network, since adding capacity anywhere
+/// else cannot increase the flow. A maximum flow is found with [`max_flow`],
+/// and its residual graph tells the nodes the source can still send flow
+/// to, which are on its side of the cut, from all the other nodes.
+///
+/// The capacities and the errors are the same as with [`max_flow`]. A node
+/// is not separated from itself, so the cut between a node and itself is
+/// empty.
+pub fn min_cut<G>(
+    graph: &G,
+    source: &G::Node,
+    sink: &G::Node,
+) -> Result<MinCut<G::Node, G::Weight>, PathError>
+where
+    G: GraphLike,
+    G::Weight: Sub<Output = G::Weight>,
+{
+    let mut network = Network::new(graph, |_, _, capacity| capacity);
+    let source = *network.index.get(source).ok_or(PathError::UnknownSource)?;
+    let sink = *network
+        .index
+        .get(sink)
+        .ok_or(PathError::UnknownDestination)?;
+    let capacity = network.saturate(source, sink)?;
+
+    let sides = network.reachable(source);
+    let mut cut = MinCut {
+        capacity,
+        source_side: Vec::new(),
+        sink_side: Vec::new(),
+        edges: Vec::new(),
+    };
+    for (node, &side) in network.nodes.iter().zip(&sides) {
+        if side {
+            cut.source_side.push((*node).clone());
+        } else {
+            cut.sink_side.push((*node).clone());
+        }
+    }
+    for &(arc, capacity, _) in &network.edges {
+        let Arc { to, reverse, .. } = network.arcs[arc];
+        let from = network.arcs[reverse].to;
+        if sides[from] && !sides[to] && capacity > G::Weight::zero() {
+            cut.edges.push((
+                network.nodes[from].clone(),
+                network.nodes[to].clone(),
+                capacity,
+            ));
+        }
+    }
+    Ok(cut)
+}
+
+/// A minimum cut, as returned by [`min_cut`].
+///
+/// The nodes are split in two sides, and the cut is made of the edges going
+/// from the side of the source to the side of the sink. The nodes of every
+/// side are in no particular order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCut<N, W> {
+    /// The capacity of the cut, i.e. the sum of the capacities of its edges,
+    /// which is the value of a maximum flow.
+    pub capacity: W,
+    /// The nodes on the side of the source, the source included.
+    pub source_side: Vec<N>,
+    /// The nodes on the side of the sink, the sink included unless it is the
+    /// source.
+    pub sink_side: Vec<N>,
+    /// The `(source, destination, capacity)` edges of the cut, all saturated
+    /// by a maximum flow, in the order of [`GraphLike::edges`]. The edges
+    /// without any capacity are left out.
+    pub edges: Vec<(N, N, W)>,
+}
+
+/// Finds a maximum flow from `source` to `sink` in `graph` whose total cost
+/// is as small as possible, with the successive shortest paths algorithm.
+///
+/// Every edge carries both a cost, its weight in the graph, and a capacity,
+/// given by `capacity` from the source, the destination and the cost of the
+/// edge, so that the capacities can be stored anywhere, for instance as the
+/// data of the edges of a [`Graph`](crate::Graph). Sending some amount along
+/// an edge costs that amount times the cost of the edge. The flow is pushed
+/// along the cheapest path of the residual graph, over and over until the
+/// sink cannot be reached anymore. These paths are found by Dijkstra's
+/// algorithm, the costs being shifted by potentials so that none of them is
+/// negative: the potentials start from the costs found by Bellman-Ford if
+/// some edge has a negative cost, and are then updated with the costs of the
+/// cheapest paths after every search.
+///
+/// Every search takes O((V + E) log V) time, and there are at most as many
+/// as the value of the flow. The costs and the capacities have to support
+/// subtraction and multiplication, which all the primitive integer types do,
+/// the unsigned ones included since no cost is ever negated: the costs are
+/// only subtracted from larger ones. The edges whose capacity is negative
+/// are left empty. The query fails if one of the nodes is not part of the
+/// graph, with [`PathError::NegativeCycle`] if the edges which can carry
+/// some flow form a cycle of negative cost, and with [`PathError::Overflow`]
+/// if the value or the cost of the flow overflows. The product of an amount
+/// and of a cost is not checked though.
+pub fn min_cost_flow<G, F>(
+    graph: &G,
+    source: &G::Node,
+    sink: &G::Node,
+    capacity: F,
+) -> Result<MinCostFlow<G::Node, G::Weight>, PathError>
+where
+    G: GraphLike,
+    G::Weight: Sub<Output = G::Weight> + Mul<Output = G::Weight>,
+    F: FnMut(&G::Node, &G::Node, G::Weight) -> G::Weight,
+{
+    let policy = OverflowPolicy::default();
+    let mut network = Network::new(graph, capacity);
+    let source = *network.index.get(source).ok_or(PathError::UnknownSource)?;
+    let sink = *network
+        .index
+        .get(sink)
+        .ok_or(PathError::UnknownDestination)?;
+
+    let mut value = G::Weight::zero();
+    let mut potentials = network.potentials()?;
+    if source != sink {
+        while let Some((path, amount)) = network.cheapest_path(source, sink, &mut potentials)? {
+            value = policy.add(value, amount).ok_or(PathError::Overflow)?;
+            network.push(&path, amount);
+        }
+    }
+
+    // The total cost is summed over the edges rather than over the paths,
+    // whose costs are differences of potentials.
+    let mut cost = G::Weight::zero();
+    for &(arc, capacity, edge_cost) in &network.edges {
+        let flow = capacity - network.arcs[arc].left;
+        cost = policy
+            .add(cost, flow * edge_cost)
+            .ok_or(PathError::Overflow)?;
+    }
+
+    Ok(MinCostFlow {
+        value,
+        cost,
+        flows: network.flows(),
+    })
+}
+
+/// A maximum flow of minimum cost, as returned by [`min_cost_flow`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCostFlow<N, W> {
+    /// The value of the flow, i.e. the total amount going from the source to
+    /// the sink.
+    pub value: W,
+    /// The total cost of the flow, i.e. the sum of the flows on the edges
+    /// multiplied by their costs.
+    pub cost: W,
+    /// The `(source, destination, flow)` edges carrying some flow, in the
+    /// order of [`GraphLike::edges`].
+    pub flows: Vec<(N, N, W)>,
+}
+
+// The residual graph of a flow through a graph, whose nodes are numbered.
+// Every edge of the graph is an arc holding the capacity left on it, paired
+// with the reverse arc holding the flow which can be sent back. The arcs
+// leaving every node are stored next to each other, so that the searches
+// read them in order.
+//
+// The searches for the cheapest paths are run here rather than with
+// bellman_ford and the SearchWorkspace of the crate: those search a GraphLike
+// and remember the node every node was reached from, while the residual
+// graph may hold several arcs between the same nodes, an edge and the
+// reverse arc of another one for instance, which have to be told apart to
+// push the flow. Their capacities also change after every path.
+#[derive(Debug)]
+struct Network<'g, N, W> {
+    // The node associated with each index, as stored in the graph.
+    nodes: Vec<&'g N>,
+    // The index associated with each node.
+    index: HashMap<&'g N, usize>,
+    // The arcs leaving the node with index i are found between offsets[i]
+    // and offsets[i + 1].
+    offsets: Vec<usize>,
+    // The arcs of the residual graph.
+    arcs: Vec<Arc<W>>,
+    // The position of the forward arc of every edge of the graph, along with
+    // its capacity and its cost.
+    edges: Vec<(usize, W, W)>,
+}
+
+// An arc of the residual graph.
+#[derive(Debug, Clone, Copy)]
+struct Arc<W> {
+    // The index of the node the arc leads to.
+    to: usize,
+    // The capacity left on the arc.
+    left: W,
+    // The position of the edge the arc comes from in the edges of the
+    // network, the cost of the edge being only read by the searches for the
+    // cheapest paths.
+    edge: usize,
+    // The position of the reverse arc.
+    reverse: usize,
+}
+
+impl<'g, N, W> Network<'g, N, W>
+where
+    N: Eq + Hash + Clone,
+    W: Weight + Sub<Output = W>,
+{
+    // Builds the residual graph of the empty flow through the graph, the
+    // capacity of every edge being given from its ends and its cost.
+    fn new<G, F>(graph: &'g G, mut capacity: F) -> Self
+    where
+        G: GraphLike<Node = N, Weight = W>,
+        F: FnMut(&N, &N, W) -> W,
+    {
+        let nodes: Vec<&'g N> = graph.nodes().collect();
+        let index: HashMap<&'g N, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(position, &node)| (node, position))
+            .collect();
+
+        // The edges leading out of the graph cannot carry any flow.
+        let ends: Vec<(usize, usize, W, W)> = graph
+            .edges()
+            .filter_map(|(from, to, cost)| {
+                let capacity = capacity(from, to, cost).max(W::zero());
+                Some((index[from], *index.get(to)?, capacity, cost))
+            })
+            .collect();
+
+        // We count the arcs leaving every node, the reverse arcs included, to
+        // place them.
+        let mut offsets = vec![0; nodes.len() + 1];
+        for &(from, to, _, _) in &ends {
+            offsets[from + 1] += 1;
+            offsets[to + 1] += 1;
+        }
+        for node in 0..nodes.len() {
+            offsets[node + 1] += offsets[node];
+        }
+        let mut free = offsets.clone();
+        let placeholder = Arc {
+            to: 0,
+            left: W::zero(),
+            edge: 0,
+            reverse: 0,
+        };
+        let mut arcs = vec![placeholder; 2 * ends.len()];
+        let mut edges = Vec::with_capacity(ends.len());
+        for (from, to, capacity, cost) in ends {
+            // The slot of the forward arc is taken before looking for the
+            // slot of the reverse arc, which differ even for a loop.
+            let forward = free[from];
+            free[from] += 1;
+            let backward = free[to];
+            free[to] += 1;
+            arcs[forward] = Arc {
+                to,
+                left: capacity,
+                edge: edges.len(),
+                reverse: backward,
+            };
+            arcs[backward] = Arc {
+                to: from,
+                left: W::zero(),
+                edge: edges.len(),
+                reverse: forward,
+            };
+            edges.push((forward, capacity, cost));
+        }
+
+        Network {
+            nodes,
+            index,
+            offsets,
+            arcs,
+            edges,
+        }
+    }
+
+    // Pushes as much flow as possible from source to sink, returning the
+    // amount pushed.
+    fn saturate(&mut self, source: usize, sink: usize) -> Result<W, PathError> {
+        let policy = OverflowPolicy::default();
+        let mut value = W::zero();
+        if source == sink {
+            return Ok(value);
+        }
+
+        // Every phase pushes a blocking flow along the shortest paths, until
+        // the sink cannot be reached anymore.
+        while let Some(levels) = self.levels(source, sink) {
+            // The position of the next arc to try from every node, the arcs
+            // before it leading nowhere.
+            let mut next = self.offsets[..self.nodes.len()].to_vec();
+            // The arcs of the current path from the source.
+            let mut path: Vec<usize> = Vec::new();
+            let mut node = source;
+            loop {
+                if node == sink {
+                    // We push as much as the path can carry, saturating at
+                    // least one of its arcs.
+                    let amount = path
+                        .iter()
+                        .map(|&arc| self.arcs[arc].left)
+                        .min()
+                        .expect("the path leads to the sink");
+                    self.push(&path, amount);
+                    value = policy.add(value, amount).ok_or(PathError::Overflow)?;
+
+                    // The path is resumed before its first saturated arc.
+                    let saturated = path
+                        .iter()
+                        .position(|&arc| self.arcs[arc].left == W::zero())
+                        .expect("an arc was saturated");
+                    path.truncate(saturated);
+                    node = path.last().map_or(source, |&arc| self.arcs[arc].to);
+                    continue;
+                }
+
+                // We follow the next arc leading one level further, if any.
+                let end = self.offsets[node + 1];
+                while next[node] < end {
+                    let arc = self.arcs[next[node]];
+                    if arc.left > W::zero() && levels[arc.to] == levels[node] + 1 {
+                        break;
+                    }
+                    next[node] += 1;
+                }
+                if next[node] < end {
+                    path.push(next[node]);
+                    node = self.arcs[next[node]].to;
+                } else {
+                    // The node leads nowhere, so we step back and skip the
+                    // arc leading to it.
+                    match path.pop() {
+                        Some(arc) => {
+                            node = self.arcs[self.arcs[arc].reverse].to;
+                            next[node] += 1;
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+        Ok(value)
+    }
+
+    // The number of arcs on the shortest path from the source to every node
+    // of the residual graph, usize::MAX for the nodes which cannot be
+    // reached, or None if the sink cannot be reached.
+    fn levels(&self, source: usize, sink: usize) -> Option<Vec<usize>> {
+        let mut levels = vec![usize::MAX; self.nodes.len()];
+        levels[source] = 0;
+        let mut queue = VecDeque::new();
+        queue.push_back(source);
+        while let Some(node) = queue.pop_front() {
+            // The nodes beyond the sink cannot be on a shortest path to it.
+            if levels[node] >= levels[sink] {
+                break;
+            }
+            for arc in &self.arcs[self.offsets[node]..self.offsets[node + 1]] {
+                if arc.left > W::zero() && levels[arc.to] == usize::MAX {
+                    levels[arc.to] = levels[node] + 1;
+                    queue.push_back(arc.to);
+                }
+            }
+        }
+        (levels[sink] != usize::MAX).then_some(levels)
+    }
+
+    // The potentials of the nodes before any flow is pushed, such that the
+    // cost of every arc which can carry some flow plus the potential of its
+    // source is never cheaper than the potential of its destination. They
+    // are all zero if no such arc has a negative cost, and otherwise the
+    // costs of the cheapest paths from an extra source linked to all the
+    // nodes for free, found by Bellman-Ford. Only the forward arcs can carry
+    // some flow then, so no cost is negated.
+    fn potentials(&self) -> Result<Vec<W>, PathError> {
+        let policy = OverflowPolicy::default();
+        let mut potentials = vec![W::zero(); self.nodes.len()];
+        // A cheapest path has at most as many arcs as there are nodes, minus
+        // one, so the paths improving after that many rounds go through a
+        // negative cycle.
+        for _ in 0..=self.nodes.len() {
+            let mut improved = false;
+            for &(forward, capacity, cost) in &self.edges {
+                if capacity <= W::zero() {
+                    continue;
+                }
+                let Arc { to, reverse, .. } = self.arcs[forward];
+                let from = self.arcs[reverse].to;
+                let cost = policy
+                    .add(potentials[from], cost)
+                    .ok_or(PathError::Overflow)?;
+                if cost < potentials[to] {
+                    potentials[to] = cost;
+                    improved = true;
+                }
+            }
+            if !improved {
+                return Ok(potentials);
+            }
+        }
+        Err(PathError::NegativeCycle)
+    }
+
+    // Finds the cheapest path from source to sink in the residual graph with
+    // Dijkstra's algorithm over the costs shifted by the potentials, which
+    // are then updated with the costs found. The arcs of the path are
+    // returned along with the amount it can carry, or None if the sink
+    // cannot be reached.
+    fn cheapest_path(
+        &self,
+        source: usize,
+        sink: usize,
+        potentials: &mut [W],
+    ) -> Result<Option<(Vec<usize>, W)>, PathError> {
+        let policy = OverflowPolicy::default();
+        let mut costs: Vec<Option<W>> = vec![None; self.nodes.len()];
+        // The arc every node was reached through.
+        let mut through = vec![usize::MAX; self.nodes.len()];
+        let mut settled = vec![false; self.nodes.len()];
+        let mut queue = BinaryHeap::new();
+        costs[source] = Some(W::zero());
+        queue.push(Reverse((W::zero(), source)));
+
+        while let Some(Reverse((cost, node))) = queue.pop() {
+            if settled[node] {
+                continue;
+            }
+            settled[node] = true;
+            for position in self.offsets[node]..self.offsets[node + 1] {
+                let arc = self.arcs[position];
+                if arc.left <= W::zero() || settled[arc.to] {
+                    continue;
+                }
+                let shifted = self.shifted_cost(position, node, potentials)?;
+                let new_cost = policy.add(cost, shifted).ok_or(PathError::Overflow)?;
+                if costs[arc.to].is_none_or(|old| old > new_cost) {
+                    costs[arc.to] = Some(new_cost);
+                    through[arc.to] = position;
+                    queue.push(Reverse((new_cost, arc.to)));
+                }
+            }
+        }
+        if costs[sink].is_none() {
+            return Ok(None);
+        }
+
+        // The nodes which were not reached never will be, since the flow
+        // only changes along the paths of the reached nodes, so their
+        // potentials don't matter anymore.
+        for (potential, cost) in potentials.iter_mut().zip(&costs) {
+            if let Some(cost) = *cost {
+                *potential = policy.add(*potential, cost).ok_or(PathError::Overflow)?;
+            }
+        }
+
+        // We walk back from the sink to find the arcs of the path.
+        let mut path = Vec::new();
+        let mut node = sink;
+        while node != source {
+            let arc = through[node];
+            path.push(arc);
+            node = self.arcs[self.arcs[arc].reverse].to;
+        }
+        path.reverse();
+        let amount = path
+            .iter()
+            .map(|&arc| self.arcs[arc].left)
+            .min()
+            .expect("the path leads to the sink");
+        Ok(Some((path, amount)))
+    }
+
+    // The cost of sending flow along the arc at the given position, leaving
+    // the given node, shifted by the potentials of its ends. Sending flow
+    // back along a reverse arc costs the opposite of the cost of its edge,
+    // so that the shifted cost is the potential of the node minus the cost
+    // of the edge and the potential of the other end. The shifted cost of
+    // an arc which can carry some flow is never negative, so the difference
+    // is taken in the order which never goes below zero, and works with
+    // unsigned costs as well.
+    fn shifted_cost(&self, position: usize, node: usize, potentials: &[W]) -> Result<W, PathError> {
+        let policy = OverflowPolicy::default();
+        let arc = self.arcs[position];
+        let (forward, _, cost) = self.edges[arc.edge];
+        Ok(if position == forward {
+            policy
+                .add(cost, potentials[node])
+                .ok_or(PathError::Overflow)?
+                - potentials[arc.to]
+        } else {
+            potentials[node]
+                - policy
+                    .add(cost, potentials[arc.to])
+                    .ok_or(PathError::Overflow)?
+        })
+    }
+
+    // Pushes the given amount along the arcs of a path.
+    fn push(&mut self, path: &[usize], amount: W) {
+        for &arc in path {
+            let reverse = self.arcs[arc].reverse;
+            self.arcs[arc].left = self.arcs[arc].left - amount;
+            self.arcs[reverse].left = self.arcs[reverse].left + amount;
+        }
+    }
+
+    // Whether every node can be reached from the source in the residual
+    // graph.
+    fn reachable(&self, source: usize) -> Vec<bool> {
+        let mut reached = vec![false; self.nodes.len()];
+        reached[source] = true;
+        let mut stack = vec![source];
+        while let Some(node) = stack.pop() {
+            for arc in &self.arcs[self.offsets[node]..self.offsets[node + 1]] {
+                if arc.left > W::zero() && !reached[arc.to] {
+                    reached[arc.to] = true;
+                    stack.push(arc.to);
+                }
+            }
+        }
+        reached
+    }
+
+    // The edges carrying some flow, along with their flow.
+    fn flows(&self) -> Vec<(N, N, W)> {
+        self.edges
+            .iter()
+            .filter_map(|&(arc, capacity, _)| {
+                let Arc {
+                    to, left, reverse, ..
+                } = self.arcs[arc];
+                let from = self.arcs[reverse].to;
+                (left < capacity).then(|| {
+                    (
+                        self.nodes[from].clone(),
+                        self.nodes[to].clone(),
+                        capacity - left,
+                    )
+                })
+            })
+            .collect()
+    }
+}
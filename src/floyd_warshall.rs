@@ -0,0 +1,168 @@
+// Required by the keys of the index.
+use std::hash::Hash;
+
+// Used to find the index of the nodes.
+use crate::hash::HashMap;
+// The graph being explored, and the paths extracted from the matrices.
+use crate::{GraphLike, OverflowPolicy, PathError, PathResult, ShortestPathResult, Weight};
+
+/// Finds the shortest paths between all the pairs of nodes of `graph` with
+/// the Floyd-Warshall algorithm.
+///
+/// The algorithm fills a matrix with the cost of the shortest path between
+/// every pair of nodes, going through each node in turn to check whether it
+/// shortens the paths between the others. It takes O(V³) time and O(V²)
+/// memory whatever the number of edges, which suits small and medium dense
+/// graphs, for instance an [`AdjacencyMatrix`](crate::AdjacencyMatrix).
+/// Negative costs are supported.
+///
+/// The query fails with [`PathError::NegativeCycle`] if the graph has a cycle
+/// whose total cost is negative, and with [`PathError::Overflow`] if the cost
+/// of a path overflows.
+pub fn floyd_warshall<G: GraphLike>(
+    graph: &G,
+) -> Result<DistanceMatrix<G::Node, G::Weight>, PathError> {
+    let policy = OverflowPolicy::default();
+    let nodes: Vec<G::Node> = graph.nodes().cloned().collect();
+    let index: HashMap<G::Node, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(position, node)| (node.clone(), position))
+        .collect();
+    let size = nodes.len();
+
+    // Every node starts with the empty path to itself, and with the edges to
+    // its neighbors, the cheapest one being kept for the parallel edges.
+    let mut distances = vec![None; size * size];
+    let mut successors = vec![None; size * size];
+    for source in 0..size {
+        distances[source * size + source] = Some(G::Weight::zero());
+        successors[source * size + source] = Some(source);
+        for (neighbor, cost) in graph.neighbors(&nodes[source]) {
+            // The edges leading out of the graph are ignored.
+            let neighbor = match index.get(neighbor) {
+                Some(&neighbor) => neighbor,
+                None => continue,
+            };
+            let cell = source * size + neighbor;
+            if distances[cell].is_none_or(|old| old > cost) {
+                distances[cell] = Some(cost);
+                successors[cell] = Some(neighbor);
+            }
+        }
+    }
+
+    for middle in 0..size {
+        for source in 0..size {
+            // Only the nodes which reach the middle can go through it.
+            let to_middle = match distances[source * size + middle] {
+                Some(cost) => cost,
+                None => continue,
+            };
+
+            for destination in 0..size {
+                let from_middle = match distances[middle * size + destination] {
+                    Some(cost) => cost,
+                    None => continue,
+                };
+                let cost = policy
+                    .add(to_middle, from_middle)
+                    .ok_or(PathError::Overflow)?;
+
+                let cell = source * size + destination;
+                if distances[cell].is_none_or(|old| old > cost) {
+                    // A path from a node to itself cheaper than the empty
+                    // path is a negative cycle, and would only get cheaper.
+                    if source == destination {
+                        return Err(PathError::NegativeCycle);
+                    }
+                    distances[cell] = Some(cost);
+                    successors[cell] = successors[source * size + middle];
+                }
+            }
+        }
+    }
+
+    Ok(DistanceMatrix {
+        nodes,
+        index,
+        distances,
+        successors,
+    })
+}
+
+/// The shortest paths between all the pairs of nodes of a graph, as found by
+/// [`floyd_warshall`] or [`johnson`](crate::johnson).
+///
+/// The nodes are numbered in the order of the graph, and two dense matrices
+/// hold the cost of the shortest path between every pair of nodes and the
+/// node following the source on this path. Any of these paths can then be
+/// extracted with [`DistanceMatrix::shortest_path`] in time linear in its
+/// length. The nodes are cloned from the graph, so the matrix doesn't borrow
+/// it.
+#[derive(Debug, Clone)]
+pub struct DistanceMatrix<N, W> {
+    // The node associated with each index.
+    pub(crate) nodes: Vec<N>,
+    // The index associated with each node.
+    pub(crate) index: HashMap<N, usize>,
+    // The cost of the shortest path between every pair of nodes, row by row,
+    // or None if there is no path.
+    pub(crate) distances: Vec<Option<W>>,
+    // The index of the node following the source on every shortest path, row
+    // by row, or None if there is no path.
+    pub(crate) successors: Vec<Option<usize>>,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> DistanceMatrix<N, W> {
+    /// The number of nodes in the matrix.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Iterates over all the nodes of the matrix, in the order of their
+    /// indices.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> + '_ {
+        self.nodes.iter()
+    }
+
+    /// The cost of the shortest path from `source` to `destination`, or
+    /// `None` if there is no such path or if one of the nodes is not part of
+    /// the graph.
+    pub fn distance(&self, source: &N, destination: &N) -> Option<W> {
+        let source = *self.index.get(source)?;
+        let destination = *self.index.get(destination)?;
+        self.distances[source * self.nodes.len() + destination]
+    }
+
+    /// Extracts the shortest path from `source` to `destination`.
+    ///
+    /// The errors are the same as with [`dijkstra`](crate::dijkstra). The
+    /// returned path carries no statistics, since no search is run to find
+    /// it.
+    pub fn shortest_path(&self, source: &N, destination: &N) -> PathResult<N, W> {
+        let source = *self.index.get(source).ok_or(PathError::UnknownSource)?;
+        let destination = *self
+            .index
+            .get(destination)
+            .ok_or(PathError::UnknownDestination)?;
+        let size = self.nodes.len();
+        let cost = self.distances[source * size + destination].ok_or(PathError::NoPath)?;
+
+        // We follow the successors from the source until we reach the
+        // destination.
+        let mut path = vec![self.nodes[source].clone()];
+        let mut location = source;
+        while location != destination {
+            location = self.successors[location * size + destination].ok_or(PathError::NoPath)?;
+            path.push(self.nodes[location].clone());
+        }
+
+        Ok(ShortestPathResult {
+            nodes: path,
+            cost,
+            settled: None,
+            stats: None,
+        })
+    }
+}
@@ -0,0 +1,272 @@
+// Used as priority queue by the default frontier.
+use std::collections::BinaryHeap;
+
+// The entries of the binary heap, and the costs of the edges.
+use crate::queue::HeapEntry;
+use crate::Weight;
+
+/// The priority queue picking the next node settled by a search.
+///
+/// The relaxation loop of
+/// [`dijkstra_with_frontier`](crate::dijkstra_with_frontier) and of
+/// [`SearchWorkspace`](crate::SearchWorkspace) is written once against this
+/// trait, so that the queue can be swapped or benchmarked. The
+/// [`BinaryHeapFrontier`] is used by default, [`BucketFrontier`] and
+/// [`PairingHeapFrontier`] are provided as alternatives.
+///
+/// A node is pushed again every time a better path to it is found, rather
+/// than having its entry updated in place. The frontier may therefore return
+/// outdated entries, which the search skips since the node was already
+/// settled with a lower cost.
+///
+/// The costs pushed during a search never decrease below the cost of the last
+/// popped entry, which some frontiers rely on. The costs pushed by
+/// [`SearchWorkspace::astar`](crate::SearchWorkspace::astar) are the estimates
+/// of the heuristic, for which this only holds if it is consistent.
+pub trait Frontier<'a, N, W> {
+    /// Adds a node reached for `cost`.
+    fn push(&mut self, node: &'a N, cost: W);
+
+    /// Removes and returns the cheapest node, along with its cost, or `None`
+    /// if the frontier is empty. Among equally cheap nodes, any can be
+    /// returned.
+    fn pop(&mut self) -> Option<(&'a N, W)>;
+
+    /// Removes all the nodes, keeping the allocated memory if possible.
+    fn clear(&mut self);
+}
+
+/// A frontier backed by the binary heap of the standard library, which works
+/// for any [`Weight`].
+#[derive(Debug, Clone)]
+pub struct BinaryHeapFrontier<'a, N, W> {
+    // The nodes, the cheapest one first.
+    heap: BinaryHeap<HeapEntry<'a, N, W>>,
+}
+
+impl<N, W: Weight> BinaryHeapFrontier<'_, N, W> {
+    /// Creates an empty frontier.
+    pub fn new() -> Self {
+        BinaryHeapFrontier {
+            heap: BinaryHeap::new(),
+        }
+    }
+}
+
+impl<N, W: Weight> Default for BinaryHeapFrontier<'_, N, W> {
+    fn default() -> Self {
+        BinaryHeapFrontier::new()
+    }
+}
+
+impl<'a, N, W: Weight> Frontier<'a, N, W> for BinaryHeapFrontier<'a, N, W> {
+    fn push(&mut self, node: &'a N, cost: W) {
+        self.heap.push(HeapEntry { cost, node });
+    }
+
+    fn pop(&mut self) -> Option<(&'a N, W)> {
+        self.heap.pop().map(|entry| (entry.node, entry.cost))
+    }
+
+    fn clear(&mut self) {
+        self.heap.clear();
+    }
+}
+
+/// Dial's bucket queue, for small integer costs.
+///
+/// The nodes are kept in one bucket per cost, so that pushing and popping a
+/// node takes constant time, plus the time needed to skip the empty buckets.
+/// Since a search never pushes a node further than the largest edge cost from
+/// the last popped one, only that many buckets are needed, and they are
+/// reused in a circular fashion.
+///
+/// The costs are converted with [`Weight::to_usize`].
+#[derive(Debug, Clone)]
+pub struct BucketFrontier<'a, N, W> {
+    // The nodes of every cost, the bucket of the cost c being the one at c
+    // modulo the number of buckets.
+    buckets: Vec<Vec<(&'a N, W)>>,
+    // The cost of the bucket being emptied, i.e. of the last popped node.
+    current: usize,
+    // The number of nodes in all the buckets.
+    len: usize,
+}
+
+impl<N, W: Weight> BucketFrontier<'_, N, W> {
+    /// Creates an empty frontier for a graph whose edges cost at most
+    /// `largest`.
+    pub fn new(largest: usize) -> Self {
+        BucketFrontier {
+            buckets: vec![Vec::new(); largest + 1],
+            current: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<'a, N, W: Weight> Frontier<'a, N, W> for BucketFrontier<'a, N, W> {
+    /// # Panics
+    ///
+    /// Panics if the cost cannot be converted to a `usize`, or if it is lower
+    /// than the cost of the last popped node or further than the largest edge
+    /// cost from it. The cost of the last popped node is zero before any pop
+    /// or after a clear.
+    fn push(&mut self, node: &'a N, cost: W) {
+        // The frontier keeps the cost of the last popped node even once it is
+        // empty, since the nodes pushed next may be cheaper than the first of
+        // them.
+        let index = cost
+            .to_usize()
+            .expect("the costs of a BucketFrontier must fit in a usize");
+        assert!(
+            index >= self.current && index - self.current < self.buckets.len(),
+            "cost out of the range of the BucketFrontier"
+        );
+
+        let slot = index % self.buckets.len();
+        self.buckets[slot].push((node, cost));
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<(&'a N, W)> {
+        if self.len == 0 {
+            return None;
+        }
+        // We move on to the next cost until we find a bucket which isn't empty.
+        // There is one since the frontier isn't empty.
+        loop {
+            let slot = self.current % self.buckets.len();
+            if let Some(entry) = self.buckets[slot].pop() {
+                self.len -= 1;
+                return Some(entry);
+            }
+            self.current += 1;
+        }
+    }
+
+    fn clear(&mut self) {
+        for bucket in &mut self.buckets {
+            bucket.clear();
+        }
+        self.current = 0;
+        self.len = 0;
+    }
+}
+
+/// A pairing heap, which works for any [`Weight`].
+///
+/// Pushing a node only takes constant time, the work being deferred until the
+/// cheapest node is popped. This suits searches which push many more nodes
+/// than they pop, for instance on dense graphs.
+#[derive(Debug, Clone)]
+pub struct PairingHeapFrontier<'a, N, W> {
+    // The nodes of the trees of the heap. The removed nodes are left in place
+    // and reused by the next pushes.
+    arena: Vec<PairingNode<'a, N, W>>,
+    // The positions in the arena of the removed nodes.
+    free: Vec<usize>,
+    // The position of the root of the heap, i.e. of the cheapest node.
+    root: Option<usize>,
+}
+
+// A node of a pairing heap, linked to its first child and to its next sibling.
+#[derive(Debug, Clone)]
+struct PairingNode<'a, N, W> {
+    // The node of the graph and its cost.
+    node: &'a N,
+    cost: W,
+    // The position of the first child in the arena.
+    child: Option<usize>,
+    // The position of the next sibling in the arena.
+    sibling: Option<usize>,
+}
+
+impl<'a, N, W: Weight> PairingHeapFrontier<'a, N, W> {
+    /// Creates an empty frontier.
+    pub fn new() -> Self {
+        PairingHeapFrontier {
+            arena: Vec::new(),
+            free: Vec::new(),
+            root: None,
+        }
+    }
+
+    // Merges two trees, the most expensive root becoming the first child of
+    // the other one. Returns the position of the new root.
+    fn meld(&mut self, first: usize, second: usize) -> usize {
+        let (parent, child) = if self.arena[first].cost <= self.arena[second].cost {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        self.arena[child].sibling = self.arena[parent].child;
+        self.arena[parent].child = Some(child);
+        parent
+    }
+
+    // Merges a list of sibling trees into a single one, pairing them from left
+    // to right and then merging the pairs from right to left.
+    fn merge_siblings(&mut self, first: Option<usize>) -> Option<usize> {
+        let mut pairs = Vec::new();
+        let mut next = first;
+        while let Some(first) = next {
+            let second = self.arena[first].sibling.take();
+            next = second.and_then(|second| self.arena[second].sibling.take());
+            pairs.push(match second {
+                Some(second) => self.meld(first, second),
+                None => first,
+            });
+        }
+        pairs
+            .into_iter()
+            .rev()
+            .reduce(|merged, tree| self.meld(tree, merged))
+    }
+}
+
+impl<N, W: Weight> Default for PairingHeapFrontier<'_, N, W> {
+    fn default() -> Self {
+        PairingHeapFrontier::new()
+    }
+}
+
+impl<'a, N, W: Weight> Frontier<'a, N, W> for PairingHeapFrontier<'a, N, W> {
+    fn push(&mut self, node: &'a N, cost: W) {
+        let entry = PairingNode {
+            node,
+            cost,
+            child: None,
+            sibling: None,
+        };
+        // We reuse the place of a removed node if there is one.
+        let position = match self.free.pop() {
+            Some(position) => {
+                self.arena[position] = entry;
+                position
+            }
+            None => {
+                self.arena.push(entry);
+                self.arena.len() - 1
+            }
+        };
+        self.root = Some(match self.root {
+            Some(root) => self.meld(root, position),
+            None => position,
+        });
+    }
+
+    fn pop(&mut self) -> Option<(&'a N, W)> {
+        let root = self.root?;
+        self.free.push(root);
+        let children = self.arena[root].child.take();
+        self.root = self.merge_siblings(children);
+        Some((self.arena[root].node, self.arena[root].cost))
+    }
+
+    fn clear(&mut self) {
+        self.arena.clear();
+        self.free.clear();
+        self.root = None;
+    }
+}
@@ -0,0 +1,176 @@
+//! The random and regular graphs to try the algorithms on.
+//!
+//! The nodes of the generated graphs are numbered from 0, and the costs of
+//! their edges are drawn uniformly from the given range. The same seed
+//! always gives the same graph, so that a benchmark or a failing query can
+//! be reproduced.
+
+// Used to give the range of the costs.
+use std::ops::RangeInclusive;
+
+// Used to draw the edges and their costs.
+use crate::random::Random;
+// The graphs being generated.
+use crate::Graph;
+
+/// Generates a directed Erdős–Rényi graph on `n` nodes, in which every edge
+/// between two different nodes exists with probability `p`.
+///
+/// The costs of the edges are drawn uniformly from `weight_range`. The
+/// graph has `p n (n - 1)` edges on average, and is generated in time
+/// proportional to its size by skipping the missing edges at once rather
+/// than deciding on them one by one, so that large sparse graphs are cheap
+/// to generate.
+///
+/// # Panics
+///
+/// Panics if `p` is not between 0 and 1, or if `weight_range` is empty.
+pub fn gnp(n: usize, p: f64, weight_range: RangeInclusive<u32>, seed: u64) -> Graph<usize> {
+    assert!((0.0..=1.0).contains(&p), "p must be between 0 and 1");
+    assert!(!weight_range.is_empty(), "the range of the costs is empty");
+    let mut random = Random::new(seed);
+    let mut graph = Graph::new();
+    for node in 0..n {
+        graph.add_node(node);
+    }
+
+    // The edges are numbered in the order of their source, then of their
+    // destination, the loops aside.
+    let pairs = n * n.saturating_sub(1);
+    let mut edge = 0;
+    while p > 0.0 && edge < pairs {
+        // The number of missing edges before the next one follows a
+        // geometric distribution.
+        if p < 1.0 {
+            let skipped = (1.0 - random.next_f64()).ln() / (1.0 - p).ln();
+            if skipped >= (pairs - edge) as f64 {
+                break;
+            }
+            edge += skipped as usize;
+        }
+        let source = edge / (n - 1);
+        let mut destination = edge % (n - 1);
+        if destination >= source {
+            destination += 1;
+        }
+        graph.add_edge(source, destination, draw(&mut random, &weight_range));
+        edge += 1;
+    }
+
+    graph
+}
+
+/// Generates an undirected Barabási–Albert graph on `n` nodes, in which
+/// every new node attaches to `m` of the nodes before it.
+///
+/// The graph starts as a complete graph on the first `m + 1` nodes, or on
+/// all of them if there are fewer. Every node after them is then linked to
+/// `m` different nodes before it, each picked with a probability
+/// proportional to the number of its edges. This preferential attachment
+/// makes the nodes with many edges get even more, so that a few hubs are
+/// linked to a large part of the graph while most nodes only have about
+/// `m` edges, as in social networks and in the web. The costs of the edges
+/// are drawn uniformly from `weight_range`.
+///
+/// # Panics
+///
+/// Panics if `m` is 0, or if `weight_range` is empty.
+pub fn barabasi_albert(
+    n: usize,
+    m: usize,
+    weight_range: RangeInclusive<u32>,
+    seed: u64,
+) -> Graph<usize> {
+    assert!(m > 0, "a new node must attach to at least one node");
+    assert!(!weight_range.is_empty(), "the range of the costs is empty");
+    let mut random = Random::new(seed);
+    let mut graph = Graph::new_undirected();
+    // Both ends of every edge, so that a node picked uniformly from it is
+    // picked in proportion to the number of its edges.
+    let mut ends = Vec::with_capacity(2 * m * n);
+
+    let initial = n.min(m + 1);
+    for source in 0..initial {
+        graph.add_node(source);
+        for destination in 0..source {
+            graph.add_edge(source, destination, draw(&mut random, &weight_range));
+            ends.extend([source, destination]);
+        }
+    }
+
+    // The nodes the new node attaches to, kept in the order they were
+    // picked so that the costs are drawn in the same order for a seed.
+    let mut targets = Vec::with_capacity(m);
+    for source in initial..n {
+        targets.clear();
+        while targets.len() < m {
+            let target = ends[random.below(ends.len())];
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+        for &destination in &targets {
+            graph.add_edge(source, destination, draw(&mut random, &weight_range));
+            ends.extend([source, destination]);
+        }
+    }
+
+    graph
+}
+
+/// Generates an undirected grid of `width` columns and `height` rows, in
+/// which every node is linked to the nodes next to it.
+///
+/// The node in column `x` and row `y`, both numbered from 0, is the node
+/// `y * width + x`. Every node is linked to the nodes above, below, on its
+/// left and on its right, and also to its four diagonal neighbors if
+/// `diagonal` is set. The costs of the edges are drawn uniformly from
+/// `weight_range`, so that a range holding a single cost gives the same
+/// cost to every edge, diagonals included. Grids are the usual testbed of
+/// the searches guided by a heuristic, see [`astar`](crate::astar).
+///
+/// # Panics
+///
+/// Panics if `weight_range` is empty.
+pub fn grid(
+    width: usize,
+    height: usize,
+    diagonal: bool,
+    weight_range: RangeInclusive<u32>,
+    seed: u64,
+) -> Graph<usize> {
+    assert!(!weight_range.is_empty(), "the range of the costs is empty");
+    let mut random = Random::new(seed);
+    let mut graph = Graph::new_undirected();
+
+    for y in 0..height {
+        for x in 0..width {
+            let node = y * width + x;
+            graph.add_node(node);
+            // We link every node to its neighbors which come before it, so
+            // that every edge is only added once.
+            if x > 0 {
+                graph.add_edge(node, node - 1, draw(&mut random, &weight_range));
+            }
+            if y > 0 {
+                graph.add_edge(node, node - width, draw(&mut random, &weight_range));
+                if diagonal && x > 0 {
+                    graph.add_edge(node, node - width - 1, draw(&mut random, &weight_range));
+                }
+                if diagonal && x + 1 < width {
+                    graph.add_edge(node, node - width + 1, draw(&mut random, &weight_range));
+                }
+            }
+        }
+    }
+
+    graph
+}
+
+// Draws a cost uniformly from the range, which must not be empty.
+fn draw(random: &mut Random, range: &RangeInclusive<u32>) -> u32 {
+    let span = u64::from(range.end() - range.start()) + 1;
+    // As with Random::below, the number is scaled rather than taken modulo
+    // the span.
+    range.start() + ((u128::from(random.next_u64()) * u128::from(span)) >> 64) as u32
+}
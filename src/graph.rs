@@ -0,0 +1,809 @@
+// Used for the adjacency representation.
+use crate::hash::{HashMap, HashSet};
+// Used to display the graph.
+use std::fmt;
+// Used to collect graphs from edge lists.
+use std::iter::FromIterator;
+// Required by the keys of the adjacency representation.
+use std::hash::Hash;
+
+// The costs of the edges, and the paths found in the graph.
+use crate::{GraphLike, GraphStats, MergeConflict, ShortestPathResult, ValidationReport, Weight};
+
+/// A graph with weighted edges.
+///
+/// The edges of the graph are directed, unless the graph was created as an
+/// undirected one (see [`GraphKind`]). Every node is mapped to the `HashMap`
+/// of its adjacent nodes, which itself maps each neighbor to the cost of the
+/// edge leading to it. Any type which can be used as a `HashMap` key can be
+/// used for the nodes, for instance `&str`, integer ids or small custom
+/// structs. The costs can be any [`Weight`], `u32` by default.
+///
+/// Arbitrary data of type `D` can be attached to the nodes, for instance
+/// coordinates or labels, without making it part of the node itself.
+/// Similarly, data of type `E` can be attached to the edges alongside their
+/// cost, for instance a road name or a toll flag. Neither the nodes nor the
+/// edges carry any data by default.
+#[derive(Debug, Clone)]
+pub struct Graph<N, W = u32, D = (), E = ()> {
+    // The adjacency list of every node in the graph.
+    adjacency: HashMap<N, HashMap<N, W>>,
+    // The data attached to the nodes. Only the nodes which were given some data
+    // appear in this HashMap.
+    data: HashMap<N, D>,
+    // The data attached to the edges, with the same layout as the adjacency
+    // lists. Only the edges which were given some data appear in it. In an
+    // undirected graph, the data is only stored in one direction.
+    edge_data: HashMap<N, HashMap<N, E>>,
+    // Whether the edges are directed or not.
+    kind: GraphKind,
+}
+
+/// Whether the edges of a [`Graph`] are directed or not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum GraphKind {
+    /// Every edge goes from its source to its destination only. This is the
+    /// default.
+    #[default]
+    Directed,
+    /// Every edge can be followed in both directions, with the same cost. The
+    /// edges are stored in both directions in the adjacency lists.
+    Undirected,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight, D, E> Graph<N, W, D, E> {
+    /// Creates an empty directed graph.
+    pub fn new() -> Self {
+        Graph::with_kind(GraphKind::Directed)
+    }
+
+    /// Creates an empty undirected graph.
+    pub fn new_undirected() -> Self {
+        Graph::with_kind(GraphKind::Undirected)
+    }
+
+    /// Creates an empty graph of the given kind.
+    pub fn with_kind(kind: GraphKind) -> Self {
+        Graph {
+            adjacency: HashMap::default(),
+            data: HashMap::default(),
+            edge_data: HashMap::default(),
+            kind,
+        }
+    }
+
+    // Creates a directed graph from its adjacency lists, in which every
+    // destination must appear as a node.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn from_adjacency(adjacency: HashMap<N, HashMap<N, W>>) -> Self {
+        Graph {
+            adjacency,
+            ..Graph::new()
+        }
+    }
+
+    /// Whether the edges of the graph are directed or not.
+    pub fn kind(&self) -> GraphKind {
+        self.kind
+    }
+
+    /// Adds a node without any edge to the graph.
+    ///
+    /// Adding a node which is already in the graph does nothing, in
+    /// particular its edges are kept.
+    pub fn add_node(&mut self, node: N) {
+        self.adjacency.entry(node).or_default();
+    }
+
+    /// Attaches `data` to `node`, adding the node to the graph if it was not
+    /// already in it.
+    ///
+    /// The data previously attached to the node is returned, if any.
+    pub fn set_node_data(&mut self, node: N, data: D) -> Option<D> {
+        self.add_node(node.clone());
+        self.data.insert(node, data)
+    }
+
+    /// Returns the data attached to `node`, if any.
+    pub fn node_data(&self, node: &N) -> Option<&D> {
+        self.data.get(node)
+    }
+
+    /// Returns a mutable reference to the data attached to `node`, if any.
+    pub fn node_data_mut(&mut self, node: &N) -> Option<&mut D> {
+        self.data.get_mut(node)
+    }
+
+    /// Detaches the data from `node` and returns it, if any. The node itself
+    /// is kept in the graph.
+    pub fn remove_node_data(&mut self, node: &N) -> Option<D> {
+        self.data.remove(node)
+    }
+
+    /// Iterates over the nodes of a path along with the data attached to
+    /// them, for instance to display the coordinates of a route.
+    ///
+    /// The nodes without data, or which are not part of the graph, come with
+    /// `None`.
+    pub fn path_data<'a>(
+        &'a self,
+        path: &'a ShortestPathResult<N, W>,
+    ) -> impl Iterator<Item = (&'a N, Option<&'a D>)> + 'a {
+        path.nodes
+            .iter()
+            .map(move |node| (node, self.data.get(node)))
+    }
+
+    /// Adds an edge going from `source` to `destination`.
+    ///
+    /// Both nodes are added to the graph if they were not already in it. If
+    /// the edge already existed, its cost is replaced and the old cost is
+    /// returned. In an undirected graph, the edge going from `destination` to
+    /// `source` is added as well.
+    pub fn add_edge(&mut self, source: N, destination: N, cost: W) -> Option<W> {
+        self.add_edge_with_policy(source, destination, cost, DuplicateEdgePolicy::Overwrite)
+    }
+
+    /// Adds an edge going from `source` to `destination`, following `policy`
+    /// if the edge already existed.
+    ///
+    /// The old cost of the edge is returned if there was one, whether it was
+    /// replaced or not. In an undirected graph, the edge going from
+    /// `destination` to `source` is added as well, with the same cost. The
+    /// data attached to the edge, if any, is kept whatever the policy.
+    pub fn add_edge_with_policy(
+        &mut self,
+        source: N,
+        destination: N,
+        cost: W,
+        policy: DuplicateEdgePolicy,
+    ) -> Option<W> {
+        match self.kind {
+            GraphKind::Directed => self.insert_edge(source, destination, cost, policy),
+            GraphKind::Undirected => {
+                // Both directions always share the same cost, so the policy
+                // makes the same decision for both of them.
+                self.insert_edge(destination.clone(), source.clone(), cost, policy);
+                self.insert_edge(source, destination, cost, policy)
+            }
+        }
+    }
+
+    /// Adds an edge going from `source` to `destination` with some data
+    /// attached to it.
+    ///
+    /// The edge is added just like [`Graph::add_edge`] would, and `data`
+    /// replaces the data previously attached to the edge, if any.
+    pub fn add_edge_with_data(&mut self, source: N, destination: N, cost: W, data: E) -> Option<W> {
+        self.attach_edge_data(source.clone(), destination.clone(), data);
+        self.add_edge(source, destination, cost)
+    }
+
+    /// Adds an edge which can be followed in both directions, regardless of
+    /// the kind of the graph.
+    ///
+    /// The old cost of the edge going from `source` to `destination` is
+    /// returned, if there was one.
+    pub fn add_undirected_edge(&mut self, source: N, destination: N, cost: W) -> Option<W> {
+        let policy = DuplicateEdgePolicy::Overwrite;
+        self.insert_edge(destination.clone(), source.clone(), cost, policy);
+        self.insert_edge(source, destination, cost, policy)
+    }
+
+    /// Removes `node` from the graph, along with all the edges going to or
+    /// coming from it and the data attached to it.
+    ///
+    /// Returns whether the node was part of the graph.
+    pub fn remove_node(&mut self, node: &N) -> bool {
+        // The outgoing edges are removed along with the node itself.
+        if self.adjacency.remove(node).is_none() {
+            return false;
+        }
+        self.data.remove(node);
+        self.edge_data.remove(node);
+
+        // The incoming edges could come from any other node, so we have to look
+        // at all the adjacency lists.
+        for adjacent in self.adjacency.values_mut() {
+            adjacent.remove(node);
+        }
+        for adjacent in self.edge_data.values_mut() {
+            adjacent.remove(node);
+        }
+        true
+    }
+
+    /// Removes the edge going from `source` to `destination`, in both
+    /// directions if the graph is undirected.
+    ///
+    /// Returns the cost of the removed edge, or `None` if there was no such
+    /// edge. The nodes themselves are kept in the graph, but the data attached
+    /// to the edge is dropped.
+    pub fn remove_edge(&mut self, source: &N, destination: &N) -> Option<W> {
+        self.remove_edge_data(source, destination);
+        if self.kind == GraphKind::Undirected {
+            // The edges are stored in both directions.
+            self.delete_edge(destination, source);
+        }
+        self.delete_edge(source, destination)
+    }
+
+    /// Returns the cost of the edge going from `source` to `destination`, if
+    /// there is one.
+    pub fn edge_cost(&self, source: &N, destination: &N) -> Option<W> {
+        self.adjacency.get(source)?.get(destination).copied()
+    }
+
+    /// Returns whether `node` is part of the graph.
+    pub fn contains_node(&self, node: &N) -> bool {
+        self.adjacency.contains_key(node)
+    }
+
+    /// Iterates over the nodes adjacent to `node`, along with the cost of the
+    /// edge leading to them.
+    ///
+    /// A node which is not part of the graph has no neighbors.
+    pub fn neighbors<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, W)> + 'a {
+        self.adjacency
+            .get(node)
+            // A missing node is treated just like a terminal node.
+            .into_iter()
+            .flatten()
+            .map(|(neighbor, cost)| (neighbor, *cost))
+    }
+
+    /// Returns the data attached to the edge going from `source` to
+    /// `destination`, if any.
+    pub fn edge_data(&self, source: &N, destination: &N) -> Option<&E> {
+        let data = self
+            .edge_data
+            .get(source)
+            .and_then(|adjacent| adjacent.get(destination));
+        match self.kind {
+            GraphKind::Directed => data,
+            // The data of an undirected edge may be stored in the other
+            // direction.
+            GraphKind::Undirected => data.or_else(|| self.edge_data.get(destination)?.get(source)),
+        }
+    }
+
+    /// Returns a mutable reference to the data attached to the edge going from
+    /// `source` to `destination`, if any.
+    pub fn edge_data_mut(&mut self, source: &N, destination: &N) -> Option<&mut E> {
+        // We look for the direction the data is stored in first, as the borrow
+        // checker doesn't let us simply chain both lookups.
+        let reversed = self.kind == GraphKind::Undirected
+            && !self
+                .edge_data
+                .get(source)
+                .is_some_and(|adjacent| adjacent.contains_key(destination));
+        let (source, destination) = if reversed {
+            (destination, source)
+        } else {
+            (source, destination)
+        };
+        self.edge_data.get_mut(source)?.get_mut(destination)
+    }
+
+    /// Detaches the data from the edge going from `source` to `destination`
+    /// and returns it, if any. The edge itself is kept in the graph.
+    pub fn remove_edge_data(&mut self, source: &N, destination: &N) -> Option<E> {
+        let data = self
+            .edge_data
+            .get_mut(source)
+            .and_then(|adjacent| adjacent.remove(destination));
+        match self.kind {
+            GraphKind::Directed => data,
+            // The data of an undirected edge may be stored in the other
+            // direction.
+            GraphKind::Undirected => {
+                data.or_else(|| self.edge_data.get_mut(destination)?.remove(source))
+            }
+        }
+    }
+
+    /// Iterates over the edges of a path, as `(source, destination, cost,
+    /// data)` tuples, for instance to build turn-by-turn directions.
+    ///
+    /// The edges without data come with `None`. Consecutive nodes of the path
+    /// which are not connected in the graph are skipped.
+    pub fn path_edges<'a>(
+        &'a self,
+        path: &'a ShortestPathResult<N, W>,
+    ) -> impl Iterator<Item = (&'a N, &'a N, W, Option<&'a E>)> + 'a {
+        path.nodes.windows(2).filter_map(move |pair| {
+            let (source, destination) = (&pair[0], &pair[1]);
+            let cost = self.edge_cost(source, destination)?;
+            Some((
+                source,
+                destination,
+                cost,
+                self.edge_data(source, destination),
+            ))
+        })
+    }
+
+    /// Iterates over the data attached to the edges, as `(source,
+    /// destination, data)` tuples in no particular order.
+    ///
+    /// In an undirected graph, the data of every edge is only reported once,
+    /// in the direction it was attached to the edge.
+    pub fn edge_data_entries(&self) -> impl Iterator<Item = (&N, &N, &E)> + '_ {
+        self.edge_data.iter().flat_map(|(source, adjacent)| {
+            adjacent
+                .iter()
+                .map(move |(destination, data)| (source, destination, data))
+        })
+    }
+
+    // Attaches data to the edge going from source to destination, replacing the
+    // previous data. The edge itself is left untouched.
+    pub(crate) fn attach_edge_data(&mut self, source: N, destination: N, data: E) {
+        // In an undirected graph, the data may have been stored in the other
+        // direction.
+        self.remove_edge_data(&source, &destination);
+        self.edge_data
+            .entry(source)
+            .or_default()
+            .insert(destination, data);
+    }
+
+    // Inserts a single directed edge in the adjacency lists, whatever the kind
+    // of the graph. The old cost is returned if the edge already existed.
+    fn insert_edge(
+        &mut self,
+        source: N,
+        destination: N,
+        cost: W,
+        policy: DuplicateEdgePolicy,
+    ) -> Option<W> {
+        // The destination has to be known by the graph as well, even if it has
+        // no outgoing edge.
+        self.add_node(destination.clone());
+        let adjacent = self.adjacency.entry(source).or_default();
+
+        match adjacent.get_mut(&destination) {
+            // The policy decides what happens to an existing edge.
+            Some(old_cost) => {
+                let previous = *old_cost;
+                *old_cost = policy.resolve(previous, cost);
+                Some(previous)
+            }
+            // Otherwise, there is nothing to decide.
+            None => adjacent.insert(destination, cost),
+        }
+    }
+
+    // Removes a single directed edge from the adjacency lists, whatever the
+    // kind of the graph.
+    fn delete_edge(&mut self, source: &N, destination: &N) -> Option<W> {
+        self.adjacency.get_mut(source)?.remove(destination)
+    }
+
+    /// Checks the consistency of the graph, see [`validate`](crate::validate).
+    pub fn validate(&self) -> ValidationReport<N, W> {
+        crate::validate(self)
+    }
+
+    /// Describes the size and shape of the graph, see
+    /// [`graph_stats`](crate::graph_stats).
+    pub fn stats(&self) -> GraphStats<W> {
+        crate::graph_stats(self)
+    }
+
+    /// Returns the copy of `node` which is stored in the graph, if any.
+    pub fn get_node(&self, node: &N) -> Option<&N> {
+        self.adjacency.get_key_value(node).map(|(key, _)| key)
+    }
+
+    /// Iterates over all the nodes of the graph, in no particular order.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> + '_ {
+        self.adjacency.keys()
+    }
+
+    /// Iterates over all the edges of the graph as `(source, destination,
+    /// cost)` tuples, in no particular order.
+    ///
+    /// In an undirected graph, every edge is reported in both directions.
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N, W)> + '_ {
+        self.adjacency.iter().flat_map(|(source, adjacent)| {
+            adjacent
+                .iter()
+                .map(move |(destination, cost)| (source, destination, *cost))
+        })
+    }
+
+    /// The number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// The number of edges in the graph.
+    ///
+    /// In an undirected graph, every edge is counted in both directions, just
+    /// like [`Graph::edges`] reports them.
+    pub fn edge_count(&self) -> usize {
+        self.adjacency.values().map(HashMap::len).sum()
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Weight, D, E> GraphLike for Graph<N, W, D, E> {
+    type Node = N;
+    type Weight = W;
+
+    fn get_node(&self, node: &N) -> Option<&N> {
+        Graph::get_node(self, node)
+    }
+
+    fn neighbors<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, W)> + 'a {
+        Graph::neighbors(self, node)
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a N> + 'a {
+        Graph::nodes(self)
+    }
+
+    fn contains_node(&self, node: &N) -> bool {
+        Graph::contains_node(self, node)
+    }
+
+    fn edges<'a>(&'a self) -> impl Iterator<Item = (&'a N, &'a N, W)> + 'a {
+        Graph::edges(self)
+    }
+
+    fn node_count(&self) -> usize {
+        Graph::node_count(self)
+    }
+
+    fn edge_count(&self) -> usize {
+        Graph::edge_count(self)
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Weight, D: Clone, E: Clone> Graph<N, W, D, E> {
+    /// Returns a copy of the graph with the direction of every edge flipped.
+    ///
+    /// The reversed graph is what backward searches run on, for instance to
+    /// find all the nodes from which a given node can be reached. The data
+    /// attached to the nodes and edges is kept, and an undirected graph is
+    /// simply copied.
+    pub fn reversed(&self) -> Self {
+        if self.kind == GraphKind::Undirected {
+            return self.clone();
+        }
+
+        let mut reversed = Graph::with_kind(self.kind);
+
+        // The isolated nodes have to be copied explicitly, the others are added
+        // along with their edges.
+        for node in self.nodes() {
+            reversed.add_node(node.clone());
+        }
+        for (source, destination, cost) in self.edges() {
+            reversed.add_edge(destination.clone(), source.clone(), cost);
+        }
+
+        // The data is carried over, flipped as well for the edges.
+        reversed.data = self.data.clone();
+        for (source, destination, data) in self.edge_data_entries() {
+            reversed.attach_edge_data(destination.clone(), source.clone(), data.clone());
+        }
+        reversed
+    }
+
+    /// Returns the subgraph made of the nodes for which `predicate` returns
+    /// `true`, along with the edges between them.
+    ///
+    /// The data attached to the remaining nodes and edges is kept.
+    pub fn filter<P: FnMut(&N) -> bool>(&self, mut predicate: P) -> Self {
+        // We decide once for every node, as the predicate may be expensive.
+        let kept: HashSet<&N> = self.nodes().filter(|node| predicate(node)).collect();
+        self.subgraph(|node| kept.contains(node), |_, _, _| true)
+    }
+
+    /// Returns the subgraph made of all the nodes and of the edges for which
+    /// `predicate` returns `true`, given their source, destination and cost.
+    ///
+    /// In an undirected graph, an edge is only kept if `predicate` accepts it
+    /// in both directions. The data attached to the nodes and the remaining
+    /// edges is kept.
+    pub fn filter_edges<P: FnMut(&N, &N, W) -> bool>(&self, mut predicate: P) -> Self {
+        match self.kind {
+            GraphKind::Directed => self.subgraph(|_| true, predicate),
+            GraphKind::Undirected => self.subgraph(
+                |_| true,
+                |source, destination, cost| {
+                    predicate(source, destination, cost) && predicate(destination, source, cost)
+                },
+            ),
+        }
+    }
+
+    // Copies the nodes and the edges accepted by their predicates into a new
+    // graph of the same kind, along with their data. The edge predicate is
+    // only called for edges between kept nodes.
+    fn subgraph<P, Q>(&self, keep_node: P, mut keep_edge: Q) -> Self
+    where
+        P: Fn(&N) -> bool,
+        Q: FnMut(&N, &N, W) -> bool,
+    {
+        let mut subgraph = Graph::with_kind(self.kind);
+
+        for node in self.nodes().filter(|node| keep_node(node)) {
+            subgraph.add_node(node.clone());
+            if let Some(data) = self.data.get(node) {
+                subgraph.data.insert(node.clone(), data.clone());
+            }
+        }
+
+        // The adjacency lists are copied one directed edge at a time, which
+        // keeps both directions of the undirected edges as they are.
+        for (source, destination, cost) in self.edges() {
+            if keep_node(source) && keep_node(destination) && keep_edge(source, destination, cost) {
+                subgraph.insert_edge(
+                    source.clone(),
+                    destination.clone(),
+                    cost,
+                    DuplicateEdgePolicy::Overwrite,
+                );
+            }
+        }
+
+        // The data is only kept for the edges which made it in the subgraph.
+        for (source, destination, data) in self.edge_data_entries() {
+            if subgraph.edge_cost(source, destination).is_some() {
+                subgraph.attach_edge_data(source.clone(), destination.clone(), data.clone());
+            }
+        }
+        subgraph
+    }
+
+    /// Returns a graph with the same structure and data, whose costs are
+    /// computed by `map` from the source, destination and cost of every edge.
+    ///
+    /// The new costs may have a different type, for instance when converting
+    /// distances into travel times. In an undirected graph, `map` is called
+    /// for both directions of every edge, and should give them the same cost.
+    pub fn map_weights<V, F>(&self, mut map: F) -> Graph<N, V, D, E>
+    where
+        V: Weight,
+        F: FnMut(&N, &N, W) -> V,
+    {
+        let mut mapped = Graph::with_kind(self.kind);
+
+        // The isolated nodes have to be copied explicitly, the others are added
+        // along with their edges.
+        for node in self.nodes() {
+            mapped.add_node(node.clone());
+        }
+        // The edges are copied one directed edge at a time, which keeps both
+        // directions of the undirected edges as they are.
+        for (source, destination, cost) in self.edges() {
+            mapped.insert_edge(
+                source.clone(),
+                destination.clone(),
+                map(source, destination, cost),
+                DuplicateEdgePolicy::Overwrite,
+            );
+        }
+
+        // The data is simply carried over.
+        mapped.data = self.data.clone();
+        mapped.edge_data = self.edge_data.clone();
+        mapped
+    }
+
+    /// Returns a graph with the same edges and data, whose nodes are renamed
+    /// by `map`.
+    ///
+    /// `map` is called once for every node. If it gives the same name to
+    /// several nodes, they are merged into a single node: the cheapest of the
+    /// edges which end up connecting the same nodes is kept, and the data of
+    /// one of the merged nodes or edges is kept.
+    pub fn map_nodes<M, F>(&self, mut map: F) -> Graph<M, W, D, E>
+    where
+        M: Eq + Hash + Clone,
+        F: FnMut(&N) -> M,
+    {
+        // We rename every node once, as map may be expensive.
+        let names: HashMap<&N, M> = self.nodes().map(|node| (node, map(node))).collect();
+        let mut mapped = Graph::with_kind(self.kind);
+
+        for (node, name) in &names {
+            mapped.add_node(name.clone());
+            if let Some(data) = self.data.get(*node) {
+                mapped.data.insert(name.clone(), data.clone());
+            }
+        }
+        // The edges are copied one directed edge at a time, which keeps both
+        // directions of the undirected edges as they are.
+        for (source, destination, cost) in self.edges() {
+            mapped.insert_edge(
+                names[source].clone(),
+                names[destination].clone(),
+                cost,
+                DuplicateEdgePolicy::KeepMinimum,
+            );
+        }
+        for (source, destination, data) in self.edge_data_entries() {
+            mapped.attach_edge_data(
+                names[source].clone(),
+                names[destination].clone(),
+                data.clone(),
+            );
+        }
+        mapped
+    }
+
+    /// Adds all the nodes and edges of `other` to the graph.
+    ///
+    /// When both graphs contain the same edge with different costs, `policy`
+    /// decides which cost to keep. With [`MergePolicy::Error`], the first
+    /// conflict found is returned and the graph is left untouched. The edges
+    /// of `other` are added according to the kind of this graph, so an
+    /// undirected graph stays undirected.
+    ///
+    /// The data attached to the nodes and edges of `other` is copied over,
+    /// unless this graph already had some data for the same node or edge.
+    pub fn merge(
+        &mut self,
+        other: &Graph<N, W, D, E>,
+        policy: MergePolicy,
+    ) -> Result<(), MergeConflict<N, W>> {
+        // We look for conflicts before changing anything, so that a failed
+        // merge has no effect.
+        if policy == MergePolicy::Error {
+            for (source, destination, incoming) in other.edges() {
+                match self.edge_cost(source, destination) {
+                    Some(existing) if existing != incoming => {
+                        return Err(MergeConflict {
+                            source: source.clone(),
+                            destination: destination.clone(),
+                            existing,
+                            incoming,
+                        })
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        for node in other.nodes() {
+            self.add_node(node.clone());
+        }
+        for (source, destination, incoming) in other.edges() {
+            // The policy only matters if the edge already existed.
+            let cost = match (self.edge_cost(source, destination), policy) {
+                (Some(existing), MergePolicy::Min) => existing.min(incoming),
+                (Some(existing), MergePolicy::Max) => existing.max(incoming),
+                _ => incoming,
+            };
+            self.add_edge(source.clone(), destination.clone(), cost);
+        }
+
+        // The existing data always wins over the incoming one.
+        for (node, data) in &other.data {
+            if !self.data.contains_key(node) {
+                self.data.insert(node.clone(), data.clone());
+            }
+        }
+        for (source, destination, data) in other.edge_data_entries() {
+            if self.edge_data(source, destination).is_none() {
+                self.attach_edge_data(source.clone(), destination.clone(), data.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What to do when an edge is added between two nodes which are already
+/// connected in the same direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DuplicateEdgePolicy {
+    /// The cost of the new edge replaces the old one, just like a plain
+    /// `HashMap` insertion would. This is the default.
+    #[default]
+    Overwrite,
+    /// The old edge is kept and the new one is ignored.
+    KeepFirst,
+    /// Only the cheapest of both edges is kept.
+    KeepMinimum,
+}
+
+/// How to combine the costs of an edge present in both graphs given to
+/// [`Graph::merge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergePolicy {
+    /// The cheapest cost is kept.
+    Min,
+    /// The most expensive cost is kept.
+    Max,
+    /// The cost of the merged graph replaces the existing one.
+    Overwrite,
+    /// The merge fails if the costs are different.
+    Error,
+}
+
+impl DuplicateEdgePolicy {
+    // Returns the cost to keep for an edge which already had `old_cost` when
+    // `new_cost` is added.
+    pub(crate) fn resolve<W: Weight>(self, old_cost: W, new_cost: W) -> W {
+        match self {
+            DuplicateEdgePolicy::Overwrite => new_cost,
+            DuplicateEdgePolicy::KeepFirst => old_cost,
+            DuplicateEdgePolicy::KeepMinimum => old_cost.min(new_cost),
+        }
+    }
+}
+
+/// Displays every node on its own line, followed by its neighbors and the
+/// costs of the edges leading to them, for instance `A -> B (1), C (4)`. The
+/// edges of an undirected graph are shown with `--` instead of `->`.
+///
+/// The nodes and their neighbors are sorted, so that the output is stable.
+impl<N, W, D, E> fmt::Display for Graph<N, W, D, E>
+where
+    N: Eq + Hash + Clone + Ord + fmt::Display,
+    W: Weight + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arrow = match self.kind {
+            GraphKind::Directed => "->",
+            GraphKind::Undirected => "--",
+        };
+
+        let mut nodes: Vec<&N> = self.nodes().collect();
+        nodes.sort();
+
+        for (position, node) in nodes.into_iter().enumerate() {
+            if position > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", node)?;
+
+            let mut neighbors: Vec<(&N, W)> = self.neighbors(node).collect();
+            neighbors.sort();
+            for (index, (neighbor, cost)) in neighbors.into_iter().enumerate() {
+                if index == 0 {
+                    write!(f, " {} ", arrow)?;
+                } else {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{} ({})", neighbor, cost)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builds a directed graph from `(source, destination, cost)` edges.
+///
+/// When the same edge appears several times, the last cost is kept.
+impl<N: Eq + Hash + Clone, W: Weight, D, E> FromIterator<(N, N, W)> for Graph<N, W, D, E> {
+    fn from_iter<I: IntoIterator<Item = (N, N, W)>>(edges: I) -> Self {
+        let mut graph = Graph::new();
+        graph.extend(edges);
+        graph
+    }
+}
+
+/// Adds `(source, destination, cost)` edges to the graph, just like
+/// [`Graph::add_edge`] would.
+impl<N: Eq + Hash + Clone, W: Weight, D, E> Extend<(N, N, W)> for Graph<N, W, D, E> {
+    fn extend<I: IntoIterator<Item = (N, N, W)>>(&mut self, edges: I) {
+        for (source, destination, cost) in edges {
+            self.add_edge(source, destination, cost);
+        }
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Weight, D, E> Default for Graph<N, W, D, E> {
+    fn default() -> Self {
+        Graph::new()
+    }
+}
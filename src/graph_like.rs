@@ -0,0 +1,78 @@
+// Required by the nodes, which are used as keys by the algorithms.
+use std::hash::Hash;
+
+// The costs of the edges.
+use crate::Weight;
+
+/// The operations the algorithms of this crate need from a graph.
+///
+/// The algorithms are written against this trait rather than against a given
+/// representation, so that they can run over any backing structure. The
+/// [`Graph`](crate::Graph) type implements it, and so can any user defined
+/// storage.
+///
+/// The algorithms work with references to the nodes stored in the graph, so
+/// that they don't have to clone them while exploring it. The nodes are only
+/// cloned when building the returned paths.
+pub trait GraphLike {
+    /// The type of the nodes of the graph.
+    type Node: Eq + Hash + Clone;
+    /// The type of the costs of the edges of the graph.
+    type Weight: Weight;
+
+    /// Returns the copy of `node` which is stored in the graph, or `None` if
+    /// the node is not part of the graph.
+    fn get_node(&self, node: &Self::Node) -> Option<&Self::Node>;
+
+    /// Iterates over the nodes adjacent to `node`, along with the cost of the
+    /// edge leading to them.
+    ///
+    /// A node which is not part of the graph has no neighbors.
+    fn neighbors<'a>(
+        &'a self,
+        node: &Self::Node,
+    ) -> impl Iterator<Item = (&'a Self::Node, Self::Weight)> + 'a;
+
+    /// Iterates over all the nodes of the graph, in no particular order.
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a Self::Node> + 'a;
+
+    /// Returns whether `node` is part of the graph.
+    fn contains_node(&self, node: &Self::Node) -> bool {
+        self.get_node(node).is_some()
+    }
+
+    /// Iterates over all the edges of the graph as `(source, destination,
+    /// cost)` tuples, in no particular order.
+    fn edges<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (&'a Self::Node, &'a Self::Node, Self::Weight)> + 'a {
+        // The edges are simply the neighbors of every node.
+        self.nodes().flat_map(move |source| {
+            self.neighbors(source)
+                .map(move |(destination, cost)| (source, destination, cost))
+        })
+    }
+
+    /// Returns the cost shared by all the edges of the graph, or `None` if
+    /// some edges cost more than others or if there is no edge at all.
+    ///
+    /// This lets [`shortest_path`](crate::shortest_path) fall back to a
+    /// breadth-first search. The default implementation goes through all the
+    /// edges, so the representations which keep track of their costs should
+    /// answer faster.
+    fn uniform_cost(&self) -> Option<Self::Weight> {
+        let mut edges = self.edges().map(|(_, _, cost)| cost);
+        let first = edges.next()?;
+        edges.all(|cost| cost == first).then_some(first)
+    }
+
+    /// The number of nodes in the graph.
+    fn node_count(&self) -> usize {
+        self.nodes().count()
+    }
+
+    /// The number of edges in the graph.
+    fn edge_count(&self) -> usize {
+        self.edges().count()
+    }
+}
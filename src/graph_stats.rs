@@ -0,0 +1,106 @@
+// Used to count the edges entering every node.
+use crate::hash::HashMap;
+// The graph being described.
+use crate::{GraphLike, Weight};
+
+/// A summary of the size and shape of a graph, computed by [`graph_stats`].
+///
+/// This is a quick sanity check on the data a graph was loaded from: a
+/// number of nodes or edges far from the expected one, many isolated nodes
+/// or costs out of the expected range usually reveal a parsing mistake. The
+/// edges are those of [`GraphLike::edges`], so every undirected edge counts
+/// as two directed ones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphStats<W> {
+    /// The number of nodes.
+    pub node_count: usize,
+    /// The number of directed edges.
+    pub edge_count: usize,
+    /// The number of nodes with every number of incoming edges, indexed by
+    /// that number.
+    pub in_degrees: Vec<usize>,
+    /// The number of nodes with every number of outgoing edges, indexed by
+    /// that number.
+    pub out_degrees: Vec<usize>,
+    /// The cost of the cheapest edge, or `None` if there is no edge.
+    pub min_weight: Option<W>,
+    /// The cost of the most expensive edge, or `None` if there is no edge.
+    pub max_weight: Option<W>,
+    /// The average cost of the edges, or `None` if there is no edge or if a
+    /// cost cannot be converted with [`Weight::to_f64`].
+    pub mean_weight: Option<f64>,
+    /// The share of the edges the graph has among all the edges it could
+    /// have between its different nodes, which is zero for a graph with
+    /// fewer than two nodes.
+    pub density: f64,
+    /// The number of nodes without any incoming or outgoing edge.
+    pub isolated_nodes: usize,
+}
+
+/// Describes the size and shape of `graph` in a [`GraphStats`].
+///
+/// The edges are all looked at once, so this takes time linear in the size
+/// of the graph. The edges leading out of the graph only count as outgoing
+/// edges.
+pub fn graph_stats<G: GraphLike>(graph: &G) -> GraphStats<G::Weight> {
+    // The number of edges entering and leaving every node.
+    let mut degrees: HashMap<&G::Node, (usize, usize)> =
+        graph.nodes().map(|node| (node, (0, 0))).collect();
+    let mut edge_count = 0;
+    let mut min_weight: Option<G::Weight> = None;
+    let mut max_weight: Option<G::Weight> = None;
+    let mut total = Some(0.0);
+
+    for (source, destination, cost) in graph.edges() {
+        edge_count += 1;
+        if let Some((_, outgoing)) = degrees.get_mut(source) {
+            *outgoing += 1;
+        }
+        if let Some((incoming, _)) = degrees.get_mut(destination) {
+            *incoming += 1;
+        }
+        min_weight = Some(min_weight.map_or(cost, |min| min.min(cost)));
+        max_weight = Some(max_weight.map_or(cost, |max| max.max(cost)));
+        total = total.and_then(|total| Some(total + cost.to_f64()?));
+    }
+
+    // We gather the degrees of the nodes into their distributions.
+    let mut in_degrees = Vec::new();
+    let mut out_degrees = Vec::new();
+    let mut isolated_nodes = 0;
+    for &(incoming, outgoing) in degrees.values() {
+        count(&mut in_degrees, incoming);
+        count(&mut out_degrees, outgoing);
+        if incoming == 0 && outgoing == 0 {
+            isolated_nodes += 1;
+        }
+    }
+
+    let node_count = degrees.len();
+    let pairs = node_count.saturating_mul(node_count.saturating_sub(1));
+    GraphStats {
+        node_count,
+        edge_count,
+        in_degrees,
+        out_degrees,
+        min_weight,
+        max_weight,
+        mean_weight: total
+            .filter(|_| edge_count > 0)
+            .map(|total| total / edge_count as f64),
+        density: if pairs == 0 {
+            0.0
+        } else {
+            edge_count as f64 / pairs as f64
+        },
+        isolated_nodes,
+    }
+}
+
+// Counts one more node with the given degree in the distribution.
+fn count(distribution: &mut Vec<usize>, degree: usize) {
+    if distribution.len() <= degree {
+        distribution.resize(degree + 1, 0);
+    }
+    distribution[degree] += 1;
+}
@@ -0,0 +1,212 @@
+// Used to find the index of the nodes.
+use crate::hash::HashMap;
+// The graph being searched, and the reasons why nothing was found.
+use crate::{GraphLike, HamiltonianError};
+
+/// Finds a Hamiltonian path of `graph`, i.e. a path following the edges
+/// which goes through every node exactly once, as long as the graph has at
+/// most `max_nodes` nodes.
+///
+/// Finding such a path is NP-complete, so the search backtracks over the
+/// paths, which takes exponential time in the worst case. It is meant for
+/// small graphs, such as the ones of puzzles, and `max_nodes` guards against
+/// running it on a graph too large for it. The search is pruned as soon as
+/// a node left cannot be entered anymore, or as soon as two nodes left cannot
+/// be left anymore, since only the end of the path may have no successor.
+/// The successors with the fewest ways out are tried first, following
+/// Warnsdorff's rule.
+///
+/// The costs of the edges are ignored. Returns the nodes in the order of the
+/// path, an empty graph having an empty path. Fails with
+/// [`HamiltonianError::TooManyNodes`] if the graph has more than `max_nodes`
+/// nodes, and with [`HamiltonianError::NotFound`] if it has no such path.
+pub fn hamiltonian_path<G: GraphLike>(
+    graph: &G,
+    max_nodes: usize,
+) -> Result<Vec<G::Node>, HamiltonianError> {
+    hamiltonian_walk(graph, max_nodes, false)
+}
+
+/// Finds a Hamiltonian cycle of `graph`, i.e. a cycle following the edges
+/// which goes through every node exactly once, as long as the graph has at
+/// most `max_nodes` nodes.
+///
+/// The search is the same as with [`hamiltonian_path`], except that every
+/// node left must keep a way out, the first node of the path being the way
+/// out of the last one. Returns the nodes in the order of the cycle, the
+/// first node being repeated at the end, so that a single node needs an edge
+/// to itself. The errors are the same as with [`hamiltonian_path`], an empty
+/// graph having no cycle.
+pub fn hamiltonian_cycle<G: GraphLike>(
+    graph: &G,
+    max_nodes: usize,
+) -> Result<Vec<G::Node>, HamiltonianError> {
+    let mut cycle = hamiltonian_walk(graph, max_nodes, true)?;
+    match cycle.first() {
+        Some(first) => {
+            let first = first.clone();
+            cycle.push(first);
+            Ok(cycle)
+        }
+        None => Err(HamiltonianError::NotFound),
+    }
+}
+
+// Finds a Hamiltonian cycle of the graph if closed is true, without its last
+// node, and a Hamiltonian path otherwise.
+fn hamiltonian_walk<G: GraphLike>(
+    graph: &G,
+    max_nodes: usize,
+    closed: bool,
+) -> Result<Vec<G::Node>, HamiltonianError> {
+    let count = graph.node_count();
+    if count > max_nodes {
+        return Err(HamiltonianError::TooManyNodes { count, max_nodes });
+    }
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+    let nodes: Vec<&G::Node> = graph.nodes().collect();
+    let index: HashMap<&G::Node, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(position, &node)| (node, position))
+        .collect();
+
+    // We keep the distinct successors and predecessors of every node, the
+    // edges leading out of the graph being ignored. The edges from a node to
+    // itself are useless but for the cycle of a single node.
+    let mut successors = vec![Vec::new(); nodes.len()];
+    let mut predecessors = vec![Vec::new(); nodes.len()];
+    for (from, to, _) in graph.edges() {
+        if let Some(&to) = index.get(to) {
+            let from = index[from];
+            if (from != to || nodes.len() == 1) && !successors[from].contains(&to) {
+                successors[from].push(to);
+                predecessors[to].push(from);
+            }
+        }
+    }
+    let search = Search {
+        successors: &successors,
+        predecessors: &predecessors,
+        closed,
+    };
+
+    // A node without predecessor can only start the path, and the cycle
+    // may start anywhere.
+    let sources: Vec<usize> = (0..nodes.len())
+        .filter(|&node| predecessors[node].is_empty())
+        .collect();
+    let starts: Vec<usize> = match (closed, sources.len()) {
+        (true, _) => vec![0],
+        (false, 0) => (0..nodes.len()).collect(),
+        (false, _) => sources.into_iter().take(1).collect(),
+    };
+    for start in starts {
+        if let Some(path) = search.from(start) {
+            return Ok(path.into_iter().map(|node| nodes[node].clone()).collect());
+        }
+    }
+    Err(HamiltonianError::NotFound)
+}
+
+// The backtracking search over the paths of the indexed graph.
+struct Search<'a> {
+    // The distinct successors of every node.
+    successors: &'a [Vec<usize>],
+    // The distinct predecessors of every node.
+    predecessors: &'a [Vec<usize>],
+    // Whether the path must lead back to its start.
+    closed: bool,
+}
+
+impl Search<'_> {
+    // Finds a Hamiltonian path starting from the given node, which leads back
+    // to it if the search is closed.
+    fn from(&self, start: usize) -> Option<Vec<usize>> {
+        let count = self.successors.len();
+        let mut visited = vec![false; count];
+        visited[start] = true;
+        let mut path = vec![start];
+        if !self.feasible(&visited, start, start) {
+            return None;
+        }
+        // The successors left to try from every node of the path.
+        let mut candidates = vec![self.candidates(&visited, start)];
+        while let Some(frame) = candidates.last_mut() {
+            if path.len() == count {
+                let last = path[count - 1];
+                if !self.closed || self.successors[last].contains(&start) {
+                    return Some(path);
+                }
+            }
+            match frame.pop() {
+                Some(next) => {
+                    visited[next] = true;
+                    path.push(next);
+                    if self.feasible(&visited, start, next) {
+                        candidates.push(self.candidates(&visited, next));
+                    } else {
+                        visited[next] = false;
+                        path.pop();
+                    }
+                }
+                None => {
+                    // Every successor was tried, so we take the node back.
+                    candidates.pop();
+                    if let Some(last) = path.pop() {
+                        visited[last] = false;
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    // Lists the successors of the node not visited yet, the one with the
+    // most ways out first since the last one is tried first.
+    fn candidates(&self, visited: &[bool], node: usize) -> Vec<usize> {
+        let mut candidates: Vec<(usize, usize)> = self.successors[node]
+            .iter()
+            .filter(|&&next| !visited[next])
+            .map(|&next| (self.ways_out(visited, next, None), next))
+            .collect();
+        candidates.sort_unstable_by(|a, b| b.cmp(a));
+        candidates.into_iter().map(|(_, next)| next).collect()
+    }
+
+    // Counts the successors of the node not visited yet, along with the start
+    // if given.
+    fn ways_out(&self, visited: &[bool], node: usize, start: Option<usize>) -> usize {
+        self.successors[node]
+            .iter()
+            .filter(|&&next| !visited[next] || Some(next) == start)
+            .count()
+    }
+
+    // Checks whether the path ending at the given node may still be extended
+    // into a Hamiltonian one: every node left must have a way in from the
+    // nodes left or from the end of the path, and a way out to the nodes
+    // left or, for a cycle, to its start. Only the end of a path may have no
+    // way out.
+    fn feasible(&self, visited: &[bool], start: usize, last: usize) -> bool {
+        let start = if self.closed { Some(start) } else { None };
+        let mut dead_ends = 0;
+        for node in (0..visited.len()).filter(|&node| !visited[node]) {
+            let entered = self.predecessors[node]
+                .iter()
+                .any(|&previous| !visited[previous] || previous == last);
+            if !entered {
+                return false;
+            }
+            if self.ways_out(visited, node, start) == 0 {
+                dead_ends += 1;
+                if self.closed || dead_ends > 1 {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
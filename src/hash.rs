@@ -0,0 +1,20 @@
+// The hash maps used by the graphs and the algorithms.
+//
+// The standard library hashes the keys with SipHash, which resists collision
+// attacks but is slow on short keys such as strings or integers. With the
+// "fast-hash" feature, FxHash is used instead. It is much faster, but should
+// not be used with nodes chosen by an untrusted party.
+
+// The hasher used by the maps of the crate.
+#[cfg(feature = "fast-hash")]
+pub(crate) type BuildHasher = rustc_hash::FxBuildHasher;
+
+// The hasher used by the maps of the crate.
+#[cfg(not(feature = "fast-hash"))]
+pub(crate) type BuildHasher = std::collections::hash_map::RandomState;
+
+// A `HashMap` using the hasher of the crate, created with `default()`.
+pub(crate) type HashMap<K, V> = std::collections::HashMap<K, V, BuildHasher>;
+
+// A `HashSet` using the hasher of the crate, created with `default()`.
+pub(crate) type HashSet<K> = std::collections::HashSet<K, BuildHasher>;
@@ -0,0 +1,124 @@
+// Used to order the states by cost.
+use std::cmp::Reverse;
+// Used as priority queue by the search.
+use std::collections::BinaryHeap;
+
+// Used to keep track of the settled nodes.
+use crate::hash::HashMap;
+// The graph being explored, and the path found in it.
+use crate::{
+    GraphLike, OverflowPolicy, PathError, PathResult, SearchStats, ShortestPathResult, Weight,
+};
+
+/// Finds the shortest path from `source` to `destination` in `graph` which
+/// uses at most `max_hops` edges.
+///
+/// The cheapest path may take many cheap edges, while a network routing a
+/// packet with a limited time to live, for instance, can only afford a few
+/// hops. The search therefore settles the states made of a node and of the
+/// number of edges used to reach it, rather than the plain nodes. A state
+/// is only worth settling if no state of the same node was settled with
+/// fewer edges, since that one was also cheaper. The search settles each
+/// node at most `max_hops + 1` times.
+///
+/// The returned path and errors are the same as with
+/// [`dijkstra`](crate::dijkstra). The query fails with [`PathError::NoPath`]
+/// if every path to the destination uses too many edges.
+pub fn dijkstra_max_hops<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    max_hops: usize,
+) -> PathResult<G::Node, G::Weight> {
+    // We work with the copies of the nodes stored in the graph, so that all
+    // the nodes we handle share the lifetime of the graph.
+    let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+    let destination = graph
+        .get_node(destination)
+        .ok_or(PathError::UnknownDestination)?;
+    let policy = OverflowPolicy::default();
+
+    // Every state reached so far, along with the state it was reached from.
+    let mut states = vec![State {
+        node: source,
+        hops: 0,
+        previous: None,
+    }];
+    // The states reached so far along with their cost, the cheapest one
+    // first.
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((G::Weight::zero(), 0)));
+    // The fewest edges used by a settled state of every node.
+    let mut fewest_hops: HashMap<&G::Node, usize> = HashMap::default();
+    let mut stats = SearchStats {
+        pushes: 1,
+        ..SearchStats::default()
+    };
+
+    while let Some(Reverse((cost, state))) = queue.pop() {
+        let State { node, hops, .. } = states[state];
+        // A state is dominated by the cheaper states of the node which used
+        // fewer edges.
+        if fewest_hops.get(node).is_some_and(|&fewest| fewest <= hops) {
+            continue;
+        }
+        fewest_hops.insert(node, hops);
+        stats.settled += 1;
+
+        if node == destination {
+            return Ok(ShortestPathResult {
+                nodes: backtrack(&states, state),
+                cost,
+                settled: Some(stats.settled),
+                stats: Some(stats),
+            });
+        }
+
+        // The states which used all the edges allowed cannot go further.
+        if hops == max_hops {
+            continue;
+        }
+        for (neighbor, edge_cost) in graph.neighbors(node) {
+            stats.relaxations += 1;
+            if fewest_hops
+                .get(neighbor)
+                .is_some_and(|&fewest| fewest <= hops + 1)
+            {
+                continue;
+            }
+            let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+            states.push(State {
+                node: neighbor,
+                hops: hops + 1,
+                previous: Some(state),
+            });
+            queue.push(Reverse((new_cost, states.len() - 1)));
+            stats.pushes += 1;
+        }
+    }
+
+    Err(PathError::NoPath)
+}
+
+// A node reached with a given number of edges.
+#[derive(Debug, Clone, Copy)]
+struct State<'g, N> {
+    // The node, as stored in the graph.
+    node: &'g N,
+    // The number of edges used to reach it.
+    hops: usize,
+    // The position of the state it was reached from, None for the source.
+    previous: Option<usize>,
+}
+
+// Rebuilds the path leading to the given state, starting from the source.
+fn backtrack<N: Clone>(states: &[State<'_, N>], last: usize) -> Vec<N> {
+    let mut path = Vec::with_capacity(states[last].hops + 1);
+    let mut state = Some(last);
+    while let Some(position) = state {
+        path.push(states[position].node.clone());
+        state = states[position].previous;
+    }
+    path.reverse();
+    path
+}
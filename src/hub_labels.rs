@@ -0,0 +1,199 @@
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to find the index of the nodes, and to merge the labels.
+use crate::hash::HashMap;
+// The graph being labelled, and the hierarchy the labels are built from.
+use crate::{ContractionHierarchy, GraphLike, OverflowPolicy, PathError, Weight};
+
+/// The hub labels of a graph, which answer the distance queries between any
+/// two nodes without searching the graph, following Abraham et al.
+///
+/// Every node is labelled with a few hubs, along with the cost of its
+/// shortest path to each of them, and with the cost of the shortest path from
+/// each of them in a second label. The labels are built so that a hub of the
+/// source lies on every shortest path to any destination, and is a hub of the
+/// destination as well: the cost of the path is then the lowest sum of the
+/// costs of both nodes to a hub they share, which only takes a merge of two
+/// short sorted lists. The hubs are picked from a contraction hierarchy, the
+/// hubs of a node being the nodes contracted after it which its searches up
+/// the hierarchy reach along a shortest path.
+///
+/// On road networks the labels hold about a hundred hubs each, so a query
+/// takes well under a microsecond, but the labels take far more memory than
+/// the graph. They suit the workloads needing millions of costs, but none of
+/// the paths themselves. The labels clone the nodes, so they don't borrow
+/// the graph, and they have to be built again if the graph changes.
+#[derive(Debug, Clone)]
+pub struct HubLabels<N, W> {
+    // The position of every node.
+    index: HashMap<N, usize>,
+    // The position of the first hub of the forward label of every node,
+    // followed by the number of hubs.
+    forward_offsets: Vec<usize>,
+    // The hubs of the forward labels, sorted by rank, along with the cost of
+    // the path to them.
+    forward: Vec<(usize, W)>,
+    // The position of the first hub of the backward label of every node,
+    // followed by the number of hubs.
+    backward_offsets: Vec<usize>,
+    // The hubs of the backward labels, sorted by rank, along with the cost
+    // of the path from them.
+    backward: Vec<(usize, W)>,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> HubLabels<N, W> {
+    /// Builds the hub labels of `graph`, from its
+    /// [`ContractionHierarchy`].
+    ///
+    /// The errors are the same as with [`ContractionHierarchy::new`].
+    pub fn new<G>(graph: &G) -> Result<Self, PathError>
+    where
+        G: GraphLike<Node = N, Weight = W>,
+    {
+        Self::from_hierarchy(&ContractionHierarchy::new(graph)?)
+    }
+
+    /// Builds the hub labels of the graph of `hierarchy`.
+    ///
+    /// The labels are built from the last node contracted to the first one:
+    /// the label of a node is made of the node itself and of the labels of
+    /// the nodes its edges up the hierarchy lead to, which are already
+    /// built. The hubs which turn out not to lie on a shortest path, since
+    /// the labels built so far give a cheaper path to them, are then pruned.
+    ///
+    /// Fails with [`PathError::Overflow`] if the cost of a path overflows.
+    pub fn from_hierarchy(hierarchy: &ContractionHierarchy<N, W>) -> Result<Self, PathError> {
+        let node_count = hierarchy.nodes.len();
+        let mut rank = vec![0; node_count];
+        for (position, &node) in hierarchy.order.iter().enumerate() {
+            rank[node] = position;
+        }
+
+        // The hubs are numbered by rank, so that the labels are sorted by
+        // rank, and each label only refers to the labels of higher ranks.
+        let mut forward: Vec<Vec<(usize, W)>> = vec![Vec::new(); node_count];
+        let mut backward: Vec<Vec<(usize, W)>> = vec![Vec::new(); node_count];
+        for &node in hierarchy.order.iter().rev() {
+            let hub = rank[node];
+            forward[hub] = label(hub, &hierarchy.upward[node], &rank, &forward, &backward)?;
+            backward[hub] = label(hub, &hierarchy.downward[node], &rank, &backward, &forward)?;
+        }
+
+        // The labels are stored by position rather than by rank.
+        let (forward_offsets, forward) = flatten(&hierarchy.order, forward);
+        let (backward_offsets, backward) = flatten(&hierarchy.order, backward);
+        Ok(HubLabels {
+            index: hierarchy.index.clone(),
+            forward_offsets,
+            forward,
+            backward_offsets,
+            backward,
+        })
+    }
+
+    /// The cost of the shortest path from `source` to `destination`.
+    ///
+    /// Fails with [`PathError::UnknownSource`] or
+    /// [`PathError::UnknownDestination`] if either node is not part of the
+    /// graph, with [`PathError::NoPath`] if the destination cannot be reached
+    /// from the source, and with [`PathError::Overflow`] if the cost of the
+    /// path overflows.
+    pub fn distance(&self, source: &N, destination: &N) -> Result<W, PathError> {
+        let source = *self.index.get(source).ok_or(PathError::UnknownSource)?;
+        let destination = *self
+            .index
+            .get(destination)
+            .ok_or(PathError::UnknownDestination)?;
+        let forward = &self.forward[self.forward_offsets[source]..self.forward_offsets[source + 1]];
+        let backward = &self.backward
+            [self.backward_offsets[destination]..self.backward_offsets[destination + 1]];
+        merge(forward, backward)?.ok_or(PathError::NoPath)
+    }
+
+    /// The average number of hubs of the labels, both labels of every node
+    /// being counted.
+    pub fn average_label_size(&self) -> f64 {
+        if self.index.is_empty() {
+            return 0.0;
+        }
+        (self.forward.len() + self.backward.len()) as f64 / (2 * self.index.len()) as f64
+    }
+}
+
+// Builds the label of the hub with the given rank from the node itself and
+// the labels of the nodes its edges up the hierarchy lead to. The label is
+// pruned with the opposite labels, i.e. the backward labels for a forward
+// label and the other way around.
+fn label<W: Weight>(
+    hub: usize,
+    edges: &[(usize, W)],
+    rank: &[usize],
+    labels: &[Vec<(usize, W)>],
+    opposite: &[Vec<(usize, W)>],
+) -> Result<Vec<(usize, W)>, PathError> {
+    let policy = OverflowPolicy::default();
+    let mut costs: HashMap<usize, W> = HashMap::default();
+    costs.insert(hub, W::zero());
+    for &(neighbor, edge_cost) in edges {
+        for &(other, cost) in &labels[rank[neighbor]] {
+            let cost = policy.add(edge_cost, cost).ok_or(PathError::Overflow)?;
+            if costs.get(&other).is_none_or(|&old| cost < old) {
+                costs.insert(other, cost);
+            }
+        }
+    }
+    let mut label: Vec<(usize, W)> = costs.into_iter().collect();
+    label.sort_unstable_by_key(|&(other, _)| other);
+
+    // A hub is useless if a cheaper path to it goes through another hub.
+    let mut pruned = Vec::with_capacity(label.len());
+    for &(other, cost) in &label {
+        if other == hub || merge(&label, &opposite[other])?.is_none_or(|best| best >= cost) {
+            pruned.push((other, cost));
+        }
+    }
+    Ok(pruned)
+}
+
+// The lowest sum of the costs of the hubs both labels share, if any.
+fn merge<W: Weight>(first: &[(usize, W)], second: &[(usize, W)]) -> Result<Option<W>, PathError> {
+    let policy = OverflowPolicy::default();
+    let mut best = None;
+    let (mut i, mut j) = (0, 0);
+    while i < first.len() && j < second.len() {
+        let ((first_hub, first_cost), (second_hub, second_cost)) = (first[i], second[j]);
+        if first_hub < second_hub {
+            i += 1;
+        } else if second_hub < first_hub {
+            j += 1;
+        } else {
+            let cost = policy
+                .add(first_cost, second_cost)
+                .ok_or(PathError::Overflow)?;
+            if best.is_none_or(|best| cost < best) {
+                best = Some(cost);
+            }
+            i += 1;
+            j += 1;
+        }
+    }
+    Ok(best)
+}
+
+// Stores the labels, indexed by rank, one after the other, the label of the
+// node at any position starting at its offset.
+fn flatten<W>(order: &[usize], labels: Vec<Vec<(usize, W)>>) -> (Vec<usize>, Vec<(usize, W)>) {
+    let mut by_position: Vec<Vec<(usize, W)>> = (0..order.len()).map(|_| Vec::new()).collect();
+    for (label, &node) in labels.into_iter().zip(order) {
+        by_position[node] = label;
+    }
+    let mut offsets = Vec::with_capacity(order.len() + 1);
+    let mut hubs = Vec::new();
+    for label in by_position {
+        offsets.push(hubs.len());
+        hubs.extend(label);
+    }
+    offsets.push(hubs.len());
+    (offsets, hubs)
+}
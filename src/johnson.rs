@@ -0,0 +1,194 @@
+// Used to reweight the costs of the edges.
+use std::ops::Sub;
+
+// Used to keep track of the potentials and of the indices of the nodes.
+use crate::bellman_ford::relax;
+use crate::hash::HashMap;
+// The graph being explored, the search run from every node, and the matrix
+// of the paths found.
+use crate::{DistanceMatrix, GraphLike, OverflowPolicy, PathError, SearchWorkspace, Weight};
+
+/// Finds the shortest paths between all the pairs of nodes of `graph` with
+/// Johnson's algorithm, which supports negative costs.
+///
+/// A single run of Bellman-Ford gives every node a potential, which is used
+/// to reweight the edges so that none of them has a negative cost anymore
+/// while the shortest paths stay the same. Dijkstra's algorithm can then be
+/// run from every node on the reweighted graph, which takes O(V·E·log V)
+/// time overall. This is much faster than
+/// [`floyd_warshall`](crate::floyd_warshall) on large sparse graphs, the
+/// result being the same [`DistanceMatrix`].
+///
+/// The costs have to support subtraction to be reweighted, which is the case
+/// of the signed integer types. The edges leading out of the graph are left
+/// out. The query fails with [`PathError::NegativeCycle`] if the graph has a
+/// cycle whose total cost is negative, and with [`PathError::Overflow`] if
+/// the cost of a path or the reweighted cost of an edge overflows, which can
+/// happen to the edges costing nearly the largest cost when some costs are
+/// negative.
+pub fn johnson<G>(graph: &G) -> Result<DistanceMatrix<G::Node, G::Weight>, PathError>
+where
+    G: GraphLike,
+    G::Weight: Sub<Output = G::Weight>,
+{
+    // The potential of every node is the cost of its shortest path from an
+    // extra source linked to all the nodes for free, which is the same as
+    // starting all the nodes at zero.
+    let mut potentials: HashMap<_, _> = graph
+        .nodes()
+        .map(|node| (node, G::Weight::zero()))
+        .collect();
+    if relax(graph, &mut potentials, &mut HashMap::default())?.is_some() {
+        return Err(PathError::NegativeCycle);
+    }
+    // The edges leading out of the graph are left out of the reweighted
+    // graph, so the potentials of the nodes they lead to are not needed.
+    potentials.retain(|node, _| graph.contains_node(node));
+
+    // We make sure that every reweighted cost can be represented before
+    // running any search, since the reweighted graph cannot report it.
+    let policy = OverflowPolicy::default();
+    for (from, to, cost) in graph.edges() {
+        if let Some(&to_potential) = potentials.get(to) {
+            reweight(policy, cost, potentials[from], to_potential)?;
+        }
+    }
+    let reweighted = Reweighted { graph, potentials };
+
+    let nodes: Vec<G::Node> = graph.nodes().cloned().collect();
+    let index: HashMap<G::Node, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(position, node)| (node.clone(), position))
+        .collect();
+    let size = nodes.len();
+    let mut distances = vec![None; size * size];
+    let mut successors = vec![None; size * size];
+
+    // A single workspace is reused by the searches from all the nodes.
+    let mut workspace = SearchWorkspace::new();
+    let mut settled = Vec::new();
+    for (source, node) in nodes.iter().enumerate() {
+        // We settle all the nodes reachable from the source, keeping track of
+        // the order in which they are settled.
+        settled.clear();
+        let start = workspace.start(&reweighted, node)?;
+        workspace.progress(&reweighted, policy, None, |node| {
+            settled.push(node);
+            false
+        })?;
+
+        let row = source * size;
+        let source_potential = reweighted.potentials[start];
+        for &node in &settled {
+            // The cost of a path is its reweighted cost, minus the difference
+            // of potential between both ends added by the reweighting.
+            // The nodes which are not part of the graph are left out.
+            let destination = match index.get(node) {
+                Some(&destination) => destination,
+                None => continue,
+            };
+            // The reweighted cost is not negative and the potential is not
+            // positive, so only the subtraction can overflow.
+            let reweighted_cost = workspace.progression()[node];
+            let cost = policy
+                .add(reweighted_cost, reweighted.potentials[node])
+                .ok_or(PathError::Overflow)?;
+            distances[row + destination] = Some(
+                policy
+                    .sub(cost, source_potential)
+                    .ok_or(PathError::Overflow)?,
+            );
+
+            // The node following the source on the path of a node is the one
+            // on the path of its origin, which was settled before the node.
+            successors[row + destination] = Some(match workspace.origin().get(node) {
+                None => destination,
+                Some(&origin) if origin == start => destination,
+                Some(origin) => successors[row + index[*origin]]
+                    .expect("the origin of a node is settled before it"),
+            });
+        }
+    }
+
+    Ok(DistanceMatrix {
+        nodes,
+        index,
+        distances,
+        successors,
+    })
+}
+
+// A graph whose edges are reweighted according to the potentials of their
+// ends, so that none of them has a negative cost.
+struct Reweighted<'g, G: GraphLike> {
+    // The graph being reweighted.
+    graph: &'g G,
+    // The potential of every node of the graph.
+    potentials: HashMap<&'g G::Node, G::Weight>,
+}
+
+impl<G> GraphLike for Reweighted<'_, G>
+where
+    G: GraphLike,
+    G::Weight: Sub<Output = G::Weight>,
+{
+    type Node = G::Node;
+    type Weight = G::Weight;
+
+    fn get_node(&self, node: &G::Node) -> Option<&G::Node> {
+        self.graph.get_node(node)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        node: &G::Node,
+    ) -> impl Iterator<Item = (&'a G::Node, G::Weight)> + 'a {
+        // The reweighted costs were all checked before any search, so they
+        // are computed with the saturating policy, which never fails. A
+        // missing node has no neighbors anyway, and the edges leading out of
+        // the graph are skipped.
+        let potential = self
+            .potentials
+            .get(node)
+            .copied()
+            .unwrap_or_else(G::Weight::zero);
+        self.graph
+            .neighbors(node)
+            .filter_map(move |(neighbor, cost)| {
+                let neighbor_potential = *self.potentials.get(neighbor)?;
+                let cost = reweight(
+                    OverflowPolicy::Saturating,
+                    cost,
+                    potential,
+                    neighbor_potential,
+                )
+                .expect("the saturating policy never fails");
+                Some((neighbor, cost))
+            })
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a G::Node> + 'a {
+        self.graph.nodes()
+    }
+
+    fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+}
+
+// The cost of an edge plus the potential of its source minus the potential of
+// its destination. The potentials are the costs of shortest paths, so the
+// potential of the destination is never more than the cost of the edge plus
+// the potential of the source, and the reweighted cost is never negative.
+// The difference of the potentials is taken first: both are not positive,
+// and the difference is at most the cost of the edge, so it never overflows.
+// Only the reweighted costs too large to be represented fail, with
+// PathError::Overflow.
+fn reweight<W>(policy: OverflowPolicy, cost: W, from: W, to: W) -> Result<W, PathError>
+where
+    W: Weight + Sub<Output = W>,
+{
+    let difference = policy.sub(to, from).ok_or(PathError::Overflow)?;
+    policy.sub(cost, difference).ok_or(PathError::Overflow)
+}
@@ -0,0 +1,412 @@
+// Used to order the paths by cost.
+use std::cmp::{Ordering, Reverse};
+// Used as priority queue by both the search and the enumeration.
+use std::collections::BinaryHeap;
+// Used to share the heaps and the deviations between the paths.
+use std::rc::Rc;
+// Used to compute the extra cost of the deviations.
+use std::ops::Sub;
+
+// Used to find the index of the nodes.
+use crate::hash::HashMap;
+// The graph being explored, and the paths found in it.
+use crate::{GraphLike, OverflowPolicy, PathError, ShortestPathResult, Weight};
+
+/// Enumerates the paths from `source` to `destination` in `graph` by
+/// increasing cost, following Eppstein's algorithm.
+///
+/// A single search from the destination, over the edges followed backward,
+/// gives the shortest path from every node to the destination. Every other
+/// path can then be described by the edges where it deviates from these
+/// shortest paths, each one costing some extra amount. The deviations
+/// available from every node are kept in persistent heaps sharing their
+/// structure, so that the next path is found from a heap of candidates in
+/// O(log k) time once the O(E log V) preprocessing is done. This is much
+/// faster than looking for the next path from scratch every time when many
+/// paths are needed on a large graph.
+///
+/// The returned iterator yields the shortest path first, and then as many
+/// paths as wanted in order of increasing cost. The paths may go through a
+/// node several times, and the paths which only differ by their parallel
+/// edges are all yielded. The iterator is infinite as soon as a cycle can be
+/// taken on the way to the destination, and empty if there is no path at
+/// all. The paths whose cost overflows are skipped.
+///
+/// The costs must not be negative, just like with
+/// [`dijkstra`](crate::dijkstra), and have to support subtraction to compute
+/// the extra cost of the deviations, which all the primitive integer types
+/// do. The query fails if one of the nodes is not part of the graph, or with
+/// [`PathError::Overflow`] if the cost of a shortest path overflows.
+pub fn k_shortest_paths<'g, G>(
+    graph: &'g G,
+    source: &G::Node,
+    destination: &G::Node,
+) -> Result<KShortestPaths<'g, G::Node, G::Weight>, PathError>
+where
+    G: GraphLike,
+    G::Weight: Sub<Output = G::Weight>,
+{
+    let policy = OverflowPolicy::default();
+
+    // We number the nodes and the edges, so that the parallel edges can be
+    // told apart.
+    let nodes: Vec<&'g G::Node> = graph.nodes().collect();
+    let index: HashMap<&'g G::Node, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(position, &node)| (node, position))
+        .collect();
+    let source = *index.get(source).ok_or(PathError::UnknownSource)?;
+    let destination = *index
+        .get(destination)
+        .ok_or(PathError::UnknownDestination)?;
+    let mut edges = Vec::new();
+    let mut outgoing = vec![Vec::new(); nodes.len()];
+    let mut incoming = vec![Vec::new(); nodes.len()];
+    for (from, to, cost) in graph.edges() {
+        // The edges leading out of the graph cannot be on any path.
+        let (from, to) = match index.get(to) {
+            Some(&to) => (index[from], to),
+            None => continue,
+        };
+        outgoing[from].push(edges.len());
+        incoming[to].push(edges.len());
+        edges.push(Edge { from, to, cost });
+    }
+
+    // We find the shortest path from every node to the destination, going
+    // backward along the edges. The nodes are settled by increasing cost,
+    // every node after the next node of its path.
+    let mut distances = vec![None; nodes.len()];
+    let mut next_edge = vec![None; nodes.len()];
+    let mut settled = Vec::new();
+    let mut visited = vec![false; nodes.len()];
+    let mut queue = BinaryHeap::new();
+    distances[destination] = Some(G::Weight::zero());
+    queue.push(Reverse((G::Weight::zero(), destination)));
+    while let Some(Reverse((cost, node))) = queue.pop() {
+        // The outdated entries are skipped.
+        if visited[node] {
+            continue;
+        }
+        visited[node] = true;
+        settled.push(node);
+
+        for &edge in &incoming[node] {
+            let Edge {
+                from,
+                cost: edge_cost,
+                ..
+            } = edges[edge];
+            let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+            if distances[from].is_none_or(|old| old > new_cost) {
+                distances[from] = Some(new_cost);
+                next_edge[from] = Some(edge);
+                queue.push(Reverse((new_cost, from)));
+            }
+        }
+    }
+
+    // The deviations available from a node are those of the nodes along its
+    // shortest path, i.e. its own edges not on the path and those of the
+    // next node, whose heap is therefore built first and shared.
+    let mut heaps: Vec<Heap<G::Weight>> = vec![None; nodes.len()];
+    for &node in &settled {
+        let cost = distances[node].expect("a settled node has a distance");
+        let mut heap = match next_edge[node] {
+            Some(edge) => heaps[edges[edge].to].clone(),
+            None => None,
+        };
+        for &edge in &outgoing[node] {
+            let Edge {
+                to,
+                cost: edge_cost,
+                ..
+            } = edges[edge];
+            let rest = match distances[to] {
+                Some(rest) if next_edge[node] != Some(edge) => rest,
+                _ => continue,
+            };
+            // A deviation costs the cost of its edge and of the shortest path
+            // after it, minus the cost of the shortest path it leaves.
+            let through = policy.add(edge_cost, rest).ok_or(PathError::Overflow)?;
+            heap = merge(heap, single(through - cost, edge));
+        }
+        heaps[node] = heap;
+    }
+
+    // The shortest path comes first, the path deviating once as little as
+    // possible being the first candidate for the next one.
+    let first = distances[source];
+    let mut candidates = BinaryHeap::new();
+    if let (Some(cost), Some(heap)) = (first, &heaps[source]) {
+        if let Some(cost) = policy.add(cost, heap.extra) {
+            candidates.push(Candidate {
+                cost,
+                heap: heap.clone(),
+                deviations: None,
+            });
+        }
+    }
+
+    Ok(KShortestPaths {
+        nodes,
+        edges,
+        next_edge,
+        heaps,
+        source,
+        destination,
+        first,
+        candidates,
+    })
+}
+
+/// The iterator returned by [`k_shortest_paths`], yielding the paths by
+/// increasing cost.
+///
+/// The results carry no statistics, since the paths are not found by a
+/// search.
+#[derive(Debug)]
+pub struct KShortestPaths<'g, N, W> {
+    // The node associated with each index, as stored in the graph.
+    nodes: Vec<&'g N>,
+    // The edges of the graph, between the indices of the nodes.
+    edges: Vec<Edge<W>>,
+    // The edge leaving every node on its shortest path to the destination,
+    // or None for the destination and the nodes which cannot reach it.
+    next_edge: Vec<Option<usize>>,
+    // The deviations available from every node.
+    heaps: Vec<Heap<W>>,
+    // The index of the source.
+    source: usize,
+    // The index of the destination.
+    destination: usize,
+    // The cost of the shortest path, until it is yielded.
+    first: Option<W>,
+    // The paths to yield next, the cheapest one first.
+    candidates: BinaryHeap<Candidate<W>>,
+}
+
+impl<N: Clone, W: Weight + Sub<Output = W>> Iterator for KShortestPaths<'_, N, W> {
+    type Item = ShortestPathResult<N, W>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(cost) = self.first.take() {
+            return Some(self.path(&None, cost));
+        }
+
+        let policy = OverflowPolicy::default();
+        let Candidate {
+            cost,
+            heap,
+            deviations,
+        } = self.candidates.pop()?;
+        let taken = Some(Rc::new(Deviation {
+            edge: heap.edge,
+            previous: deviations.clone(),
+        }));
+
+        // The path can deviate again after this deviation, as little as
+        // possible to begin with.
+        let after = &self.heaps[self.edges[heap.edge].to];
+        offer(
+            &mut self.candidates,
+            after
+                .as_ref()
+                .and_then(|after| policy.add(cost, after.extra)),
+            after,
+            &taken,
+        );
+
+        // The path can also take the next cheapest deviations instead of this
+        // one, which are its children in the heap.
+        let without = cost - heap.extra;
+        for child in [&heap.left, &heap.right] {
+            offer(
+                &mut self.candidates,
+                child
+                    .as_ref()
+                    .and_then(|child| policy.add(without, child.extra)),
+                child,
+                &deviations,
+            );
+        }
+
+        Some(self.path(&taken, cost))
+    }
+}
+
+impl<N: Clone, W> KShortestPaths<'_, N, W> {
+    // Builds the path going from the source to the destination along the
+    // shortest paths, except for the given deviations.
+    fn path(&self, deviations: &Deviations, cost: W) -> ShortestPathResult<N, W> {
+        // The deviations are linked from the last to the first.
+        let mut taken = Vec::new();
+        let mut link = deviations;
+        while let Some(deviation) = link {
+            taken.push(deviation.edge);
+            link = &deviation.previous;
+        }
+
+        let mut path = vec![self.nodes[self.source].clone()];
+        let mut location = self.source;
+        for &edge in taken.iter().rev() {
+            // The deviation leaves a node on the shortest path from the last
+            // location.
+            let Edge { from, to, .. } = self.edges[edge];
+            while location != from {
+                location = self.follow(location);
+                path.push(self.nodes[location].clone());
+            }
+            location = to;
+            path.push(self.nodes[location].clone());
+        }
+        while location != self.destination {
+            location = self.follow(location);
+            path.push(self.nodes[location].clone());
+        }
+
+        ShortestPathResult {
+            nodes: path,
+            cost,
+            settled: None,
+            stats: None,
+        }
+    }
+
+    // The node following the given one on its shortest path to the
+    // destination.
+    fn follow(&self, node: usize) -> usize {
+        let edge = self.next_edge[node].expect("the deviations lead to the destination");
+        self.edges[edge].to
+    }
+}
+
+// Adds the path made of the deviations followed by the root of the heap to
+// the candidates, unless the heap is empty or the cost of the path
+// overflowed.
+fn offer<W: Ord>(
+    candidates: &mut BinaryHeap<Candidate<W>>,
+    cost: Option<W>,
+    heap: &Heap<W>,
+    deviations: &Deviations,
+) {
+    if let (Some(cost), Some(heap)) = (cost, heap) {
+        candidates.push(Candidate {
+            cost,
+            heap: heap.clone(),
+            deviations: deviations.clone(),
+        });
+    }
+}
+
+// An edge of the graph, between the indices of its ends.
+#[derive(Debug, Clone, Copy)]
+struct Edge<W> {
+    from: usize,
+    to: usize,
+    cost: W,
+}
+
+// A persistent leftist heap of deviations, i.e. of edges along with the
+// extra cost of taking them, the cheapest one at the root. A heap is never
+// modified once built, the merged heaps sharing their subtrees instead.
+type Heap<W> = Option<Rc<HeapNode<W>>>;
+
+#[derive(Debug)]
+struct HeapNode<W> {
+    // The extra cost of the deviation.
+    extra: W,
+    // The edge taken by the deviation.
+    edge: usize,
+    // The length of the rightmost path of the heap, which is never longer
+    // than the one of the left child.
+    rank: usize,
+    left: Heap<W>,
+    right: Heap<W>,
+}
+
+// A heap holding a single deviation.
+fn single<W>(extra: W, edge: usize) -> Heap<W> {
+    Some(Rc::new(HeapNode {
+        extra,
+        edge,
+        rank: 1,
+        left: None,
+        right: None,
+    }))
+}
+
+// The rank of a heap, an empty heap having none.
+fn rank<W>(heap: &Heap<W>) -> usize {
+    heap.as_ref().map_or(0, |node| node.rank)
+}
+
+// Merges two heaps along their rightmost paths, copying the nodes on these
+// paths only. These paths are logarithmic in the size of the heaps, and so is
+// the depth of the recursion.
+fn merge<W: Weight>(first: Heap<W>, second: Heap<W>) -> Heap<W> {
+    let (first, second) = match (first, second) {
+        (None, heap) | (heap, None) => return heap,
+        (Some(first), Some(second)) if second.extra < first.extra => (second, first),
+        (Some(first), Some(second)) => (first, second),
+    };
+
+    let merged = merge(first.right.clone(), Some(second));
+    let (left, right) = if rank(&first.left) >= rank(&merged) {
+        (first.left.clone(), merged)
+    } else {
+        (merged, first.left.clone())
+    };
+    Some(Rc::new(HeapNode {
+        extra: first.extra,
+        edge: first.edge,
+        rank: rank(&right) + 1,
+        left,
+        right,
+    }))
+}
+
+// The deviations taken by a path, linked from the last one to the first one
+// so that the paths deviating from the same prefix share it.
+type Deviations = Option<Rc<Deviation>>;
+
+#[derive(Debug)]
+struct Deviation {
+    // The edge taken by the deviation.
+    edge: usize,
+    previous: Deviations,
+}
+
+// A path which has not been yielded yet: its deviations, followed by the
+// deviation at the root of a heap.
+#[derive(Debug)]
+struct Candidate<W> {
+    // The cost of the path.
+    cost: W,
+    // The heap whose root is the last deviation of the path.
+    heap: Rc<HeapNode<W>>,
+    // The deviations before the last one.
+    deviations: Deviations,
+}
+
+impl<W: Ord> PartialEq for Candidate<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl<W: Ord> Eq for Candidate<W> {}
+
+impl<W: Ord> PartialOrd for Candidate<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: Ord> Ord for Candidate<W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The cheapest path is the greatest one.
+        other.cost.cmp(&self.cost)
+    }
+}
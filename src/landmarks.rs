@@ -0,0 +1,203 @@
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+// Used to subtract the costs of the paths through the landmarks.
+use std::ops::Sub;
+
+// Used to find the index of the nodes.
+use crate::hash::HashMap;
+// The graph being preprocessed, and the searches run on it.
+use crate::{astar, GraphLike, OverflowPolicy, PathError, PathResult, SearchWorkspace, Weight};
+
+/// The costs of the shortest paths from and to a few landmarks, which speed
+/// up the A* searches of a static graph, following the ALT algorithm of
+/// Goldberg and Harrelson (A*, Landmarks and Triangle inequality).
+///
+/// The shortest path from a node to a destination costs at least the
+/// difference between the costs of their paths to any landmark, or from any
+/// landmark, by the triangle inequality. The largest of these differences is
+/// a consistent heuristic for [`astar`], which works on any graph, as opposed
+/// to the straight-line distance which requires coordinates. Well spread
+/// landmarks make it tight: a search heading away from a landmark lying
+/// behind the destination settles few nodes off the shortest path.
+///
+/// The landmarks are preprocessed once with [`Landmarks::new`], after which
+/// [`Landmarks::shortest_path`] answers any query on the graph. They have to
+/// be preprocessed again if the graph changes, the paths returned being no
+/// longer the shortest ones otherwise. Two costs are stored for every node
+/// and every landmark.
+#[derive(Debug, Clone)]
+pub struct Landmarks<N, W> {
+    // The landmarks, in the order they were picked.
+    landmarks: Vec<N>,
+    // The position of every node of the graph among the costs.
+    index: HashMap<N, usize>,
+    // The cost of the shortest path from every landmark to every node, the
+    // costs of a node being stored together. None if there is no such path.
+    from_landmarks: Vec<Option<W>>,
+    // The cost of the shortest path from every node to every landmark,
+    // stored just like the costs from the landmarks.
+    to_landmarks: Vec<Option<W>>,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight + Sub<Output = W>> Landmarks<N, W> {
+    /// Picks `count` landmarks of `graph`, and computes the cost of the
+    /// shortest paths from and to them.
+    ///
+    /// `reversed` has to be the transpose of `graph`, i.e. the same graph
+    /// with every edge flipped, as returned by
+    /// [`Graph::reversed`](crate::Graph::reversed) for instance, which is
+    /// searched for the paths leading to the landmarks. The landmarks are
+    /// spread over the graph by picking the node farthest from the landmarks
+    /// picked so far every time, the nodes they don't reach being picked
+    /// first, and the first landmark being the node farthest from the first
+    /// node of the graph. Every node is a landmark if there are fewer than
+    /// `count`. Two searches are run for every landmark, so this takes
+    /// O(count (V + E) log V) time. A dozen landmarks is usually enough on a
+    /// road network, more landmarks giving a tighter heuristic but a slower
+    /// one.
+    ///
+    /// Fails with [`PathError::Overflow`] if the cost of a path overflows,
+    /// and with [`PathError::UnknownSource`] if a landmark is not part of
+    /// `reversed`.
+    pub fn new<G>(graph: &G, reversed: &G, count: usize) -> Result<Self, PathError>
+    where
+        G: GraphLike<Node = N, Weight = W>,
+    {
+        let nodes: Vec<&N> = graph.nodes().collect();
+        let index: HashMap<&N, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(position, &node)| (node, position))
+            .collect();
+        let count = count.min(nodes.len());
+        let mut from_landmarks = vec![None; nodes.len() * count];
+        let mut to_landmarks = vec![None; nodes.len() * count];
+        let mut forward = SearchWorkspace::new();
+        let mut backward = SearchWorkspace::new();
+
+        // The cost of the path from the closest landmark to every node, None
+        // while no landmark reaches it. The first node of the graph stands
+        // for a landmark until the first one is picked.
+        let mut closest = match nodes.first() {
+            Some(first) => costs(graph, &mut forward, first, &index)?,
+            None => Vec::new(),
+        };
+        let mut picked = vec![false; nodes.len()];
+        let mut landmarks = Vec::with_capacity(count);
+        for landmark in 0..count {
+            // The nodes no landmark reaches are the farthest ones, the ties
+            // going to the first node.
+            let farthest = (0..nodes.len())
+                .rev()
+                .filter(|&node| !picked[node])
+                .max_by_key(|&node| match closest[node] {
+                    Some(cost) => (false, cost),
+                    None => (true, W::zero()),
+                })
+                .expect("fewer landmarks than nodes are picked");
+            picked[farthest] = true;
+            landmarks.push(nodes[farthest].clone());
+
+            let from = costs(graph, &mut forward, nodes[farthest], &index)?;
+            let to = costs(reversed, &mut backward, nodes[farthest], &index)?;
+            for node in 0..nodes.len() {
+                from_landmarks[node * count + landmark] = from[node];
+                to_landmarks[node * count + landmark] = to[node];
+                if let Some(cost) = from[node] {
+                    closest[node] = Some(closest[node].map_or(cost, |old| old.min(cost)));
+                }
+            }
+        }
+
+        Ok(Landmarks {
+            landmarks,
+            index: index
+                .into_iter()
+                .map(|(node, position)| (node.clone(), position))
+                .collect(),
+            from_landmarks,
+            to_landmarks,
+        })
+    }
+
+    /// The landmarks, in the order they were picked.
+    pub fn landmarks(&self) -> &[N] {
+        &self.landmarks
+    }
+
+    /// A lower bound of the cost of the shortest path from `from` to `to`,
+    /// zero if either node was not part of the preprocessed graph.
+    pub fn lower_bound(&self, from: &N, to: &N) -> W {
+        match (self.index.get(from), self.index.get(to)) {
+            (Some(&from), Some(&to)) => self.bound(from, to),
+            _ => W::zero(),
+        }
+    }
+
+    /// Finds the shortest path from `source` to `destination` in `graph` with
+    /// [`astar`], guided by the lower bounds of the landmarks.
+    ///
+    /// `graph` has to be the graph the landmarks were preprocessed on. The
+    /// returned path and errors are the same as with
+    /// [`dijkstra`](crate::dijkstra).
+    pub fn shortest_path<G>(&self, graph: &G, source: &N, destination: &N) -> PathResult<N, W>
+    where
+        G: GraphLike<Node = N, Weight = W>,
+    {
+        let target = self.index.get(destination).copied();
+        astar(graph, source, destination, |node| {
+            match (self.index.get(node), target) {
+                (Some(&node), Some(target)) => self.bound(node, target),
+                _ => W::zero(),
+            }
+        })
+    }
+
+    // The largest lower bound given by the landmarks of the cost of the
+    // shortest path between the nodes at the given positions.
+    fn bound(&self, from: usize, to: usize) -> W {
+        let count = self.landmarks.len();
+        let mut bound = W::zero();
+        for landmark in 0..count {
+            // The path from the landmark to the destination costs at most the
+            // path to the node plus the path from the node to the destination.
+            let before = (
+                self.from_landmarks[to * count + landmark],
+                self.from_landmarks[from * count + landmark],
+            );
+            // The path from the node to the landmark costs at most the path
+            // to the destination plus the path from the destination onwards.
+            let after = (
+                self.to_landmarks[from * count + landmark],
+                self.to_landmarks[to * count + landmark],
+            );
+            for (longer, shorter) in [before, after] {
+                if let (Some(longer), Some(shorter)) = (longer, shorter) {
+                    if longer > shorter {
+                        bound = bound.max(longer - shorter);
+                    }
+                }
+            }
+        }
+        bound
+    }
+}
+
+// The cost of the shortest path from the source to every node of the index,
+// None for the nodes it does not reach.
+fn costs<'g, G: GraphLike>(
+    graph: &'g G,
+    workspace: &mut SearchWorkspace<'g, G>,
+    source: &G::Node,
+    index: &HashMap<&G::Node, usize>,
+) -> Result<Vec<Option<G::Weight>>, PathError> {
+    workspace.start(graph, source)?;
+    workspace.progress(graph, OverflowPolicy::default(), None, |_| false)?;
+    let mut costs = vec![None; index.len()];
+    for (node, &cost) in workspace.progression() {
+        if let Some(&position) = index.get(node) {
+            costs[position] = Some(cost);
+        }
+    }
+    Ok(costs)
+}
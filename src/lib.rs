@@ -0,0 +1,450 @@
+//! A minimal implementation of Dijkstra's algorithm.
+//!
+//! The graph is represented by the [`Graph`] type, which is built node by node
+//! and edge by edge before running any query on it. The algorithms only rely
+//! on the [`GraphLike`] trait though, so other representations can be used
+//! as well.
+//!
+//! The main entry point is [`dijkstra`], which returns the shortest path
+//! between two nodes, if any. The path itself is reconstructed from the
+//! progression of the algorithm with [`dijkstra_backtrack`].
+//!
+//! # Features
+//!
+//! - `serde`: implements `Serialize` and `Deserialize` for [`Graph`] and
+//!   [`FloatWeight`], so that graphs can be loaded from JSON or TOML files.
+//! - `petgraph`: implements `From` conversions between [`Graph`] and the
+//!   graphs of the `petgraph` crate.
+//! - `rayon`: answers batches of queries in parallel with
+//!   `par_shortest_paths`, spreads the phases of the delta-stepping algorithm
+//!   over several threads with `par_delta_stepping`, and parses large edge
+//!   lists in parallel with `Graph::from_file_parallel`.
+//! - `fast-hash`: replaces the default hasher of the `HashMap`s used by the
+//!   graphs and the algorithms with FxHash, which is much faster on short
+//!   keys such as strings or integers. FxHash does not resist collision
+//!   attacks though, so it should not be used with nodes chosen by an
+//!   untrusted party.
+
+// The nodes are used as keys by the backtracking, and the origin maps given to
+// it may use any hasher.
+use std::hash::{BuildHasher, Hash};
+
+// The hash maps used by the crate, see the "fast-hash" feature.
+mod hash;
+
+// The operations the algorithms need from a graph.
+mod graph_like;
+pub use graph_like::GraphLike;
+
+// The default graph representation.
+mod graph;
+pub use graph::{DuplicateEdgePolicy, Graph, GraphKind, MergePolicy};
+
+// A compact representation for large static graphs.
+mod csr;
+pub use csr::CsrGraph;
+
+// A compressed representation for the largest static graphs.
+mod compressed;
+pub use compressed::CompressedGraph;
+
+// The binary form of the static graphs, queried in place.
+mod mapped;
+pub use mapped::MappedGraph;
+
+// The construction of the static graphs from streams of edges.
+mod streaming;
+pub use streaming::StreamingBuilder;
+
+// The text format of the edge lists.
+mod edge_list;
+pub use edge_list::{read_edge_list, EdgeListReader};
+
+// The parallel loading of the edge lists, see the "rayon" feature.
+#[cfg(feature = "rayon")]
+mod loading;
+
+// A dense representation for small graphs with many edges.
+mod matrix;
+pub use matrix::AdjacencyMatrix;
+
+// A representation which keeps the parallel edges.
+mod multigraph;
+pub use multigraph::{EdgeId, MultiGraph};
+
+// A fluent interface to build the graphs.
+mod builder;
+pub use builder::GraphBuilder;
+
+// The storage of the names of the nodes, each stored once.
+mod arena;
+pub use arena::{NameArena, NameId};
+
+// Consistency checks for the graphs.
+mod validate;
+pub use validate::{validate, ValidationReport};
+
+// The summaries of the size and shape of the graphs.
+mod graph_stats;
+pub use graph_stats::{graph_stats, GraphStats};
+
+// Serialization of the graphs, see the "serde" feature.
+#[cfg(feature = "serde")]
+mod serialization;
+
+// Conversions from and to petgraph, see the "petgraph" feature.
+#[cfg(feature = "petgraph")]
+mod petgraph_conversion;
+
+// The costs of the edges of the graph.
+mod weight;
+pub use weight::{Float, FloatWeight, OverflowPolicy, Weight};
+
+// A variant of the algorithm for small integer costs.
+mod bucket;
+pub use bucket::{dijkstra_bucket, BUCKET_THRESHOLD};
+
+// The pseudo-random numbers drawn by the sampling algorithms.
+mod random;
+
+// The generators of graphs to try the algorithms on.
+pub mod generators;
+
+// The priority queue of the algorithms.
+mod queue;
+
+// The priority queues picking the next node to settle.
+mod frontier;
+pub use frontier::{BinaryHeapFrontier, BucketFrontier, Frontier, PairingHeapFrontier};
+
+// The state of the search, reusable across queries.
+mod workspace;
+pub use workspace::SearchWorkspace;
+
+// The searches guided by a heuristic.
+mod astar;
+pub use astar::astar;
+
+// The landmarks guiding the searches of the static graphs.
+mod landmarks;
+pub use landmarks::Landmarks;
+
+// The contraction hierarchies answering the queries of the static graphs.
+mod ch;
+pub use ch::ContractionHierarchy;
+
+// The hub labels answering the distance queries of the static graphs.
+mod hub_labels;
+pub use hub_labels::HubLabels;
+
+// The arc-flags pruning the queries of the static graphs.
+mod arc_flags;
+pub use arc_flags::ArcFlags;
+
+// The searches limited to a maximal cost.
+mod bounded;
+pub use bounded::{dijkstra_bounded, dijkstra_within};
+
+// The searches towards several destinations.
+mod many;
+pub use many::dijkstra_to_many;
+
+// The searches going both ways.
+mod bidirectional;
+pub use bidirectional::{bidirectional_astar, bidirectional_dijkstra};
+
+// The delta-stepping algorithm.
+mod delta;
+pub use delta::delta_stepping;
+
+// The queries answered in parallel, see the "rayon" feature.
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use parallel::{par_delta_stepping, par_shortest_paths};
+
+// The shortest paths from a source to all the nodes.
+mod tree;
+pub use tree::ShortestPathTree;
+
+// The shortest paths kept up to date as the edges change.
+mod dynamic;
+pub use dynamic::DynamicShortestPaths;
+
+// The plans of the agents moving through changing graphs.
+mod dstar;
+pub use dstar::DStarLite;
+
+// The Bellman-Ford algorithm, for negative costs.
+mod bellman_ford;
+pub use bellman_ford::{bellman_ford, find_negative_cycle};
+
+// The queue-based variant of Bellman-Ford.
+mod spfa;
+pub use spfa::spfa;
+
+// The Floyd-Warshall algorithm, for all the pairs of nodes.
+mod floyd_warshall;
+pub use floyd_warshall::{floyd_warshall, DistanceMatrix};
+
+// Johnson's algorithm, for all the pairs of nodes of sparse graphs.
+mod johnson;
+pub use johnson::johnson;
+
+// The depth-first traversal, and the reachability queries.
+mod traversal;
+pub use traversal::{dfs, is_reachable, Dfs};
+
+// The partitions of the graphs into components.
+mod components;
+pub use components::{connected_components, strongly_connected_components, Components};
+
+// The algorithms for directed acyclic graphs.
+mod dag;
+pub use dag::{dag_longest_path, dag_shortest_path, find_cycle, topological_sort};
+
+// The breadth-first search, for the graphs whose edges all cost the same.
+mod bfs;
+pub use bfs::{bfs_shortest_path, shortest_path};
+
+// The paths between two nodes by increasing cost.
+mod k_shortest;
+pub use k_shortest::{k_shortest_paths, KShortestPaths};
+
+// The searches limiting the number of edges of the paths.
+mod hops;
+pub use hops::dijkstra_max_hops;
+
+// The searches limiting the usage of a second resource along the paths.
+mod constrained;
+pub use constrained::constrained_shortest_path;
+
+// The paths trading several objectives off.
+mod pareto;
+pub use pareto::{pareto_shortest_paths, ParetoPath};
+
+// The paths whose edges are the most likely to succeed.
+mod reliable;
+pub use reliable::{most_reliable_path, ReliablePath};
+
+// The searches whose costs depend on the time.
+mod time_dependent;
+pub use time_dependent::{time_dependent_dijkstra, TravelTimeProfile};
+
+// The searches avoiding some nodes and edges.
+mod avoid;
+pub use avoid::{dijkstra_avoiding, Avoid, Avoiding};
+
+// The searches restricted in the turns they take.
+mod turns;
+pub use turns::{dijkstra_with_turns, Turns};
+
+// The paths optimizing their worst edge rather than their total cost.
+mod bottleneck;
+pub use bottleneck::{minimax_path, widest_path};
+
+// The shortest paths tying for the lowest cost.
+mod ties;
+pub use ties::{all_shortest_paths, shortest_path_count, AllShortestPaths};
+
+// The routes going through several stops.
+mod waypoints;
+pub use waypoints::{shortest_path_through, shortest_path_via, EXACT_WAYPOINTS};
+
+// The searches starting from several sources at once.
+mod multi_source;
+pub use multi_source::{multi_source_dijkstra, multi_source_dijkstra_with_offsets, NearestSources};
+
+// The flows through the graphs, the costs being capacities.
+mod flow;
+pub use flow::{max_flow, min_cost_flow, min_cut, MaxFlow, MinCostFlow, MinCut};
+
+// The bipartite graphs, and their matchings.
+mod bipartite;
+pub use bipartite::{is_bipartite, maximum_matching};
+
+// The walks following every edge once.
+mod euler;
+pub use euler::{eulerian_circuit, eulerian_path, EulerWalk};
+
+// The paths going through every node once.
+mod hamiltonian;
+pub use hamiltonian::{hamiltonian_cycle, hamiltonian_path};
+
+// The measures of how central the nodes are.
+mod centrality;
+pub use centrality::{closeness_centrality, closeness_centrality_sampled, pagerank};
+
+// The eccentricities of the nodes, and the diameter and radius of the graphs.
+mod eccentricity;
+pub use eccentricity::{diameter, diameter_sampled, eccentricity, radius, radius_sampled};
+
+// The random walks through the graphs.
+mod walk;
+pub use walk::{random_walk, weighted_random_walk};
+
+// The statistics of the searches.
+mod stats;
+pub use stats::SearchStats;
+
+// The paths returned by the algorithm.
+mod path;
+pub use path::{PathDisplay, PathResult, ShortestPathResult};
+
+// The errors returned when no path can be found.
+mod error;
+pub use error::{
+    BinaryFormatError, CycleError, EdgeListError, EulerError, HamiltonianError, InvalidWeight,
+    MergeConflict, PathError,
+};
+
+/// Finds the shortest path from `source` to `destination` in `graph`.
+///
+/// The returned path starts with `source` and ends with `destination`, with
+/// all the intermediate nodes in between. It comes along with its total cost,
+/// i.e. the sum of the costs of its edges, and the number of nodes settled by
+/// the search. A [`PathError`] explains why no path could be found, if the
+/// destination cannot be reached from the source.
+///
+/// The graph and the nodes are only borrowed, so any number of queries can be
+/// issued against the same graph. The nodes of the returned path are cloned
+/// from the graph itself, the exploration only works with references to them.
+///
+/// The query fails with [`PathError::Overflow`] if the cost of a path
+/// overflows, see [`dijkstra_with_policy`] for the alternatives.
+pub fn dijkstra<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+) -> Result<ShortestPathResult<G::Node, G::Weight>, PathError> {
+    dijkstra_with_policy(graph, source, destination, OverflowPolicy::default())
+}
+
+/// Finds the shortest path from `source` to `destination` in `graph`, using
+/// the given policy when the cost of a path overflows.
+///
+/// See [`dijkstra`] for the details of the query, and [`SearchWorkspace`] to
+/// run many queries without allocating the state of the search every time.
+pub fn dijkstra_with_policy<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    policy: OverflowPolicy,
+) -> Result<ShortestPathResult<G::Node, G::Weight>, PathError> {
+    // A fresh workspace is only used for this query.
+    SearchWorkspace::new().dijkstra_with_policy(graph, source, destination, policy)
+}
+
+/// Finds the shortest path from `source` to `destination` in `graph`, picking
+/// the nodes to settle with the given [`Frontier`].
+///
+/// The choice of the frontier doesn't change the cost of the returned path,
+/// only how fast it is found. See [`dijkstra`] for the details of the query.
+pub fn dijkstra_with_frontier<'g, G, F>(
+    graph: &'g G,
+    source: &G::Node,
+    destination: &G::Node,
+    frontier: F,
+) -> PathResult<G::Node, G::Weight>
+where
+    G: GraphLike,
+    F: Frontier<'g, G::Node, G::Weight>,
+{
+    SearchWorkspace::with_frontier(frontier).dijkstra(graph, source, destination)
+}
+
+/// Finds the shortest paths from `source` to all the nodes it can reach in
+/// `graph`.
+///
+/// The search runs until every reachable node is settled, and returns their
+/// distances along with their predecessors as a [`ShortestPathTree`]. The
+/// path to any destination can then be extracted from the tree without
+/// running the search again, which pays off as soon as several destinations
+/// are needed.
+///
+/// The query fails with [`PathError::UnknownSource`] if the source is not
+/// part of the graph, and with [`PathError::Overflow`] if the cost of a path
+/// overflows.
+pub fn dijkstra_all<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+) -> Result<ShortestPathTree<G::Node, G::Weight>, PathError> {
+    let mut workspace = SearchWorkspace::new();
+    let source = workspace.start(graph, source)?;
+    workspace.progress(graph, OverflowPolicy::default(), None, |_| false)?;
+
+    // Every reached node was settled, so its progression is final.
+    Ok(ShortestPathTree::new(
+        source.clone(),
+        workspace
+            .progression()
+            .iter()
+            .map(|(&node, &cost)| (node.clone(), cost))
+            .collect(),
+        workspace
+            .origin()
+            .iter()
+            .map(|(&node, &origin)| (node.clone(), origin.clone()))
+            .collect(),
+    ))
+}
+
+/// Rebuilds the path found by Dijkstra's algorithm from the `origin` map.
+///
+/// `origin` maps every reached node to the node it was reached from. The
+/// returned path goes from `source` to `destination`, or is `None` if the
+/// destination was never reached.
+///
+/// The nodes in the returned vector come from the origin HashMap. We also
+/// have to give the same lifetime to the source as it is used in the base
+/// case.
+pub fn dijkstra_backtrack<'a, N: Eq + Hash, S: BuildHasher>(
+    origin: &std::collections::HashMap<&'a N, &'a N, S>,
+    source: &'a N,
+    destination: &'a N,
+) -> Option<Vec<&'a N>> {
+    // We perform the backtracking, and then return the path in the right order
+    // by reversing it.
+    let mut path = dijkstra_backtrack_recursive(origin, source, destination, &mut Vec::new())?;
+    path.reverse();
+    Some(path)
+}
+
+/// Walks the `origin` map from `location` back to `source`.
+///
+/// The nodes are pushed to `path` in reverse order, i.e. from `location` to
+/// `source`, and the completed path is returned. Used by
+/// [`dijkstra_backtrack`].
+///
+/// Despite its name, the walk is a simple loop, so that arbitrarily long paths
+/// can be rebuilt. `None` is returned if `source` cannot be reached by
+/// following `origin`, including when `origin` loops.
+pub fn dijkstra_backtrack_recursive<'a, N: Eq + Hash, S: BuildHasher>(
+    origin: &std::collections::HashMap<&'a N, &'a N, S>,
+    source: &'a N,
+    location: &'a N,
+    path: &mut Vec<&'a N>,
+) -> Option<Vec<&'a N>> {
+    let mut location = location;
+    // A path without loops follows every origin at most once.
+    let mut remaining = origin.len();
+
+    while location != source {
+        // We see how we reached the current location. This fails if the
+        // current location was never reached.
+        let &origin_node = origin.get(location)?;
+        if remaining == 0 {
+            return None;
+        }
+        remaining -= 1;
+
+        // We push the current location to the path, and move on to the node it
+        // was reached from.
+        path.push(location);
+        location = origin_node;
+    }
+
+    // We add the final source to the path, and return the prepared path.
+    path.push(source);
+    Some(path.to_vec())
+}
@@ -0,0 +1,176 @@
+// Used to spread the parsing over the threads.
+use rayon::prelude::*;
+// Used to send every node to its shard.
+use std::hash::BuildHasher as _;
+// Used to read the edge lists.
+use std::path::Path;
+// Used to parse the costs of the edges.
+use std::str::FromStr;
+
+// Used to parse the lines of the edge lists.
+use crate::edge_list::parse_line;
+// Used for the adjacency lists of the shards.
+use crate::hash::{BuildHasher, HashMap};
+// The graph being built, and the errors found while reading.
+use crate::{EdgeListError, Graph, Weight};
+
+// The smallest number of bytes parsed by a single task.
+const MIN_CHUNK_SIZE: usize = 1 << 20;
+
+// The edges and nodes found by a chunk of the file for a shard, i.e. for the
+// nodes whose hash falls in this shard.
+struct ShardPart<W> {
+    // The edges leaving the nodes of the shard.
+    edges: Vec<(String, String, W)>,
+    // The destinations of the edges which belong to the shard.
+    nodes: Vec<String>,
+}
+
+// What a chunk of the file was parsed into: its parts for every shard and its
+// number of lines, or the error found on a line counted from the start of the
+// chunk.
+type ParsedChunk<W> = Result<(Vec<ShardPart<W>>, usize), EdgeListError>;
+
+impl<W: Weight + FromStr + Send> Graph<String, W> {
+    /// Reads a directed graph from the text edge list at `path`, using all
+    /// the threads of the rayon pool.
+    ///
+    /// The file follows the format of [`read_edge_list`](crate::read_edge_list)
+    /// and the graph is the same as the one collected from it, the last cost
+    /// being kept for the edges which appear several times. The file is read
+    /// at once, then cut into chunks of whole lines which are parsed in
+    /// parallel. The edges of every chunk are spread into shards according to
+    /// the hash of their source, so that the adjacency lists of every shard
+    /// can also be built in parallel, without locking, before being moved
+    /// into the graph.
+    ///
+    /// The first error of the file is returned, with its line number counted
+    /// from the start of the file.
+    pub fn from_file_parallel<P: AsRef<Path>>(path: P) -> Result<Self, EdgeListError> {
+        let text = std::fs::read_to_string(path)?;
+        let shards = rayon::current_num_threads();
+        let hasher = BuildHasher::default();
+        let shard_of = |node: &str| (hasher.hash_one(node) % shards as u64) as usize;
+
+        // We parse the chunks in parallel, keeping them in the order of the
+        // file.
+        let parsed: Vec<ParsedChunk<W>> = chunks(&text, shards)
+            .par_iter()
+            .map(|chunk| parse_chunk(chunk, shards, &shard_of))
+            .collect();
+
+        // We look for the first error, and give the parts of every chunk to
+        // their shard otherwise.
+        let mut columns: Vec<Vec<ShardPart<W>>> = (0..shards).map(|_| Vec::new()).collect();
+        let mut lines_before = 0;
+        for chunk in parsed {
+            let (parts, lines) = chunk.map_err(|error| shift_line(error, lines_before))?;
+            lines_before += lines;
+            for (column, part) in columns.iter_mut().zip(parts) {
+                column.push(part);
+            }
+        }
+
+        // We build the adjacency lists of every shard in parallel, going
+        // through its parts in the order of the file so that the last cost of
+        // an edge is kept.
+        let adjacencies: Vec<HashMap<String, HashMap<String, W>>> = columns
+            .into_par_iter()
+            .map(|parts| {
+                let mut adjacency: HashMap<String, HashMap<String, W>> = HashMap::default();
+                for part in parts {
+                    for (source, destination, cost) in part.edges {
+                        adjacency
+                            .entry(source)
+                            .or_default()
+                            .insert(destination, cost);
+                    }
+                    for node in part.nodes {
+                        adjacency.entry(node).or_default();
+                    }
+                }
+                adjacency
+            })
+            .collect();
+
+        // The shards hold distinct nodes, so we just have to move them into a
+        // single map.
+        let mut adjacency = HashMap::default();
+        adjacency.reserve(adjacencies.iter().map(HashMap::len).sum());
+        for shard in adjacencies {
+            adjacency.extend(shard);
+        }
+        Ok(Graph::from_adjacency(adjacency))
+    }
+}
+
+// Cuts the text into about `tasks` chunks of whole lines per thread, each one
+// being at least MIN_CHUNK_SIZE bytes long unless it is the last one.
+fn chunks(text: &str, tasks: usize) -> Vec<&str> {
+    let size = (text.len() / (4 * tasks)).max(MIN_CHUNK_SIZE);
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        // The chunk goes on until the end of the line it would stop in.
+        let newline = rest
+            .as_bytes()
+            .iter()
+            .skip(size)
+            .position(|&byte| byte == b'\n');
+        let end = match newline {
+            Some(newline) => size + newline + 1,
+            None => rest.len(),
+        };
+        chunks.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    chunks
+}
+
+// Parses the lines of a chunk, spreading the edges and the nodes into their
+// shards.
+fn parse_chunk<W: FromStr, S: Fn(&str) -> usize>(
+    chunk: &str,
+    shards: usize,
+    shard_of: &S,
+) -> ParsedChunk<W> {
+    let mut parts: Vec<ShardPart<W>> = (0..shards)
+        .map(|_| ShardPart {
+            edges: Vec::new(),
+            nodes: Vec::new(),
+        })
+        .collect();
+
+    let mut lines = 0;
+    for line in chunk.lines() {
+        lines += 1;
+        if let Some(edge) = parse_line(line, lines) {
+            let (source, destination, cost) = edge?;
+            // The destination has to be a node of the graph as well.
+            parts[shard_of(&destination)]
+                .nodes
+                .push(destination.clone());
+            parts[shard_of(&source)]
+                .edges
+                .push((source, destination, cost));
+        }
+    }
+    Ok((parts, lines))
+}
+
+// Moves the line of an error found in a chunk after the lines of the previous
+// chunks.
+fn shift_line(error: EdgeListError, lines_before: usize) -> EdgeListError {
+    match error {
+        EdgeListError::MissingField { line } => EdgeListError::MissingField {
+            line: line + lines_before,
+        },
+        EdgeListError::InvalidCost { line } => EdgeListError::InvalidCost {
+            line: line + lines_before,
+        },
+        EdgeListError::TrailingField { line } => EdgeListError::TrailingField {
+            line: line + lines_before,
+        },
+        EdgeListError::Io(error) => EdgeListError::Io(error),
+    }
+}
@@ -0,0 +1,102 @@
+// Used to keep track of the destinations.
+use crate::hash::HashMap;
+// The graph being explored, and the search itself.
+use crate::{
+    dijkstra_backtrack, Frontier, GraphLike, OverflowPolicy, PathError, PathResult,
+    SearchWorkspace, ShortestPathResult,
+};
+
+/// Finds the shortest paths from `source` to every node of `destinations` in
+/// `graph`, with a single search.
+///
+/// The search stops as soon as all the destinations are settled, which is
+/// much cheaper than a query per destination. The results are returned in
+/// the order of `destinations`, each of them being what
+/// [`dijkstra`](crate::dijkstra) would have returned for this destination.
+/// The number of settled nodes of every path is the number of nodes settled
+/// by the shared search when its destination was reached.
+///
+/// See [`SearchWorkspace::dijkstra_to_many`] to run many such queries
+/// without allocating the state of the search every time.
+pub fn dijkstra_to_many<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destinations: &[G::Node],
+) -> Vec<PathResult<G::Node, G::Weight>> {
+    SearchWorkspace::new().dijkstra_to_many(graph, source, destinations)
+}
+
+impl<'g, G: GraphLike, F: Frontier<'g, G::Node, G::Weight>> SearchWorkspace<'g, G, F> {
+    /// Finds the shortest paths from `source` to every node of
+    /// `destinations` in `graph` with a single search, just like
+    /// [`dijkstra_to_many`] would.
+    ///
+    /// The search stops as soon as the last destination is settled, and the
+    /// paths are extracted from the workspace at that point.
+    pub fn dijkstra_to_many(
+        &mut self,
+        graph: &'g G,
+        source: &G::Node,
+        destinations: &[G::Node],
+    ) -> Vec<PathResult<G::Node, G::Weight>> {
+        let source = match self.start(graph, source) {
+            Ok(source) => source,
+            Err(error) => return vec![Err(error); destinations.len()],
+        };
+
+        // The destinations which are part of the graph, along with the number
+        // of nodes settled when they were reached, None until then.
+        let mut settled_at: HashMap<&G::Node, Option<usize>> = destinations
+            .iter()
+            .filter_map(|destination| graph.get_node(destination))
+            .map(|destination| (destination, None))
+            .collect();
+        let mut remaining = settled_at.len();
+        let mut settled = 0;
+
+        // We stop once the last destination is about to be settled. A search
+        // without any destination left to reach stops right away.
+        let progress = if remaining == 0 {
+            Ok(None)
+        } else {
+            self.progress(graph, OverflowPolicy::default(), None, |node| {
+                settled += 1;
+                match settled_at.get_mut(node) {
+                    Some(reached @ None) => {
+                        *reached = Some(settled);
+                        remaining -= 1;
+                        remaining == 0
+                    }
+                    _ => false,
+                }
+            })
+        };
+        // An overflow spoils all the paths which are not known yet.
+        let overflow = progress.is_err();
+
+        destinations
+            .iter()
+            .map(|destination| {
+                let destination = graph
+                    .get_node(destination)
+                    .ok_or(PathError::UnknownDestination)?;
+                let settled = match settled_at[destination] {
+                    Some(settled) => settled,
+                    None if overflow => return Err(PathError::Overflow),
+                    None => return Err(PathError::NoPath),
+                };
+
+                // We backtrack to find the path used to reach the destination,
+                // its total cost being its final progression.
+                let path = dijkstra_backtrack(self.origin(), source, destination)
+                    .ok_or(PathError::NoPath)?;
+                Ok(ShortestPathResult {
+                    nodes: path.into_iter().cloned().collect(),
+                    cost: self.progression()[destination],
+                    settled: Some(settled),
+                    stats: None,
+                })
+            })
+            .collect()
+    }
+}
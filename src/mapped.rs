@@ -0,0 +1,340 @@
+// Used to sort the nodes by name.
+use std::cmp::Ordering;
+// Used to check the sizes found in the header.
+use std::convert::TryFrom;
+// Required by the nodes of the saved graphs.
+use std::hash::Hash;
+// Used to save the graphs.
+use std::io::{self, BufWriter, Write};
+
+// The search shared with the graphs in memory.
+use crate::csr::indexed_search;
+// The graphs being saved, and the results of the queries.
+use crate::{BinaryFormatError, CsrGraph, PathError, ShortestPathResult};
+
+// The first bytes of every saved graph.
+const MAGIC: [u8; 8] = *b"DIJKSTRA";
+// The version of the layout written by this crate.
+const VERSION: u32 = 1;
+// The size of the header, in bytes.
+const HEADER_SIZE: usize = 40;
+
+/// A graph with string nodes and `u32` costs, queried in place from its binary
+/// form.
+///
+/// The binary form is written with [`CsrGraph::write_binary`]. It holds the
+/// arrays of the [`CsrGraph`] along with the names of the nodes, so that it
+/// can be queried without being deserialized: building a `MappedGraph` only
+/// checks the header and the sizes of the arrays, the bytes themselves being
+/// read when a query needs them. Loading a multi-gigabyte road network thus
+/// takes milliseconds, if the bytes are memory-mapped from the file by a crate
+/// such as `memmap2` rather than read into memory.
+///
+/// The layout starts with a 40 bytes header, followed by the arrays, all the
+/// integers being stored in little endian:
+///
+/// - the magic bytes `DIJKSTRA`, the version of the layout as a `u32`, and 4
+///   reserved bytes,
+/// - the number of nodes, the number of edges and the size of the names in
+///   bytes, as `u64`s,
+/// - the offsets of the edges of every node, as the `offsets` array of the
+///   [`CsrGraph`], then the offsets of the names of every node, as one more
+///   `u64` than there are nodes each,
+/// - the index of the destination, then the cost, of every edge, as `u32`s,
+/// - the indices of the nodes sorted by name, as `u32`s, which are used to
+///   find the nodes by binary search,
+/// - the names of the nodes, concatenated, in UTF-8.
+///
+/// The nodes are identified by their index in the queries returning them, and
+/// by their name in the others. `MappedGraph` doesn't implement
+/// [`GraphLike`](crate::GraphLike), since it has no node stored in memory to
+/// return references to.
+///
+/// # Panics
+///
+/// The arrays are not checked when the graph is built, so the queries may
+/// panic if they were corrupted.
+#[derive(Debug, Clone, Copy)]
+pub struct MappedGraph<'a> {
+    // The number of nodes in the graph.
+    node_count: usize,
+    // The number of edges in the graph.
+    edge_count: usize,
+    // The offsets of the edges of every node, one u64 each.
+    offsets: &'a [u8],
+    // The offsets of the names of every node, one u64 each.
+    name_offsets: &'a [u8],
+    // The destination of every edge, one u32 each.
+    targets: &'a [u8],
+    // The cost of every edge, one u32 each.
+    costs: &'a [u8],
+    // The indices of the nodes sorted by name, one u32 each.
+    sorted: &'a [u8],
+    // The names of all the nodes, concatenated.
+    names: &'a str,
+}
+
+impl<'a> MappedGraph<'a> {
+    /// Prepares the graph saved in `bytes` for queries.
+    ///
+    /// Only the header, the sizes of the arrays and the encoding of the names
+    /// are checked, which doesn't depend on the number of edges.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, BinaryFormatError> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(BinaryFormatError::Truncated);
+        }
+        if bytes[..MAGIC.len()] != MAGIC {
+            return Err(BinaryFormatError::BadMagic);
+        }
+        let version = read_u32(&bytes[8..12], 0);
+        if version != VERSION {
+            return Err(BinaryFormatError::UnsupportedVersion(version));
+        }
+
+        // The sizes which don't fit in memory cannot be those of the bytes we
+        // were given.
+        let size = |position| {
+            usize::try_from(read_u64(&bytes[16..HEADER_SIZE], position))
+                .map_err(|_| BinaryFormatError::Truncated)
+        };
+        let node_count = size(0)?;
+        let edge_count = size(1)?;
+        let names_size = size(2)?;
+        if u32::try_from(node_count).is_err() {
+            return Err(BinaryFormatError::Inconsistent);
+        }
+
+        // We cut the arrays out of the bytes, one after the other.
+        let mut sections = Sections {
+            bytes,
+            position: HEADER_SIZE,
+        };
+        let offsets = sections.take(node_count + 1, 8)?;
+        let name_offsets = sections.take(node_count + 1, 8)?;
+        let targets = sections.take(edge_count, 4)?;
+        let costs = sections.take(edge_count, 4)?;
+        let sorted = sections.take(node_count, 4)?;
+        let names = sections.take(names_size, 1)?;
+        let names = std::str::from_utf8(names).map_err(|_| BinaryFormatError::InvalidUtf8)?;
+
+        // The last offsets must match the sizes of the arrays they index.
+        if read_u64(offsets, node_count) != edge_count as u64
+            || read_u64(name_offsets, node_count) != names_size as u64
+        {
+            return Err(BinaryFormatError::Inconsistent);
+        }
+
+        Ok(MappedGraph {
+            node_count,
+            edge_count,
+            offsets,
+            name_offsets,
+            targets,
+            costs,
+            sorted,
+            names,
+        })
+    }
+
+    /// The number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// The number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// Returns the name of the node with the given index, if any.
+    pub fn node(&self, index: u32) -> Option<&'a str> {
+        if (index as usize) < self.node_count {
+            Some(self.name(index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the node with the given name, if any.
+    ///
+    /// The node is found by binary search over the sorted names, without
+    /// building any index in memory.
+    pub fn index_of(&self, name: &str) -> Option<u32> {
+        let mut low = 0;
+        let mut high = self.node_count;
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let index = read_u32(self.sorted, middle);
+            match self.name(index).cmp(name) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Greater => high = middle,
+                Ordering::Equal => return Some(index),
+            }
+        }
+        None
+    }
+
+    /// Iterates over the names of all the nodes, in the order of their
+    /// indices.
+    pub fn nodes(&self) -> impl Iterator<Item = &'a str> + 'a {
+        let graph = *self;
+        (0..self.node_count as u32).map(move |index| graph.name(index))
+    }
+
+    /// Iterates over the nodes adjacent to the node called `name`, along with
+    /// the cost of the edge leading to them.
+    ///
+    /// A node which is not part of the graph has no neighbors.
+    pub fn neighbors(&self, name: &str) -> impl Iterator<Item = (&'a str, u32)> + 'a {
+        let graph = *self;
+        // A missing node is treated just like a node without edges.
+        let edges = match self.index_of(name) {
+            Some(index) => self.edges(index),
+            None => 0..0,
+        };
+
+        edges.map(move |edge| {
+            (
+                graph.name(read_u32(graph.targets, edge)),
+                read_u32(graph.costs, edge),
+            )
+        })
+    }
+
+    /// Finds the shortest path from `source` to `destination`, just like
+    /// [`CsrGraph::shortest_path`] would on the saved graph.
+    ///
+    /// Only the names of the nodes of the path are copied out of the bytes.
+    pub fn shortest_path(
+        &self,
+        source: &str,
+        destination: &str,
+    ) -> Result<ShortestPathResult<String, u32>, PathError> {
+        let source = self.index_of(source).ok_or(PathError::UnknownSource)?;
+        let destination = self
+            .index_of(destination)
+            .ok_or(PathError::UnknownDestination)?;
+
+        // The search itself only works with the indices.
+        let (path, cost, stats) = indexed_search(self.node_count, source, destination, |node| {
+            self.edges(node)
+                .map(move |edge| (read_u32(self.targets, edge), read_u32(self.costs, edge)))
+        })?;
+
+        Ok(ShortestPathResult {
+            nodes: path
+                .into_iter()
+                .map(|index| self.name(index).to_string())
+                .collect(),
+            cost,
+            settled: Some(stats.settled),
+            stats: Some(stats),
+        })
+    }
+
+    // The name of the node with the given index.
+    fn name(&self, index: u32) -> &'a str {
+        let start = read_u64(self.name_offsets, index as usize) as usize;
+        let end = read_u64(self.name_offsets, index as usize + 1) as usize;
+        self.names
+            .get(start..end)
+            .expect("corrupted names in a MappedGraph")
+    }
+
+    // The positions of the edges leaving the node with the given index.
+    fn edges(&self, index: u32) -> std::ops::Range<usize> {
+        let start = read_u64(self.offsets, index as usize) as usize;
+        let end = read_u64(self.offsets, index as usize + 1) as usize;
+        start..end
+    }
+}
+
+impl<N: Eq + Hash + Clone + AsRef<str>> CsrGraph<N, u32> {
+    /// Saves the graph to `writer` in the binary form read by
+    /// [`MappedGraph`].
+    ///
+    /// The nodes are saved as their names, so two nodes with the same name
+    /// cannot be told apart once saved. The writes are buffered.
+    pub fn write_binary<W: Write>(&self, writer: W) -> io::Result<()> {
+        let (nodes, offsets, targets, costs) =
+            (&self.nodes, &self.offsets, &self.targets, &self.costs);
+        let mut writer = BufWriter::new(writer);
+        let names_size: usize = nodes.iter().map(|node| node.as_ref().len()).sum();
+
+        // The header.
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&[0; 4])?;
+        for size in [nodes.len(), targets.len(), names_size] {
+            writer.write_all(&(size as u64).to_le_bytes())?;
+        }
+
+        // The offsets of the edges and of the names.
+        for &offset in offsets {
+            writer.write_all(&(offset as u64).to_le_bytes())?;
+        }
+        let mut name_offset = 0;
+        writer.write_all(&0u64.to_le_bytes())?;
+        for node in nodes {
+            name_offset += node.as_ref().len() as u64;
+            writer.write_all(&name_offset.to_le_bytes())?;
+        }
+
+        // The edges.
+        for array in [targets, costs] {
+            for &value in array {
+                writer.write_all(&value.to_le_bytes())?;
+            }
+        }
+
+        // The indices of the nodes sorted by name, used to find them.
+        let mut sorted: Vec<u32> = (0..nodes.len() as u32).collect();
+        sorted
+            .sort_unstable_by(|&a, &b| nodes[a as usize].as_ref().cmp(nodes[b as usize].as_ref()));
+        for index in sorted {
+            writer.write_all(&index.to_le_bytes())?;
+        }
+
+        // Finally the names.
+        for node in nodes {
+            writer.write_all(node.as_ref().as_bytes())?;
+        }
+        writer.flush()
+    }
+}
+
+// Cuts consecutive arrays out of the saved bytes.
+struct Sections<'a> {
+    // All the saved bytes.
+    bytes: &'a [u8],
+    // Where the next array starts.
+    position: usize,
+}
+
+impl<'a> Sections<'a> {
+    // Returns the next array, holding `count` values of `size` bytes each.
+    fn take(&mut self, count: usize, size: usize) -> Result<&'a [u8], BinaryFormatError> {
+        let end = count
+            .checked_mul(size)
+            .and_then(|length| length.checked_add(self.position))
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or(BinaryFormatError::Truncated)?;
+        let array = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(array)
+    }
+}
+
+// Reads the value at `position` in an array of u32s.
+fn read_u32(array: &[u8], position: usize) -> u32 {
+    let mut bytes = [0; 4];
+    bytes.copy_from_slice(&array[4 * position..4 * position + 4]);
+    u32::from_le_bytes(bytes)
+}
+
+// Reads the value at `position` in an array of u64s.
+fn read_u64(array: &[u8], position: usize) -> u64 {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&array[8 * position..8 * position + 8]);
+    u64::from_le_bytes(bytes)
+}
@@ -0,0 +1,223 @@
+// Used to find the index of the nodes.
+use crate::hash::HashMap;
+// Required by the keys of the index.
+use std::hash::Hash;
+
+// The operations needed by the algorithms, and the costs of the edges.
+use crate::{GraphLike, Weight};
+
+/// A directed graph stored as a dense adjacency matrix.
+///
+/// The nodes are numbered in the order they are added, and the cost of the
+/// edge going from the node `i` to the node `j` is found in the cell `(i, j)`
+/// of a flat `Vec`. Looking up an edge is therefore a simple index, at the
+/// price of a memory usage quadratic in the number of nodes. This suits small
+/// dense graphs, and the all-pairs algorithms which look at every cell anyway.
+///
+/// Adding a node grows the whole matrix, so the nodes should preferably be
+/// given upfront with [`AdjacencyMatrix::with_nodes`].
+#[derive(Debug, Clone)]
+pub struct AdjacencyMatrix<N, W = u32> {
+    // The node associated with each index.
+    nodes: Vec<N>,
+    // The index associated with each node.
+    index: HashMap<N, usize>,
+    // The cost of every edge, row by row. The cell of the edge going from i to
+    // j is at i * nodes.len() + j, and holds None if there is no such edge.
+    cells: Vec<Option<W>>,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> AdjacencyMatrix<N, W> {
+    /// Creates an empty matrix.
+    pub fn new() -> Self {
+        AdjacencyMatrix {
+            nodes: Vec::new(),
+            index: HashMap::default(),
+            cells: Vec::new(),
+        }
+    }
+
+    /// Creates a matrix holding the given nodes, without any edge.
+    ///
+    /// The nodes are numbered in the order they are given, duplicates being
+    /// ignored.
+    pub fn with_nodes<I: IntoIterator<Item = N>>(nodes: I) -> Self {
+        let mut matrix = AdjacencyMatrix::new();
+
+        // We number the nodes first, so that the matrix is only allocated once.
+        for node in nodes {
+            if !matrix.index.contains_key(&node) {
+                matrix.index.insert(node.clone(), matrix.nodes.len());
+                matrix.nodes.push(node);
+            }
+        }
+        matrix.cells = vec![None; matrix.nodes.len() * matrix.nodes.len()];
+        matrix
+    }
+
+    /// The number of nodes in the matrix.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// The number of edges in the matrix.
+    pub fn edge_count(&self) -> usize {
+        self.cells.iter().filter(|cost| cost.is_some()).count()
+    }
+
+    /// Iterates over all the nodes of the matrix, in the order of their
+    /// indices.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> + '_ {
+        self.nodes.iter()
+    }
+
+    /// Iterates over all the edges of the matrix as `(source, destination,
+    /// cost)` tuples, row by row.
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N, W)> + '_ {
+        self.nodes.iter().flat_map(move |source| {
+            self.neighbors(source)
+                .map(move |(destination, cost)| (source, destination, cost))
+        })
+    }
+
+    /// Adds a node without any edge to the matrix, and returns its index.
+    ///
+    /// If the node was already in the matrix, its index is returned and the
+    /// matrix is left untouched.
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&index) = self.index.get(&node) {
+            return index;
+        }
+
+        // We copy the old rows into a bigger matrix, leaving the last row and
+        // column empty for the new node.
+        let old_size = self.nodes.len();
+        let new_size = old_size + 1;
+        let mut cells = vec![None; new_size * new_size];
+        for row in 0..old_size {
+            cells[row * new_size..row * new_size + old_size]
+                .copy_from_slice(&self.cells[row * old_size..(row + 1) * old_size]);
+        }
+        self.cells = cells;
+
+        // The new node gets the last index.
+        self.index.insert(node.clone(), old_size);
+        self.nodes.push(node);
+        old_size
+    }
+
+    /// Adds a directed edge going from `source` to `destination`.
+    ///
+    /// Both nodes are added to the matrix if they were not already in it. If
+    /// the edge already existed, its cost is replaced and the old cost is
+    /// returned.
+    pub fn add_edge(&mut self, source: N, destination: N, cost: W) -> Option<W> {
+        let source = self.add_node(source);
+        let destination = self.add_node(destination);
+        let cell = self.cell(source, destination);
+        self.cells[cell].replace(cost)
+    }
+
+    /// Removes the edge going from `source` to `destination`.
+    ///
+    /// Returns the cost of the removed edge, or `None` if there was no such
+    /// edge.
+    pub fn remove_edge(&mut self, source: &N, destination: &N) -> Option<W> {
+        let cell = self.cell(*self.index.get(source)?, *self.index.get(destination)?);
+        self.cells[cell].take()
+    }
+
+    /// Returns the cost of the edge going from `source` to `destination`, if
+    /// there is one.
+    pub fn edge_cost(&self, source: &N, destination: &N) -> Option<W> {
+        self.cost_at(*self.index.get(source)?, *self.index.get(destination)?)
+    }
+
+    /// Returns the index of `node` in the matrix, if it is part of it.
+    pub fn index_of(&self, node: &N) -> Option<usize> {
+        self.index.get(node).copied()
+    }
+
+    /// Returns the node with the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no node with this index.
+    pub fn node_at(&self, index: usize) -> &N {
+        &self.nodes[index]
+    }
+
+    /// Returns the cost of the edge going from the node with index `source` to
+    /// the node with index `destination`, if there is one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the indices is out of bounds.
+    pub fn cost_at(&self, source: usize, destination: usize) -> Option<W> {
+        self.cells[self.cell(source, destination)]
+    }
+
+    /// Iterates over the nodes adjacent to `node`, along with the cost of the
+    /// edge leading to them.
+    ///
+    /// A node which is not part of the matrix has no neighbors.
+    pub fn neighbors<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, W)> + 'a {
+        // A missing node is treated just like a node without edges.
+        let row = match self.index.get(node) {
+            Some(&index) => &self.cells[index * self.nodes.len()..(index + 1) * self.nodes.len()],
+            None => &[],
+        };
+
+        // The column of every cell is the index of the adjacent node.
+        row.iter()
+            .zip(&self.nodes)
+            .filter_map(|(cost, node)| cost.map(|cost| (node, cost)))
+    }
+
+    /// Returns the copy of `node` which is stored in the matrix, if any.
+    pub fn get_node(&self, node: &N) -> Option<&N> {
+        self.index.get(node).map(|&index| &self.nodes[index])
+    }
+
+    // Returns the position of the cell of the edge going from source to
+    // destination in the flat matrix.
+    fn cell(&self, source: usize, destination: usize) -> usize {
+        assert!(destination < self.nodes.len(), "node index out of bounds");
+        source * self.nodes.len() + destination
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> GraphLike for AdjacencyMatrix<N, W> {
+    type Node = N;
+    type Weight = W;
+
+    fn get_node(&self, node: &N) -> Option<&N> {
+        AdjacencyMatrix::get_node(self, node)
+    }
+
+    fn neighbors<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, W)> + 'a {
+        AdjacencyMatrix::neighbors(self, node)
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a N> + 'a {
+        AdjacencyMatrix::nodes(self)
+    }
+
+    fn edges<'a>(&'a self) -> impl Iterator<Item = (&'a N, &'a N, W)> + 'a {
+        AdjacencyMatrix::edges(self)
+    }
+
+    fn node_count(&self) -> usize {
+        AdjacencyMatrix::node_count(self)
+    }
+
+    fn edge_count(&self) -> usize {
+        AdjacencyMatrix::edge_count(self)
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> Default for AdjacencyMatrix<N, W> {
+    fn default() -> Self {
+        AdjacencyMatrix::new()
+    }
+}
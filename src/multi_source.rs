@@ -0,0 +1,183 @@
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to store the distances, the nearest sources and the predecessors.
+use crate::hash::HashMap;
+// The graph being explored, the search itself and the paths extracted from
+// its result.
+use crate::{
+    GraphLike, OverflowPolicy, PathError, PathResult, SearchWorkspace, ShortestPathResult, Weight,
+};
+
+/// Finds, for every node of `graph` reachable from one of the `sources`, the
+/// nearest source and the cost of the shortest path from it.
+///
+/// A single search starts from all the sources at once, so this costs as much
+/// as a single [`dijkstra_all`](crate::dijkstra_all) whatever the number of
+/// sources. This is the building block of the nearest facility queries, the
+/// sources being the facilities, and splits the graph into the areas served
+/// by every source.
+///
+/// The query fails with [`PathError::UnknownSource`] if one of the sources is
+/// not part of the graph, and with [`PathError::Overflow`] if the cost of a
+/// path overflows.
+pub fn multi_source_dijkstra<G: GraphLike>(
+    graph: &G,
+    sources: &[G::Node],
+) -> Result<NearestSources<G::Node, G::Weight>, PathError> {
+    multi_source(
+        graph,
+        sources.iter().map(|source| (source, G::Weight::zero())),
+    )
+}
+
+/// Finds, for every node of `graph` reachable from one of the `sources`, the
+/// nearest source and the cost of the shortest path from it, every source
+/// starting with the cost of its offset.
+///
+/// The offset of a source is added to the cost of all the paths starting from
+/// it, for instance the time it takes to leave a facility. A source may
+/// therefore be nearer to another source than to itself. A source given
+/// several times keeps its lowest offset.
+///
+/// The errors are the same as with [`multi_source_dijkstra`].
+pub fn multi_source_dijkstra_with_offsets<G: GraphLike>(
+    graph: &G,
+    sources: &[(G::Node, G::Weight)],
+) -> Result<NearestSources<G::Node, G::Weight>, PathError> {
+    multi_source(
+        graph,
+        sources.iter().map(|(source, offset)| (source, *offset)),
+    )
+}
+
+// Runs the search from all the sources until every reachable node is settled.
+fn multi_source<'s, G, I>(
+    graph: &G,
+    sources: I,
+) -> Result<NearestSources<G::Node, G::Weight>, PathError>
+where
+    G: GraphLike,
+    G::Node: 's,
+    I: IntoIterator<Item = (&'s G::Node, G::Weight)>,
+{
+    let mut workspace = SearchWorkspace::new();
+    workspace.start_many(graph, sources)?;
+
+    // A node reached from another one is served by the same source, which is
+    // known since that node was settled first. The other nodes are sources
+    // serving themselves.
+    let mut nearest = HashMap::default();
+    let mut settled = Vec::new();
+    workspace.progress(graph, OverflowPolicy::default(), None, |node| {
+        settled.push(node);
+        false
+    })?;
+    for node in settled {
+        let source = match workspace.origin().get(node) {
+            Some(previous) => nearest[previous],
+            None => node,
+        };
+        nearest.insert(node, source);
+    }
+
+    Ok(NearestSources {
+        distances: workspace
+            .progression()
+            .iter()
+            .map(|(&node, &cost)| (node.clone(), cost))
+            .collect(),
+        nearest: nearest
+            .into_iter()
+            .map(|(node, source)| (node.clone(), source.clone()))
+            .collect(),
+        predecessors: workspace
+            .origin()
+            .iter()
+            .map(|(&node, &origin)| (node.clone(), origin.clone()))
+            .collect(),
+    })
+}
+
+/// The nearest source of every node, as found by [`multi_source_dijkstra`].
+///
+/// Every reached node is mapped to the cost of its shortest path from any of
+/// the sources, to the source this path starts from, and to its predecessor
+/// on this path. The path itself can be extracted with
+/// [`NearestSources::path_to`]. The nodes are cloned from the graph, so the
+/// result doesn't borrow it.
+#[derive(Debug, Clone)]
+pub struct NearestSources<N, W> {
+    // The cost of the shortest path to every reached node, the offset of its
+    // source included.
+    distances: HashMap<N, W>,
+    // The source every reached node is the nearest to.
+    nearest: HashMap<N, N>,
+    // The node every reached node is reached from, the sources excepted.
+    predecessors: HashMap<N, N>,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> NearestSources<N, W> {
+    /// The cost of the shortest path from the nearest source to `node`, its
+    /// offset included, or `None` if the node was not reached.
+    pub fn distance(&self, node: &N) -> Option<W> {
+        self.distances.get(node).copied()
+    }
+
+    /// The source nearest to `node`, or `None` if the node was not reached.
+    /// Among equally near sources, any can be returned.
+    pub fn nearest_source(&self, node: &N) -> Option<&N> {
+        self.nearest.get(node)
+    }
+
+    /// Whether `node` was reached from one of the sources.
+    pub fn contains(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    /// The number of nodes reached from the sources, the sources included.
+    pub fn len(&self) -> usize {
+        self.distances.len()
+    }
+
+    /// Whether no node was reached, which only happens without any source.
+    pub fn is_empty(&self) -> bool {
+        self.distances.is_empty()
+    }
+
+    /// Iterates over the reached nodes along with their nearest source and the
+    /// cost of the shortest path from it, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, &N, W)> + '_ {
+        self.distances
+            .iter()
+            .map(move |(node, cost)| (node, &self.nearest[node], *cost))
+    }
+
+    /// Extracts the shortest path from the nearest source to `destination`.
+    ///
+    /// The query fails with [`PathError::NoPath`] if the destination was not
+    /// reached. The cost of the path includes the offset of its source, and
+    /// the path carries no statistics.
+    pub fn path_to(&self, destination: &N) -> PathResult<N, W> {
+        let cost = self.distance(destination).ok_or(PathError::NoPath)?;
+
+        // We walk the predecessors back to the nearest source.
+        let mut path = vec![destination.clone()];
+        let mut location = destination;
+        while let Some(previous) = self.predecessors.get(location) {
+            location = previous;
+            path.push(location.clone());
+            if path.len() > self.distances.len() {
+                return Err(PathError::NoPath);
+            }
+        }
+        path.reverse();
+
+        Ok(ShortestPathResult {
+            nodes: path,
+            cost,
+            settled: None,
+            stats: None,
+        })
+    }
+}
@@ -0,0 +1,234 @@
+// Used for the adjacency representation.
+use crate::hash::HashMap;
+// Required by the keys of the adjacency representation.
+use std::hash::Hash;
+
+// The operations needed by the algorithms, and the costs of the edges.
+use crate::{GraphLike, Weight};
+
+/// The identity of an edge of a [`MultiGraph`].
+///
+/// Every edge added to a multigraph gets a new identity, which stays valid
+/// until the edge is removed. The identities are never reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdgeId(usize);
+
+/// A directed graph which can hold several edges between the same nodes.
+///
+/// Unlike [`Graph`](crate::Graph), adding an edge between two nodes which are
+/// already connected keeps both edges, for instance two different roads
+/// between the same places. Every edge has its own [`EdgeId`], so that the
+/// edges actually used by a path can be reported.
+///
+/// The algorithms only ever need the cheapest of the parallel edges, so this
+/// is the only one the [`GraphLike`] implementation exposes.
+#[derive(Debug, Clone)]
+pub struct MultiGraph<N, W = u32> {
+    // The parallel edges between every pair of nodes, with their identity.
+    adjacency: HashMap<N, HashMap<N, Vec<(EdgeId, W)>>>,
+    // The ends of every edge, used to find an edge from its identity.
+    ends: HashMap<EdgeId, (N, N)>,
+    // The identity given to the next edge.
+    next_id: usize,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> MultiGraph<N, W> {
+    /// Creates an empty multigraph.
+    pub fn new() -> Self {
+        MultiGraph {
+            adjacency: HashMap::default(),
+            ends: HashMap::default(),
+            next_id: 0,
+        }
+    }
+
+    /// Adds a node without any edge to the multigraph.
+    ///
+    /// Adding a node which is already in the multigraph does nothing.
+    pub fn add_node(&mut self, node: N) {
+        self.adjacency.entry(node).or_default();
+    }
+
+    /// Adds a new edge going from `source` to `destination`, and returns its
+    /// identity.
+    ///
+    /// Both nodes are added to the multigraph if they were not already in it.
+    /// The edges which already connected both nodes are kept.
+    pub fn add_edge(&mut self, source: N, destination: N, cost: W) -> EdgeId {
+        let id = EdgeId(self.next_id);
+        self.next_id += 1;
+
+        // The destination has to be known by the multigraph as well, even if
+        // it has no outgoing edge.
+        self.add_node(destination.clone());
+        self.ends.insert(id, (source.clone(), destination.clone()));
+        self.adjacency
+            .entry(source)
+            .or_default()
+            .entry(destination)
+            .or_default()
+            .push((id, cost));
+        id
+    }
+
+    /// Removes the edge with the given identity.
+    ///
+    /// Returns the ends and the cost of the removed edge, or `None` if there
+    /// was no such edge.
+    pub fn remove_edge(&mut self, id: EdgeId) -> Option<(N, N, W)> {
+        let (source, destination) = self.ends.remove(&id)?;
+        let adjacent = self.adjacency.get_mut(&source)?;
+        let parallel = adjacent.get_mut(&destination)?;
+
+        // We remove the edge from the parallel edges, and forget about the pair
+        // of nodes if it was the last edge connecting them.
+        let position = parallel.iter().position(|(edge, _)| *edge == id)?;
+        let (_, cost) = parallel.remove(position);
+        if parallel.is_empty() {
+            adjacent.remove(&destination);
+        }
+        Some((source, destination, cost))
+    }
+
+    /// Returns the ends and the cost of the edge with the given identity.
+    pub fn edge(&self, id: EdgeId) -> Option<(&N, &N, W)> {
+        let (source, destination) = self.ends.get(&id)?;
+        let (_, cost) = self
+            .parallel_edges_slice(source, destination)
+            .iter()
+            .find(|(edge, _)| *edge == id)?;
+        Some((source, destination, *cost))
+    }
+
+    /// Iterates over all the edges going from `source` to `destination`,
+    /// in the order they were added.
+    pub fn parallel_edges(
+        &self,
+        source: &N,
+        destination: &N,
+    ) -> impl Iterator<Item = (EdgeId, W)> + '_ {
+        self.parallel_edges_slice(source, destination)
+            .iter()
+            .copied()
+    }
+
+    /// Returns the cheapest edge going from `source` to `destination`, if
+    /// there is one. Among equally cheap edges, the first one added wins.
+    pub fn cheapest_edge(&self, source: &N, destination: &N) -> Option<(EdgeId, W)> {
+        cheapest(self.parallel_edges_slice(source, destination))
+    }
+
+    /// Returns the identities of the edges followed by a path, given the list
+    /// of its nodes.
+    ///
+    /// The cheapest edge is used between every pair of consecutive nodes,
+    /// which is what the shortest path algorithms do. `None` is returned if
+    /// two consecutive nodes are not connected.
+    pub fn path_edges(&self, nodes: &[N]) -> Option<Vec<EdgeId>> {
+        nodes
+            .windows(2)
+            .map(|pair| self.cheapest_edge(&pair[0], &pair[1]).map(|(id, _)| id))
+            .collect()
+    }
+
+    /// Returns whether `node` is part of the multigraph.
+    pub fn contains_node(&self, node: &N) -> bool {
+        self.adjacency.contains_key(node)
+    }
+
+    /// Iterates over the nodes adjacent to `node`, along with the cost of the
+    /// cheapest edge leading to them.
+    ///
+    /// A node which is not part of the multigraph has no neighbors.
+    pub fn neighbors<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, W)> + 'a {
+        self.adjacency
+            .get(node)
+            // A missing node is treated just like a terminal node.
+            .into_iter()
+            .flatten()
+            .filter_map(|(neighbor, parallel)| cheapest(parallel).map(|(_, cost)| (neighbor, cost)))
+    }
+
+    /// Iterates over all the nodes of the multigraph, in no particular order.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> + '_ {
+        self.adjacency.keys()
+    }
+
+    /// Iterates over all the edges of the multigraph, parallel edges included,
+    /// as `(identity, source, destination, cost)` tuples in no particular
+    /// order.
+    pub fn edges(&self) -> impl Iterator<Item = (EdgeId, &N, &N, W)> + '_ {
+        self.adjacency.iter().flat_map(|(source, adjacent)| {
+            adjacent.iter().flat_map(move |(destination, parallel)| {
+                parallel
+                    .iter()
+                    .map(move |(id, cost)| (*id, source, destination, *cost))
+            })
+        })
+    }
+
+    /// The number of nodes in the multigraph.
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// The number of edges in the multigraph, parallel edges included.
+    pub fn edge_count(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns the copy of `node` which is stored in the multigraph, if any.
+    pub fn get_node(&self, node: &N) -> Option<&N> {
+        self.adjacency.get_key_value(node).map(|(key, _)| key)
+    }
+
+    // Returns the parallel edges going from source to destination, which may be
+    // empty.
+    fn parallel_edges_slice(&self, source: &N, destination: &N) -> &[(EdgeId, W)] {
+        self.adjacency
+            .get(source)
+            .and_then(|adjacent| adjacent.get(destination))
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Exposes the cheapest of the parallel edges between every pair of nodes.
+impl<N: Eq + Hash + Clone, W: Weight> GraphLike for MultiGraph<N, W> {
+    type Node = N;
+    type Weight = W;
+
+    fn get_node(&self, node: &N) -> Option<&N> {
+        MultiGraph::get_node(self, node)
+    }
+
+    fn neighbors<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, W)> + 'a {
+        MultiGraph::neighbors(self, node)
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a N> + 'a {
+        MultiGraph::nodes(self)
+    }
+
+    fn contains_node(&self, node: &N) -> bool {
+        MultiGraph::contains_node(self, node)
+    }
+
+    fn node_count(&self) -> usize {
+        MultiGraph::node_count(self)
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> Default for MultiGraph<N, W> {
+    fn default() -> Self {
+        MultiGraph::new()
+    }
+}
+
+// Returns the cheapest edge among parallel edges, the first one winning ties.
+fn cheapest<W: Weight>(parallel: &[(EdgeId, W)]) -> Option<(EdgeId, W)> {
+    parallel
+        .iter()
+        .copied()
+        // min_by_key returns the first minimum, i.e. the oldest edge.
+        .min_by_key(|(_, cost)| *cost)
+}
@@ -0,0 +1,71 @@
+// Used to spread the queries over the threads.
+use rayon::prelude::*;
+
+// The graph being explored, and the searches themselves.
+use crate::delta::{delta_stepping_with, node_requests, Requests};
+use crate::{GraphLike, PathResult, SearchWorkspace};
+
+/// Answers a batch of `(source, destination)` queries in parallel.
+///
+/// The graph is shared by all the threads of the rayon pool, each of them
+/// reusing a single [`SearchWorkspace`] for all the queries it handles. The
+/// results are returned in the order of `queries`, each of them being what
+/// [`dijkstra`](crate::dijkstra) would have returned for the query.
+pub fn par_shortest_paths<G>(
+    graph: &G,
+    queries: &[(G::Node, G::Node)],
+) -> Vec<PathResult<G::Node, G::Weight>>
+where
+    G: GraphLike + Sync,
+    G::Node: Send + Sync,
+    G::Weight: Send,
+{
+    queries
+        .par_iter()
+        .map_init(SearchWorkspace::new, |workspace, (source, destination)| {
+            workspace.dijkstra(graph, source, destination)
+        })
+        .collect()
+}
+
+/// Finds the shortest path from `source` to `destination` in `graph` with the
+/// delta-stepping algorithm, relaxing the nodes of every bucket in parallel.
+///
+/// See [`delta_stepping`](crate::delta_stepping) for the details of the
+/// algorithm and of the query.
+///
+/// # Panics
+///
+/// Panics if `delta` is zero.
+pub fn par_delta_stepping<G>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    delta: G::Weight,
+) -> PathResult<G::Node, G::Weight>
+where
+    G: GraphLike + Sync,
+    G::Node: Sync,
+    G::Weight: Send + Sync,
+{
+    delta_stepping_with(graph, source, destination, delta, par_requests)
+}
+
+// Computes in parallel the requests of a phase of the delta-stepping
+// algorithm.
+fn par_requests<'a, G>(
+    graph: &'a G,
+    nodes: &[(&'a G::Node, G::Weight)],
+    delta: G::Weight,
+    light: bool,
+) -> Requests<'a, G>
+where
+    G: GraphLike + Sync,
+    G::Node: Sync,
+    G::Weight: Send + Sync,
+{
+    nodes
+        .par_iter()
+        .flat_map_iter(|&(node, cost)| node_requests(graph, node, cost, delta, light))
+        .collect()
+}
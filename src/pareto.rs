@@ -0,0 +1,134 @@
+// Used to order the labels by cost.
+use std::cmp::Reverse;
+// Used as priority queue by the search.
+use std::collections::BinaryHeap;
+
+// Used to keep track of the settled labels.
+use crate::hash::HashMap;
+// The graph being explored.
+use crate::{GraphLike, OverflowPolicy, PathError, Weight};
+
+/// A path which no other path beats on every objective, as returned by
+/// [`pareto_shortest_paths`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParetoPath<N, R, const K: usize> {
+    /// The nodes of the path, from the source to the destination.
+    pub nodes: Vec<N>,
+    /// The total of every objective along the path.
+    pub costs: [R; K],
+}
+
+/// Finds the paths from `source` to `destination` in `graph` which are
+/// Pareto-optimal for the `K` objectives given by `objectives`.
+///
+/// Every edge has a cost for each objective, as given by `objectives` from
+/// its source, its destination and its cost, such as its length and its
+/// toll. A path dominates another one if it costs at most as much for every
+/// objective, and the Pareto-optimal paths are the paths no other path
+/// dominates: none of them is better than another one on every objective,
+/// so that choosing among them is a trade-off.
+///
+/// The search is Dijkstra's algorithm on labels, i.e. the paths reaching a
+/// node with given costs, rather than on the plain nodes. The labels are
+/// settled in the lexicographic order of their costs, so that a label is
+/// never dominated by a label settled after it, and the labels dominated by
+/// a label settled at the same node or at the destination are dropped. The
+/// number of Pareto-optimal paths may grow exponentially with the size of
+/// the graph, but it is usually small on road networks with a few
+/// objectives.
+///
+/// Returns one path for each Pareto-optimal vector of costs, in the
+/// lexicographic order of their costs. Fails with
+/// [`PathError::UnknownSource`] or [`PathError::UnknownDestination`] if
+/// either node is not part of the graph, with [`PathError::NoPath`] if the
+/// destination cannot be reached from the source, and with
+/// [`PathError::Overflow`] if a cost of a path overflows.
+pub fn pareto_shortest_paths<G, R, O, const K: usize>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    mut objectives: O,
+) -> Result<Vec<ParetoPath<G::Node, R, K>>, PathError>
+where
+    G: GraphLike,
+    R: Weight,
+    O: FnMut(&G::Node, &G::Node, G::Weight) -> [R; K],
+{
+    // We work with the copies of the nodes stored in the graph, so that all
+    // the nodes we handle share the lifetime of the graph.
+    let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+    let destination = graph
+        .get_node(destination)
+        .ok_or(PathError::UnknownDestination)?;
+    let policy = OverflowPolicy::default();
+
+    // Every label reached so far, as its node along with the label it was
+    // reached from.
+    let mut labels: Vec<(&G::Node, Option<usize>)> = vec![(source, None)];
+    // The labels reached so far along with their costs, in lexicographic
+    // order.
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse(([R::zero(); K], 0)));
+    // The costs of the settled labels of every node.
+    let mut settled: HashMap<&G::Node, Vec<[R; K]>> = HashMap::default();
+    // The settled labels of the destination, along with their costs.
+    let mut found = Vec::new();
+
+    while let Some(Reverse((costs, label))) = queue.pop() {
+        let (node, _) = labels[label];
+        let node_labels = settled.entry(node).or_default();
+        if node_labels.iter().any(|other| dominates(other, &costs)) {
+            continue;
+        }
+        node_labels.push(costs);
+
+        // The paths going on from the destination only come back to it more
+        // expensive.
+        if node == destination {
+            found.push((costs, label));
+            continue;
+        }
+
+        for (neighbor, edge_cost) in graph.neighbors(node) {
+            let edge_costs = objectives(node, neighbor, edge_cost);
+            let mut new_costs = costs;
+            for (cost, edge_cost) in new_costs.iter_mut().zip(edge_costs) {
+                *cost = policy.add(*cost, edge_cost).ok_or(PathError::Overflow)?;
+            }
+            // We drop the labels which cannot lead to a Pareto-optimal path.
+            let dominated = |labels: Option<&Vec<[R; K]>>| {
+                labels.is_some_and(|labels| labels.iter().any(|other| dominates(other, &new_costs)))
+            };
+            if dominated(settled.get(neighbor)) || dominated(settled.get(destination)) {
+                continue;
+            }
+            labels.push((neighbor, Some(label)));
+            queue.push(Reverse((new_costs, labels.len() - 1)));
+        }
+    }
+
+    if found.is_empty() {
+        return Err(PathError::NoPath);
+    }
+    Ok(found
+        .into_iter()
+        .map(|(costs, label)| {
+            let mut nodes = Vec::new();
+            let mut location = Some(label);
+            while let Some(position) = location {
+                nodes.push(labels[position].0.clone());
+                location = labels[position].1;
+            }
+            nodes.reverse();
+            ParetoPath { nodes, costs }
+        })
+        .collect())
+}
+
+// Whether the first costs are at most the second ones on every objective.
+fn dominates<R: Ord, const K: usize>(first: &[R; K], second: &[R; K]) -> bool {
+    first
+        .iter()
+        .zip(second)
+        .all(|(first, second)| first <= second)
+}
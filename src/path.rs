@@ -0,0 +1,119 @@
+// Used to display the paths.
+use std::fmt;
+
+// The graph the costs of the hops are read from, the reasons why no path was
+// found, and what the search did to find it.
+use crate::{GraphLike, PathError, SearchStats};
+
+/// The shortest path found between two nodes of a graph.
+///
+/// On top of the nodes of the path, the result carries everything needed to
+/// display or log a route without going back to the graph: the total cost of
+/// the path, its number of edges and how much of the graph was explored to
+/// find it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPathResult<N, W> {
+    /// The nodes of the path, starting with the source and ending with the
+    /// destination.
+    pub nodes: Vec<N>,
+    /// The total cost of the path, i.e. the sum of the costs of its edges.
+    pub cost: W,
+    /// The number of nodes which were settled by the search before the
+    /// destination was reached, the destination included. This is `None` when
+    /// the algorithm which produced the path does not keep track of it.
+    pub settled: Option<usize>,
+    /// How much of the graph the search explored, which is `None` when the
+    /// algorithm which produced the path does not keep track of it.
+    pub stats: Option<SearchStats>,
+}
+
+/// The outcome of a shortest path query: either the path found, or the reason
+/// why no path could be found.
+pub type PathResult<N, W> = Result<ShortestPathResult<N, W>, PathError>;
+
+impl<N, W> ShortestPathResult<N, W> {
+    /// The number of edges in the path.
+    ///
+    /// A path going from a node to itself has no edge.
+    pub fn hops(&self) -> usize {
+        // A path always contains at least its source.
+        self.nodes.len().saturating_sub(1)
+    }
+
+    /// The first node of the path.
+    pub fn source(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    /// The last node of the path.
+    pub fn destination(&self) -> &N {
+        &self.nodes[self.nodes.len() - 1]
+    }
+}
+
+impl<N, W> ShortestPathResult<N, W> {
+    /// Returns a value displaying the path along with the cost of every hop,
+    /// as read from `graph`, for instance `A -(1)-> B -(3)-> C (cost 4)`.
+    ///
+    /// `graph` should be the graph the path was found in. The cost of a hop
+    /// whose nodes are not connected in `graph` is displayed as `?`.
+    pub fn display_hops<'a, G>(&'a self, graph: &'a G) -> PathDisplay<'a, G>
+    where
+        G: GraphLike<Node = N, Weight = W>,
+    {
+        PathDisplay { path: self, graph }
+    }
+}
+
+/// Displays the nodes of the path joined by arrows, followed by its total
+/// cost, for instance `A -> B -> C (cost 4)`.
+impl<N: fmt::Display, W: fmt::Display> fmt::Display for ShortestPathResult<N, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (position, node) in self.nodes.iter().enumerate() {
+            if position > 0 {
+                write!(f, " -> ")?;
+            }
+            write!(f, "{}", node)?;
+        }
+        write!(f, " (cost {})", self.cost)
+    }
+}
+
+/// Displays a path along with the cost of every hop, see
+/// [`ShortestPathResult::display_hops`].
+#[derive(Debug, Clone, Copy)]
+pub struct PathDisplay<'a, G: GraphLike> {
+    // The path to display.
+    path: &'a ShortestPathResult<G::Node, G::Weight>,
+    // The graph the costs of the hops are read from.
+    graph: &'a G,
+}
+
+impl<'a, G> fmt::Display for PathDisplay<'a, G>
+where
+    G: GraphLike,
+    G::Node: fmt::Display,
+    G::Weight: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes = &self.path.nodes;
+        if let Some(source) = nodes.first() {
+            write!(f, "{}", source)?;
+        }
+
+        for pair in nodes.windows(2) {
+            // The algorithms always follow the cheapest edge between two nodes.
+            let cost = self
+                .graph
+                .neighbors(&pair[0])
+                .filter(|(neighbor, _)| **neighbor == pair[1])
+                .map(|(_, cost)| cost)
+                .min();
+            match cost {
+                Some(cost) => write!(f, " -({})-> {}", cost, pair[1])?,
+                None => write!(f, " -(?)-> {}", pair[1])?,
+            }
+        }
+        write!(f, " (cost {})", self.path.cost)
+    }
+}
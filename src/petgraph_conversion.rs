@@ -0,0 +1,76 @@
+// Used to find the petgraph index of every node.
+use crate::hash::HashMap;
+// Required by the keys of the graph.
+use std::hash::Hash;
+
+// The petgraph types we convert from and to.
+use petgraph::graph::{DiGraph, IndexType};
+use petgraph::EdgeType;
+
+// The graph being converted.
+use crate::{Graph, GraphKind, Weight};
+
+/// Converts the graph into a petgraph directed graph.
+///
+/// The nodes become the node weights of the petgraph graph and the costs
+/// become its edge weights, the data attached to the nodes and edges being
+/// left out. In an undirected graph, every edge is converted in both
+/// directions.
+impl<N, W, D, E> From<&Graph<N, W, D, E>> for DiGraph<N, W>
+where
+    N: Eq + Hash + Clone,
+    W: Weight,
+{
+    fn from(graph: &Graph<N, W, D, E>) -> Self {
+        let mut converted = DiGraph::with_capacity(graph.node_count(), graph.edge_count());
+
+        // We add all the nodes first, keeping track of the index petgraph gave
+        // to each of them.
+        let indices: HashMap<&N, _> = graph
+            .nodes()
+            .map(|node| (node, converted.add_node(node.clone())))
+            .collect();
+
+        // The edges can then refer to the indices of their ends.
+        for (source, destination, cost) in graph.edges() {
+            converted.add_edge(indices[source], indices[destination], cost);
+        }
+        converted
+    }
+}
+
+/// Converts a petgraph graph into a graph of this crate.
+///
+/// The node weights of the petgraph graph become the nodes, so petgraph nodes
+/// with equal weights are merged into a single node. Parallel edges are merged
+/// as well, the last one being kept. An undirected petgraph graph gives an
+/// undirected graph. No data is attached to the nodes or edges.
+impl<N, W, D, E, Ty, Ix> From<&petgraph::Graph<N, W, Ty, Ix>> for Graph<N, W, D, E>
+where
+    N: Eq + Hash + Clone,
+    W: Weight,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: &petgraph::Graph<N, W, Ty, Ix>) -> Self {
+        let mut converted = if graph.is_directed() {
+            Graph::with_kind(GraphKind::Directed)
+        } else {
+            Graph::with_kind(GraphKind::Undirected)
+        };
+
+        // The isolated nodes have to be added explicitly, the others are added
+        // along with their edges.
+        for node in graph.node_weights() {
+            converted.add_node(node.clone());
+        }
+        for edge in graph.raw_edges() {
+            converted.add_edge(
+                graph[edge.source()].clone(),
+                graph[edge.target()].clone(),
+                edge.weight,
+            );
+        }
+        converted
+    }
+}
@@ -0,0 +1,39 @@
+// Used to order the entries of the priority queues.
+use std::cmp::Ordering;
+
+// An entry of the binary heap used as priority queue by the algorithms, i.e. a
+// node along with the cost of the best path found so far to reach it. The
+// ordering only considers the cost and is reversed, so that the max-heap of
+// the standard library pops the cheapest entry first.
+//
+// A node is pushed again every time a better path to it is found, rather than
+// updating its entry in place. The outdated entries are simply skipped when
+// they are popped after the node was settled.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct HeapEntry<'a, N, W> {
+    // The cost of the path leading to the node.
+    pub(crate) cost: W,
+    // The node, as stored in the graph.
+    pub(crate) node: &'a N,
+}
+
+impl<N, W: Ord> PartialEq for HeapEntry<'_, N, W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl<N, W: Ord> Eq for HeapEntry<'_, N, W> {}
+
+impl<N, W: Ord> PartialOrd for HeapEntry<'_, N, W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, W: Ord> Ord for HeapEntry<'_, N, W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The cheapest entry is the greatest one.
+        other.cost.cmp(&self.cost)
+    }
+}
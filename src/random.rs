@@ -0,0 +1,63 @@
+// A small pseudo-random generator, so that the crate doesn't depend on a
+// random number crate for the few algorithms which sample the graph. The
+// numbers are drawn with SplitMix64, which is fast and good enough for
+// sampling, although it is not cryptographically secure. The same seed
+// always gives the same numbers, which keeps the samples reproducible.
+#[derive(Debug, Clone)]
+pub(crate) struct Random {
+    // The state of the generator, advanced by every number drawn.
+    state: u64,
+}
+
+impl Random {
+    // Creates a generator drawing the numbers given by the seed.
+    pub(crate) fn new(seed: u64) -> Self {
+        Random { state: seed }
+    }
+
+    // Draws a number uniformly among all the u64.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut mixed = self.state;
+        mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        mixed ^ (mixed >> 31)
+    }
+
+    // Draws a number uniformly between 0 included and bound excluded, which
+    // must not be 0, see below.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        below(self.next_u64(), bound)
+    }
+
+    // Draws a number uniformly between 0 included and 1 excluded, see
+    // fraction.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        fraction(self.next_u64())
+    }
+
+    // Keeps count items picked uniformly at random, in random order, or all
+    // of them if there are fewer.
+    pub(crate) fn sample<T>(&mut self, items: &mut Vec<T>, count: usize) {
+        let count = count.min(items.len());
+        for position in 0..count {
+            let picked = position + self.below(items.len() - position);
+            items.swap(position, picked);
+        }
+        items.truncate(count);
+    }
+}
+
+// Scales a number drawn uniformly among all the u64 to a number between 0
+// included and bound excluded, which must not be 0. The numbers are scaled
+// rather than taken modulo the bound, which keeps the bias negligible for the
+// bounds of a graph.
+pub(crate) fn below(number: u64, bound: usize) -> usize {
+    ((u128::from(number) * bound as u128) >> 64) as usize
+}
+
+// Scales a number drawn uniformly among all the u64 to a number between 0
+// included and 1 excluded, keeping the 53 bits a f64 can hold.
+pub(crate) fn fraction(number: u64) -> f64 {
+    (number >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,120 @@
+// Used as priority queue by the search.
+use std::collections::BinaryHeap;
+
+// Used to keep track of the progression of the search.
+use crate::hash::HashMap;
+// The graph being explored, and the path found in it.
+use crate::queue::HeapEntry;
+use crate::{dijkstra_backtrack, FloatWeight, GraphLike, OverflowPolicy, PathError, SearchStats};
+
+/// The path most likely to succeed, as returned by [`most_reliable_path`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReliablePath<N> {
+    /// The nodes of the path, starting with the source and ending with the
+    /// destination.
+    pub nodes: Vec<N>,
+    /// The probability that every edge of the path succeeds, i.e. the product
+    /// of their probabilities.
+    pub reliability: f64,
+    /// How much of the graph the search explored.
+    pub stats: SearchStats,
+}
+
+/// Finds the path from `source` to `destination` in `graph` whose edges are
+/// the most likely to all succeed.
+///
+/// `probability` returns the probability that an edge succeeds, given its
+/// source, its destination and its cost, such as the probability that a
+/// link of a network delivers a packet. The edges are assumed to fail
+/// independently, so that the reliability of a path is the product of the
+/// probabilities of its edges. A probability is at most 1, so the product
+/// only decreases along a path, and the search is Dijkstra's algorithm on
+/// the costs `-ln(p)`, whose sums are the logarithms of the products. The
+/// edges which never succeed, with a probability of 0, are not followed.
+///
+/// The errors are the same as with [`dijkstra`](crate::dijkstra), the query
+/// failing with [`PathError::NoPath`] if every path to the destination
+/// takes an edge which never succeeds.
+///
+/// # Panics
+///
+/// Panics if a probability is NaN, negative or greater than 1.
+pub fn most_reliable_path<G, P>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    mut probability: P,
+) -> Result<ReliablePath<G::Node>, PathError>
+where
+    G: GraphLike,
+    P: FnMut(&G::Node, &G::Node, G::Weight) -> f64,
+{
+    // We work with the copies of the nodes stored in the graph, so that all
+    // the nodes we handle share the lifetime of the graph.
+    let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+    let destination = graph
+        .get_node(destination)
+        .ok_or(PathError::UnknownDestination)?;
+    let policy = OverflowPolicy::default();
+
+    // The cheapest known cost of every node reached so far, i.e. the
+    // opposite of the logarithm of its best reliability.
+    let mut costs: HashMap<&G::Node, FloatWeight<f64>> = HashMap::default();
+    // The node every node was reached from, used to backtrack the path.
+    let mut origin: HashMap<&G::Node, &G::Node> = HashMap::default();
+    let zero = FloatWeight::new(0.0).expect("zero is a valid cost");
+    costs.insert(source, zero);
+    let mut queue = BinaryHeap::new();
+    queue.push(HeapEntry {
+        cost: zero,
+        node: source,
+    });
+    let mut stats = SearchStats {
+        pushes: 1,
+        ..SearchStats::default()
+    };
+
+    while let Some(HeapEntry { cost, node }) = queue.pop() {
+        // The outdated entries are skipped.
+        if costs[node] < cost {
+            continue;
+        }
+        stats.settled += 1;
+
+        if node == destination {
+            let path = dijkstra_backtrack(&origin, source, destination).ok_or(PathError::NoPath)?;
+            return Ok(ReliablePath {
+                nodes: path.into_iter().cloned().collect(),
+                reliability: (-cost.get()).exp(),
+                stats,
+            });
+        }
+
+        for (neighbor, edge_cost) in graph.neighbors(node) {
+            stats.relaxations += 1;
+            let edge_probability = probability(node, neighbor, edge_cost);
+            assert!(
+                (0.0..=1.0).contains(&edge_probability),
+                "the probability of an edge must be between 0 and 1"
+            );
+            if edge_probability == 0.0 {
+                continue;
+            }
+            let edge_cost = FloatWeight::new(-edge_probability.ln())
+                .expect("the logarithm of a probability is not positive");
+            let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+            if costs.get(neighbor).is_some_and(|&old| old <= new_cost) {
+                continue;
+            }
+            costs.insert(neighbor, new_cost);
+            origin.insert(neighbor, node);
+            queue.push(HeapEntry {
+                cost: new_cost,
+                node: neighbor,
+            });
+            stats.pushes += 1;
+        }
+    }
+
+    Err(PathError::NoPath)
+}
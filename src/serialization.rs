@@ -0,0 +1,110 @@
+// Used to derive the implementations for the intermediate representation.
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+// Required by the keys of the graph.
+use std::hash::Hash;
+
+// The graph being serialized.
+use crate::{Float, FloatWeight, Graph, GraphKind, Weight};
+
+// The serialized form of a graph. The nodes and the edges are stored as plain
+// lists, which keeps the format readable in JSON or TOML and doesn't require
+// the nodes to be strings like the keys of a map would.
+#[derive(Serialize)]
+struct GraphRef<'a, N, W, D, E> {
+    kind: GraphKind,
+    nodes: Vec<&'a N>,
+    edges: Vec<(&'a N, &'a N, W)>,
+    data: Vec<(&'a N, &'a D)>,
+    edge_data: Vec<(&'a N, &'a N, &'a E)>,
+}
+
+// The owned counterpart of GraphRef, used for deserialization.
+#[derive(Deserialize)]
+struct GraphOwned<N, W, D, E> {
+    #[serde(default)]
+    kind: GraphKind,
+    #[serde(default = "Vec::new")]
+    nodes: Vec<N>,
+    edges: Vec<(N, N, W)>,
+    #[serde(default = "Vec::new")]
+    data: Vec<(N, D)>,
+    #[serde(default = "Vec::new")]
+    edge_data: Vec<(N, N, E)>,
+}
+
+/// Serializes the graph as its kind, the list of its nodes, the list of its
+/// `(source, destination, cost)` edges, and the lists of the `(node, data)`
+/// and `(source, destination, data)` tuples for the nodes and edges which
+/// carry some data.
+///
+/// In an undirected graph, every edge is stored in both directions, but its
+/// data is only stored once.
+impl<N, W, D, E> Serialize for Graph<N, W, D, E>
+where
+    N: Eq + Hash + Clone + Serialize,
+    W: Weight + Serialize,
+    D: Serialize,
+    E: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GraphRef {
+            kind: self.kind(),
+            nodes: self.nodes().collect(),
+            edges: self.edges().collect(),
+            data: self
+                .nodes()
+                .filter_map(|node| Some((node, self.node_data(node)?)))
+                .collect(),
+            edge_data: self.edge_data_entries().collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes a graph from the format produced by its `Serialize`
+/// implementation.
+///
+/// The kind defaults to a directed graph and the node and data lists may be
+/// left out, in which case only the nodes which appear in an edge are part of
+/// the graph.
+impl<'de, N, W, D, E> Deserialize<'de> for Graph<N, W, D, E>
+where
+    N: Eq + Hash + Clone + Deserialize<'de>,
+    W: Weight + Deserialize<'de>,
+    D: Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let repr = GraphOwned::deserialize(deserializer)?;
+
+        // We rebuild the graph edge by edge, so that its internal invariants
+        // hold whatever the input.
+        let mut graph = Graph::with_kind(repr.kind);
+        for node in repr.nodes {
+            graph.add_node(node);
+        }
+        graph.extend(repr.edges);
+        for (node, data) in repr.data {
+            graph.set_node_data(node, data);
+        }
+        for (source, destination, data) in repr.edge_data {
+            graph.attach_edge_data(source, destination, data);
+        }
+        Ok(graph)
+    }
+}
+
+// The floating point costs are serialized as plain floats, and checked again
+// when they are deserialized.
+impl<F: Float + Serialize> Serialize for FloatWeight<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, F: Float + Deserialize<'de>> Deserialize<'de> for FloatWeight<F> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        FloatWeight::new(F::deserialize(deserializer)?).map_err(De::Error::custom)
+    }
+}
@@ -0,0 +1,118 @@
+// Used as the queue of the nodes to scan, which grows at both ends.
+use std::collections::VecDeque;
+
+// Used to keep track of the progression of the algorithm.
+use crate::hash::{HashMap, HashSet};
+// The graph being explored, and the shortest paths found in it.
+use crate::{GraphLike, OverflowPolicy, PathError, ShortestPathTree, Weight};
+
+/// Finds the shortest paths from `source` to all the nodes it can reach in
+/// `graph` with the Shortest Path Faster Algorithm, a variant of
+/// [`bellman_ford`](crate::bellman_ford) which supports negative costs.
+///
+/// Rather than relaxing every edge in rounds, the algorithm keeps a queue of
+/// the nodes whose path improved, and only relaxes the edges leaving them.
+/// Two heuristics of Bertsekas reorder the queue so that the cheapest nodes
+/// are scanned first, which avoids scanning again the nodes reached through
+/// too expensive a path. With Small Label First, a node whose path improved
+/// is scanned next if it is cheaper than the node at the front of the queue.
+/// With Large Label Last, the nodes at the front of the queue which are more
+/// expensive than the average of the queue are moved to its back; the costs
+/// are averaged as `f64`, see [`Weight::to_f64`], and Large Label Last is
+/// only used if they can be converted. On graphs with a few negative edges
+/// this is close to the speed of [`dijkstra_all`](crate::dijkstra_all), but
+/// some graphs take exponential time, while Bellman-Ford never takes more
+/// than O(V·E) time.
+///
+/// The returned tree and errors are the same as with
+/// [`bellman_ford`](crate::bellman_ford). A negative cycle is found as soon
+/// as a path with as many edges as there are nodes improves, since such a
+/// path goes through a cycle which made it cheaper.
+pub fn spfa<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+) -> Result<ShortestPathTree<G::Node, G::Weight>, PathError> {
+    let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+    let policy = OverflowPolicy::default();
+    let node_count = graph.node_count();
+
+    // The best known cost of every node reached so far, along with the
+    // number of edges of its path.
+    let mut distances = HashMap::default();
+    // The node every node was reached from on its best known path.
+    let mut predecessors = HashMap::default();
+    distances.insert(source, (G::Weight::zero(), 0));
+
+    // The nodes whose path improved since they were last scanned, along with
+    // the sum of their costs if they can all be converted.
+    let mut queue = VecDeque::new();
+    let mut queued = HashSet::default();
+    let mut total = Some(0.0);
+    queue.push_back(source);
+    queued.insert(source);
+
+    while let Some(mut node) = queue.pop_front() {
+        // We move the expensive nodes to the back until the front one is not
+        // more expensive than the average, every cost being converted.
+        if let Some(sum) = total {
+            let average = sum / (queue.len() + 1) as f64;
+            let expensive = |node| {
+                distances[node]
+                    .0
+                    .to_f64()
+                    .is_some_and(|cost: f64| cost > average)
+            };
+            for _ in 0..queue.len() {
+                if !expensive(node) {
+                    break;
+                }
+                queue.push_back(node);
+                node = queue.pop_front().expect("the queue holds the node");
+            }
+        }
+        queued.remove(node);
+        let (cost, edges) = distances[node];
+        total = total.and_then(|sum| Some(sum - cost.to_f64()?));
+
+        for (neighbor, edge_cost) in graph.neighbors(node) {
+            let new_cost = policy.add(cost, edge_cost).ok_or(PathError::Overflow)?;
+            if distances
+                .get(neighbor)
+                .is_some_and(|&(old, _)| old <= new_cost)
+            {
+                continue;
+            }
+            // Every node of a path with as many edges as there are nodes
+            // cannot be distinct, the edges leading out of the graph
+            // excepted.
+            if edges + 1 >= node_count && graph.contains_node(neighbor) {
+                return Err(PathError::NegativeCycle);
+            }
+            let old_cost = distances.insert(neighbor, (new_cost, edges + 1));
+            predecessors.insert(neighbor, node);
+
+            if queued.insert(neighbor) {
+                total = total.and_then(|sum| Some(sum + new_cost.to_f64()?));
+                match queue.front() {
+                    Some(front) if new_cost < distances[front].0 => queue.push_front(neighbor),
+                    _ => queue.push_back(neighbor),
+                }
+            } else if let Some((old_cost, _)) = old_cost {
+                // The node is already queued, with a lower cost now.
+                total = total.and_then(|sum| Some(sum - old_cost.to_f64()? + new_cost.to_f64()?));
+            }
+        }
+    }
+
+    Ok(ShortestPathTree::new(
+        source.clone(),
+        distances
+            .into_iter()
+            .map(|(node, (cost, _))| (node.clone(), cost))
+            .collect(),
+        predecessors
+            .into_iter()
+            .map(|(node, predecessor)| (node.clone(), predecessor.clone()))
+            .collect(),
+    ))
+}
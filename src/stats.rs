@@ -0,0 +1,34 @@
+// Used to add up the statistics of several searches.
+use std::ops::Add;
+
+/// How much of the graph a search explored to answer a query.
+///
+/// The statistics are returned along with the paths, see
+/// [`ShortestPathResult::stats`](crate::ShortestPathResult::stats), so that
+/// the variants of the algorithms can be compared on the same queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SearchStats {
+    /// The number of settled nodes, i.e. of nodes whose shortest path was
+    /// final, the destination included.
+    pub settled: usize,
+    /// The number of edges followed from the settled nodes, whether they led
+    /// to a better path or not.
+    pub relaxations: usize,
+    /// The number of nodes pushed to the priority queue, the source included.
+    /// A node is pushed again every time a better path to it is found.
+    pub pushes: usize,
+}
+
+/// Adds up the statistics of two searches, for instance of both halves of a
+/// bidirectional search.
+impl Add for SearchStats {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        SearchStats {
+            settled: self.settled + other.settled,
+            relaxations: self.relaxations + other.relaxations,
+            pushes: self.pushes + other.pushes,
+        }
+    }
+}
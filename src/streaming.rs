@@ -0,0 +1,154 @@
+// Used to print the builder without its progress callback.
+use std::fmt;
+// Required by the nodes of the graph.
+use std::hash::Hash;
+
+// Used to number the nodes.
+use crate::csr::intern;
+use crate::hash::HashMap;
+// The graph being built, and the costs of its edges.
+use crate::{CsrGraph, Weight};
+
+/// Builds a [`CsrGraph`] from a stream of edges, in a single pass.
+///
+/// Building a [`Graph`](crate::Graph) assembles one `HashMap` per node, which
+/// takes a lot of memory and time for graphs with millions of edges. The
+/// streaming builder instead numbers the nodes as they come, and appends the
+/// edges to a flat list which is sorted into the arrays of the [`CsrGraph`]
+/// once all of them were received. The edges can thus be read one by one from
+/// a file, for instance with [`read_edge_list`](crate::read_edge_list),
+/// without ever keeping the file in memory.
+///
+/// When the size of the graph is known in advance, for instance from the
+/// header of the file, [`StreamingBuilder::with_capacity`] avoids growing the
+/// structures while reading. Long loads can be followed with
+/// [`StreamingBuilder::on_progress`].
+pub struct StreamingBuilder<'a, N, W = u32> {
+    // The nodes, in the order they were first seen.
+    nodes: Vec<N>,
+    // The index of every node.
+    index: HashMap<N, u32>,
+    // The edges received so far, between the indices of their ends.
+    edges: Vec<(u32, u32, W)>,
+    // How many edges are received between two reports, and who to report to.
+    progress: Option<(usize, Report<'a>)>,
+}
+
+// The callback receiving the number of edges received so far.
+type Report<'a> = Box<dyn FnMut(usize) + 'a>;
+
+impl<'a, N: Eq + Hash + Clone, W: Weight> StreamingBuilder<'a, N, W> {
+    /// Creates a builder for an empty graph.
+    pub fn new() -> Self {
+        StreamingBuilder::with_capacity(0, 0)
+    }
+
+    /// Creates a builder for a graph of about `nodes` nodes and `edges` edges.
+    ///
+    /// The hints only size the structures of the builder, the graph can end
+    /// up with any number of nodes and edges.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
+        let mut index = HashMap::default();
+        index.reserve(nodes);
+        StreamingBuilder {
+            nodes: Vec::with_capacity(nodes),
+            index,
+            edges: Vec::with_capacity(edges),
+            progress: None,
+        }
+    }
+
+    /// Calls `report` with the number of edges received so far, every time
+    /// `every` more edges were received.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `every` is zero.
+    pub fn on_progress<F: FnMut(usize) + 'a>(mut self, every: usize, report: F) -> Self {
+        assert!(every > 0, "the progress cannot be reported every 0 edges");
+        self.progress = Some((every, Box::new(report)));
+        self
+    }
+
+    /// Adds a node without any edge to the graph.
+    pub fn add_node(&mut self, node: N) {
+        intern(&mut self.nodes, &mut self.index, node);
+    }
+
+    /// Adds an edge going from `source` to `destination`.
+    ///
+    /// Parallel edges are all kept, the algorithms will simply favor the
+    /// cheapest one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edges involve more than `u32::MAX` distinct nodes.
+    pub fn add_edge(&mut self, source: N, destination: N, cost: W) {
+        let source = intern(&mut self.nodes, &mut self.index, source);
+        let destination = intern(&mut self.nodes, &mut self.index, destination);
+        self.edges.push((source, destination, cost));
+
+        // We report the progress once enough edges were received.
+        if let Some((every, report)) = &mut self.progress {
+            if self.edges.len().is_multiple_of(*every) {
+                report(self.edges.len());
+            }
+        }
+    }
+
+    /// Adds all the edges of a fallible stream, such as the one returned by
+    /// [`read_edge_list`](crate::read_edge_list), stopping at the first error.
+    ///
+    /// The edges received before the error are kept.
+    pub fn try_extend<I, E>(&mut self, edges: I) -> Result<(), E>
+    where
+        I: IntoIterator<Item = Result<(N, N, W), E>>,
+    {
+        for edge in edges {
+            let (source, destination, cost) = edge?;
+            self.add_edge(source, destination, cost);
+        }
+        Ok(())
+    }
+
+    /// The number of nodes received so far.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// The number of edges received so far.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns the graph made of all the received nodes and edges.
+    ///
+    /// The nodes are numbered in the order they were first received.
+    pub fn build(self) -> CsrGraph<N, W> {
+        CsrGraph::from_numbered_edges(self.nodes, self.index, self.edges)
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> Extend<(N, N, W)> for StreamingBuilder<'_, N, W> {
+    fn extend<I: IntoIterator<Item = (N, N, W)>>(&mut self, edges: I) {
+        for (source, destination, cost) in edges {
+            self.add_edge(source, destination, cost);
+        }
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> Default for StreamingBuilder<'_, N, W> {
+    fn default() -> Self {
+        StreamingBuilder::new()
+    }
+}
+
+// The progress callback cannot be printed, so only the sizes are.
+impl<N, W> fmt::Debug for StreamingBuilder<'_, N, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamingBuilder")
+            .field("nodes", &self.nodes.len())
+            .field("edges", &self.edges.len())
+            .finish()
+    }
+}
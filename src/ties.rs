@@ -0,0 +1,249 @@
+// Used to sort the nodes of the shortest paths.
+use std::collections::VecDeque;
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to keep track of the shortest paths to every node.
+use crate::hash::{HashMap, HashSet};
+// The graph being explored, the search finding the shortest paths and the
+// paths enumerated.
+use crate::{GraphLike, OverflowPolicy, PathError, SearchWorkspace, ShortestPathResult, Weight};
+
+/// Counts the different shortest paths from `source` to `destination` in
+/// `graph`.
+///
+/// Several paths may tie for the lowest cost, while
+/// [`dijkstra`](crate::dijkstra) only returns one of them. A first search
+/// finds the cost of the shortest paths to every node, and an edge lies on a
+/// shortest path whenever it leads from a node to a neighbor exactly as
+/// cheaply as the cheapest path to that neighbor. Going through these edges
+/// in topological order, the number of shortest paths to a node is the sum
+/// of the numbers of shortest paths to the nodes leading to it. The edges of
+/// zero cost are therefore handled as well, whatever the order in which the
+/// nodes tying with them were settled. This takes O((V + E) log V) time.
+///
+/// The errors are the same as with [`dijkstra`](crate::dijkstra), and the
+/// query fails with [`PathError::ZeroCostCycle`] if a cycle of zero cost
+/// lies on the shortest paths, since there are then infinitely many of
+/// them, and with [`PathError::Overflow`] if the number of paths overflows.
+pub fn shortest_path_count<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+) -> Result<usize, PathError> {
+    let ties = Ties::new(graph, source, destination)?;
+
+    // The source is the first node of the order, and the only node of the
+    // shortest paths which no edge leads to.
+    let mut counts: HashMap<&G::Node, usize> = HashMap::default();
+    counts.insert(ties.source, 1);
+    for &node in &ties.order[1..] {
+        let mut count: usize = 0;
+        for predecessor in &ties.predecessors[node] {
+            count = count
+                .checked_add(counts[predecessor])
+                .ok_or(PathError::Overflow)?;
+        }
+        counts.insert(node, count);
+    }
+    Ok(counts[ties.destination])
+}
+
+/// Enumerates all the shortest paths from `source` to `destination` in
+/// `graph`, when several of them tie for the lowest cost.
+///
+/// The edges lying on a shortest path are found just like with
+/// [`shortest_path_count`], every node keeping all the nodes leading to it
+/// on a shortest path rather than the single one
+/// [`dijkstra_backtrack`](crate::dijkstra_backtrack) works with. The
+/// returned iterator then unwinds these edges backward from the destination,
+/// one path at a time, so that only the directed acyclic graph of the edges
+/// and the current path are kept in memory however many paths there are.
+/// Every path is found in O(V) time, and the paths which only differ by
+/// their parallel edges are all yielded, just like they are all counted.
+///
+/// The errors are the same as with [`shortest_path_count`], except for the
+/// overflow of the number of paths since they are not counted.
+pub fn all_shortest_paths<'g, G: GraphLike>(
+    graph: &'g G,
+    source: &G::Node,
+    destination: &G::Node,
+) -> Result<AllShortestPaths<'g, G::Node, G::Weight>, PathError> {
+    let ties = Ties::new(graph, source, destination)?;
+    Ok(AllShortestPaths {
+        stack: vec![(ties.destination, 0)],
+        started: false,
+        ties,
+    })
+}
+
+/// The iterator returned by [`all_shortest_paths`], yielding the shortest
+/// paths in no particular order.
+///
+/// The results carry no statistics, since the paths are not found by a
+/// search.
+#[derive(Debug)]
+pub struct AllShortestPaths<'g, N, W> {
+    // The edges lying on the shortest paths.
+    ties: Ties<'g, N, W>,
+    // The nodes of the current path from the destination, along with the
+    // position of the next node leading to them to take.
+    stack: Vec<(&'g N, usize)>,
+    // Whether the iterator yielded anything yet.
+    started: bool,
+}
+
+impl<N: Eq + Hash + Clone, W: Copy> Iterator for AllShortestPaths<'_, N, W> {
+    type Item = ShortestPathResult<N, W>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let source = self.ties.source;
+        // The path from a node to itself is the only one with no edge.
+        if !self.started {
+            self.started = true;
+            if source == self.ties.destination {
+                self.stack.clear();
+                return Some(self.path());
+            }
+        }
+
+        // We go back to the nodes leading to the last node of the path, one
+        // after the other, every one of them leading back to the source.
+        loop {
+            let (node, next) = self.stack.last_mut()?;
+            let predecessor = self
+                .ties
+                .predecessors
+                .get(*node)
+                .and_then(|predecessors| predecessors.get(*next));
+            match predecessor {
+                Some(&predecessor) => {
+                    *next += 1;
+                    if predecessor == source {
+                        return Some(self.path());
+                    }
+                    self.stack.push((predecessor, 0));
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+impl<N: Clone, W: Copy> AllShortestPaths<'_, N, W> {
+    // The current path, from the source to the destination.
+    fn path(&self) -> ShortestPathResult<N, W> {
+        let nodes = std::iter::once(self.ties.source)
+            .chain(self.stack.iter().rev().map(|&(node, _)| node))
+            .cloned()
+            .collect();
+        ShortestPathResult {
+            nodes,
+            cost: self.ties.cost,
+            settled: None,
+            stats: None,
+        }
+    }
+}
+
+// The shortest paths from a source to a destination, seen as the directed
+// acyclic graph of the edges lying on one of them.
+#[derive(Debug)]
+struct Ties<'g, N, W> {
+    // The source of the paths, as stored in the graph.
+    source: &'g N,
+    // The destination of the paths, as stored in the graph.
+    destination: &'g N,
+    // The cost of the shortest paths.
+    cost: W,
+    // The nodes leading to every node of the paths along one of them. Only
+    // the nodes of the paths are kept.
+    predecessors: HashMap<&'g N, Vec<&'g N>>,
+    // The nodes of the paths in topological order, starting with the source.
+    order: Vec<&'g N>,
+}
+
+impl<'g, N: Eq + Hash, W: Weight> Ties<'g, N, W> {
+    // Finds the shortest paths from source to destination.
+    fn new<G>(graph: &'g G, source: &N, destination: &N) -> Result<Self, PathError>
+    where
+        G: GraphLike<Node = N, Weight = W>,
+    {
+        let policy = OverflowPolicy::default();
+        let destination = graph
+            .get_node(destination)
+            .ok_or(PathError::UnknownDestination)?;
+        let mut workspace = SearchWorkspace::new();
+        let source = workspace.start(graph, source)?;
+        workspace.progress(graph, policy, None, |_| false)?;
+        let distances = workspace.progression();
+        let cost = *distances.get(destination).ok_or(PathError::NoPath)?;
+
+        // An edge lies on a shortest path if its neighbor is reached through
+        // it as cheaply as possible.
+        let mut predecessors: HashMap<&G::Node, Vec<&G::Node>> = HashMap::default();
+        for (&node, &distance) in distances {
+            for (neighbor, cost) in graph.neighbors(node) {
+                if distances.get(neighbor).copied() == policy.add(distance, cost) {
+                    predecessors.entry(neighbor).or_default().push(node);
+                }
+            }
+        }
+
+        // Only the nodes leading to the destination along these edges are
+        // on its shortest paths.
+        let mut kept = HashSet::default();
+        let mut stack = vec![destination];
+        kept.insert(destination);
+        while let Some(node) = stack.pop() {
+            for &predecessor in predecessors.get(node).into_iter().flatten() {
+                if kept.insert(predecessor) {
+                    stack.push(predecessor);
+                }
+            }
+        }
+        predecessors.retain(|node, _| kept.contains(node));
+
+        // We sort the nodes kept with Kahn's algorithm, counting the edges
+        // leading to every node from the nodes not sorted yet.
+        let mut incoming: HashMap<&G::Node, usize> = HashMap::default();
+        let mut successors: HashMap<&G::Node, Vec<&G::Node>> = HashMap::default();
+        for (&node, node_predecessors) in &predecessors {
+            incoming.insert(node, node_predecessors.len());
+            for &predecessor in node_predecessors {
+                successors.entry(predecessor).or_default().push(node);
+            }
+        }
+        let mut ready: VecDeque<&G::Node> = kept
+            .iter()
+            .copied()
+            .filter(|node| incoming.get(node).is_none_or(|&count| count == 0))
+            .collect();
+        let mut order = Vec::with_capacity(kept.len());
+        while let Some(node) = ready.pop_front() {
+            order.push(node);
+            for &successor in successors.get(node).into_iter().flatten() {
+                let count = incoming.get_mut(successor).expect("every node is counted");
+                *count -= 1;
+                if *count == 0 {
+                    ready.push_back(successor);
+                }
+            }
+        }
+
+        // The nodes left unsorted are on a cycle of edges lying on shortest
+        // paths, which can only cost zero.
+        if order.len() < kept.len() {
+            return Err(PathError::ZeroCostCycle);
+        }
+        Ok(Ties {
+            source,
+            destination,
+            cost,
+            predecessors,
+            order,
+        })
+    }
+}
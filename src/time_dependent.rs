@@ -0,0 +1,212 @@
+// Used as priority queue by the search.
+use std::collections::BinaryHeap;
+// Used to interpolate the travel times with wider integers.
+use std::convert::{TryFrom, TryInto};
+
+// Used to keep track of the progression of the search.
+use crate::hash::HashMap;
+// The graph being explored, and the path found in it.
+use crate::queue::HeapEntry;
+use crate::{
+    dijkstra_backtrack, GraphLike, OverflowPolicy, PathError, PathResult, SearchStats,
+    ShortestPathResult, Weight,
+};
+
+/// Finds the route from `source` to `destination` in `graph` arriving the
+/// earliest, when leaving the source at `departure`, while the time taken by
+/// an edge depends on when it is entered.
+///
+/// `travel_time` returns the time taken by an edge, given its source, its
+/// destination, its cost in the graph and the time at which it is entered.
+/// The cost of the edge can for instance index a [`TravelTimeProfile`] of
+/// the edge, or be its travel time outside the rush hours. The search is
+/// Dijkstra's algorithm on the arrival times, which finds the earliest
+/// arrival as long as waiting never pays off, i.e. as long as entering an
+/// edge later never leads out of it earlier. Road networks have this FIFO
+/// property, since a car cannot overtake the traffic jam which delays it.
+///
+/// The cost of the returned path is the time of arrival at the destination,
+/// rather than the time the route takes. The errors are the same as with
+/// [`dijkstra`](crate::dijkstra).
+pub fn time_dependent_dijkstra<G, T>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    departure: G::Weight,
+    mut travel_time: T,
+) -> PathResult<G::Node, G::Weight>
+where
+    G: GraphLike,
+    T: FnMut(&G::Node, &G::Node, G::Weight, G::Weight) -> G::Weight,
+{
+    // We work with the copies of the nodes stored in the graph, so that all
+    // the nodes we handle share the lifetime of the graph.
+    let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+    let destination = graph
+        .get_node(destination)
+        .ok_or(PathError::UnknownDestination)?;
+    let policy = OverflowPolicy::default();
+
+    // The earliest known arrival at every node reached so far.
+    let mut arrivals: HashMap<&G::Node, G::Weight> = HashMap::default();
+    // The node every node was reached from, used to backtrack the route.
+    let mut origin: HashMap<&G::Node, &G::Node> = HashMap::default();
+    arrivals.insert(source, departure);
+    let mut queue = BinaryHeap::new();
+    queue.push(HeapEntry {
+        cost: departure,
+        node: source,
+    });
+    let mut stats = SearchStats {
+        pushes: 1,
+        ..SearchStats::default()
+    };
+
+    while let Some(HeapEntry {
+        cost: arrival,
+        node,
+    }) = queue.pop()
+    {
+        // The outdated entries are skipped.
+        if arrivals[node] < arrival {
+            continue;
+        }
+        stats.settled += 1;
+
+        if node == destination {
+            let path = dijkstra_backtrack(&origin, source, destination).ok_or(PathError::NoPath)?;
+            return Ok(ShortestPathResult {
+                nodes: path.into_iter().cloned().collect(),
+                cost: arrival,
+                settled: Some(stats.settled),
+                stats: Some(stats),
+            });
+        }
+
+        for (neighbor, edge_cost) in graph.neighbors(node) {
+            stats.relaxations += 1;
+            let duration = travel_time(node, neighbor, edge_cost, arrival);
+            let new_arrival = policy.add(arrival, duration).ok_or(PathError::Overflow)?;
+            if arrivals
+                .get(neighbor)
+                .is_some_and(|&old| old <= new_arrival)
+            {
+                continue;
+            }
+            arrivals.insert(neighbor, new_arrival);
+            origin.insert(neighbor, node);
+            queue.push(HeapEntry {
+                cost: new_arrival,
+                node: neighbor,
+            });
+            stats.pushes += 1;
+        }
+    }
+
+    Err(PathError::NoPath)
+}
+
+/// The time taken by an edge as a function of the time it is entered, known
+/// at a few breakpoints and interpolated linearly between them, see
+/// [`time_dependent_dijkstra`].
+///
+/// The travel time stays the same as at the first breakpoint before it, and
+/// as at the last breakpoint after it. The profile has the FIFO property as
+/// long as the travel time never decreases faster than time passes between
+/// two breakpoints.
+///
+/// The profiles are only available for the primitive integer types, and not
+/// for [`FloatWeight`](crate::FloatWeight). The interpolated travel times
+/// are rounded towards the travel time at the breakpoint before them, and
+/// computed with 128-bit integers so that the interpolation never overflows
+/// for the types of up to 64 bits, such as times in milliseconds held by a
+/// `u32` or in microseconds held by a `u64`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TravelTimeProfile<W> {
+    // The times at which the edge is entered, in increasing order, along
+    // with the time it takes then.
+    breakpoints: Vec<(W, W)>,
+}
+
+impl<W> TravelTimeProfile<W>
+where
+    W: Weight + TryInto<i128> + TryFrom<i128>,
+{
+    /// Creates the profile going through the given `(time, travel time)`
+    /// breakpoints.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no breakpoint, or if the times of the breakpoints
+    /// are not in strictly increasing order.
+    pub fn new(breakpoints: Vec<(W, W)>) -> Self {
+        assert!(!breakpoints.is_empty(), "a profile needs a breakpoint");
+        assert!(
+            breakpoints.windows(2).all(|pair| pair[0].0 < pair[1].0),
+            "the breakpoints must be in increasing order of time"
+        );
+        TravelTimeProfile { breakpoints }
+    }
+
+    /// Creates the profile of an edge always taking `travel_time`.
+    pub fn constant(travel_time: W) -> Self {
+        TravelTimeProfile {
+            breakpoints: vec![(W::zero(), travel_time)],
+        }
+    }
+
+    /// The time taken by the edge when entered at `time`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the travel time cannot be interpolated with 128-bit
+    /// integers, which only happens with wider costs whose breakpoints are
+    /// very far apart.
+    pub fn travel_time(&self, time: W) -> W {
+        // The breakpoints around the time, the first one after it being
+        // found by binary search.
+        let after = self
+            .breakpoints
+            .partition_point(|&(breakpoint, _)| breakpoint <= time);
+        if after == 0 {
+            return self.breakpoints[0].1;
+        }
+        if after == self.breakpoints.len() {
+            return self.breakpoints[after - 1].1;
+        }
+        let (start, from) = self.breakpoints[after - 1];
+        let (end, to) = self.breakpoints[after];
+
+        interpolate(start, end, from, to, time)
+            .expect("the travel time cannot be interpolated with 128-bit integers")
+    }
+}
+
+// The travel time at the given time, on the line going through the travel
+// times at the start and at the end, computed with 128-bit integers. None
+// means that a cost or an intermediate result doesn't fit.
+fn interpolate<W>(start: W, end: W, from: W, to: W, time: W) -> Option<W>
+where
+    W: TryInto<i128> + TryFrom<i128>,
+{
+    let [start, end, from, to, time] =
+        [start, end, from, to, time].map(|cost| cost.try_into().ok());
+    let (start, end, from, to, time) = (start?, end?, from?, to?, time?);
+    let elapsed = time.checked_sub(start)?.unsigned_abs();
+    let span = end.checked_sub(start)?.unsigned_abs();
+    let change = to.checked_sub(from)?;
+
+    // The change is split into whole spans and a remainder, so that the
+    // products are never larger than the span squared. The time is before
+    // the end, so the part of the change is never larger than the change.
+    let magnitude = change.unsigned_abs();
+    let whole = magnitude / span * elapsed;
+    let part = (magnitude % span).checked_mul(elapsed)? / span;
+    let part = i128::try_from(whole + part).ok()?;
+    let travel_time = if change < 0 {
+        from.checked_sub(part)?
+    } else {
+        from.checked_add(part)?
+    };
+    W::try_from(travel_time).ok()
+}
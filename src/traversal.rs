@@ -0,0 +1,85 @@
+// Used to display the state of the traversal.
+use std::fmt;
+
+// Used to keep track of the visited nodes.
+use crate::hash::HashSet;
+// The graph being explored.
+use crate::GraphLike;
+
+/// Iterates over the nodes reachable from `start` in `graph`, in the order
+/// they are visited by a depth-first search.
+///
+/// The start comes first, and every node is followed by the nodes reachable
+/// from it which were not visited yet, its neighbors being explored in the
+/// order of [`GraphLike::neighbors`]. Every node is yielded once, and the
+/// iterator is empty if the start is not part of the graph.
+///
+/// The costs of the edges are ignored, so going through the whole iterator
+/// takes O(V + E) time. The nodes are only explored as the iterator is
+/// advanced, so stopping early saves the rest of the search.
+pub fn dfs<'g, G: GraphLike>(graph: &'g G, start: &G::Node) -> Dfs<'g, G> {
+    Dfs {
+        graph,
+        stack: graph.get_node(start).into_iter().collect(),
+        visited: HashSet::default(),
+    }
+}
+
+/// Returns whether there is a path from `source` to `destination` in `graph`.
+///
+/// This only looks for a path with [`dfs`], without computing its cost, and
+/// stops as soon as the destination is found. A node always reaches itself,
+/// and a node which is not part of the graph reaches nothing.
+pub fn is_reachable<G: GraphLike>(graph: &G, source: &G::Node, destination: &G::Node) -> bool {
+    dfs(graph, source).any(|node| node == destination)
+}
+
+/// The iterator returned by [`dfs`].
+pub struct Dfs<'g, G: GraphLike> {
+    // The graph being explored.
+    graph: &'g G,
+    // The nodes reached but not visited yet, the next one last.
+    stack: Vec<&'g G::Node>,
+    // The nodes already visited.
+    visited: HashSet<&'g G::Node>,
+}
+
+impl<'g, G: GraphLike> Iterator for Dfs<'g, G> {
+    type Item = &'g G::Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // A node may be reached several times before being visited, the
+        // later copies being skipped.
+        while let Some(node) = self.stack.pop() {
+            if !self.visited.insert(node) {
+                continue;
+            }
+
+            // The neighbors are stacked in reverse order, so that the first
+            // one is visited first.
+            let visited = &self.visited;
+            let start = self.stack.len();
+            self.stack.extend(
+                self.graph
+                    .neighbors(node)
+                    .map(|(neighbor, _)| neighbor)
+                    .filter(|neighbor| !visited.contains(neighbor)),
+            );
+            self.stack[start..].reverse();
+            return Some(node);
+        }
+        None
+    }
+}
+
+impl<G: GraphLike> fmt::Debug for Dfs<'_, G>
+where
+    G::Node: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Dfs")
+            .field("stack", &self.stack)
+            .field("visited", &self.visited)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,104 @@
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to store the distances and the predecessors.
+use crate::hash::HashMap;
+// The paths extracted from the tree.
+use crate::{PathError, PathResult, ShortestPathResult, Weight};
+
+/// The shortest paths from a source to all the nodes it can reach, as found
+/// by [`dijkstra_all`](crate::dijkstra_all) or
+/// [`bellman_ford`](crate::bellman_ford).
+///
+/// The tree maps every reached node to the cost of its shortest path, and to
+/// its predecessor on this path. Any of these paths can then be extracted with
+/// [`ShortestPathTree::path_to`] without running the algorithm again. The
+/// nodes are cloned from the graph, so the tree doesn't borrow it.
+#[derive(Debug, Clone)]
+pub struct ShortestPathTree<N, W> {
+    // The node all the paths start from.
+    source: N,
+    // The cost of the shortest path to every reached node.
+    distances: HashMap<N, W>,
+    // The node every reached node is reached from, the source excepted.
+    predecessors: HashMap<N, N>,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> ShortestPathTree<N, W> {
+    // Creates a tree from the distances and the predecessors found by an
+    // algorithm, which must have reached the source.
+    pub(crate) fn new(source: N, distances: HashMap<N, W>, predecessors: HashMap<N, N>) -> Self {
+        ShortestPathTree {
+            source,
+            distances,
+            predecessors,
+        }
+    }
+
+    /// The node all the paths start from.
+    pub fn source(&self) -> &N {
+        &self.source
+    }
+
+    /// The cost of the shortest path from the source to `node`, or `None` if
+    /// the node was not reached.
+    pub fn distance(&self, node: &N) -> Option<W> {
+        self.distances.get(node).copied()
+    }
+
+    /// The node preceding `node` on its shortest path from the source, or
+    /// `None` for the source and for the nodes which were not reached.
+    pub fn predecessor(&self, node: &N) -> Option<&N> {
+        self.predecessors.get(node)
+    }
+
+    /// Whether `node` was reached from the source.
+    pub fn contains(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    /// The number of nodes reached from the source, the source included.
+    pub fn len(&self) -> usize {
+        self.distances.len()
+    }
+
+    /// Whether no node was reached, which only happens for an empty tree.
+    pub fn is_empty(&self) -> bool {
+        self.distances.is_empty()
+    }
+
+    /// Iterates over the reached nodes along with the cost of their shortest
+    /// path, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (&N, W)> + '_ {
+        self.distances.iter().map(|(node, cost)| (node, *cost))
+    }
+
+    /// Extracts the shortest path from the source to `destination`.
+    ///
+    /// The query fails with [`PathError::NoPath`] if the destination was not
+    /// reached. The returned path carries no statistics, since no search is
+    /// run to find it.
+    pub fn path_to(&self, destination: &N) -> PathResult<N, W> {
+        let cost = self.distance(destination).ok_or(PathError::NoPath)?;
+
+        // We walk the predecessors back to the source. A tree has no cycle,
+        // but we still stop after as many steps as there are nodes.
+        let mut path = vec![destination.clone()];
+        let mut location = destination;
+        while *location != self.source {
+            location = self.predecessors.get(location).ok_or(PathError::NoPath)?;
+            path.push(location.clone());
+            if path.len() > self.distances.len() {
+                return Err(PathError::NoPath);
+            }
+        }
+        path.reverse();
+
+        Ok(ShortestPathResult {
+            nodes: path,
+            cost,
+            settled: None,
+            stats: None,
+        })
+    }
+}
@@ -0,0 +1,194 @@
+// Used to order the states by cost.
+use std::cmp::Reverse;
+// Used as priority queue by the search.
+use std::collections::BinaryHeap;
+// Required by the nodes, which are used as keys.
+use std::hash::Hash;
+
+// Used to store the turns, and to keep track of the settled states.
+use crate::hash::HashMap;
+// The graph being explored, and the path found in it.
+use crate::{
+    GraphLike, OverflowPolicy, PathError, PathResult, SearchStats, ShortestPathResult, Weight,
+};
+
+/// The turns a query must not take, or pays a penalty for.
+///
+/// A turn is made of three nodes: the path comes from the first one, goes
+/// through the second one, and leaves towards the third one. Forbidding a
+/// turn forbids the paths following both edges in a row, without forbidding
+/// either edge, which a graph of nodes alone cannot express: "no left turn
+/// here" forbids a single way of going through the crossing. A penalty adds
+/// to the cost of the paths taking the turn, such as the time waited at a
+/// traffic light. The turns are used by [`dijkstra_with_turns`].
+#[derive(Debug, Clone)]
+pub struct Turns<N, W> {
+    // The turns through every node, as the node the path comes from and the
+    // node it leaves towards, along with the penalty of the turn, None if the
+    // turn is forbidden.
+    turns: HashMap<N, Vec<(N, N, Option<W>)>>,
+    // Whether the paths may not go back to the node they come from.
+    no_u_turns: bool,
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> Turns<N, W> {
+    /// Creates a list which allows every turn for free.
+    pub fn new() -> Self {
+        Turns {
+            turns: HashMap::default(),
+            no_u_turns: false,
+        }
+    }
+
+    /// Forbids the paths coming from `from` to go through `via` towards `to`.
+    pub fn forbid(self, from: N, via: N, to: N) -> Self {
+        self.turn(from, via, to, None)
+    }
+
+    /// Adds `penalty` to the cost of the paths coming from `from` which go
+    /// through `via` towards `to`.
+    pub fn penalize(self, from: N, via: N, to: N, penalty: W) -> Self {
+        self.turn(from, via, to, Some(penalty))
+    }
+
+    /// Forbids the paths to go back to the node they just came from, which
+    /// forbids most of the U-turns of a road network.
+    pub fn forbid_u_turns(mut self) -> Self {
+        self.no_u_turns = true;
+        self
+    }
+
+    /// Whether the paths coming from `from` may not go through `via` towards
+    /// `to`.
+    pub fn forbids(&self, from: &N, via: &N, to: &N) -> bool {
+        (self.no_u_turns && from == to) || self.find(from, via, to) == Some(None)
+    }
+
+    /// The penalty of the paths coming from `from` which go through `via`
+    /// towards `to`, zero if the turn is free or forbidden.
+    pub fn penalty(&self, from: &N, via: &N, to: &N) -> W {
+        self.find(from, via, to).flatten().unwrap_or_else(W::zero)
+    }
+
+    // Sets the penalty of the turn, which is forbidden if there is none.
+    fn turn(mut self, from: N, via: N, to: N, penalty: Option<W>) -> Self {
+        let turns = self.turns.entry(via).or_default();
+        match turns
+            .iter_mut()
+            .find(|(old_from, old_to, _)| *old_from == from && *old_to == to)
+        {
+            Some(turn) => turn.2 = penalty,
+            None => turns.push((from, to, penalty)),
+        }
+        self
+    }
+
+    // The penalty of the turn if it was set, None if the turn is forbidden.
+    fn find(&self, from: &N, via: &N, to: &N) -> Option<Option<W>> {
+        self.turns
+            .get(via)?
+            .iter()
+            .find(|(turn_from, turn_to, _)| turn_from == from && turn_to == to)
+            .map(|&(_, _, penalty)| penalty)
+    }
+}
+
+impl<N: Eq + Hash + Clone, W: Weight> Default for Turns<N, W> {
+    fn default() -> Self {
+        Turns::new()
+    }
+}
+
+/// Finds the shortest path from `source` to `destination` in `graph` which
+/// doesn't take any of the turns forbidden by `turns`, and pays for the
+/// penalties of the turns it takes.
+///
+/// The best path to a node may not be the start of the best path through
+/// it, since the turn it ends with may be forbidden or expensive. The
+/// search therefore settles the edges rather than the nodes, i.e. the states
+/// made of a node and of the node the path came from, which settles every
+/// node once per edge entering it in the worst case.
+///
+/// The cost of the returned path includes the penalties of its turns. The
+/// returned path may go through a node several times, for instance to go
+/// around a block rather than turning left. The errors are the same as with
+/// [`dijkstra`](crate::dijkstra).
+pub fn dijkstra_with_turns<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    destination: &G::Node,
+    turns: &Turns<G::Node, G::Weight>,
+) -> PathResult<G::Node, G::Weight> {
+    // We work with the copies of the nodes stored in the graph, so that all
+    // the nodes we handle share the lifetime of the graph.
+    let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+    let destination = graph
+        .get_node(destination)
+        .ok_or(PathError::UnknownDestination)?;
+    let policy = OverflowPolicy::default();
+
+    // Every state reached so far, as its node and the state it was reached
+    // from.
+    let mut states: Vec<(&G::Node, Option<usize>)> = vec![(source, None)];
+    // The states reached so far along with their cost, the cheapest one
+    // first.
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((G::Weight::zero(), 0)));
+    // The best known cost of every state, as the node the path came from and
+    // the node it reached, the source coming from nowhere.
+    let mut costs = HashMap::default();
+    costs.insert((None, source), G::Weight::zero());
+    let mut stats = SearchStats {
+        pushes: 1,
+        ..SearchStats::default()
+    };
+
+    while let Some(Reverse((cost, state))) = queue.pop() {
+        let (node, previous) = states[state];
+        let from = previous.map(|previous| states[previous].0);
+        // The outdated entries are skipped.
+        if costs[&(from, node)] < cost {
+            continue;
+        }
+        stats.settled += 1;
+
+        if node == destination {
+            let mut path = vec![node.clone()];
+            let mut location = previous;
+            while let Some(position) = location {
+                path.push(states[position].0.clone());
+                location = states[position].1;
+            }
+            path.reverse();
+            return Ok(ShortestPathResult {
+                nodes: path,
+                cost,
+                settled: Some(stats.settled),
+                stats: Some(stats),
+            });
+        }
+
+        for (neighbor, edge_cost) in graph.neighbors(node) {
+            stats.relaxations += 1;
+            let penalty = match from {
+                Some(from) if turns.forbids(from, node, neighbor) => continue,
+                Some(from) => turns.penalty(from, node, neighbor),
+                None => G::Weight::zero(),
+            };
+            let new_cost = policy
+                .add(cost, edge_cost)
+                .and_then(|new_cost| policy.add(new_cost, penalty))
+                .ok_or(PathError::Overflow)?;
+            let key = (Some(node), neighbor);
+            if costs.get(&key).is_some_and(|&old| old <= new_cost) {
+                continue;
+            }
+            costs.insert(key, new_cost);
+            states.push((neighbor, Some(state)));
+            queue.push(Reverse((new_cost, states.len() - 1)));
+            stats.pushes += 1;
+        }
+    }
+
+    Err(PathError::NoPath)
+}
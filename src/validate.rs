@@ -0,0 +1,150 @@
+// Used to keep track of the nodes and edges already seen.
+use crate::hash::{HashMap, HashSet};
+
+// The graph being validated.
+use crate::{GraphLike, Weight};
+
+/// The oddities found in a graph by [`validate`].
+///
+/// None of them prevents the algorithms from running, but they usually
+/// reveal a mistake in the data the graph was built from, and they can make
+/// the algorithms return surprising paths. The nodes are reported in no
+/// particular order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationReport<N, W> {
+    /// The `(source, destination, cost)` edges whose destination is not a
+    /// node of the graph.
+    pub dangling_edges: Vec<(N, N, W)>,
+    /// The `(node, cost)` edges going from a node to itself.
+    pub self_loops: Vec<(N, W)>,
+    /// The `(source, destination)` pairs connected by more than one edge.
+    pub duplicate_edges: Vec<(N, N)>,
+    /// The `(source, destination, cost)` edges with a negative cost, on which
+    /// Dijkstra's algorithm is not guaranteed to find the shortest paths.
+    pub negative_edges: Vec<(N, N, W)>,
+    /// Cycles made only of edges with a zero cost, each given as the list of
+    /// its nodes. Such cycles make the shortest paths ambiguous.
+    pub zero_weight_cycles: Vec<Vec<N>>,
+}
+
+impl<N, W> ValidationReport<N, W> {
+    /// Whether nothing odd was found in the graph.
+    pub fn is_valid(&self) -> bool {
+        self.dangling_edges.is_empty()
+            && self.self_loops.is_empty()
+            && self.duplicate_edges.is_empty()
+            && self.negative_edges.is_empty()
+            && self.zero_weight_cycles.is_empty()
+    }
+}
+
+/// Checks the consistency of `graph` and reports everything odd found in it.
+///
+/// The whole graph is scanned, so this takes time linear in its size.
+pub fn validate<G: GraphLike>(graph: &G) -> ValidationReport<G::Node, G::Weight> {
+    let mut report = ValidationReport {
+        dangling_edges: Vec::new(),
+        self_loops: Vec::new(),
+        duplicate_edges: Vec::new(),
+        negative_edges: Vec::new(),
+        zero_weight_cycles: Vec::new(),
+    };
+
+    // We first look at the edges one by one.
+    for source in graph.nodes() {
+        // Used to find the parallel edges leaving the current node. Each pair
+        // is only reported once, however many edges connect it.
+        let mut destinations = HashMap::default();
+
+        for (destination, cost) in graph.neighbors(source) {
+            if !graph.contains_node(destination) {
+                report
+                    .dangling_edges
+                    .push((source.clone(), destination.clone(), cost));
+            }
+            if source == destination {
+                report.self_loops.push((source.clone(), cost));
+            }
+            if cost < G::Weight::zero() {
+                report
+                    .negative_edges
+                    .push((source.clone(), destination.clone(), cost));
+            }
+
+            let count = destinations.entry(destination).or_insert(0);
+            *count += 1;
+            if *count == 2 {
+                report
+                    .duplicate_edges
+                    .push((source.clone(), destination.clone()));
+            }
+        }
+    }
+
+    // The cycles need a look at the whole graph.
+    report.zero_weight_cycles = zero_weight_cycles(graph);
+    report
+}
+
+// Finds cycles made of zero cost edges with a depth first search over these
+// edges only. Every edge leading back to a node which is still being explored
+// closes a cycle, which is read from the stack of the search.
+fn zero_weight_cycles<G: GraphLike>(graph: &G) -> Vec<Vec<G::Node>> {
+    let mut cycles = Vec::new();
+    // The nodes whose exploration is complete.
+    let mut done = HashSet::default();
+
+    for root in graph.nodes() {
+        if done.contains(root) {
+            continue;
+        }
+
+        // The nodes being explored, along with the zero cost edges left to
+        // follow from them, and the position of every node in the stack.
+        let mut stack = vec![(root, zero_neighbors(graph, root))];
+        let mut position = HashMap::default();
+        position.insert(root, 0);
+
+        while let Some((node, neighbors)) = stack.last_mut() {
+            let node = *node;
+            match neighbors.next() {
+                // A node on the stack closes a cycle, which goes from that node
+                // to the current one.
+                Some(neighbor) if position.contains_key(neighbor) => {
+                    let start = position[neighbor];
+                    cycles.push(
+                        stack[start..]
+                            .iter()
+                            .map(|(node, _)| (*node).clone())
+                            .collect(),
+                    );
+                }
+                // The cycles going through a completed node were already found.
+                Some(neighbor) if done.contains(neighbor) => {}
+                // Otherwise we explore the neighbor.
+                Some(neighbor) => {
+                    position.insert(neighbor, stack.len());
+                    stack.push((neighbor, zero_neighbors(graph, neighbor)));
+                }
+                // Once all its edges have been followed, the node is complete.
+                None => {
+                    position.remove(node);
+                    done.insert(node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+    cycles
+}
+
+// Iterates over the nodes reached from node through a zero cost edge.
+fn zero_neighbors<'a, G: GraphLike>(
+    graph: &'a G,
+    node: &'a G::Node,
+) -> impl Iterator<Item = &'a G::Node> + 'a {
+    graph
+        .neighbors(node)
+        .filter(|(_, cost)| *cost == G::Weight::zero())
+        .map(|(neighbor, _)| neighbor)
+}
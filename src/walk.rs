@@ -0,0 +1,107 @@
+// Used to turn the numbers drawn into the edges followed.
+use crate::random::{below, fraction};
+// The graph being walked through.
+use crate::{GraphLike, PathError, Weight};
+
+/// Walks through `graph` from `start` for `steps` edges picked at random, and
+/// returns the nodes visited in order, starting with `start`.
+///
+/// At every step, the walk follows one of the edges leaving its node, all of
+/// them with the same probability. The walk stops early on the nodes without
+/// any edge, so that it visits at most `steps + 1` nodes. A node is visited
+/// again every time the walk comes back to it.
+///
+/// `rng` draws the numbers picking the edges, uniformly among all the `u64`,
+/// one per step. It can wrap any generator, e.g. `|| generator.next_u64()`
+/// with one of the `rand` crate, and the same numbers always give the same
+/// walk. The query fails with [`PathError::UnknownSource`] if `start` is not
+/// part of the graph.
+pub fn random_walk<G, R>(
+    graph: &G,
+    start: &G::Node,
+    steps: usize,
+    rng: R,
+) -> Result<Vec<G::Node>, PathError>
+where
+    G: GraphLike,
+    R: FnMut() -> u64,
+{
+    walk(graph, start, steps, false, rng)
+}
+
+/// Walks through `graph` from `start` like [`random_walk`], except that the
+/// probability of an edge is proportional to its cost, as for a Markov chain
+/// whose costs are the transition weights.
+///
+/// The edges of a node whose costs are all zero are followed with the same
+/// probability. The edges whose cost is zero are never followed otherwise.
+///
+/// # Panics
+///
+/// Panics if a cost of an edge followed from a node cannot be converted, see
+/// [`Weight::to_f64`].
+pub fn weighted_random_walk<G, R>(
+    graph: &G,
+    start: &G::Node,
+    steps: usize,
+    rng: R,
+) -> Result<Vec<G::Node>, PathError>
+where
+    G: GraphLike,
+    R: FnMut() -> u64,
+{
+    walk(graph, start, steps, true, rng)
+}
+
+// Walks through the graph, following the edges with a probability
+// proportional to their costs if weighted is set.
+fn walk<G, R>(
+    graph: &G,
+    start: &G::Node,
+    steps: usize,
+    weighted: bool,
+    mut rng: R,
+) -> Result<Vec<G::Node>, PathError>
+where
+    G: GraphLike,
+    R: FnMut() -> u64,
+{
+    let mut node = graph.get_node(start).ok_or(PathError::UnknownSource)?;
+    let mut walk = vec![node.clone()];
+    // The edges leaving the current node, along with the running total of
+    // their weights, reused across the steps.
+    let mut edges = Vec::new();
+
+    for _ in 0..steps {
+        edges.clear();
+        let mut total = 0.0;
+        // We only follow the edges to the nodes of the graph, working with
+        // the copies stored in it.
+        for (neighbor, cost) in graph.neighbors(node) {
+            if let Some(neighbor) = graph.get_node(neighbor) {
+                if weighted {
+                    total += cost
+                        .to_f64()
+                        .expect("the costs must be convertible to be used as weights");
+                }
+                edges.push((neighbor, total));
+            }
+        }
+        if edges.is_empty() {
+            break;
+        }
+
+        node = if weighted && total > 0.0 {
+            // We pick the first edge whose running total goes past the drawn
+            // number, which is never an edge whose weight is zero.
+            let drawn = fraction(rng()) * total;
+            let position = edges.partition_point(|&(_, running)| running <= drawn);
+            edges[position.min(edges.len() - 1)].0
+        } else {
+            edges[below(rng(), edges.len())].0
+        };
+        walk.push(node.clone());
+    }
+
+    Ok(walk)
+}
@@ -0,0 +1,283 @@
+// Used to go through the stops of a route.
+use std::iter;
+
+// The graph being explored, and the search run for every leg.
+use crate::{GraphLike, OverflowPolicy, PathError, PathResult, SearchWorkspace, Weight};
+
+/// Finds the shortest path from `source` to `destination` in `graph` going
+/// through all the stops of `via`, in this order.
+///
+/// Every leg between two consecutive stops is the shortest path between them,
+/// found with a single reusable [`SearchWorkspace`]. The legs are then
+/// stitched into a single path, whose cost is the sum of their costs and
+/// whose statistics add up those of the searches. A stop may be given several
+/// times, and the path may go through the same node several times.
+///
+/// The query fails with [`PathError::UnknownSource`] if the source is not part
+/// of the graph, with [`PathError::UnknownDestination`] if the destination or
+/// one of the stops is not, and with [`PathError::NoPath`] if one of the legs
+/// has no path.
+pub fn shortest_path_via<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    via: &[G::Node],
+    destination: &G::Node,
+) -> PathResult<G::Node, G::Weight> {
+    // An unknown stop would otherwise be reported as the source of its leg.
+    if !graph.contains_node(source) {
+        return Err(PathError::UnknownSource);
+    }
+
+    let mut workspace = SearchWorkspace::new();
+    let stops: Vec<&G::Node> = iter::once(source)
+        .chain(via)
+        .chain(iter::once(destination))
+        .collect();
+    let legs = stops.windows(2).map(|leg| {
+        workspace
+            .dijkstra(graph, leg[0], leg[1])
+            .map_err(|error| match error {
+                PathError::UnknownSource => PathError::UnknownDestination,
+                error => error,
+            })
+    });
+    stitch(legs)
+}
+
+// Stitches consecutive legs into a single path, every leg starting where the
+// previous one ends. The statistics are only kept if every leg has them.
+pub(crate) fn stitch<N, W: Weight, I>(legs: I) -> PathResult<N, W>
+where
+    I: IntoIterator<Item = PathResult<N, W>>,
+{
+    let policy = OverflowPolicy::default();
+    let mut legs = legs.into_iter();
+    let mut route = legs.next().ok_or(PathError::NoPath)??;
+
+    for leg in legs {
+        let leg = leg?;
+        route.cost = policy
+            .add(route.cost, leg.cost)
+            .ok_or(PathError::Overflow)?;
+        route.settled = route.settled.zip(leg.settled).map(|(a, b)| a + b);
+        route.stats = route.stats.zip(leg.stats).map(|(a, b)| a + b);
+        // The first node of the leg is the last node of the route.
+        route.nodes.extend(leg.nodes.into_iter().skip(1));
+    }
+    Ok(route)
+}
+
+/// The largest number of waypoints for which [`shortest_path_through`] finds
+/// the best order exactly.
+///
+/// The exact search takes O(2ⁿ·n²) time for n waypoints, which is instant up
+/// to this number but quickly becomes out of reach beyond it.
+pub const EXACT_WAYPOINTS: usize = 10;
+
+/// Finds a short path from `source` to `destination` in `graph` going through
+/// all the `waypoints`, in any order.
+///
+/// The shortest path between every pair of stops is first found with a
+/// search from the source and from every waypoint, each one stopping once it
+/// reached all the stops, see [`dijkstra_to_many`](crate::dijkstra_to_many).
+/// The order of the waypoints is then picked from the costs of these legs,
+/// which is a small instance of the traveling salesman problem:
+///
+/// - Up to [`EXACT_WAYPOINTS`] waypoints, the cheapest order is found by
+///   dynamic programming over the subsets of waypoints.
+/// - Beyond, the route goes to the nearest waypoint not visited yet, and is
+///   then improved by reversing parts of it as long as this makes it
+///   cheaper. The result is usually close to the cheapest order, but may not
+///   be it.
+///
+/// The legs are then stitched into a single path like with
+/// [`shortest_path_via`], which carries no statistics. The errors are the
+/// same as with [`shortest_path_via`], the query failing with
+/// [`PathError::NoPath`] if no order of the waypoints has a path.
+pub fn shortest_path_through<G: GraphLike>(
+    graph: &G,
+    source: &G::Node,
+    waypoints: &[G::Node],
+    destination: &G::Node,
+) -> PathResult<G::Node, G::Weight> {
+    if !graph.contains_node(source) {
+        return Err(PathError::UnknownSource);
+    }
+    if !waypoints
+        .iter()
+        .chain(iter::once(destination))
+        .all(|stop| graph.contains_node(stop))
+    {
+        return Err(PathError::UnknownDestination);
+    }
+
+    // The legs from the source and from every waypoint to every waypoint and
+    // to the destination. The waypoint i starts the legs i + 1, and ends the
+    // legs i of every start.
+    let mut workspace = SearchWorkspace::new();
+    let targets: Vec<G::Node> = waypoints
+        .iter()
+        .chain(iter::once(destination))
+        .cloned()
+        .collect();
+    let legs: Vec<Vec<PathResult<G::Node, G::Weight>>> = iter::once(source)
+        .chain(waypoints)
+        .map(|start| workspace.dijkstra_to_many(graph, start, &targets))
+        .collect();
+    let cost = |start: usize, end: usize| legs[start][end].as_ref().ok().map(|leg| leg.cost);
+
+    let order = if waypoints.len() <= EXACT_WAYPOINTS {
+        exact_order(waypoints.len(), cost)
+    } else {
+        improved_order(waypoints.len(), cost)
+    }
+    .ok_or(PathError::NoPath)?;
+
+    // We follow the legs in the chosen order, the last one leading to the
+    // destination.
+    let mut start = 0;
+    let mut route = stitch(
+        order
+            .into_iter()
+            .chain(iter::once(waypoints.len()))
+            .map(|end| {
+                let leg = legs[start][end].clone();
+                start = end + 1;
+                leg
+            }),
+    )?;
+    route.settled = None;
+    route.stats = None;
+    Ok(route)
+}
+
+// The order of the waypoints minimizing the cost of the route, found by
+// dynamic programming, or None if no order has a path. cost(start, end) gives
+// the cost of the leg from a start to an end, the start 0 being the source
+// and the end n being the destination.
+fn exact_order<W, C>(n: usize, cost: C) -> Option<Vec<usize>>
+where
+    W: Weight,
+    C: Fn(usize, usize) -> Option<W>,
+{
+    // Without any waypoint, the route is the leg to the destination.
+    if n == 0 {
+        return cost(0, 0).map(|_| Vec::new());
+    }
+
+    let policy = OverflowPolicy::default();
+    // best[visited][last] is the cost of the cheapest route from the source
+    // through the waypoints of the visited set, ending with the last one,
+    // along with the waypoint visited just before it.
+    let mut best = vec![vec![None; n]; 1 << n];
+    for first in 0..n {
+        best[1 << first][first] = cost(0, first).map(|cost| (cost, None));
+    }
+
+    for visited in 1..1usize << n {
+        for last in 0..n {
+            let (so_far, _) = match best[visited][last] {
+                Some(entry) => entry,
+                None => continue,
+            };
+            for next in (0..n).filter(|next| visited & (1 << next) == 0) {
+                let total = match cost(last + 1, next).and_then(|leg| policy.add(so_far, leg)) {
+                    Some(total) => total,
+                    None => continue,
+                };
+                let cell = &mut best[visited | (1 << next)][next];
+                if cell.is_none_or(|(old, _)| old > total) {
+                    *cell = Some((total, Some(last)));
+                }
+            }
+        }
+    }
+
+    // The route ends with the leg from the last waypoint to the destination.
+    let all = (1 << n) - 1;
+    let (_, last) = (0..n)
+        .filter_map(|last| {
+            let (so_far, _) = best[all][last]?;
+            let total = policy.add(so_far, cost(last + 1, n)?)?;
+            Some((total, last))
+        })
+        .min()?;
+
+    // We walk the previous waypoints back to the first one.
+    let mut order = vec![last];
+    let mut visited = all;
+    let mut location = last;
+    while let Some((_, Some(previous))) = best[visited][location] {
+        visited &= !(1 << location);
+        location = previous;
+        order.push(location);
+    }
+    order.reverse();
+    Some(order)
+}
+
+// A good order of the waypoints, found by going to the nearest waypoint not
+// visited yet and then reversing parts of the route while it gets cheaper.
+// Returns None if no path was found. The cost of the legs is given like for
+// exact_order.
+fn improved_order<W, C>(n: usize, cost: C) -> Option<Vec<usize>>
+where
+    W: Weight,
+    C: Fn(usize, usize) -> Option<W>,
+{
+    let policy = OverflowPolicy::default();
+    let total = |order: &[usize]| -> Option<W> {
+        let mut start = 0;
+        let mut total = W::zero();
+        for &end in order.iter().chain(iter::once(&n)) {
+            total = policy.add(total, cost(start, end)?)?;
+            start = end + 1;
+        }
+        Some(total)
+    };
+    // A route without any path is worse than all the others.
+    let cheaper = |new: Option<W>, old: Option<W>| match (new, old) {
+        (Some(new), Some(old)) => new < old,
+        (Some(_), None) => true,
+        (None, _) => false,
+    };
+
+    // We go to the nearest waypoint every time, the unreachable ones being
+    // left for the end.
+    let mut order = Vec::with_capacity(n);
+    let mut left: Vec<usize> = (0..n).collect();
+    let mut start = 0;
+    while !left.is_empty() {
+        let nearest = (0..left.len())
+            .min_by_key(|&position| match cost(start, left[position]) {
+                Some(cost) => (false, Some(cost)),
+                None => (true, None),
+            })
+            .expect("a waypoint is left");
+        let next = left.swap_remove(nearest);
+        order.push(next);
+        start = next + 1;
+    }
+
+    // We then reverse every part of the route whose reversal makes it
+    // cheaper, until no reversal does.
+    let mut best = total(&order);
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for first in 0..n {
+            for last in first + 1..n {
+                order[first..=last].reverse();
+                let candidate = total(&order);
+                if cheaper(candidate, best) {
+                    best = candidate;
+                    improved = true;
+                } else {
+                    order[first..=last].reverse();
+                }
+            }
+        }
+    }
+
+    best.map(|_| order)
+}
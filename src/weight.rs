@@ -0,0 +1,314 @@
+// Used to compare and hash the floating point costs.
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+// Used to convert the floating point costs from and to the primitive types.
+use std::convert::TryFrom;
+use std::fmt;
+// Used to accumulate the costs along a path, and to reweight them.
+use std::ops::{Add, Sub};
+
+// The error returned by invalid floating point costs.
+use crate::InvalidWeight;
+
+/// The cost of an edge of the graph.
+///
+/// The costs along a path are added together, and the resulting sums are
+/// compared to find the shortest one. The cost of an empty path, i.e. the
+/// cost of going from a node to itself, is `zero`.
+///
+/// The trait is implemented for all the primitive integer types, and for the
+/// floating point types through [`FloatWeight`]. It can be implemented for
+/// other wrapper types as well.
+pub trait Weight: Copy + Ord + Add<Output = Self> {
+    /// The cost of an empty path.
+    fn zero() -> Self;
+
+    /// Adds two costs, or returns `None` if the sum cannot be represented.
+    ///
+    /// The default implementation assumes that the sum never overflows.
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    /// Adds two costs, clamping the sum to the largest representable cost.
+    ///
+    /// The default implementation assumes that the sum never overflows.
+    fn saturating_add(self, other: Self) -> Self {
+        self + other
+    }
+
+    /// Subtracts a cost from another one, or returns `None` if the difference
+    /// cannot be represented. This is used by the algorithms which reweight
+    /// the costs, such as [`johnson`](crate::johnson).
+    ///
+    /// The default implementation assumes that the difference never
+    /// overflows.
+    fn checked_sub(self, other: Self) -> Option<Self>
+    where
+        Self: Sub<Output = Self>,
+    {
+        Some(self - other)
+    }
+
+    /// Subtracts a cost from another one, clamping the difference to the
+    /// representable costs.
+    ///
+    /// The default implementation assumes that the difference never
+    /// overflows.
+    fn saturating_sub(self, other: Self) -> Self
+    where
+        Self: Sub<Output = Self>,
+    {
+        self - other
+    }
+
+    /// Converts the cost to a `usize`, if it is a non negative integer which
+    /// fits in one. This is used by [`dijkstra_bucket`](crate::dijkstra_bucket)
+    /// to index its buckets.
+    ///
+    /// The default implementation returns `None`.
+    fn to_usize(self) -> Option<usize> {
+        None
+    }
+
+    /// Converts the cost to a `f64`, possibly losing precision. This is used
+    /// by the measures averaging the costs, such as
+    /// [`closeness_centrality`](crate::closeness_centrality).
+    ///
+    /// The default implementation converts the cost returned by
+    /// [`Weight::to_usize`], if any.
+    fn to_f64(self) -> Option<f64> {
+        self.to_usize().map(|cost| cost as f64)
+    }
+}
+
+/// How the algorithms handle a path whose cost overflows its [`Weight`] type.
+///
+/// The plain `+` operator would silently wrap around in release builds, and
+/// turn a very long path into a very short one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OverflowPolicy {
+    /// The query fails with [`PathError::Overflow`](crate::PathError::Overflow).
+    #[default]
+    Checked,
+    /// The cost is clamped to the largest representable cost, so that the
+    /// overflowing paths are considered longer than all the others.
+    Saturating,
+}
+
+impl OverflowPolicy {
+    // Adds two costs according to the policy, None meaning that the sum
+    // overflowed with the Checked policy.
+    pub(crate) fn add<W: Weight>(self, first: W, second: W) -> Option<W> {
+        match self {
+            OverflowPolicy::Checked => first.checked_add(second),
+            OverflowPolicy::Saturating => Some(first.saturating_add(second)),
+        }
+    }
+
+    // Subtracts a cost from another one according to the policy, None
+    // meaning that the difference overflowed with the Checked policy.
+    pub(crate) fn sub<W: Weight + Sub<Output = W>>(self, first: W, second: W) -> Option<W> {
+        match self {
+            OverflowPolicy::Checked => first.checked_sub(second),
+            OverflowPolicy::Saturating => Some(first.saturating_sub(second)),
+        }
+    }
+}
+
+// All the primitive integer types share the same implementation.
+macro_rules! impl_weight {
+    ($($integer:ty),*) => {
+        $(
+            impl Weight for $integer {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$integer>::checked_add(self, other)
+                }
+
+                fn saturating_add(self, other: Self) -> Self {
+                    <$integer>::saturating_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$integer>::checked_sub(self, other)
+                }
+
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$integer>::saturating_sub(self, other)
+                }
+
+                fn to_usize(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+
+                fn to_f64(self) -> Option<f64> {
+                    Some(self as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_weight!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A floating point cost, which is guaranteed to be neither NaN nor negative.
+///
+/// The primitive floating point types cannot implement [`Weight`], since NaN
+/// prevents them from being totally ordered. This wrapper checks its value
+/// when it is built instead, which also rules out the negative costs which
+/// would break the invariants of Dijkstra's algorithm. It is available for
+/// both `f64` and `f32`.
+///
+/// Infinity is accepted, and behaves like the cost of an edge which is never
+/// worth following. A negative zero is stored as a positive zero.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FloatWeight<F = f64>(F);
+
+/// The primitive floating point types which can be wrapped in a
+/// [`FloatWeight`], i.e. `f64` and `f32`.
+///
+/// This trait is sealed, and cannot be implemented outside of this crate.
+pub trait Float: Copy + PartialOrd + Add<Output = Self> + sealed::Sealed {
+    /// The positive zero.
+    const ZERO: Self;
+
+    /// Whether the value is NaN.
+    fn is_nan(self) -> bool;
+
+    /// The total ordering of the floats, see `f64::total_cmp`.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// The memory representation of the value, used to hash it.
+    fn to_bits(self) -> u64;
+
+    /// The value as a `f64`.
+    fn to_f64(self) -> f64;
+}
+
+// Prevents the implementation of Float for other types.
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+// Both floating point types share the same implementation.
+macro_rules! impl_float {
+    ($($float:ty),*) => {
+        $(
+            impl Float for $float {
+                const ZERO: Self = 0.0;
+
+                fn is_nan(self) -> bool {
+                    <$float>::is_nan(self)
+                }
+
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$float>::total_cmp(self, other)
+                }
+
+                fn to_bits(self) -> u64 {
+                    <$float>::to_bits(self).into()
+                }
+
+                fn to_f64(self) -> f64 {
+                    self.into()
+                }
+            }
+
+            impl TryFrom<$float> for FloatWeight<$float> {
+                type Error = InvalidWeight;
+
+                fn try_from(value: $float) -> Result<Self, InvalidWeight> {
+                    FloatWeight::new(value)
+                }
+            }
+
+            impl From<FloatWeight<$float>> for $float {
+                fn from(weight: FloatWeight<$float>) -> Self {
+                    weight.0
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+impl<F: Float> FloatWeight<F> {
+    /// Wraps `value`, or returns an error if it is NaN or negative.
+    pub fn new(value: F) -> Result<Self, InvalidWeight> {
+        if value.is_nan() {
+            Err(InvalidWeight::NaN)
+        } else if value < F::ZERO {
+            Err(InvalidWeight::Negative)
+        } else {
+            // Adding a positive zero turns a negative zero into a positive one,
+            // which keeps the ordering and the hashes consistent with the
+            // equality.
+            Ok(FloatWeight(value + F::ZERO))
+        }
+    }
+
+    /// Returns the wrapped value.
+    pub fn get(self) -> F {
+        self.0
+    }
+}
+
+impl<F: Float> Weight for FloatWeight<F> {
+    fn zero() -> Self {
+        FloatWeight(F::ZERO)
+    }
+
+    fn to_f64(self) -> Option<f64> {
+        Some(self.0.to_f64())
+    }
+}
+
+// The sum of two costs which are neither NaN nor negative is neither NaN nor
+// negative either, so no check is needed.
+impl<F: Float> Add for FloatWeight<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        FloatWeight(self.0 + other.0)
+    }
+}
+
+// NaN being ruled out, the usual ordering of the floats is total.
+impl<F: Float> PartialEq for FloatWeight<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: Float> Eq for FloatWeight<F> {}
+
+impl<F: Float> PartialOrd for FloatWeight<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: Float> Ord for FloatWeight<F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<F: Float> Hash for FloatWeight<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl<F: Float + fmt::Display> fmt::Display for FloatWeight<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
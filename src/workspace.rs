@@ -0,0 +1,367 @@
+// Used to keep track of the progression of the search.
+use crate::hash::{HashMap, HashSet};
+// The graph being explored, and the search itself.
+use crate::{
+    dijkstra_backtrack, BinaryHeapFrontier, Frontier, GraphLike, OverflowPolicy, PathError,
+    PathResult, SearchStats, ShortestPathResult, Weight,
+};
+
+/// The state of a search, which can be reused across queries.
+///
+/// Every call to [`dijkstra`](crate::dijkstra) allocates the maps holding the
+/// progression of the search, and frees them once the path is found. A
+/// workspace keeps these maps around instead: they are cleared at the start
+/// of every query, but keep their capacity, so that a server answering many
+/// routing requests doesn't allocate for every one of them.
+///
+/// The workspace holds references to the nodes of the graphs it explores,
+/// hence the lifetime `'g`. It can be used with any number of graphs, as long
+/// as they all outlive it.
+///
+/// The nodes to settle are picked by a [`Frontier`], a binary heap by default.
+/// Another one can be given with [`SearchWorkspace::with_frontier`].
+#[derive(Debug)]
+pub struct SearchWorkspace<
+    'g,
+    G: GraphLike,
+    F = BinaryHeapFrontier<'g, <G as GraphLike>::Node, <G as GraphLike>::Weight>,
+> {
+    // The best known cost of every node reached so far.
+    progression: HashMap<&'g G::Node, G::Weight>,
+    // The nodes reached so far, the cheapest one first.
+    queue: F,
+    // The node every node was reached from, used to backtrack the path.
+    origin: HashMap<&'g G::Node, &'g G::Node>,
+    // The nodes already settled.
+    visited: HashSet<&'g G::Node>,
+    // What the current search did so far.
+    stats: SearchStats,
+}
+
+impl<'g, G: GraphLike> SearchWorkspace<'g, G> {
+    /// Creates an empty workspace, whose frontier is a binary heap.
+    pub fn new() -> Self {
+        SearchWorkspace::with_frontier(BinaryHeapFrontier::new())
+    }
+}
+
+impl<'g, G: GraphLike, F: Frontier<'g, G::Node, G::Weight>> SearchWorkspace<'g, G, F> {
+    /// Creates an empty workspace picking the nodes to settle with the given
+    /// frontier, which is cleared.
+    pub fn with_frontier(mut frontier: F) -> Self {
+        frontier.clear();
+        SearchWorkspace {
+            progression: HashMap::default(),
+            queue: frontier,
+            origin: HashMap::default(),
+            visited: HashSet::default(),
+            stats: SearchStats::default(),
+        }
+    }
+
+    /// Finds the shortest path from `source` to `destination` in `graph`,
+    /// just like [`dijkstra`](crate::dijkstra) would.
+    pub fn dijkstra(
+        &mut self,
+        graph: &'g G,
+        source: &G::Node,
+        destination: &G::Node,
+    ) -> Result<ShortestPathResult<G::Node, G::Weight>, PathError> {
+        self.dijkstra_with_policy(graph, source, destination, OverflowPolicy::default())
+    }
+
+    /// Finds the shortest path from `source` to `destination` in `graph`,
+    /// just like [`dijkstra_with_policy`](crate::dijkstra_with_policy) would.
+    pub fn dijkstra_with_policy(
+        &mut self,
+        graph: &'g G,
+        source: &G::Node,
+        destination: &G::Node,
+        policy: OverflowPolicy,
+    ) -> Result<ShortestPathResult<G::Node, G::Weight>, PathError> {
+        self.path(graph, source, destination, policy, None, |_| {
+            G::Weight::zero()
+        })
+    }
+
+    /// Finds the shortest path from `source` to `destination` in `graph`,
+    /// just like [`dijkstra_bounded`](crate::dijkstra_bounded) would.
+    pub fn dijkstra_bounded(
+        &mut self,
+        graph: &'g G,
+        source: &G::Node,
+        destination: &G::Node,
+        max_cost: G::Weight,
+    ) -> Result<ShortestPathResult<G::Node, G::Weight>, PathError> {
+        self.path(
+            graph,
+            source,
+            destination,
+            OverflowPolicy::default(),
+            Some(max_cost),
+            |_| G::Weight::zero(),
+        )
+    }
+
+    /// Finds the shortest path from `source` to `destination` in `graph`
+    /// guided by `heuristic`, just like [`astar`](crate::astar) would.
+    pub fn astar<H>(
+        &mut self,
+        graph: &'g G,
+        source: &G::Node,
+        destination: &G::Node,
+        heuristic: H,
+    ) -> PathResult<G::Node, G::Weight>
+    where
+        H: FnMut(&G::Node) -> G::Weight,
+    {
+        self.path(
+            graph,
+            source,
+            destination,
+            OverflowPolicy::default(),
+            None,
+            heuristic,
+        )
+    }
+
+    /// Empties the workspace, keeping the memory it allocated.
+    pub fn clear(&mut self) {
+        self.progression.clear();
+        self.queue.clear();
+        self.origin.clear();
+        self.visited.clear();
+        self.stats = SearchStats::default();
+    }
+
+    /// How much of the graph the last search explored.
+    ///
+    /// The statistics are those returned with the path, and are kept until
+    /// the next query, including when the last one failed.
+    pub fn stats(&self) -> SearchStats {
+        self.stats
+    }
+
+    // Finds the shortest path from source to destination guided by the
+    // heuristic, ignoring the paths costing more than max_cost if it is given.
+    fn path<H>(
+        &mut self,
+        graph: &'g G,
+        source: &G::Node,
+        destination: &G::Node,
+        policy: OverflowPolicy,
+        max_cost: Option<G::Weight>,
+        heuristic: H,
+    ) -> PathResult<G::Node, G::Weight>
+    where
+        H: FnMut(&G::Node) -> G::Weight,
+    {
+        // There is no point in exploring the graph if we already know that the
+        // destination isn't in it.
+        if !graph.contains_node(destination) {
+            return Err(PathError::UnknownDestination);
+        }
+        let source = self.start(graph, source)?;
+
+        // The total cost of the path is the final progression of the
+        // destination. If the progression stopped before reaching the
+        // destination, both nodes are simply not connected.
+        let (destination, cost) = self
+            .guided_progress(graph, policy, max_cost, heuristic, |node| {
+                node == destination
+            })?
+            .ok_or(PathError::NoPath)?;
+
+        // We backtrack to find the path used to reach the destination.
+        let path =
+            dijkstra_backtrack(&self.origin, source, destination).ok_or(PathError::NoPath)?;
+
+        // We return the path found in the right order. All the nodes we have
+        // visited have been settled, and so has the destination.
+        Ok(ShortestPathResult {
+            nodes: path.into_iter().cloned().collect(),
+            cost,
+            settled: Some(self.visited.len() + 1),
+            stats: Some(self.stats),
+        })
+    }
+
+    // Clears the workspace and queues the source, returning the copy of the
+    // source stored in the graph. All the nodes we handle then share the
+    // lifetime of the graph.
+    pub(crate) fn start(
+        &mut self,
+        graph: &'g G,
+        source: &G::Node,
+    ) -> Result<&'g G::Node, PathError> {
+        // A source which is not part of the graph cannot lead anywhere.
+        let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+
+        // We forget about the previous query, keeping the allocated memory.
+        self.clear();
+
+        // We initialize the progression of the algorithm.
+        self.progression.insert(source, G::Weight::zero());
+        self.queue.push(source, G::Weight::zero());
+        self.stats.pushes += 1;
+        Ok(source)
+    }
+
+    // Clears the workspace and queues all the sources, each one starting with
+    // the cost of its offset. The lowest offset is kept for the sources given
+    // several times.
+    pub(crate) fn start_many<'s, I>(&mut self, graph: &'g G, sources: I) -> Result<(), PathError>
+    where
+        I: IntoIterator<Item = (&'s G::Node, G::Weight)>,
+        G::Node: 's,
+    {
+        self.clear();
+
+        for (source, offset) in sources {
+            let source = graph.get_node(source).ok_or(PathError::UnknownSource)?;
+            if self.progression.get(source).is_none_or(|old| *old > offset) {
+                self.progression.insert(source, offset);
+                self.queue.push(source, offset);
+                self.stats.pushes += 1;
+            }
+        }
+        Ok(())
+    }
+
+    // Progression loop of Dijkstra's algorithm, settling the nodes one after
+    // the other until stop returns true for the node about to be settled. This
+    // node is returned along with the cost of the shortest path leading to it,
+    // or None if every reachable node was settled. The paths costing more than
+    // max_cost, if given, are ignored.
+    pub(crate) fn progress<S>(
+        &mut self,
+        graph: &'g G,
+        policy: OverflowPolicy,
+        max_cost: Option<G::Weight>,
+        stop: S,
+    ) -> Result<Settled<'g, G>, PathError>
+    where
+        S: FnMut(&'g G::Node) -> bool,
+    {
+        // Dijkstra's algorithm is A* without any estimate.
+        self.guided_progress(graph, policy, max_cost, |_| G::Weight::zero(), stop)
+    }
+
+    // Progression loop of A*, which is the one of Dijkstra's algorithm except
+    // that the nodes are settled in the order of the cost of their path plus
+    // the estimate of the heuristic. A node settled before its shortest path
+    // was found is settled again once it is, so that the paths are still the
+    // shortest ones with the admissible heuristics which are not consistent.
+    pub(crate) fn guided_progress<H, S>(
+        &mut self,
+        graph: &'g G,
+        policy: OverflowPolicy,
+        max_cost: Option<G::Weight>,
+        mut heuristic: H,
+        mut stop: S,
+    ) -> Result<Settled<'g, G>, PathError>
+    where
+        H: FnMut(&'g G::Node) -> G::Weight,
+        S: FnMut(&'g G::Node) -> bool,
+    {
+        // Every iteration settles a single node, so the memory used by the loop
+        // doesn't depend on the length of the paths.
+        loop {
+            // We start by getting the next node according to the progression.
+            // If there is none, every reachable node has been settled.
+            let next_node = match self.next_node() {
+                Some((next_node, _)) => next_node,
+                None => return Ok(None),
+            };
+            // The frontier holds the estimates of the nodes, their actual cost
+            // being their progression.
+            let current_progression = self.progression[next_node];
+
+            if stop(next_node) {
+                // Success condition.
+                return Ok(Some((next_node, current_progression)));
+            }
+
+            // We get all the nodes adjacent to this one in the graph. If the
+            // node doesn't exist in the graph (i.e. it is a terminal node) the
+            // iterator is simply empty.
+            for (node, cost) in graph.neighbors(next_node) {
+                self.stats.relaxations += 1;
+
+                // The cost of the path going through the current node, which
+                // may overflow. A path which overflows is beyond any bound.
+                let new_progression = match policy.add(current_progression, cost) {
+                    Some(new_progression) => new_progression,
+                    None if max_cost.is_some() => continue,
+                    None => return Err(PathError::Overflow),
+                };
+
+                // The nodes beyond the bound are abandoned.
+                if max_cost.is_some_and(|max_cost| new_progression > max_cost) {
+                    continue;
+                }
+
+                // For each adjacent node, we try to update the progression.
+                // If there is no progression for the node (i.e. it had never
+                // been reached), or if we have found a new best path, we update
+                // the origin of the node and its progression.
+                let improved = self
+                    .progression
+                    .get(node)
+                    .is_none_or(|old_progression| *old_progression > new_progression);
+                if improved {
+                    self.origin.insert(node, next_node);
+                    self.progression.insert(node, new_progression);
+                    // The node is queued again with its new estimate, the old
+                    // entry being skipped once it is popped. The estimate only
+                    // orders the nodes, so it simply saturates.
+                    let estimate = new_progression.saturating_add(heuristic(node));
+                    self.queue.push(node, estimate);
+                    self.stats.pushes += 1;
+                    // This only happens with inconsistent heuristics.
+                    self.visited.remove(node);
+                }
+            }
+
+            // We mark the current node as visited.
+            self.visited.insert(next_node);
+        }
+    }
+
+    // The best known cost of every node reached so far, which is final for the
+    // visited nodes.
+    pub(crate) fn progression(&self) -> &HashMap<&'g G::Node, G::Weight> {
+        &self.progression
+    }
+
+    // The node every node was reached from, on the shortest path found so far.
+    pub(crate) fn origin(&self) -> &HashMap<&'g G::Node, &'g G::Node> {
+        &self.origin
+    }
+
+    // Helper function to see which node we should explore next according to
+    // Dijkstra's algorithm, i.e. the cheapest node of the queue which has not
+    // been visited yet. The outdated entries of the visited nodes are dropped
+    // on the way.
+    fn next_node(&mut self) -> Option<(&'g G::Node, G::Weight)> {
+        while let Some((node, cost)) = self.queue.pop() {
+            // The first entry popped for a node is always the cheapest one.
+            if !self.visited.contains(node) {
+                self.stats.settled += 1;
+                return Some((node, cost));
+            }
+        }
+        // The queue is empty, every reachable node has been visited.
+        None
+    }
+}
+
+// The node on which a progression stopped, as stored in the graph, along with
+// the cost of the shortest path leading to it.
+pub(crate) type Settled<'g, G> = Option<(&'g <G as GraphLike>::Node, <G as GraphLike>::Weight)>;
+
+impl<G: GraphLike> Default for SearchWorkspace<'_, G> {
+    fn default() -> Self {
+        SearchWorkspace::new()
+    }
+}
@@ -0,0 +1,124 @@
+// The shortest paths between all the pairs of nodes.
+use dijkstra::{bellman_ford, dijkstra, floyd_warshall, johnson, Graph, PathError};
+
+// A small road network, with a node which can't be reached.
+fn roads() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 4);
+    graph.add_edge("a", "c", 1);
+    graph.add_edge("c", "b", 2);
+    graph.add_edge("b", "d", 1);
+    graph.add_edge("d", "a", 3);
+    graph.add_node("e");
+    graph
+}
+
+// The same network, where going from c to b pays back more than it costs.
+fn refunds() -> Graph<&'static str, i32> {
+    roads().map_weights(|source, destination, cost| {
+        if (*source, *destination) == ("c", "b") {
+            -2
+        } else {
+            cost as i32
+        }
+    })
+}
+
+#[test]
+fn floyd_warshall_agrees_with_dijkstra() {
+    let graph = roads();
+    let matrix = floyd_warshall(&graph).unwrap();
+    assert_eq!(matrix.node_count(), 5);
+
+    for source in matrix.nodes() {
+        for destination in matrix.nodes() {
+            let expected = dijkstra(&graph, source, destination);
+            assert_eq!(
+                matrix.distance(source, destination),
+                expected.as_ref().ok().map(|path| path.cost)
+            );
+            match matrix.shortest_path(source, destination) {
+                Ok(path) => {
+                    let expected = expected.unwrap();
+                    assert_eq!(path.cost, expected.cost);
+                    assert_eq!(path.nodes, expected.nodes);
+                }
+                Err(error) => assert_eq!(Err(error), expected),
+            }
+        }
+    }
+}
+
+#[test]
+fn floyd_warshall_agrees_with_bellman_ford() {
+    let graph = refunds();
+    let matrix = floyd_warshall(&graph).unwrap();
+
+    for source in graph.nodes() {
+        let tree = bellman_ford(&graph, source).unwrap();
+        for destination in graph.nodes() {
+            assert_eq!(
+                matrix.distance(source, destination),
+                tree.distance(destination)
+            );
+        }
+    }
+    assert_eq!(
+        matrix.shortest_path(&"a", &"d").unwrap().nodes,
+        vec!["a", "c", "b", "d"]
+    );
+}
+
+#[test]
+fn floyd_warshall_reports_the_negative_cycles() {
+    let mut graph = refunds();
+    graph.add_edge("b", "c", 1);
+
+    assert_eq!(
+        floyd_warshall(&graph).unwrap_err(),
+        PathError::NegativeCycle
+    );
+}
+
+#[test]
+fn distance_matrix_between_unknown_nodes() {
+    let matrix = floyd_warshall(&roads()).unwrap();
+
+    assert_eq!(matrix.distance(&"a", &"z"), None);
+    assert_eq!(
+        matrix.shortest_path(&"z", &"a"),
+        Err(PathError::UnknownSource)
+    );
+    assert_eq!(
+        matrix.shortest_path(&"a", &"z"),
+        Err(PathError::UnknownDestination)
+    );
+}
+
+#[test]
+fn johnson_agrees_with_floyd_warshall() {
+    for graph in &[roads().map_weights(|_, _, cost| cost as i32), refunds()] {
+        let expected = floyd_warshall(graph).unwrap();
+        let matrix = johnson(graph).unwrap();
+
+        for source in graph.nodes() {
+            for destination in graph.nodes() {
+                assert_eq!(
+                    matrix.distance(source, destination),
+                    expected.distance(source, destination)
+                );
+                if let Ok(path) = matrix.shortest_path(source, destination) {
+                    assert_eq!(Some(path.cost), expected.distance(source, destination));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn johnson_reports_the_negative_cycles() {
+    let mut graph = refunds();
+    graph.add_edge("b", "c", 1);
+
+    assert_eq!(johnson(&graph).unwrap_err(), PathError::NegativeCycle);
+}
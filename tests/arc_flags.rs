@@ -0,0 +1,85 @@
+// The queries pruned by the arc-flags, which must find the same paths as
+// dijkstra.
+use dijkstra::{dijkstra, ArcFlags, Graph, PathError};
+
+// A directed grid of 8 by 8 nodes with uneven costs, some streets being one
+// way only, and a node which leads into it but cannot be reached.
+fn roads() -> Graph<(u32, u32)> {
+    let mut graph: Graph<(u32, u32)> = Graph::new();
+    for x in 0..8 {
+        for y in 0..8 {
+            if x + 1 < 8 {
+                graph.add_edge((x, y), (x + 1, y), 1 + (x + 2 * y) % 5);
+                graph.add_edge((x + 1, y), (x, y), 2 + (x * y) % 4);
+            }
+            if y + 1 < 8 {
+                graph.add_edge((x, y), (x, y + 1), 1 + (3 * x + y) % 4);
+                if x % 3 != 1 {
+                    graph.add_edge((x, y + 1), (x, y), 3);
+                }
+            }
+        }
+    }
+    graph.add_edge((9, 9), (4, 4), 1);
+    graph
+}
+
+// Splits the grid into 4 by 4 squares, the unreachable node being alone in
+// its own region.
+fn squares(&(x, y): &(u32, u32)) -> usize {
+    (x / 4 + 2 * (y / 4)) as usize
+}
+
+// Checks that the arc-flags find paths as cheap as dijkstra between every
+// pair of nodes of the graph.
+fn assert_agrees_with_dijkstra(graph: &Graph<(u32, u32)>, flags: &ArcFlags<(u32, u32), u32>) {
+    for source in graph.nodes() {
+        for destination in graph.nodes() {
+            let path = flags.query(source, destination);
+            let expected = dijkstra(graph, source, destination);
+            assert_eq!(path.map(|path| path.cost), expected.map(|path| path.cost));
+        }
+    }
+}
+
+#[test]
+fn arc_flags_agree_with_dijkstra() {
+    let graph = roads();
+
+    let flags = ArcFlags::new(&graph, squares).unwrap();
+    assert_agrees_with_dijkstra(&graph, &flags);
+    // A single region keeps every edge, and one region per node flags the
+    // edges of the shortest paths to every node.
+    let flags = ArcFlags::new(&graph, |_| 0).unwrap();
+    assert_agrees_with_dijkstra(&graph, &flags);
+    let flags = ArcFlags::new(&graph, |&(x, y)| (x + 10 * y) as usize).unwrap();
+    assert_agrees_with_dijkstra(&graph, &flags);
+}
+
+#[test]
+fn arc_flags_settle_fewer_nodes_than_dijkstra() {
+    let graph = roads();
+    let flags = ArcFlags::new(&graph, squares).unwrap();
+
+    let pruned = flags.query(&(0, 0), &(7, 7)).unwrap();
+    let plain = dijkstra(&graph, &(0, 0), &(7, 7)).unwrap();
+    assert_eq!(pruned.cost, plain.cost);
+    assert_eq!(pruned.nodes.first(), Some(&(0, 0)));
+    assert_eq!(pruned.nodes.last(), Some(&(7, 7)));
+    assert!(pruned.settled.unwrap() <= plain.settled.unwrap());
+}
+
+#[test]
+fn arc_flags_query_errors() {
+    let flags = ArcFlags::new(&roads(), squares).unwrap();
+
+    assert_eq!(flags.query(&(0, 0), &(9, 9)), Err(PathError::NoPath));
+    assert_eq!(
+        flags.query(&(20, 0), &(0, 0)),
+        Err(PathError::UnknownSource)
+    );
+    assert_eq!(
+        flags.query(&(0, 0), &(20, 0)),
+        Err(PathError::UnknownDestination)
+    );
+}
@@ -0,0 +1,48 @@
+// The names stored once, and the identifiers standing for them.
+use dijkstra::{dijkstra, Graph, NameArena, NameId};
+
+#[test]
+fn arena_interns_every_name_once() {
+    let mut arena = NameArena::new();
+    assert!(arena.is_empty());
+
+    let paris = arena.intern("paris");
+    let lyon = arena.intern("lyon");
+    assert_eq!(arena.intern("paris"), paris);
+    assert_ne!(paris, lyon);
+    assert_eq!((paris.index(), lyon.index()), (0, 1));
+    assert_eq!(arena.len(), 2);
+
+    assert_eq!(arena.resolve(lyon), "lyon");
+    assert_eq!(arena.get("lyon"), Some(lyon));
+    assert_eq!(arena.get("nice"), None);
+    // The empty name is a name like any other.
+    let empty = arena.intern("");
+    assert_eq!(arena.resolve(empty), "");
+    assert_eq!(arena.len(), 3);
+}
+
+#[test]
+fn arena_lists_the_names_in_order() {
+    let mut arena = NameArena::with_capacity(3, 12);
+    for name in &["b", "a", "b", "c"] {
+        arena.intern(name);
+    }
+
+    let names: Vec<(usize, &str)> = arena.iter().map(|(id, name)| (id.index(), name)).collect();
+    assert_eq!(names, vec![(0, "b"), (1, "a"), (2, "c")]);
+}
+
+#[test]
+fn graph_over_interned_names() {
+    let mut arena = NameArena::new();
+    let mut graph: Graph<NameId> = Graph::new();
+    for &(source, destination, cost) in &[("a", "b", 1), ("b", "c", 1), ("a", "c", 5)] {
+        graph.add_edge(arena.intern(source), arena.intern(destination), cost);
+    }
+
+    let path = dijkstra(&graph, &arena.intern("a"), &arena.intern("c")).unwrap();
+    let names: Vec<&str> = path.nodes.iter().map(|&id| arena.resolve(id)).collect();
+    assert_eq!(names, vec!["a", "b", "c"]);
+    assert_eq!(path.cost, 2);
+}
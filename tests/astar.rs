@@ -0,0 +1,78 @@
+// The searches guided towards the destination by a heuristic.
+use dijkstra::{astar, dijkstra, Graph, PathError, SearchWorkspace};
+
+// The side of the grid below.
+const SIDE: i32 = 12;
+
+// A grid whose every step costs 1, on which the Manhattan distance is an
+// admissible and consistent heuristic.
+fn grid() -> Graph<(i32, i32)> {
+    let mut graph: Graph<(i32, i32)> = Graph::new_undirected();
+    for x in 0..SIDE {
+        for y in 0..SIDE {
+            if x + 1 < SIDE {
+                graph.add_edge((x, y), (x + 1, y), 1);
+            }
+            if y + 1 < SIDE {
+                graph.add_edge((x, y), (x, y + 1), 1);
+            }
+        }
+    }
+    graph
+}
+
+// The Manhattan distance to the given node.
+fn manhattan(to: (i32, i32)) -> impl FnMut(&(i32, i32)) -> u32 {
+    move |&(x, y)| ((x - to.0).abs() + (y - to.1).abs()) as u32
+}
+
+#[test]
+fn astar_agrees_with_dijkstra() {
+    let graph = grid();
+
+    for &destination in &[(11, 11), (0, 7), (5, 5), (0, 0)] {
+        let path = astar(&graph, &(0, 0), &destination, manhattan(destination)).unwrap();
+        let expected = dijkstra(&graph, &(0, 0), &destination).unwrap();
+        assert_eq!(path.cost, expected.cost);
+        assert_eq!(path.nodes.last(), Some(&destination));
+    }
+}
+
+#[test]
+fn astar_settles_fewer_nodes_than_dijkstra() {
+    let graph = grid();
+    let destination = (11, 0);
+
+    let guided = astar(&graph, &(0, 0), &destination, manhattan(destination)).unwrap();
+    let plain = dijkstra(&graph, &(0, 0), &destination).unwrap();
+    assert!(guided.settled.unwrap() < plain.settled.unwrap());
+}
+
+#[test]
+fn workspace_astar_with_and_without_heuristic() {
+    let graph = grid();
+    let mut workspace = SearchWorkspace::new();
+
+    let path = workspace.astar(&graph, &(3, 4), &(9, 1), |_| 0).unwrap();
+    assert_eq!(path.cost, 9);
+    assert_eq!(path.hops(), 9);
+    // The workspace can be reused for the next query.
+    let path = workspace
+        .astar(&graph, &(9, 1), &(3, 4), manhattan((3, 4)))
+        .unwrap();
+    assert_eq!(path.cost, 9);
+}
+
+#[test]
+fn astar_between_nodes_outside_of_the_graph() {
+    let graph = grid();
+
+    assert_eq!(
+        astar(&graph, &(0, 0), &(20, 20), |_| 0),
+        Err(PathError::UnknownDestination)
+    );
+    assert_eq!(
+        astar(&graph, &(-1, 0), &(0, 0), |_| 0),
+        Err(PathError::UnknownSource)
+    );
+}
@@ -0,0 +1,63 @@
+// The nodes and edges forbidden for a single query.
+use dijkstra::{dijkstra, dijkstra_avoiding, Avoid, Graph, GraphLike, PathError};
+
+// Two routes from a to d, through b or through c, and a direct edge.
+fn routes() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "d", 1);
+    graph.add_edge("a", "c", 2);
+    graph.add_edge("c", "d", 2);
+    graph.add_edge("a", "d", 9);
+    graph
+}
+
+#[test]
+fn avoiding_a_node_takes_the_other_route() {
+    let graph = routes();
+
+    let avoid = Avoid::new().node("b");
+    let path = dijkstra_avoiding(&graph, &"a", &"d", &avoid).unwrap();
+    assert_eq!(path.nodes, vec!["a", "c", "d"]);
+    assert_eq!(path.cost, 4);
+    // The graph itself still has the first route.
+    assert_eq!(dijkstra(&graph, &"a", &"d").unwrap().cost, 2);
+}
+
+#[test]
+fn avoiding_edges_only_forbids_their_direction() {
+    let mut graph = routes();
+    graph.add_edge("d", "b", 1);
+
+    let avoid = Avoid::new().edge("b", "d").edge("c", "d");
+    assert!(avoid.avoids_edge(&"b", &"d"));
+    assert!(!avoid.avoids_edge(&"d", &"b"));
+    assert_eq!(
+        dijkstra_avoiding(&graph, &"a", &"d", &avoid).unwrap().cost,
+        9
+    );
+    assert_eq!(
+        dijkstra_avoiding(&graph, &"d", &"b", &avoid).unwrap().cost,
+        1
+    );
+}
+
+#[test]
+fn avoided_nodes_are_not_part_of_the_view() {
+    let graph = routes();
+    let avoid = Avoid::new().node("c");
+    assert!(avoid.avoids_node(&"c"));
+
+    let view = avoid.apply(&graph);
+    assert_eq!(view.nodes().count(), 3);
+    assert!(view.get_node(&"c").is_none());
+    assert_eq!(view.neighbors(&"a").count(), 2);
+    assert_eq!(
+        dijkstra_avoiding(&graph, &"c", &"d", &avoid),
+        Err(PathError::UnknownSource)
+    );
+    assert_eq!(
+        dijkstra_avoiding(&graph, &"a", &"c", &avoid),
+        Err(PathError::UnknownDestination)
+    );
+}
@@ -0,0 +1,209 @@
+// The other storages of the graphs, which must find the same paths as Graph.
+use dijkstra::{dijkstra, AdjacencyMatrix, CompressedGraph, CsrGraph, Graph, GraphLike};
+
+// A small road network, with a few detours and a node without any edge.
+fn edges() -> Vec<(&'static str, &'static str, u32)> {
+    vec![
+        ("a", "b", 4),
+        ("a", "c", 1),
+        ("c", "b", 2),
+        ("b", "d", 1),
+        ("c", "d", 5),
+        ("d", "e", 3),
+        ("e", "a", 1),
+        ("f", "e", 1),
+    ]
+}
+
+// The graph of the edges, as stored by Graph.
+fn graph() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    for (source, destination, cost) in edges() {
+        graph.add_edge(source, destination, cost);
+    }
+    graph
+}
+
+// The graph of the edges, as stored by AdjacencyMatrix.
+fn matrix() -> AdjacencyMatrix<&'static str> {
+    let mut matrix = AdjacencyMatrix::with_nodes(NODES.iter().copied());
+    for (source, destination, cost) in edges() {
+        matrix.add_edge(source, destination, cost);
+    }
+    matrix
+}
+
+// The nodes of the edges, in a fixed order.
+const NODES: [&str; 6] = ["a", "b", "c", "d", "e", "f"];
+
+#[test]
+fn csr_graph_finds_the_same_paths() {
+    let graph = graph();
+    let csr = CsrGraph::from_edges(edges());
+
+    assert_eq!(csr.node_count(), 6);
+    assert_eq!(csr.edge_count(), 8);
+    for source in &NODES {
+        for destination in &NODES {
+            assert_eq!(
+                dijkstra(&csr, source, destination),
+                dijkstra(&graph, source, destination)
+            );
+        }
+    }
+}
+
+#[test]
+fn csr_shortest_path_agrees_with_dijkstra() {
+    let graph = graph();
+    let csr = CsrGraph::from_graph(&graph);
+
+    for source in &NODES {
+        for destination in &NODES {
+            let expected = dijkstra(&graph, source, destination);
+            let found = csr.shortest_path(source, destination);
+            assert_eq!(
+                found.as_ref().map(|path| path.cost),
+                expected.as_ref().map(|path| path.cost)
+            );
+            if let Ok(path) = found {
+                assert_eq!(path.nodes.first(), Some(source));
+                assert_eq!(path.nodes.last(), Some(destination));
+            }
+        }
+    }
+    assert!(csr.shortest_path(&"z", &"a").is_err());
+}
+
+#[test]
+fn csr_graph_from_a_graph_keeps_the_lonely_nodes() {
+    let mut graph = graph();
+    graph.add_node("g");
+
+    let csr = CsrGraph::from_graph(&graph);
+    assert_eq!(csr.node_count(), 7);
+    assert_eq!(csr.shortest_path(&"g", &"g").unwrap().cost, 0);
+}
+
+#[test]
+fn csr_graph_keeps_the_parallel_edges() {
+    let csr = CsrGraph::from_edges(vec![("a", "b", 5), ("a", "b", 2), ("b", "c", 1)]);
+
+    assert_eq!(csr.edge_count(), 3);
+    assert_eq!(dijkstra(&csr, &"a", &"c").unwrap().cost, 3);
+}
+
+#[test]
+fn adjacency_matrix_finds_the_same_paths() {
+    let graph = graph();
+    let matrix = matrix();
+
+    assert_eq!(matrix.node_count(), 6);
+    for source in &NODES {
+        for destination in &NODES {
+            assert_eq!(
+                dijkstra(&matrix, source, destination),
+                dijkstra(&graph, source, destination)
+            );
+        }
+    }
+}
+
+#[test]
+fn adjacency_matrix_indexes_the_nodes_in_order() {
+    let mut matrix = AdjacencyMatrix::new();
+    matrix.add_edge("a", "b", 3);
+    assert_eq!(matrix.add_edge("a", "b", 1), Some(3));
+    matrix.add_node("c");
+
+    assert_eq!(matrix.index_of(&"c"), Some(2));
+    assert_eq!(matrix.node_at(1), &"b");
+    assert_eq!(matrix.cost_at(0, 1), Some(1));
+    assert_eq!(matrix.remove_edge(&"a", &"b"), Some(1));
+    assert_eq!(matrix.edge_cost(&"a", &"b"), None);
+    assert_eq!(matrix.node_count(), 3);
+}
+
+#[test]
+fn every_backend_lists_the_same_edges() {
+    let graph = graph();
+    let csr = CsrGraph::from_edges(edges());
+    let matrix = matrix();
+    let compressed = CompressedGraph::from_edges(edges());
+
+    let mut expected = edges();
+    expected.sort();
+    let sorted = |edges: Vec<(&&'static str, &&'static str, u32)>| {
+        let mut edges: Vec<_> = edges
+            .into_iter()
+            .map(|(from, to, cost)| (*from, *to, cost))
+            .collect();
+        edges.sort();
+        edges
+    };
+    assert_eq!(sorted(graph.edges().collect()), expected);
+    assert_eq!(sorted(csr.edges().collect()), expected);
+    assert_eq!(sorted(matrix.edges().collect()), expected);
+    assert_eq!(sorted(compressed.edges().collect()), expected);
+    assert_eq!(matrix.edge_count(), 8);
+    assert_eq!(csr.nodes().count(), 6);
+    assert_eq!(matrix.nodes().copied().collect::<Vec<_>>(), NODES);
+}
+
+#[test]
+fn compressed_graph_finds_the_same_paths() {
+    let graph = graph();
+    let compressed = CompressedGraph::from_graph(&graph);
+
+    assert_eq!(compressed.node_count(), 6);
+    assert_eq!(compressed.edge_count(), 8);
+    for source in &NODES {
+        for destination in &NODES {
+            assert_eq!(
+                dijkstra(&compressed, source, destination),
+                dijkstra(&graph, source, destination)
+            );
+        }
+    }
+}
+
+#[test]
+fn compressed_graph_encodes_distant_destinations() {
+    // Every node links to nodes far before and far after it, and the costs
+    // span the whole range of u32.
+    let edges: Vec<(u32, u32, u32)> = (0..1000)
+        .flat_map(|node| {
+            vec![
+                (node, (node + 997) % 1000, u32::MAX - node),
+                (node, (node + 3) % 1000, node),
+            ]
+        })
+        .collect();
+    let compressed = CompressedGraph::from_edges(edges.clone());
+    let csr = CsrGraph::from_edges(edges);
+
+    let mut neighbors: Vec<(u32, u32)> = compressed
+        .neighbors(&5)
+        .map(|(node, cost)| (*node, cost))
+        .collect();
+    neighbors.sort_unstable();
+    assert_eq!(neighbors, vec![(2, u32::MAX - 5), (8, 5)]);
+    assert!(compressed.neighbors(&1000).next().is_none());
+    for destination in (0..1000).step_by(37) {
+        assert_eq!(
+            dijkstra(&compressed, &0, &destination),
+            dijkstra(&csr, &0, &destination)
+        );
+    }
+}
+
+#[test]
+fn compressed_graph_is_smaller_than_the_csr_arrays() {
+    // The edges of a chain have small gaps and small costs, which take a byte
+    // each once encoded.
+    let compressed = CompressedGraph::from_edges((0..1000u32).map(|node| (node, node + 1, 1)));
+
+    let csr_bytes = 1000 * 8 + 1002 * std::mem::size_of::<usize>();
+    assert!(compressed.edge_bytes() < csr_bytes);
+    assert_eq!(dijkstra(&compressed, &0, &1000).unwrap().cost, 1000);
+}
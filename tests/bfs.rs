@@ -0,0 +1,60 @@
+// The breadth-first searches, used when all the edges cost the same.
+use dijkstra::{bfs_shortest_path, dijkstra, shortest_path, CsrGraph, Graph, GraphLike};
+
+// A ring of twelve nodes with a few chords, every edge costing 2.
+fn ring() -> Graph<u32> {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..12 {
+        graph.add_edge(node, (node + 1) % 12, 2);
+        if node % 3 == 0 {
+            graph.add_edge(node, (node + 5) % 12, 2);
+        }
+    }
+    graph
+}
+
+#[test]
+fn bfs_agrees_with_dijkstra_on_uniform_costs() {
+    let graph = ring();
+    assert_eq!(graph.uniform_cost(), Some(2));
+
+    for destination in 0..12 {
+        let path = bfs_shortest_path(&graph, &0, &destination).unwrap();
+        let expected = dijkstra(&graph, &0, &destination).unwrap();
+        assert_eq!(path.cost, expected.cost);
+        assert_eq!(path.hops(), expected.hops());
+        assert_eq!(
+            shortest_path(&graph, &0, &destination).unwrap().cost,
+            expected.cost
+        );
+    }
+}
+
+#[test]
+fn bfs_takes_the_fewest_edges_whatever_their_costs() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 1);
+    graph.add_edge("a", "c", 5);
+    assert_eq!(graph.uniform_cost(), None);
+
+    let path = bfs_shortest_path(&graph, &"a", &"c").unwrap();
+    assert_eq!(path.nodes, vec!["a", "c"]);
+    assert_eq!(path.cost, 5);
+    // The costs differ, so shortest_path falls back to dijkstra.
+    assert_eq!(shortest_path(&graph, &"a", &"c").unwrap().cost, 2);
+}
+
+#[test]
+fn shortest_path_over_a_csr_graph() {
+    let csr = CsrGraph::from_edges(vec![("a", "b", 3), ("b", "c", 3), ("c", "d", 3)]);
+    assert_eq!(csr.uniform_cost(), Some(3));
+    assert_eq!(
+        CsrGraph::<&str>::from_edges(Vec::new()).uniform_cost(),
+        None
+    );
+
+    let path = shortest_path(&csr, &"a", &"d").unwrap();
+    assert_eq!(path.nodes, vec!["a", "b", "c", "d"]);
+    assert_eq!(path.cost, 9);
+}
@@ -0,0 +1,112 @@
+// The searches meeting in the middle, which must find the same paths as
+// dijkstra.
+use dijkstra::{bidirectional_astar, bidirectional_dijkstra, dijkstra, Graph, PathError};
+
+// A grid of 6 by 6 nodes, with costs changing from one edge to the next.
+fn grid() -> Graph<(u32, u32)> {
+    let mut graph: Graph<(u32, u32)> = Graph::new();
+    for x in 0..6 {
+        for y in 0..6 {
+            if x + 1 < 6 {
+                graph.add_edge((x, y), (x + 1, y), 1 + (x + y) % 4);
+            }
+            if y + 1 < 6 {
+                graph.add_edge((x, y), (x, y + 1), 1 + (x * y) % 3);
+            }
+            if x > 0 {
+                graph.add_edge((x, y), (x - 1, y), 2);
+            }
+        }
+    }
+    graph
+}
+
+#[test]
+fn bidirectional_dijkstra_agrees_with_dijkstra() {
+    let graph = grid();
+    let reversed = graph.reversed();
+
+    for destination in graph.nodes() {
+        let path = bidirectional_dijkstra(&graph, &reversed, &(0, 0), destination).unwrap();
+        let expected = dijkstra(&graph, &(0, 0), destination).unwrap();
+        assert_eq!(path.cost, expected.cost);
+        assert_eq!(path.nodes.first(), Some(&(0, 0)));
+        assert_eq!(path.nodes.last(), Some(destination));
+        // The path is made of the edges of the forward graph.
+        for pair in path.nodes.windows(2) {
+            assert!(graph.edge_cost(&pair[0], &pair[1]).is_some());
+        }
+    }
+}
+
+#[test]
+fn bidirectional_dijkstra_over_an_undirected_graph() {
+    let mut graph: Graph<&str> = Graph::new_undirected();
+    graph.add_edge("a", "b", 2);
+    graph.add_edge("b", "c", 2);
+    graph.add_edge("a", "c", 5);
+
+    let path = bidirectional_dijkstra(&graph, &graph, &"c", &"a").unwrap();
+    assert_eq!(path.nodes, vec!["c", "b", "a"]);
+    assert_eq!(path.cost, 4);
+}
+
+#[test]
+fn bidirectional_dijkstra_without_any_path() {
+    let graph = grid();
+    let reversed = graph.reversed();
+
+    // Nothing goes down from the last row.
+    assert_eq!(
+        bidirectional_dijkstra(&graph, &reversed, &(0, 5), &(0, 0)),
+        Err(PathError::NoPath)
+    );
+    assert_eq!(
+        bidirectional_dijkstra(&graph, &reversed, &(0, 0), &(9, 9)),
+        Err(PathError::UnknownDestination)
+    );
+}
+
+// The Manhattan distance between two nodes of a grid, which is a consistent
+// heuristic on grids whose steps all cost at least 1.
+fn manhattan(from: (u32, u32), to: (u32, u32)) -> u32 {
+    ((from.0 as i32 - to.0 as i32).abs() + (from.1 as i32 - to.1 as i32).abs()) as u32
+}
+
+#[test]
+fn bidirectional_astar_agrees_with_dijkstra() {
+    let graph = grid();
+    let reversed = graph.reversed();
+    let source = (0, 0);
+
+    for &destination in graph.nodes() {
+        let path = bidirectional_astar(
+            &graph,
+            &reversed,
+            &source,
+            &destination,
+            |node| manhattan(*node, destination),
+            |node| manhattan(source, *node),
+        )
+        .unwrap();
+        assert_eq!(
+            path.cost,
+            dijkstra(&graph, &source, &destination).unwrap().cost
+        );
+        assert_eq!(path.nodes.first(), Some(&source));
+        assert_eq!(path.nodes.last(), Some(&destination));
+    }
+}
+
+#[test]
+fn bidirectional_astar_without_heuristics() {
+    let graph = grid();
+    let reversed = graph.reversed();
+
+    let path = bidirectional_astar(&graph, &reversed, &(2, 1), &(4, 5), |_| 0, |_| 0).unwrap();
+    assert_eq!(path.cost, dijkstra(&graph, &(2, 1), &(4, 5)).unwrap().cost);
+    assert_eq!(
+        bidirectional_astar(&graph, &reversed, &(0, 5), &(0, 0), |_| 0, |_| 0),
+        Err(PathError::NoPath)
+    );
+}
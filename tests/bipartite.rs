@@ -0,0 +1,101 @@
+// The bipartite graphs, and the matchings between their two sides.
+use dijkstra::{is_bipartite, max_flow, maximum_matching, Graph};
+
+// Workers, numbered from 0 to 7, and the tasks they can take on, numbered
+// from 10 to 17.
+fn skills() -> Graph<u32> {
+    let mut graph: Graph<u32> = Graph::new();
+    for &(worker, task) in &[
+        (0, 10),
+        (0, 11),
+        (1, 10),
+        (2, 11),
+        (2, 12),
+        (3, 12),
+        (3, 13),
+        (4, 13),
+        (5, 13),
+        (5, 14),
+        (6, 10),
+        (7, 13),
+    ] {
+        graph.add_edge(worker, task, 1);
+    }
+    graph.add_node(15);
+    graph
+}
+
+#[test]
+fn cycle_is_bipartite_if_its_length_is_even() {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..6 {
+        graph.add_edge(node, (node + 1) % 6, 1);
+    }
+    let colors = is_bipartite(&graph).unwrap();
+    for node in 0..6 {
+        assert_ne!(colors[&node], colors[&((node + 1) % 6)]);
+    }
+
+    // The direction of the edges doesn't matter.
+    graph.add_edge(0, 3, 1);
+    graph.add_edge(4, 1, 1);
+    assert!(is_bipartite(&graph).is_some());
+
+    graph.add_edge(0, 2, 1);
+    assert_eq!(is_bipartite(&graph), None);
+}
+
+#[test]
+fn self_loop_is_not_bipartite() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "b", 1);
+
+    assert_eq!(is_bipartite(&graph), None);
+    assert_eq!(maximum_matching(&graph), None);
+}
+
+#[test]
+fn maximum_matching_is_as_large_as_a_unit_flow() {
+    let graph = skills();
+    let matching = maximum_matching(&graph).unwrap();
+
+    // The pairs are edges of the graph, without any node in common.
+    let colors = is_bipartite(&graph).unwrap();
+    let mut matched = Vec::new();
+    for &(first, second) in &matching {
+        assert!(!colors[&first] && colors[&second]);
+        assert!(
+            graph.edge_cost(&first, &second).is_some()
+                || graph.edge_cost(&second, &first).is_some()
+        );
+        matched.push(first);
+        matched.push(second);
+    }
+    matched.sort_unstable();
+    matched.dedup();
+    assert_eq!(matched.len(), 2 * matching.len());
+
+    // A flow of unit capacities from every worker to the tasks they can take
+    // on gives the size of the largest assignment.
+    let mut network = graph.clone();
+    for worker in 0..8 {
+        network.add_edge(100, worker, 1);
+    }
+    for task in 10..16 {
+        network.add_edge(task, 200, 1);
+    }
+    let flow = max_flow(&network, &100, &200).unwrap();
+    assert_eq!(matching.len() as u32, flow.value);
+    assert_eq!(matching.len(), 5);
+}
+
+#[test]
+fn graph_without_edges_has_an_empty_matching() {
+    let mut graph: Graph<&str> = Graph::new();
+    assert_eq!(maximum_matching(&graph), Some(Vec::new()));
+
+    graph.add_node("alone");
+    assert_eq!(maximum_matching(&graph), Some(Vec::new()));
+    assert_eq!(is_bipartite(&graph).unwrap().len(), 1);
+}
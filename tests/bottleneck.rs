@@ -0,0 +1,88 @@
+// The paths judged by their narrowest edge.
+use dijkstra::{is_reachable, minimax_path, widest_path, Graph, PathError};
+
+// Pipes of different capacities between a and f.
+fn pipes() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 7);
+    graph.add_edge("b", "f", 2);
+    graph.add_edge("a", "c", 4);
+    graph.add_edge("c", "d", 6);
+    graph.add_edge("d", "f", 5);
+    graph.add_edge("b", "d", 3);
+    graph.add_edge("a", "f", 1);
+    graph.add_node("e");
+    graph
+}
+
+// The largest capacity such that the destination can be reached over the
+// pipes at least as wide, found by trying every capacity.
+fn widest_by_threshold(graph: &Graph<&'static str>, source: &str, destination: &str) -> u32 {
+    graph
+        .edges()
+        .map(|(_, _, cost)| cost)
+        .filter(|&width| {
+            let wide = graph.filter_edges(|_, _, cost| cost >= width);
+            is_reachable(&wide, &source, &destination)
+        })
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn widest_path_maximizes_the_narrowest_edge() {
+    let graph = pipes();
+
+    let path = widest_path(&graph, &"a", &"f").unwrap();
+    assert_eq!(path.nodes, vec!["a", "c", "d", "f"]);
+    assert_eq!(path.cost, 4);
+    for destination in &["b", "c", "d", "f"] {
+        assert_eq!(
+            widest_path(&graph, &"a", destination).unwrap().cost,
+            widest_by_threshold(&graph, "a", destination)
+        );
+    }
+}
+
+#[test]
+fn widest_path_edge_cases() {
+    let graph = pipes();
+
+    assert_eq!(widest_path(&graph, &"a", &"a").unwrap().cost, 0);
+    assert_eq!(widest_path(&graph, &"a", &"e"), Err(PathError::NoPath));
+    assert_eq!(
+        widest_path(&graph, &"a", &"z"),
+        Err(PathError::UnknownDestination)
+    );
+}
+
+// The smallest cost such that the destination can be reached over the pipes
+// at most as expensive, found by trying every cost.
+fn minimax_by_threshold(graph: &Graph<&'static str>, source: &str, destination: &str) -> u32 {
+    graph
+        .edges()
+        .map(|(_, _, cost)| cost)
+        .filter(|&limit| {
+            let cheap = graph.filter_edges(|_, _, cost| cost <= limit);
+            is_reachable(&cheap, &source, &destination)
+        })
+        .min()
+        .unwrap()
+}
+
+#[test]
+fn minimax_path_minimizes_the_most_expensive_edge() {
+    let graph = pipes();
+
+    let path = minimax_path(&graph, &"a", &"f").unwrap();
+    assert_eq!(path.nodes, vec!["a", "f"]);
+    assert_eq!(path.cost, 1);
+    for destination in &["b", "c", "d", "f"] {
+        assert_eq!(
+            minimax_path(&graph, &"a", destination).unwrap().cost,
+            minimax_by_threshold(&graph, "a", destination)
+        );
+    }
+    assert_eq!(minimax_path(&graph, &"a", &"e"), Err(PathError::NoPath));
+    assert_eq!(minimax_path(&graph, &"b", &"b").unwrap().cost, 0);
+}
@@ -0,0 +1,54 @@
+// The searches which give up past a maximum cost.
+use dijkstra::{dijkstra_bounded, dijkstra_within, Graph, PathError};
+
+// The line a - b - c - d, with a costly shortcut from a to d.
+fn line() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 2);
+    graph.add_edge("c", "d", 3);
+    graph.add_edge("a", "d", 10);
+    graph
+}
+
+#[test]
+fn dijkstra_within_lists_the_nodes_in_reach() {
+    let reached = dijkstra_within(&line(), &"a", 3).unwrap();
+    assert_eq!(reached, vec![("a", 0), ("b", 1), ("c", 3)]);
+
+    let reached = dijkstra_within(&line(), &"a", 0).unwrap();
+    assert_eq!(reached, vec![("a", 0)]);
+}
+
+#[test]
+fn dijkstra_within_an_unknown_source() {
+    assert_eq!(
+        dijkstra_within(&line(), &"z", 3),
+        Err(PathError::UnknownSource)
+    );
+}
+
+#[test]
+fn dijkstra_bounded_gives_up_past_the_maximum_cost() {
+    let graph = line();
+
+    assert_eq!(dijkstra_bounded(&graph, &"a", &"d", 6).unwrap().cost, 6);
+    assert_eq!(
+        dijkstra_bounded(&graph, &"a", &"d", 5),
+        Err(PathError::NoPath)
+    );
+}
+
+#[test]
+fn dijkstra_bounded_skips_the_overflowing_costs() {
+    let mut graph: Graph<&str, u8> = Graph::new();
+    graph.add_edge("a", "b", 200);
+    graph.add_edge("b", "c", 100);
+    graph.add_edge("a", "d", 1);
+
+    assert_eq!(dijkstra_bounded(&graph, &"a", &"d", 255).unwrap().cost, 1);
+    assert_eq!(
+        dijkstra_bounded(&graph, &"a", &"c", 255),
+        Err(PathError::NoPath)
+    );
+}
@@ -0,0 +1,53 @@
+// The bucket queue, which only handles the costs it can convert.
+use dijkstra::{dijkstra, dijkstra_bucket, Graph};
+
+#[test]
+fn dijkstra_bucket_hands_negative_costs_over() {
+    let mut graph: Graph<&str, i32> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", -1);
+    graph.add_edge("a", "d", 3);
+
+    for destination in &["b", "c", "d"] {
+        assert_eq!(
+            dijkstra_bucket(&graph, &"a", destination),
+            dijkstra(&graph, &"a", destination)
+        );
+    }
+}
+
+#[test]
+fn dijkstra_bucket_with_the_expensive_edge_first() {
+    // The neighbors of the source come out in the order of the hash map, so
+    // the graph is built again with other labels until both orders were
+    // tried.
+    for round in 0..64u32 {
+        let (source, cheap, expensive) = (3 * round, 3 * round + 1, 3 * round + 2);
+        let mut graph: Graph<u32> = Graph::new();
+        graph.add_edge(source, expensive, 5);
+        graph.add_edge(source, cheap, 3);
+        graph.add_edge(cheap, expensive, 1);
+
+        let path = dijkstra_bucket(&graph, &source, &expensive).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.nodes, vec![source, cheap, expensive]);
+    }
+}
+
+#[test]
+fn dijkstra_bucket_agrees_with_dijkstra() {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..30 {
+        graph.add_edge(node, (node + 1) % 30, 1 + node % 4);
+        graph.add_edge(node, (node * 7) % 30, 2 + node % 5);
+    }
+
+    for destination in 0..30 {
+        let bucket = dijkstra_bucket(&graph, &0, &destination).unwrap();
+        assert_eq!(
+            bucket.cost,
+            dijkstra(&graph, &0, &destination).unwrap().cost
+        );
+        assert_eq!(bucket.nodes.last(), Some(&destination));
+    }
+}
@@ -0,0 +1,33 @@
+// Building graphs with the fluent GraphBuilder.
+use dijkstra::{dijkstra, DuplicateEdgePolicy, Graph, GraphBuilder};
+
+// The same edges, an edge being added three times.
+fn duplicated(policy: DuplicateEdgePolicy) -> Option<u32> {
+    GraphBuilder::<&str>::new()
+        .duplicates(policy)
+        .edge("a", "b", 3)
+        .edge("a", "b", 1)
+        .edge("a", "b", 2)
+        .build()
+        .edge_cost(&"a", &"b")
+}
+
+#[test]
+fn graph_builder_follows_the_duplicate_edge_policy() {
+    assert_eq!(duplicated(DuplicateEdgePolicy::Overwrite), Some(2));
+    assert_eq!(duplicated(DuplicateEdgePolicy::KeepFirst), Some(3));
+    assert_eq!(duplicated(DuplicateEdgePolicy::KeepMinimum), Some(1));
+}
+
+#[test]
+fn graph_builder_adds_nodes_and_edges_in_bulk() {
+    let graph: Graph<&str> = GraphBuilder::new()
+        .node("alone")
+        .edges(vec![("a", "b", 1), ("b", "c", 1), ("a", "c", 5)])
+        .build();
+
+    assert!(graph.contains_node(&"alone"));
+    assert_eq!(graph.edge_cost(&"a", &"c"), Some(5));
+    assert_eq!(graph.edge_cost(&"c", &"a"), None);
+    assert_eq!(dijkstra(&graph, &"a", &"c").unwrap().cost, 2);
+}
@@ -0,0 +1,105 @@
+// The measures of how central the nodes of a graph are.
+use dijkstra::{closeness_centrality, closeness_centrality_sampled, pagerank, Graph};
+
+// A star of four leaves around a center, plus its own lonely node.
+fn star() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new_undirected();
+    for leaf in &["a", "b", "c", "d"] {
+        graph.add_edge("center", *leaf, 1);
+    }
+    graph.add_node("lonely");
+    graph
+}
+
+// Whether two measures are equal, up to the rounding of the floats.
+fn close(first: f64, second: f64) -> bool {
+    (first - second).abs() < 1e-9
+}
+
+#[test]
+fn center_of_the_star_is_the_most_central() {
+    let closeness = closeness_centrality(&star()).unwrap();
+
+    // The center is reached from 4 of the 5 other nodes, at a cost of 1.
+    assert!(close(closeness[&"center"], 4.0 / 5.0));
+    // A leaf is reached from the center for 1, and from the other leaves for
+    // 2 each.
+    assert!(close(closeness[&"a"], (4.0 / 7.0) * (4.0 / 5.0)));
+    assert_eq!(closeness[&"lonely"], 0.0);
+    assert_eq!(closeness.len(), 6);
+}
+
+#[test]
+fn enough_samples_give_the_exact_closeness() {
+    let graph = star();
+    let exact = closeness_centrality(&graph).unwrap();
+
+    let sampled = closeness_centrality_sampled(&graph, 100, 7).unwrap();
+    for (node, value) in &exact {
+        assert!(close(sampled[node], *value));
+    }
+}
+
+#[test]
+fn sampled_closeness_depends_on_the_seed_only() {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..40 {
+        graph.add_edge(node, (node + 1) % 40, 1 + node % 4);
+        graph.add_edge(node, (node * 7) % 40, 3);
+    }
+
+    let first = closeness_centrality_sampled(&graph, 10, 42).unwrap();
+    let second = closeness_centrality_sampled(&graph, 10, 42).unwrap();
+    assert_eq!(first.len(), 40);
+    for (node, value) in &first {
+        assert_eq!(second[node], *value);
+    }
+}
+
+#[test]
+fn pagerank_ranks_sum_to_one() {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..20 {
+        graph.add_edge(node, (node * 3 + 1) % 20, 1);
+        graph.add_edge(node, (node + 7) % 20, 1);
+    }
+    // A node without any edge spreads its rank over every node.
+    graph.add_edge(5, 20, 1);
+
+    let ranks = pagerank(&graph, 0.85, 1e-10);
+    assert_eq!(ranks.len(), 21);
+    let total: f64 = ranks.iter().map(|(_, rank)| rank).sum();
+    assert!(close(total, 1.0));
+    assert!(ranks.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+}
+
+#[test]
+fn pagerank_puts_the_most_linked_node_first() {
+    let mut graph: Graph<&str> = Graph::new();
+    for fan in &["a", "b", "c", "d"] {
+        graph.add_edge(*fan, "idol", 1);
+    }
+    graph.add_edge("idol", "a", 1);
+
+    let ranks = pagerank(&graph, 0.85, 1e-10);
+    assert_eq!(ranks[0].0, "idol");
+    assert_eq!(ranks[1].0, "a");
+}
+
+#[test]
+fn pagerank_of_a_cycle_is_uniform() {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..8 {
+        graph.add_edge(node, (node + 1) % 8, node + 1);
+    }
+
+    for (_, rank) in pagerank(&graph, 0.85, 1e-12) {
+        assert!(close(rank, 1.0 / 8.0));
+    }
+}
+
+#[test]
+#[should_panic(expected = "damping must be between zero and one")]
+fn pagerank_rejects_a_damping_of_one() {
+    pagerank(&star(), 1.0, 1e-6);
+}
@@ -0,0 +1,91 @@
+// The queries of the contraction hierarchies, which must find the same paths
+// as dijkstra.
+use dijkstra::{dijkstra, ContractionHierarchy, Graph, PathError};
+
+// A directed grid of 8 by 8 nodes with uneven costs, some streets being one
+// way only, and a node which leads into it but cannot be reached.
+fn roads() -> Graph<(u32, u32)> {
+    let mut graph: Graph<(u32, u32)> = Graph::new();
+    for x in 0..8 {
+        for y in 0..8 {
+            if x + 1 < 8 {
+                graph.add_edge((x, y), (x + 1, y), 1 + (x + 2 * y) % 5);
+                graph.add_edge((x + 1, y), (x, y), 2 + (x * y) % 4);
+            }
+            if y + 1 < 8 {
+                graph.add_edge((x, y), (x, y + 1), 1 + (3 * x + y) % 4);
+                if x % 3 != 1 {
+                    graph.add_edge((x, y + 1), (x, y), 3);
+                }
+            }
+        }
+    }
+    graph.add_edge((9, 9), (4, 4), 1);
+    graph
+}
+
+#[test]
+fn hierarchy_agrees_with_dijkstra() {
+    let graph = roads();
+    let hierarchy = ContractionHierarchy::new(&graph).unwrap();
+
+    let nodes: Vec<(u32, u32)> = graph.nodes().copied().collect();
+    for source in &nodes {
+        for destination in nodes.iter().step_by(3) {
+            let path = hierarchy.query(source, destination);
+            let expected = dijkstra(&graph, source, destination);
+            assert_eq!(
+                path.as_ref().map(|path| path.cost),
+                expected.as_ref().map(|path| path.cost)
+            );
+
+            // The shortcuts are unpacked into the edges of the graph.
+            if let Ok(path) = path {
+                assert_eq!(path.nodes.first(), Some(source));
+                assert_eq!(path.nodes.last(), Some(destination));
+                let cost: u32 = path
+                    .nodes
+                    .windows(2)
+                    .map(|pair| graph.edge_cost(&pair[0], &pair[1]).unwrap())
+                    .sum();
+                assert_eq!(cost, path.cost);
+            }
+        }
+    }
+}
+
+#[test]
+fn hierarchy_of_an_undirected_graph_with_loops_and_parallel_edges() {
+    let mut graph: Graph<u32> = Graph::new_undirected();
+    for node in 0..30 {
+        graph.add_edge(node, (node + 1) % 30, 1 + node % 7);
+        graph.add_edge(node, (node * 11) % 30, 9);
+    }
+    graph.add_edge(4, 4, 1);
+    let hierarchy = ContractionHierarchy::new(&graph).unwrap();
+
+    for source in 0..30 {
+        for destination in 0..30 {
+            assert_eq!(
+                hierarchy.query(&source, &destination).unwrap().cost,
+                dijkstra(&graph, &source, &destination).unwrap().cost
+            );
+        }
+    }
+}
+
+#[test]
+fn hierarchy_query_errors() {
+    let hierarchy = ContractionHierarchy::new(&roads()).unwrap();
+
+    assert_eq!(hierarchy.query(&(0, 0), &(9, 9)), Err(PathError::NoPath));
+    assert_eq!(
+        hierarchy.query(&(20, 0), &(0, 0)),
+        Err(PathError::UnknownSource)
+    );
+    assert_eq!(
+        hierarchy.query(&(0, 0), &(20, 0)),
+        Err(PathError::UnknownDestination)
+    );
+    assert_eq!(hierarchy.query(&(3, 3), &(3, 3)).unwrap().cost, 0);
+}
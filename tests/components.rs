@@ -0,0 +1,98 @@
+// The components of the graphs.
+use dijkstra::{connected_components, is_reachable, strongly_connected_components, Graph};
+
+// Three islands: a directed chain, a pair of nodes, and a lonely node.
+fn islands() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("c", "b", 1);
+    graph.add_edge("c", "d", 1);
+    graph.add_edge("x", "y", 1);
+    graph.add_node("z");
+    graph
+}
+
+#[test]
+fn components_ignore_the_direction_of_the_edges() {
+    let components = connected_components(&islands());
+
+    assert_eq!(components.count(), 3);
+    assert!(components.same_component(&"a", &"d"));
+    assert!(components.same_component(&"y", &"x"));
+    assert!(!components.same_component(&"a", &"x"));
+    assert!(!components.same_component(&"a", &"nowhere"));
+    assert_eq!(components.component_of(&"nowhere"), None);
+
+    let mut sizes = components.sizes().to_vec();
+    sizes.sort_unstable();
+    assert_eq!(sizes, vec![1, 2, 4]);
+    assert_eq!(
+        components.sizes()[components.component_of(&"b").unwrap()],
+        4
+    );
+    assert_eq!(components.iter().count(), 7);
+}
+
+#[test]
+fn nodes_of_different_components_never_reach_each_other() {
+    let graph = islands();
+    let components = connected_components(&graph);
+
+    for (first, first_component) in components.iter() {
+        for (second, second_component) in components.iter() {
+            if first_component != second_component {
+                assert!(!is_reachable(&graph, first, second));
+            }
+        }
+    }
+}
+
+#[test]
+fn empty_graph_has_no_component() {
+    let graph: Graph<u32> = Graph::new();
+
+    assert_eq!(connected_components(&graph).count(), 0);
+}
+
+#[test]
+fn strongly_connected_components_follow_the_cycles() {
+    let mut graph: Graph<u32> = Graph::new();
+    // The cycles 0 -> 1 -> 2 -> 0 and 3 <-> 4, linked one way, and 5 alone.
+    for &(source, destination) in &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3), (4, 5)] {
+        graph.add_edge(source, destination, 1);
+    }
+
+    let components = strongly_connected_components(&graph);
+    let sorted: Vec<Vec<u32>> = components
+        .iter()
+        .map(|component| {
+            let mut component = component.clone();
+            component.sort_unstable();
+            component
+        })
+        .collect();
+    // No edge leads back to an earlier component.
+    assert_eq!(sorted, vec![vec![5], vec![3, 4], vec![0, 1, 2]]);
+
+    for component in &components {
+        for first in component {
+            for second in component {
+                assert!(is_reachable(&graph, first, second));
+            }
+        }
+    }
+}
+
+#[test]
+fn strongly_connected_components_of_a_very_long_chain() {
+    // A chain long enough to overflow the stack of the test thread if it
+    // were explored recursively, closed into a single cycle.
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..100_000 {
+        graph.add_edge(node, (node + 1) % 100_000, 1);
+    }
+
+    let components = strongly_connected_components(&graph);
+    assert_eq!(components.len(), 1);
+    assert_eq!(components[0].len(), 100_000);
+}
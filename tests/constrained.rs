@@ -0,0 +1,103 @@
+// The cheapest paths whose usage of a second resource stays within a
+// budget.
+use dijkstra::{constrained_shortest_path, dijkstra, Graph, PathError};
+
+// A network of 8 nodes whose edges carry their usage of the resource as
+// data, the cheap edges using a lot of it.
+fn network() -> Graph<u32, u32, (), u32> {
+    let mut graph: Graph<u32, u32, (), u32> = Graph::new();
+    for from in 0..8 {
+        for step in 1..4 {
+            let to = (from + step) % 8;
+            let cost = 1 + (from * 3 + step * 5) % 7;
+            graph.add_edge_with_data(from, to, cost, 9 - cost);
+        }
+    }
+    graph
+}
+
+// The lowest cost of the simple paths from the node to the destination whose
+// usage stays within the budget, found by trying all of them.
+fn brute_force(
+    graph: &Graph<u32, u32, (), u32>,
+    node: u32,
+    destination: u32,
+    budget: u32,
+    visited: &mut Vec<u32>,
+) -> Option<u32> {
+    if node == destination {
+        return Some(0);
+    }
+    visited.push(node);
+    let mut best = None;
+    for (next, cost) in graph.neighbors(&node) {
+        let usage = *graph.edge_data(&node, next).unwrap();
+        if usage <= budget && !visited.contains(next) {
+            if let Some(rest) = brute_force(graph, *next, destination, budget - usage, visited) {
+                best = Some(best.map_or(cost + rest, |best: u32| best.min(cost + rest)));
+            }
+        }
+    }
+    visited.pop();
+    best
+}
+
+#[test]
+fn constrained_path_agrees_with_the_brute_force() {
+    let graph = network();
+    let usage = |from: &u32, to: &u32, _| *graph.edge_data(from, to).unwrap();
+
+    for budget in 0..30 {
+        for destination in 1..8 {
+            let path = constrained_shortest_path(&graph, &0, &destination, usage, budget);
+            let expected = brute_force(&graph, 0, destination, budget, &mut Vec::new());
+            assert_eq!(path.as_ref().ok().map(|path| path.cost), expected);
+
+            // The returned path stays within the budget.
+            if let Ok(path) = path {
+                let used: u32 = path
+                    .nodes
+                    .windows(2)
+                    .map(|pair| *graph.edge_data(&pair[0], &pair[1]).unwrap())
+                    .sum();
+                assert!(used <= budget);
+            }
+        }
+    }
+}
+
+#[test]
+fn large_budget_gives_the_path_of_dijkstra() {
+    let graph = network();
+    let usage = |from: &u32, to: &u32, _| *graph.edge_data(from, to).unwrap();
+
+    for destination in 0..8 {
+        let path = constrained_shortest_path(&graph, &0, &destination, usage, 1_000).unwrap();
+        assert_eq!(path.cost, dijkstra(&graph, &0, &destination).unwrap().cost);
+    }
+}
+
+#[test]
+fn constrained_path_errors() {
+    let graph = network();
+    let usage = |from: &u32, to: &u32, _| *graph.edge_data(from, to).unwrap();
+
+    assert_eq!(
+        constrained_shortest_path(&graph, &0, &5, usage, 0),
+        Err(PathError::NoPath)
+    );
+    assert_eq!(
+        constrained_shortest_path(&graph, &9, &5, usage, 10),
+        Err(PathError::UnknownSource)
+    );
+    assert_eq!(
+        constrained_shortest_path(&graph, &0, &9, usage, 10),
+        Err(PathError::UnknownDestination)
+    );
+    assert_eq!(
+        constrained_shortest_path(&graph, &0, &0, usage, 0)
+            .unwrap()
+            .cost,
+        0
+    );
+}
@@ -0,0 +1,129 @@
+// The algorithms working on directed acyclic graphs.
+use dijkstra::{
+    bellman_ford, dag_longest_path, dag_shortest_path, dijkstra, find_cycle, topological_sort,
+    Graph, PathError,
+};
+
+// The tasks of a small project, every edge going from a task to a task
+// depending on it.
+fn tasks() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("design", "code", 3);
+    graph.add_edge("design", "docs", 1);
+    graph.add_edge("code", "test", 2);
+    graph.add_edge("docs", "release", 1);
+    graph.add_edge("test", "release", 1);
+    graph.add_node("coffee");
+    graph
+}
+
+#[test]
+fn topological_sort_puts_every_edge_forward() {
+    let graph = tasks();
+    let order = topological_sort(&graph).unwrap();
+
+    assert_eq!(order.len(), graph.node_count());
+    let position = |node: &&str| order.iter().position(|other| other == node).unwrap();
+    for (source, destination, _) in graph.edges() {
+        assert!(position(source) < position(destination));
+    }
+}
+
+#[test]
+fn topological_sort_returns_a_cycle() {
+    let mut graph = tasks();
+    graph.add_edge("test", "code", 1);
+
+    let cycle = topological_sort(&graph).unwrap_err().cycle;
+    assert_eq!(cycle.first(), cycle.last());
+    let mut nodes = cycle[1..].to_vec();
+    nodes.sort_unstable();
+    assert_eq!(nodes, vec!["code", "test"]);
+}
+
+#[test]
+fn self_loop_is_a_cycle() {
+    let mut graph = tasks();
+    graph.add_edge("coffee", "coffee", 1);
+
+    assert_eq!(
+        topological_sort(&graph).unwrap_err().cycle,
+        vec!["coffee", "coffee"]
+    );
+}
+
+#[test]
+fn dag_shortest_path_agrees_with_dijkstra() {
+    let graph = tasks();
+
+    for destination in graph.nodes() {
+        let expected = dijkstra(&graph, &"design", destination);
+        let path = dag_shortest_path(&graph, &"design", destination);
+        assert_eq!(
+            path.as_ref().map(|path| path.cost),
+            expected.as_ref().map(|path| path.cost)
+        );
+    }
+}
+
+#[test]
+fn dag_shortest_path_with_negative_costs() {
+    let graph = tasks().map_weights(|_, destination, cost| {
+        if *destination == "test" {
+            -4
+        } else {
+            cost as i32
+        }
+    });
+
+    let path = dag_shortest_path(&graph, &"design", &"release").unwrap();
+    assert_eq!(path.nodes, vec!["design", "code", "test", "release"]);
+    assert_eq!(path.cost, 0);
+    assert_eq!(
+        Some(path.cost),
+        bellman_ford(&graph, &"design")
+            .unwrap()
+            .distance(&"release")
+    );
+}
+
+#[test]
+fn dag_longest_path_is_the_critical_path() {
+    let path = dag_longest_path(&tasks(), &"design", &"release").unwrap();
+
+    assert_eq!(path.nodes, vec!["design", "code", "test", "release"]);
+    assert_eq!(path.cost, 6);
+    assert_eq!(
+        dag_longest_path(&tasks(), &"design", &"coffee"),
+        Err(PathError::NoPath)
+    );
+}
+
+#[test]
+fn dag_paths_reject_the_cyclic_graphs() {
+    let mut graph = tasks();
+    graph.add_edge("coffee", "coffee", 1);
+
+    assert_eq!(
+        dag_shortest_path(&graph, &"design", &"code"),
+        Err(PathError::NotAcyclic)
+    );
+    assert_eq!(
+        dag_longest_path(&graph, &"design", &"code"),
+        Err(PathError::NotAcyclic)
+    );
+}
+
+#[test]
+fn find_cycle_returns_a_closed_walk() {
+    assert_eq!(find_cycle(&tasks()), None);
+
+    let mut graph = tasks();
+    graph.add_edge("release", "design", 1);
+    let cycle = find_cycle(&graph).unwrap();
+    assert_eq!(cycle.first(), cycle.last());
+    assert!(cycle.len() >= 4);
+    for pair in cycle.windows(2) {
+        assert!(graph.edge_cost(&pair[0], &pair[1]).is_some());
+    }
+}
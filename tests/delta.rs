@@ -0,0 +1,67 @@
+// The delta-stepping searches, which must find the same paths as dijkstra.
+#[cfg(feature = "rayon")]
+use dijkstra::par_delta_stepping;
+use dijkstra::{delta_stepping, dijkstra, Graph, PathError};
+
+#[test]
+fn delta_stepping_skips_the_empty_buckets() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 3_000_000_000);
+    graph.add_edge("b", "c", 1);
+    graph.add_edge("a", "c", 4_000_000_000);
+
+    let path = delta_stepping(&graph, &"a", &"c", 1).unwrap();
+    assert_eq!(path.cost, 3_000_000_001);
+    assert_eq!(path.nodes, vec!["a", "b", "c"]);
+}
+
+// A ring with chords, with costs around the deltas tried below.
+fn ring() -> Graph<u32> {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..25 {
+        graph.add_edge(node, (node + 1) % 25, 1 + node % 3);
+        graph.add_edge(node, (node + 6) % 25, 4 + node % 7);
+    }
+    graph
+}
+
+#[test]
+fn delta_stepping_agrees_with_dijkstra() {
+    let graph = ring();
+
+    for &delta in &[1, 3, 10, 100] {
+        for destination in 0..25 {
+            let path = delta_stepping(&graph, &0, &destination, delta).unwrap();
+            assert_eq!(path.cost, dijkstra(&graph, &0, &destination).unwrap().cost);
+            assert_eq!(path.nodes.first(), Some(&0));
+            assert_eq!(path.nodes.last(), Some(&destination));
+        }
+    }
+}
+
+#[test]
+fn delta_stepping_reports_the_missing_paths() {
+    let mut graph = ring();
+    graph.add_node(30);
+
+    assert_eq!(delta_stepping(&graph, &0, &30, 2), Err(PathError::NoPath));
+    assert_eq!(
+        delta_stepping(&graph, &31, &0, 2),
+        Err(PathError::UnknownSource)
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_delta_stepping_agrees_with_dijkstra() {
+    let graph = ring();
+
+    for destination in 0..25 {
+        assert_eq!(
+            par_delta_stepping(&graph, &0, &destination, 3)
+                .unwrap()
+                .cost,
+            dijkstra(&graph, &0, &destination).unwrap().cost
+        );
+    }
+}
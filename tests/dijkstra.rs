@@ -0,0 +1,152 @@
+// The shortest paths between two nodes.
+use std::collections::HashMap;
+
+use dijkstra::{dijkstra, dijkstra_backtrack, Graph, PathError};
+
+// The graph A -> B -> C -> D, with a shortcut from A to C which costs more
+// than going through B.
+fn square() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("A", "B", 1);
+    graph.add_edge("A", "C", 3);
+    graph.add_edge("B", "C", 1);
+    graph.add_edge("C", "D", 1);
+    graph
+}
+
+#[test]
+fn dijkstra_takes_the_cheapest_path() {
+    let path = dijkstra(&square(), &"A", &"D").unwrap();
+    assert_eq!(path.nodes, vec!["A", "B", "C", "D"]);
+    assert_eq!(path.cost, 3);
+    assert_eq!(path.hops(), 3);
+    assert_eq!((path.source(), path.destination()), (&"A", &"D"));
+}
+
+#[test]
+fn dijkstra_from_a_node_to_itself() {
+    let path = dijkstra(&square(), &"B", &"B").unwrap();
+    assert_eq!(path.nodes, vec!["B"]);
+    assert_eq!(path.cost, 0);
+    assert_eq!(path.hops(), 0);
+    assert_eq!(path.settled, Some(1));
+}
+
+#[test]
+fn dijkstra_without_any_path() {
+    assert_eq!(dijkstra(&square(), &"D", &"A"), Err(PathError::NoPath));
+}
+
+#[test]
+fn dijkstra_between_nodes_outside_of_the_graph() {
+    assert_eq!(
+        dijkstra(&square(), &"E", &"A"),
+        Err(PathError::UnknownSource)
+    );
+    assert_eq!(
+        dijkstra(&square(), &"A", &"E"),
+        Err(PathError::UnknownDestination)
+    );
+}
+
+#[test]
+fn dijkstra_over_nodes_which_are_not_strings() {
+    let mut graph: Graph<(u32, u32)> = Graph::new();
+    graph.add_edge((0, 0), (0, 1), 1);
+    graph.add_edge((0, 1), (1, 1), 1);
+    graph.add_edge((0, 0), (1, 1), 5);
+
+    let path = dijkstra(&graph, &(0, 0), &(1, 1)).unwrap();
+    assert_eq!(path.nodes, vec![(0, 0), (0, 1), (1, 1)]);
+    assert_eq!(path.cost, 2);
+}
+
+#[test]
+fn dijkstra_answers_several_queries_on_the_same_graph() {
+    let graph = square();
+
+    assert_eq!(dijkstra(&graph, &"A", &"C").unwrap().cost, 2);
+    assert_eq!(dijkstra(&graph, &"B", &"D").unwrap().cost, 2);
+    assert_eq!(
+        dijkstra(&graph, &"A", &"C").unwrap().nodes,
+        vec!["A", "B", "C"]
+    );
+}
+
+#[test]
+fn dijkstra_counts_the_settled_nodes() {
+    // The destination is settled right after the source, before the node
+    // behind the expensive edge.
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("a", "c", 5);
+
+    let path = dijkstra(&graph, &"a", &"b").unwrap();
+    assert_eq!(path.settled, Some(2));
+}
+
+#[test]
+fn dijkstra_improves_the_cost_of_queued_nodes() {
+    // The node d is first reached for 10, then for 3 once b and c are
+    // settled.
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "d", 10);
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 1);
+    graph.add_edge("c", "d", 1);
+    graph.add_edge("d", "e", 1);
+
+    let path = dijkstra(&graph, &"a", &"e").unwrap();
+    assert_eq!(path.nodes, vec!["a", "b", "c", "d", "e"]);
+    assert_eq!(path.cost, 4);
+}
+
+#[test]
+fn dijkstra_across_a_grid() {
+    // In a grid where every step costs 1, the cost of a path is the
+    // Manhattan distance between its ends.
+    let mut graph: Graph<(u32, u32)> = Graph::new_undirected();
+    for x in 0..8 {
+        for y in 0..8 {
+            graph.add_edge((x, y), (x + 1, y), 1);
+            graph.add_edge((x, y), (x, y + 1), 1);
+        }
+    }
+
+    for &(destination, cost) in &[((8, 7), 15), ((3, 5), 8), ((0, 0), 0)] {
+        let path = dijkstra(&graph, &(0, 0), &destination).unwrap();
+        assert_eq!(path.cost, cost);
+        assert_eq!(path.hops(), cost as usize);
+    }
+}
+
+#[test]
+fn dijkstra_along_a_very_long_path() {
+    // A path long enough to overflow the stack of the test thread if it were
+    // walked recursively.
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..100_000 {
+        graph.add_edge(node, node + 1, 1);
+    }
+
+    let path = dijkstra(&graph, &0, &100_000).unwrap();
+    assert_eq!(path.cost, 100_000);
+    assert_eq!(path.nodes.len(), 100_001);
+    assert_eq!(path.nodes[50_000], 50_000);
+}
+
+#[test]
+fn dijkstra_backtrack_walks_any_origin_map() {
+    let nodes = ["a", "b", "c", "d"];
+    let mut origin = HashMap::new();
+    origin.insert(&nodes[1], &nodes[0]);
+    origin.insert(&nodes[2], &nodes[1]);
+
+    let path = dijkstra_backtrack(&origin, &nodes[0], &nodes[2]).unwrap();
+    assert_eq!(path, vec![&"a", &"b", &"c"]);
+    assert_eq!(dijkstra_backtrack(&origin, &nodes[0], &nodes[3]), None);
+
+    // An origin map going around in circles never reaches the source.
+    origin.insert(&nodes[1], &nodes[2]);
+    assert_eq!(dijkstra_backtrack(&origin, &nodes[0], &nodes[2]), None);
+}
@@ -0,0 +1,36 @@
+// The text shown for the graphs and the paths.
+use dijkstra::{dijkstra, Graph};
+
+// A small graph, with an edge going nowhere useful.
+fn town() -> Graph<&'static str> {
+    let mut graph = Graph::new();
+    graph.add_edge("a", "c", 4);
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 2);
+    graph
+}
+
+#[test]
+fn graph_lists_the_nodes_and_neighbors_in_order() {
+    assert_eq!(town().to_string(), "a -> b (1), c (4)\nb -> c (2)\nc");
+}
+
+#[test]
+fn undirected_graph_uses_plain_links() {
+    let mut graph: Graph<&str> = Graph::new_undirected();
+    graph.add_edge("a", "b", 3);
+
+    assert_eq!(graph.to_string(), "a -- b (3)\nb -- a (3)");
+}
+
+#[test]
+fn path_shows_its_nodes_and_cost() {
+    let graph = town();
+    let path = dijkstra(&graph, &"a", &"c").unwrap();
+
+    assert_eq!(path.to_string(), "a -> b -> c (cost 3)");
+    assert_eq!(
+        path.display_hops(&graph).to_string(),
+        "a -(1)-> b -(2)-> c (cost 3)"
+    );
+}
@@ -0,0 +1,133 @@
+// The plans of D* Lite, which must be as cheap as the paths found by a new
+// search, even through edges of zero cost.
+use dijkstra::{dijkstra, DStarLite, Graph, PathError};
+
+// The side of the grid below.
+const SIDE: i32 = 10;
+
+// A grid whose every step costs 1 both ways, on which the Manhattan distance
+// is a consistent heuristic.
+fn grid() -> Graph<(i32, i32)> {
+    let mut graph: Graph<(i32, i32)> = Graph::new();
+    for x in 0..SIDE {
+        for y in 0..SIDE {
+            for &(dx, dy) in &[(1, 0), (0, 1)] {
+                if x + dx < SIDE && y + dy < SIDE {
+                    graph.add_edge((x, y), (x + dx, y + dy), 1);
+                    graph.add_edge((x + dx, y + dy), (x, y), 1);
+                }
+            }
+        }
+    }
+    graph
+}
+
+// The Manhattan distance between two nodes.
+fn manhattan(from: &(i32, i32), to: &(i32, i32)) -> u32 {
+    ((from.0 - to.0).abs() + (from.1 - to.1).abs()) as u32
+}
+
+// Plans the path from the start to the goal, with a heuristic of zero.
+fn plan(graph: &Graph<u32>, start: u32, goal: u32) -> (Vec<u32>, u32) {
+    let mut planner = DStarLite::new(graph, &start, &goal, |_: &u32, _: &u32| 0).unwrap();
+    let path = planner.plan().unwrap();
+    (path.nodes, path.cost)
+}
+
+#[test]
+fn dstar_lite_plans_past_a_loop_of_zero_cost() {
+    // The successors of a node come out in the order of the hash map, so the
+    // graph is built again with other labels until the loop came first.
+    for round in 0..64u32 {
+        let (start, goal) = (2 * round, 2 * round + 1);
+        let mut graph: Graph<u32> = Graph::new();
+        graph.add_edge(start, start, 0);
+        graph.add_edge(start, goal, 1);
+
+        assert_eq!(plan(&graph, start, goal), (vec![start, goal], 1));
+    }
+}
+
+#[test]
+fn dstar_lite_plans_past_a_cycle_of_zero_cost() {
+    for round in 0..64u32 {
+        let (start, other, goal) = (3 * round, 3 * round + 1, 3 * round + 2);
+        let mut graph: Graph<u32> = Graph::new();
+        graph.add_edge(start, other, 0);
+        graph.add_edge(other, start, 0);
+        graph.add_edge(start, goal, 1);
+        graph.add_edge(other, goal, 1);
+
+        let (path, cost) = plan(&graph, start, goal);
+        assert_eq!(cost, 1);
+        assert!(path == vec![start, goal] || path == vec![start, other, goal]);
+    }
+}
+
+#[test]
+fn dstar_lite_plans_back_out_of_a_cycle_of_zero_cost() {
+    // Only the start leaves the cycle, which the walk may enter first.
+    for round in 0..64u32 {
+        let (start, goal) = (4 * round, 4 * round + 3);
+        let (second, third) = (4 * round + 1, 4 * round + 2);
+        let mut graph: Graph<u32> = Graph::new();
+        graph.add_edge(start, second, 0);
+        graph.add_edge(second, third, 0);
+        graph.add_edge(third, start, 0);
+        graph.add_edge(start, goal, 1);
+
+        assert_eq!(plan(&graph, start, goal), (vec![start, goal], 1));
+    }
+}
+
+#[test]
+fn dstar_lite_replans_as_cheaply_as_a_new_search() {
+    let mut graph = grid();
+    let goal = (SIDE - 1, SIDE - 1);
+    let mut planner = DStarLite::new(&graph, &(0, 0), &goal, manhattan).unwrap();
+
+    // The agent walks along its plan, and discovers after every step that
+    // the edges around the next node of the plan got more expensive or were
+    // blocked.
+    let mut step = 0;
+    while planner.start() != &goal {
+        let path = planner.plan().unwrap();
+        let expected = dijkstra(&graph, planner.start(), &goal).unwrap();
+        assert_eq!(path.cost, expected.cost);
+        assert_eq!(path.nodes.last(), Some(&goal));
+
+        let next = path.nodes[1];
+        planner.move_to(&next).unwrap();
+        step += 1;
+        if let Some(&ahead) = path.nodes.get(2) {
+            if ahead != goal && step % 2 == 0 {
+                graph.remove_edge(&next, &ahead);
+                planner.remove_edge(&next, &ahead).unwrap();
+            } else {
+                graph.add_edge(next, ahead, 4);
+                planner.update_edge(&next, &ahead, 4).unwrap();
+            }
+        }
+        assert!(step < 100);
+    }
+}
+
+#[test]
+fn dstar_lite_reports_the_goal_cut_off() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 1);
+    let mut planner = DStarLite::new(&graph, &"a", &"c", |_: &&str, _: &&str| 0).unwrap();
+    assert_eq!(planner.plan().unwrap().cost, 2);
+
+    planner.remove_edge(&"b", &"c").unwrap();
+    assert_eq!(planner.plan(), Err(PathError::NoPath));
+    // A new edge opens another way.
+    planner.update_edge(&"a", &"d", 3).unwrap();
+    planner.update_edge(&"d", &"c", 3).unwrap();
+    assert_eq!(planner.plan().unwrap().nodes, vec!["a", "d", "c"]);
+
+    assert_eq!(planner.move_to(&"z"), Err(PathError::UnknownSource));
+    let planner = DStarLite::new(&graph, &"a", &"z", |_: &&str, _: &&str| 0);
+    assert!(matches!(planner, Err(PathError::UnknownDestination)));
+}
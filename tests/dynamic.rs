@@ -0,0 +1,94 @@
+// The shortest paths kept up to date as the edges change, which must be the
+// same as the ones found by a new search.
+use dijkstra::{dijkstra_all, DynamicShortestPaths, Graph, PathError};
+
+// A directed ring of 30 nodes with chords, whose costs change below.
+fn network() -> Graph<u32> {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..30 {
+        graph.add_edge(node, (node + 1) % 30, 2 + node % 5);
+        graph.add_edge(node, (node * 7 + 4) % 30, 6 + node % 4);
+    }
+    graph
+}
+
+// Checks that the paths kept up to date are as cheap as the ones found
+// by a new search on the graph, and that the paths follow the edges.
+fn assert_up_to_date(paths: &DynamicShortestPaths<u32, u32>, graph: &Graph<u32>) {
+    let expected = dijkstra_all(graph, paths.source()).unwrap();
+    for node in graph.nodes() {
+        assert_eq!(paths.distance(node), expected.distance(node));
+        if let Some(&predecessor) = paths.predecessor(node) {
+            let cost = graph.edge_cost(&predecessor, node).unwrap();
+            assert_eq!(
+                paths.distance(node),
+                Some(paths.distance(&predecessor).unwrap() + cost)
+            );
+        }
+    }
+}
+
+#[test]
+fn repairs_agree_with_a_new_search() {
+    let mut graph = network();
+    let mut paths = DynamicShortestPaths::new(&graph, &0).unwrap();
+    assert_up_to_date(&paths, &graph);
+
+    // We change the costs of the edges in a fixed pseudo-random order,
+    // making some of them cheaper, others more expensive, and removing a
+    // few.
+    let mut state: u32 = 12345;
+    for _ in 0..200 {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        let from = (state >> 8) % 30;
+        let to = if state & 1 == 0 {
+            (from + 1) % 30
+        } else {
+            (from * 7 + 4) % 30
+        };
+        let cost = 1 + (state >> 16) % 15;
+        if cost > 12 {
+            graph.remove_edge(&from, &to);
+            paths.remove_edge(&from, &to).unwrap();
+        } else {
+            graph.add_edge(from, to, cost);
+            paths.update_edge(&from, &to, cost).unwrap();
+        }
+        assert_up_to_date(&paths, &graph);
+    }
+}
+
+#[test]
+fn removed_edges_cut_off_the_nodes_behind_them() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 1);
+    graph.add_edge("a", "c", 5);
+    graph.add_edge("c", "d", 1);
+    let mut paths = DynamicShortestPaths::new(&graph, &"a").unwrap();
+    assert_eq!(paths.distance(&"d"), Some(3));
+
+    paths.remove_edge(&"b", &"c").unwrap();
+    assert_eq!(paths.distance(&"d"), Some(6));
+    assert_eq!(paths.path_to(&"d").unwrap().nodes, vec!["a", "c", "d"]);
+    paths.remove_edge(&"a", &"c").unwrap();
+    assert_eq!(paths.distance(&"c"), None);
+    assert_eq!(paths.path_to(&"d"), Err(PathError::NoPath));
+    // Removing an edge the graph doesn't have changes nothing.
+    paths.remove_edge(&"d", &"a").unwrap();
+    assert_eq!(paths.tree().len(), 2);
+
+    // New edges may bring new nodes.
+    paths.update_edge(&"b", &"e", 2).unwrap();
+    paths.update_edge(&"e", &"d", 2).unwrap();
+    assert_eq!(paths.distance(&"d"), Some(5));
+    assert_eq!(paths.predecessor(&"d"), Some(&"e"));
+}
+
+#[test]
+fn dynamic_paths_need_a_known_source() {
+    assert_eq!(
+        DynamicShortestPaths::new(&network(), &99).unwrap_err(),
+        PathError::UnknownSource
+    );
+}
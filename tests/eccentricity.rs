@@ -0,0 +1,80 @@
+// The eccentricities of the nodes, and the diameter and radius of the graphs.
+use dijkstra::{
+    diameter, diameter_sampled, eccentricity, radius, radius_sampled, Graph, PathError,
+};
+
+// An undirected line a - b - c - d, with costs 1, 2 and 3.
+fn line() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new_undirected();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 2);
+    graph.add_edge("c", "d", 3);
+    graph
+}
+
+// A ring of 30 nodes with uneven costs, and a few shortcuts.
+fn ring() -> Graph<u32> {
+    let mut graph: Graph<u32> = Graph::new_undirected();
+    for node in 0..30 {
+        graph.add_edge(node, (node + 1) % 30, 1 + node % 5);
+    }
+    graph.add_edge(0, 15, 4);
+    graph.add_edge(7, 22, 9);
+    graph
+}
+
+#[test]
+fn eccentricity_is_the_cost_to_the_farthest_node() {
+    let graph = line();
+
+    assert_eq!(eccentricity(&graph, &"a"), Ok(6));
+    assert_eq!(eccentricity(&graph, &"b"), Ok(5));
+    assert_eq!(eccentricity(&graph, &"c"), Ok(3));
+    assert_eq!(eccentricity(&graph, &"d"), Ok(6));
+    assert_eq!(diameter(&graph), Ok(6));
+    assert_eq!(radius(&graph), Ok(3));
+}
+
+#[test]
+fn unreachable_nodes_make_the_eccentricity_infinite() {
+    // Only the hub reaches every other node.
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("hub", "a", 2);
+    graph.add_edge("hub", "b", 5);
+    graph.add_edge("a", "b", 1);
+
+    assert_eq!(eccentricity(&graph, &"hub"), Ok(3));
+    assert_eq!(eccentricity(&graph, &"a"), Err(PathError::NoPath));
+    assert_eq!(diameter(&graph), Err(PathError::NoPath));
+    assert_eq!(radius(&graph), Ok(3));
+
+    graph.add_node("lonely");
+    assert_eq!(radius(&graph), Err(PathError::NoPath));
+    assert_eq!(
+        eccentricity(&graph, &"nowhere"),
+        Err(PathError::UnknownSource)
+    );
+}
+
+#[test]
+fn graph_without_nodes_has_zero_diameter_and_radius() {
+    let graph: Graph<&str> = Graph::new();
+
+    assert_eq!(diameter(&graph), Ok(0));
+    assert_eq!(radius(&graph), Ok(0));
+}
+
+#[test]
+fn samples_bound_the_diameter_and_radius() {
+    let graph = ring();
+    let exact_diameter = diameter(&graph).unwrap();
+    let exact_radius = radius(&graph).unwrap();
+
+    for seed in 0..10 {
+        assert!(diameter_sampled(&graph, 5, seed).unwrap() <= exact_diameter);
+        assert!(radius_sampled(&graph, 5, seed).unwrap() >= exact_radius);
+    }
+    // One sample per node gives the exact measures.
+    assert_eq!(diameter_sampled(&graph, 30, 3), Ok(exact_diameter));
+    assert_eq!(radius_sampled(&graph, 100, 3), Ok(exact_radius));
+}
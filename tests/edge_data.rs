@@ -0,0 +1,46 @@
+// The data attached to the edges of a graph, alongside their costs.
+use dijkstra::{dijkstra, Graph};
+
+#[test]
+fn edge_data_is_attached_detached_and_updated() {
+    let mut graph: Graph<&str, u32, (), &str> = Graph::new();
+    graph.add_edge_with_data("a", "b", 2, "main street");
+    graph.add_edge("b", "c", 1);
+
+    assert_eq!(graph.edge_data(&"a", &"b"), Some(&"main street"));
+    assert_eq!(graph.edge_data(&"b", &"c"), None);
+    if let Some(data) = graph.edge_data_mut(&"a", &"b") {
+        *data = "high street";
+    }
+    assert_eq!(graph.remove_edge_data(&"a", &"b"), Some("high street"));
+    assert_eq!(graph.edge_data(&"a", &"b"), None);
+    assert_eq!(graph.edge_cost(&"a", &"b"), Some(2));
+}
+
+#[test]
+fn undirected_edge_data_is_shared_by_both_directions() {
+    let mut graph: Graph<&str, u32, (), &str> = Graph::new_undirected();
+    graph.add_edge_with_data("a", "b", 2, "bridge");
+
+    assert_eq!(graph.edge_data(&"b", &"a"), Some(&"bridge"));
+    assert_eq!(
+        graph.edge_data_entries().collect::<Vec<_>>(),
+        vec![(&"a", &"b", &"bridge")]
+    );
+    graph.remove_edge(&"b", &"a");
+    assert_eq!(graph.edge_data(&"a", &"b"), None);
+}
+
+#[test]
+fn path_edges_follow_the_path() {
+    let mut graph: Graph<&str, u32, (), &str> = Graph::new();
+    graph.add_edge_with_data("a", "b", 1, "left");
+    graph.add_edge("b", "c", 1);
+    graph.add_edge_with_data("a", "c", 5, "straight");
+
+    let path = dijkstra(&graph, &"a", &"c").unwrap();
+    assert_eq!(
+        graph.path_edges(&path).collect::<Vec<_>>(),
+        vec![(&"a", &"b", 1, Some(&"left")), (&"b", &"c", 1, None)]
+    );
+}
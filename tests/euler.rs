@@ -0,0 +1,120 @@
+// The walks following every edge of a graph exactly once.
+use dijkstra::{eulerian_circuit, eulerian_path, CsrGraph, EulerError, Graph, GraphLike};
+
+// Checks that the walk follows every edge of the graph once, every edge
+// starting where the previous one ended.
+fn assert_walk<G>(graph: &G, walk: &[(G::Node, G::Node, G::Weight)])
+where
+    G: GraphLike,
+    G::Node: Ord,
+{
+    for pair in walk.windows(2) {
+        assert!(pair[0].1 == pair[1].0);
+    }
+    let mut followed: Vec<_> = walk
+        .iter()
+        .map(|(from, to, cost)| (from.clone(), to.clone(), *cost))
+        .collect();
+    let mut edges: Vec<_> = graph
+        .edges()
+        .map(|(from, to, cost)| (from.clone(), to.clone(), cost))
+        .collect();
+    followed.sort_by(|first, second| (&first.0, &first.1).cmp(&(&second.0, &second.1)));
+    edges.sort_by(|first, second| (&first.0, &first.1).cmp(&(&second.0, &second.1)));
+    assert!(followed == edges);
+}
+
+#[test]
+fn circuit_follows_every_edge_back_to_the_start() {
+    // Three cycles sharing node 0, one of them through parallel edges.
+    let csr = CsrGraph::from_edges(vec![
+        (0, 1, 1),
+        (1, 2, 2),
+        (2, 0, 3),
+        (0, 3, 4),
+        (3, 4, 5),
+        (4, 0, 6),
+        (0, 5, 7),
+        (5, 0, 8),
+        (0, 5, 9),
+        (5, 0, 10),
+    ]);
+
+    let circuit = eulerian_circuit(&csr).unwrap();
+    assert_eq!(circuit.len(), 10);
+    assert_eq!(circuit[0].0, circuit[9].1);
+    assert_walk(&csr, &circuit);
+}
+
+#[test]
+fn path_goes_from_the_extra_outgoing_edge_to_the_extra_incoming_one() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 1);
+    graph.add_edge("c", "a", 1);
+    graph.add_edge("c", "d", 1);
+    graph.add_edge("d", "e", 1);
+
+    let path = eulerian_path(&graph).unwrap();
+    assert_eq!(path.first().unwrap().0, "c");
+    assert_eq!(path.last().unwrap().1, "e");
+    assert_walk(&graph, &path);
+}
+
+#[test]
+fn unbalanced_nodes_have_no_circuit() {
+    // The nodes of a CsrGraph come out in the order they were given.
+    let csr = CsrGraph::from_edges(vec![
+        ("a", "b", 1),
+        ("b", "c", 1),
+        ("c", "a", 1),
+        ("c", "d", 1),
+    ]);
+    assert_eq!(
+        eulerian_circuit(&csr),
+        Err(EulerError::Unbalanced {
+            node: "c",
+            incoming: 1,
+            outgoing: 2,
+        })
+    );
+    assert!(eulerian_path(&csr).is_ok());
+
+    // Two nodes with an extra outgoing edge are too many for a path.
+    let csr = CsrGraph::from_edges(vec![("a", "b", 1), ("c", "b", 1)]);
+    assert_eq!(
+        eulerian_path(&csr),
+        Err(EulerError::Unbalanced {
+            node: "b",
+            incoming: 2,
+            outgoing: 0,
+        })
+    );
+}
+
+#[test]
+fn separate_cycles_have_no_circuit() {
+    let csr = CsrGraph::from_edges(vec![
+        ("a", "b", 1),
+        ("b", "a", 1),
+        ("c", "d", 1),
+        ("d", "c", 1),
+    ]);
+
+    assert_eq!(
+        eulerian_circuit(&csr),
+        Err(EulerError::Disconnected {
+            first: "a",
+            second: "c",
+        })
+    );
+}
+
+#[test]
+fn graph_without_edges_has_an_empty_circuit() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_node("alone");
+
+    assert_eq!(eulerian_circuit(&graph), Ok(Vec::new()));
+    assert_eq!(eulerian_path(&graph), Ok(Vec::new()));
+}
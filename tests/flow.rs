@@ -0,0 +1,233 @@
+// The flows through graphs whose capacities are unsigned, as the default
+// costs of the graphs are.
+use dijkstra::{is_reachable, max_flow, min_cost_flow, min_cut, Graph, PathError};
+
+// A network of 6 nodes, 0 being the source and 5 the sink.
+fn network() -> Graph<u32> {
+    let mut graph: Graph<u32> = Graph::new();
+    for &(from, to, capacity) in &[
+        (0, 1, 7),
+        (0, 2, 4),
+        (1, 2, 3),
+        (1, 3, 5),
+        (2, 4, 6),
+        (3, 2, 2),
+        (3, 5, 4),
+        (4, 3, 3),
+        (4, 5, 5),
+    ] {
+        graph.add_edge(from, to, capacity);
+    }
+    graph
+}
+
+#[test]
+fn max_flow_with_unsigned_capacities() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("s", "a", 3);
+    graph.add_edge("a", "t", 2);
+    graph.add_edge("s", "b", 1);
+    graph.add_edge("b", "t", 4);
+
+    let flow = max_flow(&graph, &"s", &"t").unwrap();
+    assert_eq!(flow.value, 3);
+    let mut flows = flow.flows;
+    flows.sort();
+    assert_eq!(
+        flows,
+        vec![("a", "t", 2), ("b", "t", 1), ("s", "a", 2), ("s", "b", 1)]
+    );
+}
+
+#[test]
+fn min_cut_with_unsigned_capacities() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("s", "a", 3);
+    graph.add_edge("a", "t", 2);
+    graph.add_edge("s", "b", 1);
+    graph.add_edge("b", "t", 4);
+
+    let cut = min_cut(&graph, &"s", &"t").unwrap();
+    assert_eq!(cut.capacity, 3);
+    let mut source_side = cut.source_side;
+    source_side.sort();
+    assert_eq!(source_side, vec!["a", "s"]);
+    let mut edges = cut.edges;
+    edges.sort();
+    assert_eq!(edges, vec![("a", "t", 2), ("s", "b", 1)]);
+}
+
+#[test]
+fn min_cost_flow_with_unsigned_costs() {
+    // The costs are the weights of the edges, and the capacities their data.
+    let mut graph: Graph<&str, u32, (), u32> = Graph::new();
+    graph.add_edge_with_data("s", "a", 1, 2);
+    graph.add_edge_with_data("s", "b", 4, 2);
+    graph.add_edge_with_data("a", "b", 1, 1);
+    graph.add_edge_with_data("a", "t", 5, 2);
+    graph.add_edge_with_data("b", "t", 1, 2);
+
+    let flow = min_cost_flow(&graph, &"s", &"t", |from, to, _| {
+        *graph.edge_data(from, to).unwrap()
+    })
+    .unwrap();
+    assert_eq!(flow.value, 4);
+    // Both edges into the sink are saturated, so the shortcut from a to b
+    // cannot be used.
+    assert_eq!(flow.cost, 22);
+}
+
+#[test]
+fn max_flow_ignores_the_loops() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("s", "s", 5);
+    graph.add_edge("s", "t", 2);
+
+    let flow = max_flow(&graph, &"s", &"t").unwrap();
+    assert_eq!(flow.value, 2);
+    assert_eq!(flow.flows, vec![("s", "t", 2)]);
+}
+
+#[test]
+fn max_flow_equals_the_minimum_cut() {
+    let graph = network();
+    let flow = max_flow(&graph, &0, &5).unwrap();
+
+    // Every node but the source and the sink lets out what it receives, and
+    // no edge carries more than its capacity.
+    for node in 1..5 {
+        let received: u32 = flow
+            .flows
+            .iter()
+            .filter(|(_, to, _)| *to == node)
+            .map(|(_, _, amount)| amount)
+            .sum();
+        let sent: u32 = flow
+            .flows
+            .iter()
+            .filter(|(from, _, _)| *from == node)
+            .map(|(_, _, amount)| amount)
+            .sum();
+        assert_eq!(received, sent);
+    }
+    for (from, to, amount) in &flow.flows {
+        assert!(*amount <= graph.edge_cost(from, to).unwrap());
+    }
+
+    // The cheapest cut, over every set of nodes holding the source but not
+    // the sink.
+    let cheapest = (0..16u32)
+        .map(|set| {
+            let side = |node: u32| node == 0 || (node < 5 && set >> (node - 1) & 1 == 1);
+            graph
+                .edges()
+                .filter(|(from, to, _)| side(**from) && !side(**to))
+                .map(|(_, _, capacity)| capacity)
+                .sum::<u32>()
+        })
+        .min()
+        .unwrap();
+    assert_eq!(flow.value, cheapest);
+}
+
+#[test]
+fn max_flow_between_unknown_nodes() {
+    let graph = network();
+
+    assert_eq!(max_flow(&graph, &9, &5), Err(PathError::UnknownSource));
+    assert_eq!(max_flow(&graph, &0, &9), Err(PathError::UnknownDestination));
+    assert_eq!(max_flow(&graph, &5, &0).unwrap().value, 0);
+    assert_eq!(max_flow(&graph, &0, &0).unwrap().value, 0);
+}
+
+#[test]
+fn min_cut_separates_the_sink() {
+    let graph = network();
+    let cut = min_cut(&graph, &0, &5).unwrap();
+
+    assert_eq!(cut.capacity, max_flow(&graph, &0, &5).unwrap().value);
+    let capacity: u32 = cut.edges.iter().map(|(_, _, capacity)| capacity).sum();
+    assert_eq!(cut.capacity, capacity);
+    assert_eq!(cut.source_side.len() + cut.sink_side.len(), 6);
+    assert!(cut.source_side.contains(&0));
+    assert!(cut.sink_side.contains(&5));
+
+    // Without the edges of the cut, the sink cannot be reached anymore.
+    let rest = graph.filter_edges(|from, to, _| {
+        !cut.edges
+            .iter()
+            .any(|(cut_from, cut_to, _)| cut_from == from && cut_to == to)
+    });
+    assert!(!is_reachable(&rest, &0, &5));
+}
+
+#[test]
+fn min_cost_flow_ignores_the_loops() {
+    let mut graph: Graph<&str, u32, (), u32> = Graph::new();
+    graph.add_edge_with_data("s", "s", 5, 3);
+    graph.add_edge_with_data("s", "t", 2, 1);
+
+    let flow = min_cost_flow(&graph, &"s", &"t", |from, to, _| {
+        *graph.edge_data(from, to).unwrap()
+    })
+    .unwrap();
+    assert_eq!(flow.value, 1);
+    assert_eq!(flow.cost, 2);
+    assert_eq!(flow.flows, vec![("s", "t", 1)]);
+}
+
+#[test]
+fn min_cost_flow_fills_the_cheapest_routes_first() {
+    // The capacities are the data of the edges.
+    let mut graph: Graph<&str, u32, (), u32> = Graph::new();
+    graph.add_edge_with_data("s", "a", 1, 2);
+    graph.add_edge_with_data("a", "t", 1, 4);
+    graph.add_edge_with_data("s", "b", 5, 3);
+    graph.add_edge_with_data("b", "t", 3, 3);
+    graph.add_edge_with_data("b", "a", 0, 3);
+    graph.add_edge_with_data("s", "t", 10, 1);
+
+    let flow = min_cost_flow(&graph, &"s", &"t", |from, to, _| {
+        *graph.edge_data(from, to).unwrap()
+    })
+    .unwrap();
+    // The same value as a maximum flow, the detour through b and a being
+    // cheaper than the direct edge.
+    assert_eq!(flow.value, 6);
+    assert_eq!(flow.cost, 2 + 3 * 5 + 10 + 4 + 3);
+    let mut flows = flow.flows;
+    flows.sort();
+    assert_eq!(
+        flows,
+        vec![
+            ("a", "t", 4),
+            ("b", "a", 2),
+            ("b", "t", 1),
+            ("s", "a", 2),
+            ("s", "b", 3),
+            ("s", "t", 1),
+        ]
+    );
+}
+
+#[test]
+fn min_cost_flow_with_negative_costs() {
+    let mut graph: Graph<&str, i32, (), i32> = Graph::new();
+    graph.add_edge_with_data("s", "a", 4, 1);
+    graph.add_edge_with_data("a", "t", -3, 1);
+    graph.add_edge_with_data("s", "t", 2, 1);
+
+    let flow = min_cost_flow(&graph, &"s", &"t", |from, to, _| {
+        *graph.edge_data(from, to).unwrap()
+    })
+    .unwrap();
+    assert_eq!(flow.value, 2);
+    assert_eq!(flow.cost, 3);
+
+    // A cycle of negative cost which can carry some flow.
+    graph.add_edge_with_data("t", "a", 1, 1);
+    let flow = min_cost_flow(&graph, &"s", &"t", |from, to, _| {
+        *graph.edge_data(from, to).unwrap()
+    });
+    assert_eq!(flow, Err(PathError::NegativeCycle));
+}
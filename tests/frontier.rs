@@ -0,0 +1,80 @@
+// The priority queues picking the next node to settle.
+use dijkstra::{
+    dijkstra, dijkstra_with_frontier, BinaryHeapFrontier, BucketFrontier, Frontier, Graph,
+    PairingHeapFrontier, SearchWorkspace,
+};
+
+// Pushes and pops nodes in an order mixing both, checking that the nodes come
+// out by increasing cost.
+fn check_order<'a, F: Frontier<'a, &'static str, u32>>(mut frontier: F, nodes: &'a [&'static str]) {
+    frontier.push(&nodes[0], 0);
+    assert_eq!(frontier.pop(), Some((&nodes[0], 0)));
+    frontier.push(&nodes[1], 5);
+    frontier.push(&nodes[2], 3);
+    frontier.push(&nodes[3], 4);
+    assert_eq!(frontier.pop(), Some((&nodes[2], 3)));
+    frontier.push(&nodes[4], 6);
+    assert_eq!(frontier.pop(), Some((&nodes[3], 4)));
+    assert_eq!(frontier.pop(), Some((&nodes[1], 5)));
+    assert_eq!(frontier.pop(), Some((&nodes[4], 6)));
+    assert_eq!(frontier.pop(), None);
+}
+
+#[test]
+fn frontiers_pop_the_cheapest_node_first() {
+    let nodes = ["a", "b", "c", "d", "e"];
+    check_order(BinaryHeapFrontier::new(), &nodes);
+    check_order(BucketFrontier::new(5), &nodes);
+    check_order(PairingHeapFrontier::new(), &nodes);
+}
+
+#[test]
+fn bucket_frontier_accepts_a_cheaper_node_pushed_second() {
+    let nodes = ["a", "b"];
+    let mut frontier = BucketFrontier::new(5);
+    frontier.push(&nodes[0], 5u32);
+    frontier.push(&nodes[1], 3);
+    assert_eq!(frontier.pop(), Some((&nodes[1], 3)));
+    assert_eq!(frontier.pop(), Some((&nodes[0], 5)));
+    assert_eq!(frontier.pop(), None);
+}
+
+// A ring with chords, whose costs fit in the buckets used below.
+fn ring() -> Graph<u32> {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..20 {
+        graph.add_edge(node, (node + 1) % 20, 1 + node % 3);
+        graph.add_edge(node, (node + 7) % 20, 2 + node % 4);
+    }
+    graph
+}
+
+#[test]
+fn every_frontier_finds_the_paths_of_dijkstra() {
+    let graph = ring();
+
+    for destination in 0..20 {
+        let expected = dijkstra(&graph, &0, &destination).unwrap().cost;
+        let paths = [
+            dijkstra_with_frontier(&graph, &0, &destination, BinaryHeapFrontier::new()),
+            dijkstra_with_frontier(&graph, &0, &destination, BucketFrontier::new(5)),
+            dijkstra_with_frontier(&graph, &0, &destination, PairingHeapFrontier::new()),
+        ];
+        for path in &paths {
+            assert_eq!(path.as_ref().unwrap().cost, expected);
+        }
+    }
+}
+
+#[test]
+fn workspace_reuses_its_frontier() {
+    let graph = ring();
+    let mut workspace = SearchWorkspace::with_frontier(PairingHeapFrontier::new());
+
+    for destination in 0..20 {
+        assert_eq!(
+            workspace.dijkstra(&graph, &0, &destination).unwrap().cost,
+            dijkstra(&graph, &0, &destination).unwrap().cost
+        );
+    }
+}
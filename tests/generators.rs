@@ -0,0 +1,141 @@
+// The random and regular graphs generated to try the algorithms on.
+use dijkstra::generators::{barabasi_albert, gnp, grid};
+use dijkstra::{connected_components, dijkstra};
+
+#[test]
+fn gnp_is_reproducible() {
+    let graph = gnp(50, 0.1, 1..=9, 42);
+    let again = gnp(50, 0.1, 1..=9, 42);
+
+    let mut edges: Vec<(usize, usize, u32)> = graph
+        .edges()
+        .map(|(&from, &to, cost)| (from, to, cost))
+        .collect();
+    let mut other: Vec<(usize, usize, u32)> = again
+        .edges()
+        .map(|(&from, &to, cost)| (from, to, cost))
+        .collect();
+    edges.sort_unstable();
+    other.sort_unstable();
+    assert_eq!(edges, other);
+    assert_ne!(gnp(50, 0.1, 1..=9, 43).edges().count(), 0);
+}
+
+#[test]
+fn gnp_draws_about_the_expected_number_of_edges() {
+    let graph = gnp(200, 0.05, 3..=5, 7);
+
+    assert_eq!(graph.node_count(), 200);
+    // The average is 0.05 * 200 * 199 = 1990 edges.
+    let count = graph.edge_count();
+    assert!(count > 1_800 && count < 2_200, "{}", count);
+    for (from, to, cost) in graph.edges() {
+        assert_ne!(from, to);
+        assert!((3..=5).contains(&cost));
+    }
+}
+
+#[test]
+fn gnp_with_the_extreme_probabilities() {
+    let empty = gnp(10, 0.0, 1..=1, 1);
+    assert_eq!(empty.node_count(), 10);
+    assert_eq!(empty.edge_count(), 0);
+
+    let complete = gnp(10, 1.0, 2..=2, 1);
+    assert_eq!(complete.edge_count(), 90);
+    assert!(complete.edges().all(|(_, _, cost)| cost == 2));
+
+    assert_eq!(gnp(1, 1.0, 1..=1, 1).edge_count(), 0);
+    assert_eq!(gnp(0, 0.5, 1..=1, 1).node_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "p must be between 0 and 1")]
+fn gnp_rejects_probabilities_above_one() {
+    gnp(10, 1.5, 1..=1, 1);
+}
+
+#[test]
+fn barabasi_albert_attaches_every_new_node_to_m_nodes() {
+    let graph = barabasi_albert(300, 3, 1..=4, 5);
+
+    assert_eq!(graph.node_count(), 300);
+    // The complete graph on the first 4 nodes, then 3 edges for every other
+    // node, every edge being counted both ways.
+    assert_eq!(graph.edge_count(), 2 * (6 + 296 * 3));
+    assert_eq!(connected_components(&graph).count(), 1);
+    for node in graph.nodes() {
+        assert!(graph.neighbors(node).count() >= 3);
+    }
+    // A few hubs get far more edges than the others.
+    let largest = graph
+        .nodes()
+        .map(|node| graph.neighbors(node).count())
+        .max()
+        .unwrap();
+    assert!(largest > 20, "{}", largest);
+    assert!(graph.edges().all(|(_, _, cost)| (1..=4).contains(&cost)));
+}
+
+#[test]
+fn barabasi_albert_is_reproducible() {
+    let edges = |seed| {
+        let mut edges: Vec<(usize, usize, u32)> = barabasi_albert(60, 2, 1..=100, seed)
+            .edges()
+            .map(|(&from, &to, cost)| (from, to, cost))
+            .collect();
+        edges.sort_unstable();
+        edges
+    };
+
+    assert_eq!(edges(9), edges(9));
+    assert_ne!(edges(9), edges(10));
+    // With fewer nodes than m + 1, the graph is complete.
+    assert_eq!(barabasi_albert(3, 5, 1..=1, 1).edge_count(), 6);
+}
+
+#[test]
+#[should_panic(expected = "a new node must attach to at least one node")]
+fn barabasi_albert_rejects_m_of_zero() {
+    barabasi_albert(10, 0, 1..=1, 1);
+}
+
+#[test]
+fn grid_links_the_nodes_next_to_each_other() {
+    let graph = grid(4, 3, false, 1..=1, 0);
+
+    assert_eq!(graph.node_count(), 12);
+    // 9 edges along the rows and 8 along the columns, both ways.
+    assert_eq!(graph.edge_count(), 2 * 17);
+    assert_eq!(graph.edge_cost(&5, &6), Some(1));
+    assert_eq!(graph.edge_cost(&5, &9), Some(1));
+    assert_eq!(graph.edge_cost(&3, &4), None);
+    // The shortest path between opposite corners is the Manhattan distance.
+    assert_eq!(dijkstra(&graph, &0, &11).unwrap().cost, 5);
+}
+
+#[test]
+fn grid_with_diagonals() {
+    let graph = grid(4, 3, true, 1..=1, 0);
+
+    // Two diagonals in each of the 6 squares, both ways.
+    assert_eq!(graph.edge_count(), 2 * (17 + 12));
+    assert_eq!(graph.edge_cost(&5, &10), Some(1));
+    assert_eq!(graph.edge_cost(&6, &9), Some(1));
+    assert_eq!(graph.neighbors(&5).count(), 8);
+    assert_eq!(graph.neighbors(&0).count(), 3);
+    assert_eq!(dijkstra(&graph, &0, &11).unwrap().cost, 3);
+}
+
+#[test]
+fn grid_costs_are_drawn_from_the_range() {
+    let graph = grid(20, 20, true, 5..=8, 3);
+    assert!(graph.edges().all(|(_, _, cost)| (5..=8).contains(&cost)));
+    let same = grid(20, 20, true, 5..=8, 3);
+    assert!(graph
+        .edges()
+        .all(|(from, to, cost)| same.edge_cost(from, to) == Some(cost)));
+
+    assert_eq!(grid(0, 5, true, 1..=1, 0).node_count(), 0);
+    assert_eq!(grid(1, 1, true, 1..=1, 0).edge_count(), 0);
+}
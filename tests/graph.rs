@@ -0,0 +1,118 @@
+// Building graphs and reading them back.
+use dijkstra::{dijkstra, CsrGraph, DuplicateEdgePolicy, Graph};
+
+#[test]
+fn add_edge_replaces_the_cost() {
+    let mut graph: Graph<&str> = Graph::new();
+    assert_eq!(graph.add_edge("a", "b", 3), None);
+    assert_eq!(graph.add_edge("a", "b", 2), Some(3));
+    assert_eq!(graph.neighbors(&"a").collect::<Vec<_>>(), vec![(&"b", 2)]);
+}
+
+#[test]
+fn add_edge_adds_both_nodes() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_node("c");
+
+    assert!(graph.contains_node(&"a"));
+    assert!(graph.contains_node(&"b"));
+    assert!(graph.contains_node(&"c"));
+    assert!(!graph.contains_node(&"d"));
+    assert_eq!(graph.neighbors(&"b").count(), 0);
+    assert_eq!(graph.neighbors(&"d").count(), 0);
+}
+
+#[test]
+fn add_edge_with_policy_keeps_the_cheapest_cost() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 2);
+
+    let policy = DuplicateEdgePolicy::KeepMinimum;
+    assert_eq!(graph.add_edge_with_policy("a", "b", 5, policy), Some(2));
+    assert_eq!(graph.edge_cost(&"a", &"b"), Some(2));
+    assert_eq!(graph.add_edge_with_policy("a", "b", 1, policy), Some(2));
+    assert_eq!(graph.edge_cost(&"a", &"b"), Some(1));
+    assert_eq!(graph.add_edge_with_policy("b", "c", 7, policy), None);
+    assert_eq!(graph.edge_cost(&"b", &"c"), Some(7));
+}
+
+#[test]
+fn add_edge_with_policy_in_both_directions() {
+    let mut graph: Graph<&str> = Graph::new_undirected();
+    graph.add_edge("a", "b", 3);
+
+    graph.add_edge_with_policy("b", "a", 9, DuplicateEdgePolicy::KeepFirst);
+    assert_eq!(graph.edge_cost(&"a", &"b"), Some(3));
+    assert_eq!(graph.edge_cost(&"b", &"a"), Some(3));
+}
+
+#[test]
+fn remove_node_removes_its_edges() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 1);
+    graph.add_edge("a", "c", 5);
+
+    assert!(graph.remove_node(&"b"));
+    assert!(!graph.remove_node(&"b"));
+    assert!(!graph.contains_node(&"b"));
+    assert_eq!(graph.neighbors(&"a").collect::<Vec<_>>(), vec![(&"c", 5)]);
+    assert_eq!(dijkstra(&graph, &"a", &"c").unwrap().cost, 5);
+}
+
+#[test]
+fn remove_edge_keeps_the_nodes() {
+    let mut graph: Graph<&str> = Graph::new_undirected();
+    graph.add_edge("a", "b", 2);
+
+    assert_eq!(graph.remove_edge(&"b", &"a"), Some(2));
+    assert_eq!(graph.remove_edge(&"b", &"a"), None);
+    assert_eq!(graph.edge_cost(&"a", &"b"), None);
+    assert!(graph.contains_node(&"a"));
+    assert!(graph.contains_node(&"b"));
+}
+
+#[test]
+fn graphs_collected_from_edges() {
+    let edges = [("a", "b", 4), ("b", "c", 1), ("a", "b", 2)];
+
+    let graph: Graph<&str> = edges.iter().copied().collect();
+    assert_eq!(graph.edge_cost(&"a", &"b"), Some(2));
+    assert_eq!(dijkstra(&graph, &"a", &"c").unwrap().cost, 3);
+
+    let csr: CsrGraph<&str> = edges.iter().copied().collect();
+    assert_eq!(dijkstra(&csr, &"a", &"c").unwrap().cost, 3);
+}
+
+#[test]
+fn graph_extended_with_edges() {
+    let mut graph: Graph<&str> = Graph::new_undirected();
+    graph.extend(vec![("a", "b", 1), ("b", "c", 1)]);
+
+    assert_eq!(graph.edge_cost(&"c", &"b"), Some(1));
+}
+
+#[test]
+fn nodes_and_edges_of_a_graph() {
+    let mut graph: Graph<&str> = Graph::new_undirected();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 2);
+    graph.add_node("d");
+
+    let mut nodes: Vec<_> = graph.nodes().copied().collect();
+    nodes.sort();
+    assert_eq!(nodes, vec!["a", "b", "c", "d"]);
+    assert_eq!(graph.node_count(), 4);
+    // Every undirected edge is reported in both directions.
+    let mut edges: Vec<_> = graph
+        .edges()
+        .map(|(from, to, cost)| (*from, *to, cost))
+        .collect();
+    edges.sort();
+    assert_eq!(
+        edges,
+        vec![("a", "b", 1), ("b", "a", 1), ("b", "c", 2), ("c", "b", 2)]
+    );
+    assert_eq!(graph.edge_count(), 4);
+}
@@ -0,0 +1,65 @@
+// The algorithms running over a graph of the caller.
+use dijkstra::{dijkstra, GraphLike, PathError};
+
+// The numbers below a bound, every number leading to the next one for 2 and
+// to the number after the next one for 3.
+struct Numbers {
+    numbers: Vec<u32>,
+}
+
+impl GraphLike for Numbers {
+    type Node = u32;
+    type Weight = u32;
+
+    fn get_node(&self, node: &u32) -> Option<&u32> {
+        self.numbers.get(*node as usize)
+    }
+
+    fn neighbors<'a>(&'a self, node: &u32) -> impl Iterator<Item = (&'a u32, u32)> + 'a {
+        let node = *node as usize;
+        let next = self.numbers.get(node + 1).map(|next| (next, 2));
+        let skip = self.numbers.get(node + 2).map(|skip| (skip, 3));
+        next.into_iter().chain(skip)
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a u32> + 'a {
+        self.numbers.iter()
+    }
+}
+
+#[test]
+fn dijkstra_over_a_graph_like() {
+    let graph = Numbers {
+        numbers: (0..10).collect(),
+    };
+
+    assert!(graph.contains_node(&9));
+    assert!(!graph.contains_node(&10));
+    let path = dijkstra(&graph, &0, &4).unwrap();
+    assert_eq!(path.nodes, vec![0, 2, 4]);
+    assert_eq!(path.cost, 6);
+    assert_eq!(dijkstra(&graph, &4, &0), Err(PathError::NoPath));
+    assert_eq!(
+        dijkstra(&graph, &0, &10),
+        Err(PathError::UnknownDestination)
+    );
+}
+
+#[test]
+fn graph_like_lists_the_edges_of_its_nodes() {
+    let graph = Numbers {
+        numbers: (0..4).collect(),
+    };
+
+    assert_eq!(graph.node_count(), 4);
+    assert_eq!(graph.edge_count(), 5);
+    let mut edges: Vec<_> = graph
+        .edges()
+        .map(|(from, to, cost)| (*from, *to, cost))
+        .collect();
+    edges.sort();
+    assert_eq!(
+        edges,
+        vec![(0, 1, 2), (0, 2, 3), (1, 2, 2), (1, 3, 3), (2, 3, 2)]
+    );
+}
@@ -0,0 +1,58 @@
+// The summaries of the size and shape of the graphs.
+use dijkstra::{graph_stats, Graph, GraphStats};
+
+#[test]
+fn stats_describe_a_directed_graph() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 2);
+    graph.add_edge("a", "c", 4);
+    graph.add_edge("b", "c", 6);
+    graph.add_node("lonely");
+
+    assert_eq!(
+        graph.stats(),
+        GraphStats {
+            node_count: 4,
+            edge_count: 3,
+            in_degrees: vec![2, 1, 1],
+            out_degrees: vec![2, 1, 1],
+            min_weight: Some(2),
+            max_weight: Some(6),
+            mean_weight: Some(4.0),
+            density: 0.25,
+            isolated_nodes: 1,
+        }
+    );
+}
+
+#[test]
+fn undirected_edges_count_twice() {
+    let mut graph: Graph<&str> = Graph::new_undirected();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 3);
+
+    let stats = graph_stats(&graph);
+    assert_eq!(stats.edge_count, 4);
+    assert_eq!(stats.in_degrees, vec![0, 2, 1]);
+    assert_eq!(stats.out_degrees, stats.in_degrees);
+    assert_eq!(stats.mean_weight, Some(2.0));
+    assert_eq!(stats.density, 4.0 / 6.0);
+    assert_eq!(stats.isolated_nodes, 0);
+}
+
+#[test]
+fn graph_without_edges_has_no_costs() {
+    let mut graph: Graph<&str> = Graph::new();
+    assert_eq!(graph_stats(&graph).density, 0.0);
+
+    graph.add_node("a");
+    graph.add_node("b");
+    let stats = graph_stats(&graph);
+    assert_eq!(stats.node_count, 2);
+    assert_eq!(stats.in_degrees, vec![2]);
+    assert_eq!(stats.min_weight, None);
+    assert_eq!(stats.max_weight, None);
+    assert_eq!(stats.mean_weight, None);
+    assert_eq!(stats.density, 0.0);
+    assert_eq!(stats.isolated_nodes, 2);
+}
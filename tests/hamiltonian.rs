@@ -0,0 +1,98 @@
+// The paths and cycles going through every node of a graph exactly once.
+use dijkstra::{hamiltonian_cycle, hamiltonian_path, Graph, HamiltonianError};
+
+// The Petersen graph, which has a Hamiltonian path but no Hamiltonian cycle:
+// an outer ring of 5 nodes, an inner star of 5 nodes, and the spokes between
+// them.
+fn petersen() -> Graph<u32> {
+    let mut graph: Graph<u32> = Graph::new_undirected();
+    for node in 0..5 {
+        graph.add_edge(node, (node + 1) % 5, 1);
+        graph.add_edge(5 + node, 5 + (node + 2) % 5, 1);
+        graph.add_edge(node, 5 + node, 1);
+    }
+    graph
+}
+
+// Checks that the nodes follow the edges of the graph and that every node of
+// the graph is found among them exactly once.
+fn assert_visits_every_node(graph: &Graph<u32>, nodes: &[u32]) {
+    for pair in nodes.windows(2) {
+        assert!(graph.edge_cost(&pair[0], &pair[1]).is_some());
+    }
+    let mut sorted = nodes.to_vec();
+    sorted.sort_unstable();
+    let mut expected: Vec<u32> = graph.nodes().copied().collect();
+    expected.sort_unstable();
+    assert_eq!(sorted, expected);
+}
+
+#[test]
+fn petersen_graph_has_a_path_but_no_cycle() {
+    let graph = petersen();
+
+    let path = hamiltonian_path(&graph, 10).unwrap();
+    assert_visits_every_node(&graph, &path);
+    assert_eq!(
+        hamiltonian_cycle(&graph, 10),
+        Err(HamiltonianError::NotFound)
+    );
+}
+
+#[test]
+fn cycle_comes_back_to_its_first_node() {
+    // The cube, whose nodes are the corners numbered by their coordinates.
+    let mut graph: Graph<u32> = Graph::new_undirected();
+    for corner in 0..8 {
+        for axis in &[1, 2, 4] {
+            graph.add_edge(corner, corner ^ axis, 1);
+        }
+    }
+
+    let cycle = hamiltonian_cycle(&graph, 8).unwrap();
+    assert_eq!(cycle.len(), 9);
+    assert_eq!(cycle.first(), cycle.last());
+    assert_visits_every_node(&graph, &cycle[..8]);
+    assert!(graph.edge_cost(&cycle[7], &cycle[8]).is_some());
+}
+
+#[test]
+fn path_follows_the_direction_of_the_edges() {
+    let mut graph: Graph<u32> = Graph::new();
+    graph.add_edge(2, 0, 1);
+    graph.add_edge(0, 1, 1);
+    graph.add_edge(1, 3, 1);
+    graph.add_edge(3, 0, 1);
+
+    assert_eq!(hamiltonian_path(&graph, 4), Ok(vec![2, 0, 1, 3]));
+    assert_eq!(
+        hamiltonian_cycle(&graph, 4),
+        Err(HamiltonianError::NotFound)
+    );
+}
+
+#[test]
+fn search_is_limited_to_small_graphs() {
+    assert_eq!(
+        hamiltonian_path(&petersen(), 9),
+        Err(HamiltonianError::TooManyNodes {
+            count: 10,
+            max_nodes: 9,
+        })
+    );
+
+    let mut graph: Graph<u32> = Graph::new();
+    assert_eq!(hamiltonian_path(&graph, 0), Ok(Vec::new()));
+    assert_eq!(
+        hamiltonian_cycle(&graph, 0),
+        Err(HamiltonianError::NotFound)
+    );
+    graph.add_node(7);
+    assert_eq!(hamiltonian_path(&graph, 1), Ok(vec![7]));
+    assert_eq!(
+        hamiltonian_cycle(&graph, 1),
+        Err(HamiltonianError::NotFound)
+    );
+    graph.add_edge(7, 7, 1);
+    assert_eq!(hamiltonian_cycle(&graph, 1), Ok(vec![7, 7]));
+}
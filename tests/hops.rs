@@ -0,0 +1,79 @@
+// The shortest paths using a limited number of edges.
+use std::collections::HashMap;
+
+use dijkstra::{dijkstra, dijkstra_max_hops, Graph, PathError};
+
+// A line of cheap edges from a to e, with more expensive shortcuts.
+fn shortcuts() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 1);
+    graph.add_edge("c", "d", 1);
+    graph.add_edge("d", "e", 1);
+    graph.add_edge("a", "c", 3);
+    graph.add_edge("a", "e", 10);
+    graph
+}
+
+// The cost of the cheapest path from the source to every node using at most
+// the given number of edges, computed round after round as in Bellman-Ford.
+fn costs_within(graph: &Graph<u32>, source: u32, hops: usize) -> HashMap<u32, u32> {
+    let mut costs = HashMap::new();
+    costs.insert(source, 0);
+    for _ in 0..hops {
+        let mut next = costs.clone();
+        for (from, to, cost) in graph.edges() {
+            if let Some(&reached) = costs.get(from) {
+                let entry = next.entry(*to).or_insert(u32::MAX);
+                *entry = (*entry).min(reached + cost);
+            }
+        }
+        costs = next;
+    }
+    costs
+}
+
+#[test]
+fn fewer_hops_cost_more() {
+    let graph = shortcuts();
+
+    for &(hops, cost) in &[(4, 4), (3, 5), (2, 10), (1, 10)] {
+        let path = dijkstra_max_hops(&graph, &"a", &"e", hops).unwrap();
+        assert_eq!(path.cost, cost);
+        assert!(path.hops() <= hops);
+    }
+    assert_eq!(
+        dijkstra_max_hops(&graph, &"a", &"e", 0),
+        Err(PathError::NoPath)
+    );
+    assert_eq!(dijkstra_max_hops(&graph, &"a", &"a", 0).unwrap().cost, 0);
+}
+
+#[test]
+fn enough_hops_give_the_path_of_dijkstra() {
+    let graph = shortcuts();
+
+    let path = dijkstra_max_hops(&graph, &"a", &"e", 100).unwrap();
+    assert_eq!(path.nodes, dijkstra(&graph, &"a", &"e").unwrap().nodes);
+}
+
+#[test]
+fn dijkstra_max_hops_agrees_with_the_rounds() {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..15 {
+        graph.add_edge(node, (node + 1) % 15, 1);
+        graph.add_edge(node, (node + 4) % 15, 3 + node % 3);
+        graph.add_edge(node, (node * 2) % 15, 7);
+    }
+
+    for hops in 0..6 {
+        let costs = costs_within(&graph, 0, hops);
+        for destination in 0..15 {
+            let path = dijkstra_max_hops(&graph, &0, &destination, hops);
+            assert_eq!(
+                path.ok().map(|path| path.cost),
+                costs.get(&destination).copied()
+            );
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// The distances given by the hub labels, which must be the costs of the
+// paths found by dijkstra.
+use dijkstra::{dijkstra, ContractionHierarchy, Graph, HubLabels, PathError};
+
+// A directed grid of 8 by 8 nodes with uneven costs, some streets being one
+// way only, and a node which leads into it but cannot be reached.
+fn roads() -> Graph<(u32, u32)> {
+    let mut graph: Graph<(u32, u32)> = Graph::new();
+    for x in 0..8 {
+        for y in 0..8 {
+            if x + 1 < 8 {
+                graph.add_edge((x, y), (x + 1, y), 1 + (x + 2 * y) % 5);
+                graph.add_edge((x + 1, y), (x, y), 2 + (x * y) % 4);
+            }
+            if y + 1 < 8 {
+                graph.add_edge((x, y), (x, y + 1), 1 + (3 * x + y) % 4);
+                if x % 3 != 1 {
+                    graph.add_edge((x, y + 1), (x, y), 3);
+                }
+            }
+        }
+    }
+    graph.add_edge((9, 9), (4, 4), 1);
+    graph
+}
+
+#[test]
+fn hub_labels_agree_with_dijkstra() {
+    let graph = roads();
+    let labels = HubLabels::new(&graph).unwrap();
+
+    for source in graph.nodes() {
+        for destination in graph.nodes() {
+            assert_eq!(
+                labels.distance(source, destination),
+                dijkstra(&graph, source, destination).map(|path| path.cost)
+            );
+        }
+    }
+}
+
+#[test]
+fn hub_labels_from_a_hierarchy() {
+    let mut graph: Graph<u32> = Graph::new_undirected();
+    for node in 0..40 {
+        graph.add_edge(node, (node + 1) % 40, 1 + node % 6);
+        graph.add_edge(node, (node * 13) % 40, 8);
+    }
+    let hierarchy = ContractionHierarchy::new(&graph).unwrap();
+    let labels = HubLabels::from_hierarchy(&hierarchy).unwrap();
+
+    for source in 0..40 {
+        for destination in 0..40 {
+            assert_eq!(
+                labels.distance(&source, &destination),
+                hierarchy.query(&source, &destination).map(|path| path.cost)
+            );
+        }
+    }
+    // Every node is a hub of both its labels, and the labels are far
+    // smaller than the graph.
+    assert!(labels.average_label_size() >= 2.0);
+    assert!(labels.average_label_size() < 40.0);
+}
+
+#[test]
+fn hub_labels_distance_errors() {
+    let labels = HubLabels::new(&roads()).unwrap();
+
+    assert_eq!(labels.distance(&(0, 0), &(9, 9)), Err(PathError::NoPath));
+    assert_eq!(
+        labels.distance(&(20, 0), &(0, 0)),
+        Err(PathError::UnknownSource)
+    );
+    assert_eq!(
+        labels.distance(&(0, 0), &(20, 0)),
+        Err(PathError::UnknownDestination)
+    );
+    assert_eq!(labels.distance(&(5, 2), &(5, 2)), Ok(0));
+}
@@ -0,0 +1,114 @@
+// The paths enumerated by increasing cost.
+use dijkstra::{dijkstra, k_shortest_paths, CsrGraph, Graph, PathError};
+
+// A layered graph with no cycle, so that it has a finite number of paths
+// from a to z.
+fn layers() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    for &(source, destination, cost) in &[
+        ("a", "b", 1),
+        ("a", "c", 2),
+        ("a", "d", 4),
+        ("b", "e", 3),
+        ("b", "f", 1),
+        ("c", "e", 1),
+        ("c", "f", 5),
+        ("d", "f", 1),
+        ("e", "z", 2),
+        ("f", "z", 3),
+        ("b", "c", 1),
+    ] {
+        graph.add_edge(source, destination, cost);
+    }
+    graph
+}
+
+// Lists the costs of all the paths from the node to z, by brute force.
+fn all_costs(graph: &Graph<&'static str>, node: &'static str, cost: u32, costs: &mut Vec<u32>) {
+    if node == "z" {
+        costs.push(cost);
+        return;
+    }
+    for (neighbor, edge_cost) in graph.neighbors(&node) {
+        all_costs(graph, neighbor, cost + edge_cost, costs);
+    }
+}
+
+#[test]
+fn first_path_is_the_one_of_dijkstra() {
+    let graph = layers();
+
+    let first = k_shortest_paths(&graph, &"a", &"z")
+        .unwrap()
+        .next()
+        .unwrap();
+    let expected = dijkstra(&graph, &"a", &"z").unwrap();
+    assert_eq!(first.cost, expected.cost);
+    assert_eq!(first.nodes.first(), Some(&"a"));
+    assert_eq!(first.nodes.last(), Some(&"z"));
+}
+
+#[test]
+fn paths_come_by_increasing_cost() {
+    let graph = layers();
+    let mut expected = Vec::new();
+    all_costs(&graph, "a", 0, &mut expected);
+    expected.sort_unstable();
+
+    let paths: Vec<_> = k_shortest_paths(&graph, &"a", &"z").unwrap().collect();
+    let costs: Vec<u32> = paths.iter().map(|path| path.cost).collect();
+    assert_eq!(costs, expected);
+    for path in &paths {
+        let cost: u32 = path
+            .nodes
+            .windows(2)
+            .map(|pair| graph.edge_cost(&pair[0], &pair[1]).unwrap())
+            .sum();
+        assert_eq!(cost, path.cost);
+    }
+    // No path is yielded twice.
+    let mut nodes: Vec<_> = paths.iter().map(|path| path.nodes.clone()).collect();
+    nodes.sort();
+    nodes.dedup();
+    assert_eq!(nodes.len(), paths.len());
+}
+
+#[test]
+fn cycles_give_endless_paths() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "a", 1);
+
+    let costs: Vec<u32> = k_shortest_paths(&graph, &"a", &"b")
+        .unwrap()
+        .take(4)
+        .map(|path| path.cost)
+        .collect();
+    assert_eq!(costs, vec![1, 3, 5, 7]);
+}
+
+#[test]
+fn parallel_edges_give_distinct_paths() {
+    let csr = CsrGraph::from_edges(vec![("a", "b", 2), ("a", "b", 3), ("b", "c", 1)]);
+
+    let costs: Vec<u32> = k_shortest_paths(&csr, &"a", &"c")
+        .unwrap()
+        .map(|path| path.cost)
+        .collect();
+    assert_eq!(costs, vec![3, 4]);
+}
+
+#[test]
+fn k_shortest_paths_without_any_path() {
+    let mut graph = layers();
+    graph.add_node("lonely");
+
+    assert_eq!(
+        k_shortest_paths(&graph, &"a", &"lonely").unwrap().count(),
+        0
+    );
+    assert_eq!(
+        k_shortest_paths(&graph, &"a", &"nowhere").err(),
+        Some(PathError::UnknownDestination)
+    );
+}
@@ -0,0 +1,81 @@
+// The A* searches guided by the lower bounds of the landmarks.
+use dijkstra::{dijkstra, Graph, Landmarks, PathError};
+
+// A directed grid of 8 by 8 nodes with uneven costs, and a node which leads
+// into it but cannot be reached.
+fn roads() -> Graph<(u32, u32)> {
+    let mut graph: Graph<(u32, u32)> = Graph::new();
+    for x in 0..8 {
+        for y in 0..8 {
+            if x + 1 < 8 {
+                graph.add_edge((x, y), (x + 1, y), 1 + (x + 2 * y) % 5);
+                graph.add_edge((x + 1, y), (x, y), 2 + (x * y) % 4);
+            }
+            if y + 1 < 8 {
+                graph.add_edge((x, y), (x, y + 1), 1 + (3 * x + y) % 4);
+                if x % 3 != 1 {
+                    graph.add_edge((x, y + 1), (x, y), 3);
+                }
+            }
+        }
+    }
+    graph.add_edge((9, 9), (4, 4), 1);
+    graph
+}
+
+#[test]
+fn landmarks_agree_with_dijkstra() {
+    let graph = roads();
+    let landmarks = Landmarks::new(&graph, &graph.reversed(), 4).unwrap();
+    assert_eq!(landmarks.landmarks().len(), 4);
+
+    let nodes: Vec<(u32, u32)> = graph.nodes().copied().collect();
+    for source in nodes.iter().step_by(5) {
+        for destination in &nodes {
+            let path = landmarks.shortest_path(&graph, source, destination);
+            let expected = dijkstra(&graph, source, destination);
+            assert_eq!(path.map(|path| path.cost), expected.map(|path| path.cost));
+        }
+    }
+}
+
+#[test]
+fn lower_bounds_never_exceed_the_costs() {
+    let graph = roads();
+    let landmarks = Landmarks::new(&graph, &graph.reversed(), 3).unwrap();
+
+    for destination in graph.nodes().filter(|&&node| node != (9, 9)) {
+        let cost = dijkstra(&graph, &(0, 0), destination).unwrap().cost;
+        assert!(landmarks.lower_bound(&(0, 0), destination) <= cost);
+    }
+    assert_eq!(landmarks.lower_bound(&(0, 0), &(0, 0)), 0);
+    assert_eq!(landmarks.lower_bound(&(0, 0), &(20, 20)), 0);
+}
+
+#[test]
+fn landmarks_settle_fewer_nodes_than_dijkstra() {
+    let graph = roads();
+    let landmarks = Landmarks::new(&graph, &graph.reversed(), 6).unwrap();
+
+    let guided = landmarks.shortest_path(&graph, &(0, 0), &(7, 7)).unwrap();
+    let plain = dijkstra(&graph, &(0, 0), &(7, 7)).unwrap();
+    assert_eq!(guided.cost, plain.cost);
+    assert!(guided.settled.unwrap() <= plain.settled.unwrap());
+}
+
+#[test]
+fn every_node_is_a_landmark_of_a_small_graph() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 2);
+    graph.add_edge("b", "c", 3);
+
+    let landmarks = Landmarks::new(&graph, &graph.reversed(), 10).unwrap();
+    let mut picked = landmarks.landmarks().to_vec();
+    picked.sort_unstable();
+    assert_eq!(picked, vec!["a", "b", "c"]);
+    assert_eq!(landmarks.lower_bound(&"a", &"c"), 5);
+    assert_eq!(
+        landmarks.shortest_path(&graph, &"c", &"a"),
+        Err(PathError::NoPath)
+    );
+}
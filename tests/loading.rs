@@ -0,0 +1,65 @@
+// The edge lists read in parallel with the "rayon" feature.
+#![cfg(feature = "rayon")]
+
+use std::path::PathBuf;
+
+use dijkstra::{read_edge_list, EdgeListError, Graph};
+
+// Writes the text to a file of the temporary directory, named after the test.
+fn write_file(name: &str, text: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("dijkstra-{}-{}.txt", name, std::process::id()));
+    std::fs::write(&path, text).unwrap();
+    path
+}
+
+// A chain of nodes long enough to be cut into several chunks, with comments
+// and a repeated edge whose last cost must be kept.
+fn chain() -> String {
+    let mut text = String::from("# a long chain\n");
+    for node in 0..200_000 {
+        text.push_str(&format!("n{} n{} {}\n", node, node + 1, 1 + node % 3));
+    }
+    text.push_str("n0 n1 7\n");
+    text
+}
+
+#[test]
+fn parallel_loading_builds_the_graph_of_the_edge_list() {
+    let text = chain();
+    let path = write_file("chain", &text);
+    let graph: Graph<String> = Graph::from_file_parallel(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let expected: Graph<String> = read_edge_list(text.as_bytes())
+        .collect::<Result<_, EdgeListError>>()
+        .unwrap();
+    assert_eq!(graph.node_count(), expected.node_count());
+    assert_eq!(graph.edge_count(), expected.edge_count());
+    for (source, destination, cost) in expected.edges() {
+        assert_eq!(graph.edge_cost(source, destination), Some(cost));
+    }
+    assert_eq!(
+        graph.edge_cost(&"n0".to_string(), &"n1".to_string()),
+        Some(7)
+    );
+}
+
+#[test]
+fn parallel_loading_reports_the_line_of_the_first_error() {
+    let mut text = chain();
+    text.push_str("n5 n6\n");
+    let path = write_file("broken", &text);
+    let error = Graph::<String>::from_file_parallel(&path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+
+    match error {
+        EdgeListError::MissingField { line } => assert_eq!(line, 200_003),
+        error => panic!("unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn parallel_loading_of_a_missing_file() {
+    let error = Graph::<String>::from_file_parallel("/nonexistent/edges.txt").unwrap_err();
+    assert!(matches!(error, EdgeListError::Io(_)));
+}
@@ -0,0 +1,65 @@
+// The searches towards several destinations at once.
+use dijkstra::{dijkstra, dijkstra_to_many, Graph, PathError, SearchWorkspace};
+
+// A star around a, plus a node which can't be reached.
+fn star() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("a", "c", 4);
+    graph.add_edge("b", "d", 2);
+    graph.add_edge("d", "a", 1);
+    graph.add_node("e");
+    graph
+}
+
+#[test]
+fn dijkstra_to_many_agrees_with_dijkstra() {
+    let graph = star();
+    let destinations = ["d", "a", "c", "b"];
+
+    let paths = dijkstra_to_many(&graph, &"a", &destinations);
+    assert_eq!(paths.len(), destinations.len());
+    for (path, destination) in paths.iter().zip(&destinations) {
+        let expected = dijkstra(&graph, &"a", destination).unwrap();
+        let path = path.as_ref().unwrap();
+        assert_eq!(path.nodes, expected.nodes);
+        assert_eq!(path.cost, expected.cost);
+    }
+}
+
+#[test]
+fn dijkstra_to_many_reports_each_failure() {
+    let paths = dijkstra_to_many(&star(), &"a", &["e", "z", "b"]);
+
+    assert_eq!(paths[0], Err(PathError::NoPath));
+    assert_eq!(paths[1], Err(PathError::UnknownDestination));
+    assert_eq!(paths[2].as_ref().unwrap().cost, 1);
+}
+
+#[test]
+fn dijkstra_to_many_from_an_unknown_source() {
+    let paths = dijkstra_to_many(&star(), &"z", &["a", "b"]);
+
+    assert_eq!(paths, vec![Err(PathError::UnknownSource); 2]);
+}
+
+#[test]
+fn workspace_answers_several_multi_target_queries() {
+    let graph = star();
+    let mut workspace = SearchWorkspace::new();
+
+    for source in &["a", "b", "d"] {
+        let destinations = ["a", "b", "c", "d", "e"];
+        let paths = workspace.dijkstra_to_many(&graph, source, &destinations);
+        for (path, destination) in paths.iter().zip(&destinations) {
+            assert_eq!(
+                path.as_ref().map(|path| path.cost),
+                dijkstra(&graph, source, destination)
+                    .as_ref()
+                    .map(|path| path.cost)
+            );
+        }
+    }
+    // The workspace still answers the plain queries afterwards.
+    assert_eq!(workspace.dijkstra(&graph, &"b", &"c").unwrap().cost, 7);
+}
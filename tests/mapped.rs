@@ -0,0 +1,89 @@
+// The graphs saved in binary form and queried in place.
+use dijkstra::{BinaryFormatError, CsrGraph, MappedGraph, PathError};
+
+// A small road network, saved in binary form.
+fn saved() -> (CsrGraph<&'static str>, Vec<u8>) {
+    let csr = CsrGraph::from_edges(vec![
+        ("paris", "lyon", 4),
+        ("paris", "lille", 2),
+        ("lille", "lyon", 5),
+        ("lyon", "nice", 3),
+        ("nice", "paris", 9),
+    ]);
+    let mut bytes = Vec::new();
+    csr.write_binary(&mut bytes).unwrap();
+    (csr, bytes)
+}
+
+#[test]
+fn mapped_graph_reads_the_saved_graph() {
+    let (_, bytes) = saved();
+    let mapped = MappedGraph::new(&bytes).unwrap();
+
+    assert_eq!(mapped.node_count(), 4);
+    assert_eq!(mapped.edge_count(), 5);
+    let mut nodes: Vec<&str> = mapped.nodes().collect();
+    nodes.sort_unstable();
+    assert_eq!(nodes, vec!["lille", "lyon", "nice", "paris"]);
+
+    let index = mapped.index_of("lyon").unwrap();
+    assert_eq!(mapped.node(index), Some("lyon"));
+    assert_eq!(mapped.index_of("rome"), None);
+    let mut neighbors: Vec<(&str, u32)> = mapped.neighbors("paris").collect();
+    neighbors.sort_unstable();
+    assert_eq!(neighbors, vec![("lille", 2), ("lyon", 4)]);
+    assert_eq!(mapped.neighbors("rome").count(), 0);
+}
+
+#[test]
+fn mapped_graph_finds_the_paths_of_the_csr_graph() {
+    let (csr, bytes) = saved();
+    let mapped = MappedGraph::new(&bytes).unwrap();
+
+    for source in mapped.nodes() {
+        for destination in mapped.nodes() {
+            let found = mapped.shortest_path(source, destination).unwrap();
+            let expected = csr.shortest_path(&source, &destination).unwrap();
+            assert_eq!(found.cost, expected.cost);
+            assert_eq!(found.nodes, expected.nodes);
+        }
+    }
+    assert_eq!(
+        mapped.shortest_path("paris", "rome"),
+        Err(PathError::UnknownDestination)
+    );
+}
+
+#[test]
+fn mapped_graph_rejects_broken_bytes() {
+    let (_, bytes) = saved();
+
+    assert_eq!(
+        MappedGraph::new(&bytes[..20]).unwrap_err(),
+        BinaryFormatError::Truncated
+    );
+    assert_eq!(
+        MappedGraph::new(&bytes[..bytes.len() - 1]).unwrap_err(),
+        BinaryFormatError::Truncated
+    );
+
+    // The header claims one edge less than the offsets hold.
+    let mut wrong = bytes.clone();
+    wrong[24] -= 1;
+    assert_eq!(
+        MappedGraph::new(&wrong).unwrap_err(),
+        BinaryFormatError::Inconsistent
+    );
+    let mut wrong = bytes.clone();
+    wrong[0] = b'X';
+    assert_eq!(
+        MappedGraph::new(&wrong).unwrap_err(),
+        BinaryFormatError::BadMagic
+    );
+    let mut wrong = bytes;
+    wrong[8] = 2;
+    assert_eq!(
+        MappedGraph::new(&wrong).unwrap_err(),
+        BinaryFormatError::UnsupportedVersion(2)
+    );
+}
@@ -0,0 +1,68 @@
+// The searches starting from several sources at once.
+use dijkstra::{
+    dijkstra, multi_source_dijkstra, multi_source_dijkstra_with_offsets, Graph, PathError,
+};
+
+// A street of ten houses, with a shop at both ends, plus a house which can't
+// be reached.
+fn street() -> Graph<u32> {
+    let mut graph: Graph<u32> = Graph::new_undirected();
+    for house in 0..9 {
+        graph.add_edge(house, house + 1, 1 + house % 2);
+    }
+    graph.add_node(10);
+    graph
+}
+
+#[test]
+fn every_node_gets_its_nearest_source() {
+    let graph = street();
+    let nearest = multi_source_dijkstra(&graph, &[0, 9]).unwrap();
+    assert_eq!(nearest.len(), 10);
+    assert!(!nearest.contains(&10));
+
+    for (node, source, cost) in nearest.iter() {
+        let from_first = dijkstra(&graph, &0, node).unwrap().cost;
+        let from_last = dijkstra(&graph, &9, node).unwrap().cost;
+        assert_eq!(cost, from_first.min(from_last));
+        let expected = if from_first <= from_last { 0 } else { 9 };
+        if from_first != from_last {
+            assert_eq!(*source, expected);
+        }
+    }
+    assert_eq!(nearest.nearest_source(&2), Some(&0));
+    assert_eq!(nearest.nearest_source(&8), Some(&9));
+    assert_eq!(nearest.distance(&8), Some(1));
+}
+
+#[test]
+fn paths_start_from_the_nearest_source() {
+    let nearest = multi_source_dijkstra(&street(), &[0, 9]).unwrap();
+
+    let path = nearest.path_to(&7).unwrap();
+    assert_eq!(path.nodes, vec![9, 8, 7]);
+    assert_eq!(path.cost, 3);
+    assert_eq!(nearest.path_to(&10), Err(PathError::NoPath));
+}
+
+#[test]
+fn offsets_are_added_to_the_paths_of_their_source() {
+    let nearest = multi_source_dijkstra_with_offsets(&street(), &[(0, 20), (9, 0)]).unwrap();
+
+    // The first shop is so slow that even its own house is better served by
+    // the other shop.
+    assert_eq!(nearest.nearest_source(&0), Some(&9));
+    assert_eq!(nearest.distance(&0), Some(13));
+    assert_eq!(nearest.path_to(&0).unwrap().nodes.len(), 10);
+}
+
+#[test]
+fn multi_source_without_sources() {
+    let graph = street();
+
+    assert!(multi_source_dijkstra(&graph, &[]).unwrap().is_empty());
+    assert_eq!(
+        multi_source_dijkstra(&graph, &[0, 42]).err(),
+        Some(PathError::UnknownSource)
+    );
+}
@@ -0,0 +1,34 @@
+// The multigraphs, keeping the parallel edges between the same nodes.
+use dijkstra::{dijkstra, MultiGraph};
+
+#[test]
+fn multigraph_keeps_the_parallel_edges() {
+    let mut graph = MultiGraph::new();
+    let highway = graph.add_edge("a", "b", 5);
+    let road = graph.add_edge("a", "b", 3);
+    let bridge = graph.add_edge("b", "c", 1);
+
+    assert_eq!(
+        graph.parallel_edges(&"a", &"b").collect::<Vec<_>>(),
+        vec![(highway, 5), (road, 3)]
+    );
+    assert_eq!(graph.cheapest_edge(&"a", &"b"), Some((road, 3)));
+
+    let path = dijkstra(&graph, &"a", &"c").unwrap();
+    assert_eq!(path.cost, 4);
+    assert_eq!(graph.path_edges(&path.nodes), Some(vec![road, bridge]));
+}
+
+#[test]
+fn multigraph_falls_back_on_the_remaining_edges() {
+    let mut graph = MultiGraph::new();
+    let highway = graph.add_edge("a", "b", 5);
+    let road = graph.add_edge("a", "b", 3);
+
+    assert_eq!(graph.remove_edge(road), Some(("a", "b", 3)));
+    assert_eq!(graph.remove_edge(road), None);
+    assert_eq!(graph.edge(road), None);
+    assert_eq!(graph.edge(highway), Some((&"a", &"b", 5)));
+    assert_eq!(dijkstra(&graph, &"a", &"b").unwrap().cost, 5);
+    assert_eq!(graph.path_edges(&["b", "a"]), None);
+}
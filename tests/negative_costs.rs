@@ -0,0 +1,209 @@
+// The searches supporting negative costs.
+use dijkstra::{bellman_ford, find_negative_cycle, johnson, spfa, Graph, GraphLike, PathError};
+
+// A graph listing its nodes and its edges, the edges being allowed to lead
+// out of the graph.
+struct EdgeList {
+    nodes: Vec<&'static str>,
+    edges: Vec<(&'static str, &'static str, i32)>,
+}
+
+impl GraphLike for EdgeList {
+    type Node = &'static str;
+    type Weight = i32;
+
+    fn get_node(&self, node: &&'static str) -> Option<&&'static str> {
+        self.nodes.iter().find(|other| *other == node)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        node: &&'static str,
+    ) -> impl Iterator<Item = (&'a &'static str, i32)> + 'a {
+        let node = *node;
+        self.edges
+            .iter()
+            .filter(move |(from, _, _)| *from == node)
+            .map(|(_, to, cost)| (to, *cost))
+    }
+
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a &'static str> + 'a {
+        self.nodes.iter()
+    }
+}
+
+#[test]
+fn edge_leading_out_of_the_graph_is_not_a_negative_cycle() {
+    let graph = EdgeList {
+        nodes: vec!["a"],
+        edges: vec![("a", "x", -1)],
+    };
+
+    let tree = bellman_ford(&graph, &"a").unwrap();
+    assert_eq!(tree.distance(&"a"), Some(0));
+    assert_eq!(tree.distance(&"x"), Some(-1));
+    assert_eq!(
+        tree.distance(&"x"),
+        spfa(&graph, &"a").unwrap().distance(&"x")
+    );
+    assert_eq!(find_negative_cycle(&graph).unwrap(), None);
+}
+
+#[test]
+fn negative_cycle_is_found() {
+    let graph = EdgeList {
+        nodes: vec!["a", "b", "c"],
+        edges: vec![("a", "b", 1), ("b", "c", -3), ("c", "b", 1), ("c", "x", 2)],
+    };
+
+    assert_eq!(
+        bellman_ford(&graph, &"a").unwrap_err(),
+        PathError::NegativeCycle
+    );
+    let mut cycle = find_negative_cycle(&graph).unwrap().unwrap();
+    cycle.pop();
+    cycle.sort();
+    assert_eq!(cycle, vec!["b", "c"]);
+}
+
+// A graph whose cheapest paths go through negative edges.
+fn detours() -> Graph<&'static str, i32> {
+    let mut graph: Graph<&str, i32> = Graph::new();
+    graph.add_edge("a", "b", 4);
+    graph.add_edge("a", "c", 2);
+    graph.add_edge("c", "b", -3);
+    graph.add_edge("b", "d", 2);
+    graph.add_edge("d", "e", -1);
+    graph.add_node("f");
+    graph
+}
+
+#[test]
+fn bellman_ford_follows_the_negative_edges() {
+    let tree = bellman_ford(&detours(), &"a").unwrap();
+
+    assert_eq!(tree.source(), &"a");
+    assert_eq!(tree.distance(&"b"), Some(-1));
+    assert_eq!(tree.distance(&"e"), Some(0));
+    assert_eq!(tree.predecessor(&"b"), Some(&"c"));
+    assert_eq!(tree.predecessor(&"a"), None);
+    assert_eq!(tree.len(), 5);
+    assert!(!tree.contains(&"f"));
+
+    let path = tree.path_to(&"e").unwrap();
+    assert_eq!(path.nodes, vec!["a", "c", "b", "d", "e"]);
+    assert_eq!(path.cost, 0);
+    assert_eq!(tree.path_to(&"f"), Err(PathError::NoPath));
+}
+
+#[test]
+fn negative_cycle_out_of_reach_is_ignored() {
+    let mut graph = detours();
+    graph.add_edge("f", "g", -2);
+    graph.add_edge("g", "f", 1);
+
+    assert_eq!(bellman_ford(&graph, &"a").unwrap().distance(&"e"), Some(0));
+    assert_eq!(
+        bellman_ford(&graph, &"z").unwrap_err(),
+        PathError::UnknownSource
+    );
+}
+
+#[test]
+fn find_negative_cycle_looks_beyond_any_source() {
+    assert_eq!(find_negative_cycle(&detours()), Ok(None));
+
+    let mut graph = detours();
+    graph.add_edge("f", "g", -2);
+    graph.add_edge("g", "h", 0);
+    graph.add_edge("h", "f", 1);
+
+    let cycle = find_negative_cycle(&graph).unwrap().unwrap();
+    assert_eq!(cycle.first(), cycle.last());
+    assert_eq!(cycle.len(), 4);
+    let cost: i32 = cycle
+        .windows(2)
+        .map(|pair| graph.edge_cost(&pair[0], &pair[1]).unwrap())
+        .sum();
+    assert_eq!(cost, -1);
+}
+
+#[test]
+fn johnson_skips_the_edges_leading_out_of_the_graph() {
+    let graph = EdgeList {
+        nodes: vec!["a", "b"],
+        edges: vec![("a", "b", 2), ("b", "x", -4), ("a", "x", -1)],
+    };
+
+    let matrix = johnson(&graph).unwrap();
+    assert_eq!(matrix.distance(&"a", &"b"), Some(2));
+    assert_eq!(matrix.distance(&"b", &"a"), None);
+}
+
+#[test]
+fn johnson_reports_the_reweighted_costs_which_overflow() {
+    let graph = EdgeList {
+        nodes: vec!["a", "b", "c"],
+        edges: vec![("a", "b", -5), ("c", "b", i32::MAX)],
+    };
+
+    assert_eq!(johnson(&graph).unwrap_err(), PathError::Overflow);
+}
+
+#[test]
+fn johnson_reweights_the_negative_costs() {
+    let graph = EdgeList {
+        nodes: vec!["a", "b", "c", "d"],
+        edges: vec![
+            ("a", "b", 4),
+            ("a", "c", 1),
+            ("c", "b", -2),
+            ("b", "d", -3),
+            ("c", "d", 5),
+        ],
+    };
+
+    let matrix = johnson(&graph).unwrap();
+    assert_eq!(matrix.distance(&"a", &"b"), Some(-1));
+    assert_eq!(matrix.distance(&"a", &"d"), Some(-4));
+    assert_eq!(matrix.distance(&"c", &"d"), Some(-5));
+    assert_eq!(
+        matrix.shortest_path(&"a", &"d").unwrap().nodes,
+        vec!["a", "c", "b", "d"]
+    );
+}
+
+#[test]
+fn spfa_agrees_with_bellman_ford() {
+    let tree = spfa(&detours(), &"a").unwrap();
+    assert_eq!(tree.distance(&"b"), Some(-1));
+    assert_eq!(
+        tree.path_to(&"e").unwrap().nodes,
+        vec!["a", "c", "b", "d", "e"]
+    );
+
+    // A ring of refunds and charges, with chords jumping ahead.
+    let mut graph: Graph<u32, i32> = Graph::new();
+    for node in 0..50 {
+        let cost = if node % 3 == 0 { -2 } else { 5 };
+        graph.add_edge(node, (node + 1) % 50, cost);
+        graph.add_edge(node, (node * 7 + 3) % 50, 4 + node as i32 % 5);
+    }
+    for source in &[0, 17, 42] {
+        let expected = bellman_ford(&graph, source).unwrap();
+        let tree = spfa(&graph, source).unwrap();
+        for node in 0..50 {
+            assert_eq!(tree.distance(&node), expected.distance(&node));
+        }
+    }
+}
+
+#[test]
+fn spfa_finds_the_negative_cycles() {
+    let mut graph = detours();
+    graph.add_edge("e", "c", -1);
+
+    assert_eq!(spfa(&graph, &"a").unwrap_err(), PathError::NegativeCycle);
+    assert_eq!(spfa(&graph, &"f").unwrap().len(), 1);
+    assert_eq!(spfa(&graph, &"z").unwrap_err(), PathError::UnknownSource);
+}
@@ -0,0 +1,40 @@
+// The data attached to the nodes of a graph.
+use dijkstra::{dijkstra, Graph};
+
+#[test]
+fn node_data_is_attached_detached_and_updated() {
+    let mut graph: Graph<&str, u32, (f64, f64)> = Graph::new();
+    graph.add_edge("paris", "lyon", 465);
+    assert_eq!(graph.set_node_data("paris", (48.85, 2.35)), None);
+    assert_eq!(graph.set_node_data("lyon", (45.76, 4.84)), None);
+
+    assert_eq!(graph.node_data(&"paris"), Some(&(48.85, 2.35)));
+    if let Some(data) = graph.node_data_mut(&"lyon") {
+        data.0 = 45.75;
+    }
+    assert_eq!(graph.node_data(&"lyon"), Some(&(45.75, 4.84)));
+    assert_eq!(graph.remove_node_data(&"lyon"), Some((45.75, 4.84)));
+    assert_eq!(graph.node_data(&"lyon"), None);
+    assert!(graph.contains_node(&"lyon"));
+}
+
+#[test]
+fn path_data_follows_the_path() {
+    let mut graph: Graph<&str, u32, &str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 1);
+    graph.set_node_data("a", "start");
+    graph.set_node_data("c", "end");
+    // The data of a node is attached before it has any edge as well.
+    graph.set_node_data("d", "alone");
+
+    let path = dijkstra(&graph, &"a", &"c").unwrap();
+    let data: Vec<_> = graph.path_data(&path).collect();
+    assert_eq!(
+        data,
+        vec![(&"a", Some(&"start")), (&"b", None), (&"c", Some(&"end"))]
+    );
+    assert!(graph.contains_node(&"d"));
+    assert!(graph.remove_node(&"d"));
+    assert_eq!(graph.node_data(&"d"), None);
+}
@@ -0,0 +1,36 @@
+// The queries answered in parallel with the "rayon" feature.
+#![cfg(feature = "rayon")]
+
+use dijkstra::{dijkstra, par_shortest_paths, Graph, PathError};
+
+#[test]
+fn par_shortest_paths_agrees_with_dijkstra() {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..20 {
+        graph.add_edge(node, (node + 1) % 20, 2);
+        graph.add_edge(node, (node + 5) % 20, 7);
+    }
+    let queries: Vec<(u32, u32)> = (0..20)
+        .flat_map(|source| (0..20).map(move |destination| (source, destination)))
+        .collect();
+
+    let paths = par_shortest_paths(&graph, &queries);
+    assert_eq!(paths.len(), queries.len());
+    for (path, (source, destination)) in paths.iter().zip(&queries) {
+        assert_eq!(
+            path.as_ref().unwrap().cost,
+            dijkstra(&graph, source, destination).unwrap().cost
+        );
+    }
+}
+
+#[test]
+fn par_shortest_paths_keeps_the_failures_in_place() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+
+    let paths = par_shortest_paths(&graph, &[("b", "a"), ("a", "b"), ("z", "a")]);
+    assert_eq!(paths[0], Err(PathError::NoPath));
+    assert_eq!(paths[1].as_ref().unwrap().nodes, vec!["a", "b"]);
+    assert_eq!(paths[2], Err(PathError::UnknownSource));
+}
@@ -0,0 +1,109 @@
+// The paths which no other path beats on every objective.
+use dijkstra::{dijkstra, pareto_shortest_paths, Graph, PathError};
+
+// A network of 8 nodes whose edges carry a toll as data, the short edges
+// having the highest tolls.
+fn network() -> Graph<u32, u32, (), u32> {
+    let mut graph: Graph<u32, u32, (), u32> = Graph::new();
+    for from in 0..8 {
+        for step in 1..4 {
+            let to = (from + step) % 8;
+            let length = 1 + (from * 3 + step * 5) % 7;
+            graph.add_edge_with_data(from, to, length, 9 - length);
+        }
+    }
+    graph
+}
+
+// The costs of every simple path from the node to the destination, found by
+// trying all of them.
+fn every_path(
+    graph: &Graph<u32, u32, (), u32>,
+    node: u32,
+    destination: u32,
+    visited: &mut Vec<u32>,
+) -> Vec<[u32; 2]> {
+    if node == destination {
+        return vec![[0, 0]];
+    }
+    visited.push(node);
+    let mut costs = Vec::new();
+    for (next, length) in graph.neighbors(&node) {
+        if !visited.contains(next) {
+            let toll = *graph.edge_data(&node, next).unwrap();
+            for [rest_length, rest_toll] in every_path(graph, *next, destination, visited) {
+                costs.push([length + rest_length, toll + rest_toll]);
+            }
+        }
+    }
+    visited.pop();
+    costs
+}
+
+#[test]
+fn pareto_paths_agree_with_the_brute_force() {
+    let graph = network();
+
+    for destination in 1..8 {
+        let paths = pareto_shortest_paths(&graph, &0, &destination, |from, to, length| {
+            [length, *graph.edge_data(from, to).unwrap()]
+        })
+        .unwrap();
+
+        // The costs no other path beats on both objectives, once each.
+        let costs = every_path(&graph, 0, destination, &mut Vec::new());
+        let mut expected: Vec<[u32; 2]> = costs
+            .iter()
+            .filter(|cost| {
+                !costs
+                    .iter()
+                    .any(|other| other != *cost && other[0] <= cost[0] && other[1] <= cost[1])
+            })
+            .copied()
+            .collect();
+        expected.sort_unstable();
+        expected.dedup();
+        let found: Vec<[u32; 2]> = paths.iter().map(|path| path.costs).collect();
+        assert_eq!(found, expected);
+
+        for path in &paths {
+            assert_eq!(path.nodes.first(), Some(&0));
+            assert_eq!(path.nodes.last(), Some(&destination));
+        }
+    }
+}
+
+#[test]
+fn single_objective_gives_the_path_of_dijkstra() {
+    let graph = network();
+
+    for destination in 0..8 {
+        let paths =
+            pareto_shortest_paths(&graph, &0, &destination, |_, _, length| [length]).unwrap();
+        assert_eq!(paths.len(), 1);
+        assert_eq!(
+            paths[0].costs,
+            [dijkstra(&graph, &0, &destination).unwrap().cost]
+        );
+    }
+}
+
+#[test]
+fn pareto_paths_errors() {
+    let mut graph = network();
+    graph.add_node(8);
+    let objectives = |_: &u32, _: &u32, length: u32| [length, 1];
+
+    assert_eq!(
+        pareto_shortest_paths(&graph, &0, &8, objectives),
+        Err(PathError::NoPath)
+    );
+    assert_eq!(
+        pareto_shortest_paths(&graph, &9, &0, objectives),
+        Err(PathError::UnknownSource)
+    );
+    assert_eq!(
+        pareto_shortest_paths(&graph, &0, &9, objectives),
+        Err(PathError::UnknownDestination)
+    );
+}
@@ -0,0 +1,44 @@
+// The conversions from and to petgraph, with the "petgraph" feature.
+#![cfg(feature = "petgraph")]
+
+use dijkstra::{dijkstra, Graph, GraphKind};
+use petgraph::graph::{DiGraph, UnGraph};
+
+#[test]
+fn graph_converted_to_petgraph_keeps_the_shortest_paths() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 2);
+    graph.add_edge("a", "c", 5);
+
+    let converted = DiGraph::from(&graph);
+    assert_eq!(converted.node_count(), 3);
+    assert_eq!(converted.edge_count(), 3);
+    let start = converted
+        .node_indices()
+        .find(|&node| converted[node] == "a");
+    let costs = petgraph::algo::dijkstra(&converted, start.unwrap(), None, |edge| *edge.weight());
+    let end = converted
+        .node_indices()
+        .find(|&node| converted[node] == "c");
+    assert_eq!(
+        costs[&end.unwrap()],
+        dijkstra(&graph, &"a", &"c").unwrap().cost
+    );
+}
+
+#[test]
+fn petgraph_converted_to_a_graph_merges_the_equal_nodes() {
+    let mut petgraph: UnGraph<&str, u32> = UnGraph::new_undirected();
+    let a = petgraph.add_node("a");
+    let b = petgraph.add_node("b");
+    let other_a = petgraph.add_node("a");
+    petgraph.add_edge(a, b, 4);
+    petgraph.add_edge(b, other_a, 1);
+
+    let graph: Graph<&str> = Graph::from(&petgraph);
+    assert_eq!(graph.kind(), GraphKind::Undirected);
+    assert_eq!(graph.node_count(), 2);
+    // The parallel edges are merged, the last one being kept.
+    assert_eq!(graph.edge_cost(&"a", &"b"), Some(1));
+}
@@ -0,0 +1,98 @@
+// The paths most likely to succeed, given the probabilities of their edges.
+use dijkstra::{most_reliable_path, Graph, PathError};
+
+// A network of 8 nodes whose costs are the percentages of success of the
+// edges.
+fn network() -> Graph<u32> {
+    let mut graph: Graph<u32> = Graph::new();
+    for from in 0..8 {
+        for step in 1..4 {
+            let to = (from + step) % 8;
+            graph.add_edge(from, to, 100 - (from * 13 + step * 29) % 60);
+        }
+    }
+    graph
+}
+
+// The probability of success of the edge whose cost is a percentage.
+fn percentage(_: &u32, _: &u32, cost: u32) -> f64 {
+    f64::from(cost) / 100.0
+}
+
+// The highest reliability of the simple paths from the node to the
+// destination, found by trying all of them.
+fn brute_force(graph: &Graph<u32>, node: u32, destination: u32, visited: &mut Vec<u32>) -> f64 {
+    if node == destination {
+        return 1.0;
+    }
+    visited.push(node);
+    let mut best: f64 = 0.0;
+    for (next, cost) in graph.neighbors(&node) {
+        if !visited.contains(next) {
+            let rest = brute_force(graph, *next, destination, visited);
+            best = best.max(percentage(&node, next, cost) * rest);
+        }
+    }
+    visited.pop();
+    best
+}
+
+#[test]
+fn most_reliable_path_agrees_with_the_brute_force() {
+    let graph = network();
+
+    for destination in 0..8 {
+        let path = most_reliable_path(&graph, &0, &destination, percentage).unwrap();
+        let expected = brute_force(&graph, 0, destination, &mut Vec::new());
+        assert!((path.reliability - expected).abs() < 1e-9);
+
+        // The reliability is the product of the probabilities of the edges.
+        let product: f64 = path
+            .nodes
+            .windows(2)
+            .map(|pair| {
+                percentage(
+                    &pair[0],
+                    &pair[1],
+                    graph.edge_cost(&pair[0], &pair[1]).unwrap(),
+                )
+            })
+            .product();
+        assert!((path.reliability - product).abs() < 1e-9);
+    }
+    assert_eq!(
+        most_reliable_path(&graph, &3, &3, percentage)
+            .unwrap()
+            .reliability,
+        1.0
+    );
+}
+
+#[test]
+fn edges_which_never_succeed_are_not_followed() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 0);
+    graph.add_edge("a", "c", 50);
+    graph.add_edge("c", "b", 50);
+    let probability = |_: &&str, _: &&str, cost: u32| f64::from(cost) / 100.0;
+
+    let path = most_reliable_path(&graph, &"a", &"b", probability).unwrap();
+    assert_eq!(path.nodes, vec!["a", "c", "b"]);
+    assert_eq!(path.reliability, 0.25);
+
+    graph.add_edge("c", "b", 0);
+    assert_eq!(
+        most_reliable_path(&graph, &"a", &"b", probability).unwrap_err(),
+        PathError::NoPath
+    );
+    assert_eq!(
+        most_reliable_path(&graph, &"z", &"b", probability).unwrap_err(),
+        PathError::UnknownSource
+    );
+}
+
+#[test]
+#[should_panic(expected = "the probability of an edge must be between 0 and 1")]
+fn probabilities_above_one_are_rejected() {
+    most_reliable_path(&network(), &0, &7, |_, _, cost| f64::from(cost)).unwrap();
+}
@@ -0,0 +1,36 @@
+// The graphs serialized with the "serde" feature.
+#![cfg(feature = "serde")]
+
+use dijkstra::{FloatWeight, Graph, GraphKind};
+
+#[test]
+fn graph_survives_a_round_trip_through_json() {
+    let mut graph: Graph<String> = Graph::new_undirected();
+    graph.add_edge("a".to_string(), "b".to_string(), 3);
+    graph.add_node("c".to_string());
+
+    let json = serde_json::to_string(&graph).unwrap();
+    let back: Graph<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.kind(), GraphKind::Undirected);
+    assert_eq!(back.node_count(), 3);
+    assert_eq!(back.edge_cost(&"b".to_string(), &"a".to_string()), Some(3));
+}
+
+#[test]
+fn graph_deserialized_from_its_edges_only() {
+    let graph: Graph<u32> = serde_json::from_str(r#"{"edges": [[1, 2, 5]]}"#).unwrap();
+
+    assert_eq!(graph.kind(), GraphKind::Directed);
+    assert_eq!(graph.node_count(), 2);
+    assert_eq!(graph.edge_cost(&1, &2), Some(5));
+    assert_eq!(graph.edge_cost(&2, &1), None);
+}
+
+#[test]
+fn float_weights_are_checked_when_deserialized() {
+    let weight: FloatWeight = serde_json::from_str("1.5").unwrap();
+    assert_eq!(weight.get(), 1.5);
+    assert_eq!(serde_json::to_string(&weight).unwrap(), "1.5");
+
+    assert!(serde_json::from_str::<FloatWeight>("-1.5").is_err());
+}
@@ -0,0 +1,65 @@
+// The statistics returned along with the paths.
+use dijkstra::{bidirectional_dijkstra, dijkstra, CsrGraph, Graph, SearchStats};
+
+// A triangle whose direct edge from a to c is improved upon through b.
+fn triangle() -> Vec<(&'static str, &'static str, u32)> {
+    vec![("a", "b", 1), ("a", "c", 5), ("b", "c", 1)]
+}
+
+// What any search from a to c over the triangle does: settling a follows two
+// edges and queues b and c, then settling b improves the path to c, which is
+// queued again and settled.
+const TRIANGLE_STATS: SearchStats = SearchStats {
+    settled: 3,
+    relaxations: 3,
+    pushes: 4,
+};
+
+#[test]
+fn dijkstra_counts_what_it_explored() {
+    let graph: Graph<&str> = triangle().into_iter().collect();
+
+    let path = dijkstra(&graph, &"a", &"c").unwrap();
+    assert_eq!(path.stats, Some(TRIANGLE_STATS));
+    assert_eq!(path.settled, Some(3));
+}
+
+#[test]
+fn csr_shortest_path_counts_what_it_explored() {
+    let csr = CsrGraph::from_edges(triangle());
+
+    assert_eq!(
+        csr.shortest_path(&"a", &"c").unwrap().stats,
+        Some(TRIANGLE_STATS)
+    );
+}
+
+#[test]
+fn stats_of_a_path_to_the_source() {
+    let graph: Graph<&str> = triangle().into_iter().collect();
+
+    let stats = dijkstra(&graph, &"a", &"a").unwrap().stats.unwrap();
+    assert_eq!(
+        stats,
+        SearchStats {
+            settled: 1,
+            relaxations: 0,
+            pushes: 1,
+        }
+    );
+}
+
+#[test]
+fn bidirectional_stats_add_up_both_searches() {
+    let graph: Graph<&str> = triangle().into_iter().collect();
+    let reversed = graph.reversed();
+
+    let path = bidirectional_dijkstra(&graph, &reversed, &"a", &"c").unwrap();
+    let stats = path.stats.unwrap();
+    assert_eq!(path.settled, Some(stats.settled));
+    assert!(stats.pushes >= 2);
+    assert_eq!(
+        SearchStats::default() + stats + SearchStats::default(),
+        stats
+    );
+}
@@ -0,0 +1,76 @@
+// The static graphs loaded in one pass from streams of edges.
+use dijkstra::{dijkstra, read_edge_list, EdgeListError, StreamingBuilder};
+
+// An edge list with comments and blank lines.
+const ROADS: &str = "# source destination cost
+paris lyon 465
+
+lyon marseille 315
+paris marseille 900
+";
+
+#[test]
+fn edge_list_is_read_line_by_line() {
+    let edges: Vec<(String, String, u32)> = read_edge_list(ROADS.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(edges.len(), 3);
+    assert_eq!(edges[1], ("lyon".to_string(), "marseille".to_string(), 315));
+}
+
+#[test]
+fn edge_list_reports_the_broken_lines() {
+    let text = "a b 1\na b\na b x\na b 1 2\n";
+    let lines: Vec<Result<(String, String, u32), EdgeListError>> =
+        read_edge_list(text.as_bytes()).collect();
+
+    assert!(lines[0].is_ok());
+    assert!(matches!(
+        lines[1],
+        Err(EdgeListError::MissingField { line: 2 })
+    ));
+    assert!(matches!(
+        lines[2],
+        Err(EdgeListError::InvalidCost { line: 3 })
+    ));
+    assert!(matches!(
+        lines[3],
+        Err(EdgeListError::TrailingField { line: 4 })
+    ));
+}
+
+#[test]
+fn streaming_builder_loads_the_edge_list() {
+    let mut builder = StreamingBuilder::<String, u32>::new();
+    builder
+        .try_extend(read_edge_list(ROADS.as_bytes()))
+        .unwrap();
+    builder.add_node("nice".to_string());
+    assert_eq!((builder.node_count(), builder.edge_count()), (4, 3));
+
+    let graph = builder.build();
+    let path = dijkstra(&graph, &"paris".to_string(), &"marseille".to_string()).unwrap();
+    assert_eq!(path.cost, 780);
+    assert_eq!(path.hops(), 2);
+}
+
+#[test]
+fn streaming_builder_keeps_the_edges_before_an_error() {
+    let mut builder = StreamingBuilder::<String, u32>::new();
+    let result = builder.try_extend(read_edge_list("a b 1\nb c\nc d 1\n".as_bytes()));
+
+    assert!(result.is_err());
+    assert_eq!(builder.edge_count(), 1);
+}
+
+#[test]
+fn streaming_builder_reports_its_progress() {
+    let mut reports = Vec::new();
+    let mut builder = StreamingBuilder::new().on_progress(2, |received| reports.push(received));
+    builder.extend((0..5u32).map(|node| (node, node + 1, 1)));
+    let graph = builder.build();
+
+    assert_eq!(dijkstra(&graph, &0, &5).unwrap().cost, 5);
+    assert_eq!(reports, vec![2, 4]);
+}
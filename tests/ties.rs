@@ -0,0 +1,114 @@
+// The shortest paths tying for the lowest cost.
+use dijkstra::{
+    all_shortest_paths, dijkstra, k_shortest_paths, shortest_path_count, CsrGraph, Graph, PathError,
+};
+
+// A grid whose edges only go right or down, every step costing 1, so that
+// all the paths between two nodes are shortest paths.
+fn grid(width: u32, height: u32) -> Graph<(u32, u32)> {
+    let mut graph: Graph<(u32, u32)> = Graph::new();
+    for x in 0..=width {
+        for y in 0..=height {
+            if x < width {
+                graph.add_edge((x, y), (x + 1, y), 1);
+            }
+            if y < height {
+                graph.add_edge((x, y), (x, y + 1), 1);
+            }
+        }
+    }
+    graph
+}
+
+#[test]
+fn shortest_paths_across_a_grid() {
+    let graph = grid(4, 3);
+
+    // There are 7 choose 3 ways to interleave the steps.
+    assert_eq!(shortest_path_count(&graph, &(0, 0), &(4, 3)), Ok(35));
+    assert_eq!(shortest_path_count(&graph, &(0, 0), &(4, 0)), Ok(1));
+    assert_eq!(shortest_path_count(&graph, &(2, 2), &(2, 2)), Ok(1));
+    assert_eq!(
+        shortest_path_count(&graph, &(4, 3), &(0, 0)),
+        Err(PathError::NoPath)
+    );
+}
+
+#[test]
+fn shortest_path_count_agrees_with_k_shortest_paths() {
+    let mut graph = grid(3, 3);
+    graph.add_edge((0, 0), (2, 2), 4);
+    graph.add_edge((1, 0), (3, 3), 7);
+
+    let mut paths = k_shortest_paths(&graph, &(0, 0), &(3, 3)).unwrap();
+    let first = paths.next().unwrap().cost;
+    let tying = 1 + paths.take_while(|path| path.cost == first).count();
+    assert_eq!(shortest_path_count(&graph, &(0, 0), &(3, 3)), Ok(tying));
+}
+
+#[test]
+fn edges_of_zero_cost_are_counted() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 0);
+    graph.add_edge("b", "c", 1);
+    graph.add_edge("a", "c", 1);
+    graph.add_edge("c", "d", 0);
+    graph.add_edge("b", "d", 1);
+
+    assert_eq!(shortest_path_count(&graph, &"a", &"d"), Ok(3));
+
+    graph.add_edge("d", "c", 0);
+    assert_eq!(
+        shortest_path_count(&graph, &"a", &"d"),
+        Err(PathError::ZeroCostCycle)
+    );
+}
+
+#[test]
+fn all_shortest_paths_yields_every_tie_once() {
+    let graph = grid(3, 2);
+    let cost = dijkstra(&graph, &(0, 0), &(3, 2)).unwrap().cost;
+
+    let mut paths: Vec<Vec<(u32, u32)>> = all_shortest_paths(&graph, &(0, 0), &(3, 2))
+        .unwrap()
+        .map(|path| {
+            assert_eq!(path.cost, cost);
+            assert_eq!(path.nodes.first(), Some(&(0, 0)));
+            assert_eq!(path.nodes.last(), Some(&(3, 2)));
+            path.nodes
+        })
+        .collect();
+    assert_eq!(paths.len(), 10);
+    paths.sort();
+    paths.dedup();
+    assert_eq!(paths.len(), 10);
+}
+
+#[test]
+fn all_shortest_paths_over_parallel_edges() {
+    let csr = CsrGraph::from_edges(vec![
+        ("a", "b", 1),
+        ("a", "b", 1),
+        ("b", "c", 2),
+        ("a", "c", 4),
+    ]);
+
+    assert_eq!(all_shortest_paths(&csr, &"a", &"c").unwrap().count(), 2);
+    assert_eq!(shortest_path_count(&csr, &"a", &"c"), Ok(2));
+}
+
+#[test]
+fn all_shortest_paths_errors() {
+    let mut graph = grid(1, 1);
+    graph.add_edge((1, 0), (0, 1), 0);
+    graph.add_edge((0, 1), (1, 0), 0);
+
+    assert_eq!(
+        all_shortest_paths(&graph, &(0, 0), &(1, 1)).err(),
+        Some(PathError::ZeroCostCycle)
+    );
+    assert_eq!(
+        all_shortest_paths(&graph, &(0, 0), &(5, 5)).err(),
+        Some(PathError::UnknownDestination)
+    );
+}
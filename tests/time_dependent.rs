@@ -0,0 +1,99 @@
+// The travel times interpolated between breakpoints far apart, as the times
+// in milliseconds are.
+use dijkstra::{dijkstra, time_dependent_dijkstra, Graph, PathError, TravelTimeProfile};
+
+#[test]
+fn travel_time_in_milliseconds() {
+    let profile = TravelTimeProfile::new(vec![(0u32, 60_000), (3_600_000, 120_000)]);
+    assert_eq!(profile.travel_time(1_800_000), 90_000);
+
+    let profile = TravelTimeProfile::new(vec![(0u32, 120_000), (3_600_000, 60_000)]);
+    assert_eq!(profile.travel_time(900_000), 105_000);
+    assert_eq!(profile.travel_time(4_000_000), 60_000);
+}
+
+#[test]
+fn travel_time_at_the_limits_of_the_type() {
+    let profile = TravelTimeProfile::new(vec![(0u64, u64::MAX), (u64::MAX, 0)]);
+    assert_eq!(profile.travel_time(u64::MAX / 2), u64::MAX / 2 + 1);
+}
+
+#[test]
+fn earliest_arrival_with_profiles() {
+    // The cost of every edge is the index of its profile.
+    let profiles = [
+        TravelTimeProfile::new(vec![(0u32, 10_000), (60_000, 200_000)]),
+        TravelTimeProfile::constant(40_000),
+    ];
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 0);
+    graph.add_edge("a", "c", 1);
+    graph.add_edge("c", "b", 1);
+
+    let travel_time =
+        |_: &&str, _: &&str, cost: u32, time: u32| profiles[cost as usize].travel_time(time);
+    let early = time_dependent_dijkstra(&graph, &"a", &"b", 0, travel_time).unwrap();
+    assert_eq!(early.cost, 10_000);
+    assert_eq!(early.nodes, vec!["a", "b"]);
+    let late = time_dependent_dijkstra(&graph, &"a", &"b", 60_000, travel_time).unwrap();
+    assert_eq!(late.cost, 140_000);
+    assert_eq!(late.nodes, vec!["a", "c", "b"]);
+}
+
+#[test]
+fn constant_travel_times_give_the_paths_of_dijkstra() {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..30 {
+        graph.add_edge(node, (node + 1) % 30, 3 + node % 4);
+        graph.add_edge(node, (node * 7 + 2) % 30, 10 + node % 6);
+    }
+
+    for destination in 0..30 {
+        let expected = dijkstra(&graph, &0, &destination).unwrap();
+        let path =
+            time_dependent_dijkstra(&graph, &0, &destination, 500, |_, _, cost, _| cost).unwrap();
+        assert_eq!(path.cost, 500 + expected.cost);
+        assert_eq!(path.nodes.first(), Some(&0));
+        assert_eq!(path.nodes.last(), Some(&destination));
+    }
+}
+
+#[test]
+fn earliest_arrival_errors() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_node("c");
+    let travel_time = |_: &&str, _: &&str, cost: u32, _: u32| cost;
+
+    assert_eq!(
+        time_dependent_dijkstra(&graph, &"a", &"c", 0, travel_time),
+        Err(PathError::NoPath)
+    );
+    assert_eq!(
+        time_dependent_dijkstra(&graph, &"z", &"a", 0, travel_time),
+        Err(PathError::UnknownSource)
+    );
+    assert_eq!(
+        time_dependent_dijkstra(&graph, &"a", &"z", 0, travel_time),
+        Err(PathError::UnknownDestination)
+    );
+    let path = time_dependent_dijkstra(&graph, &"a", &"b", u32::MAX, travel_time);
+    assert_eq!(path, Err(PathError::Overflow));
+}
+
+#[test]
+fn travel_time_before_the_first_breakpoint() {
+    let profile = TravelTimeProfile::new(vec![(100u32, 30), (200, 10)]);
+
+    assert_eq!(profile.travel_time(0), 30);
+    assert_eq!(profile.travel_time(100), 30);
+    assert_eq!(profile.travel_time(150), 20);
+    assert_eq!(profile.travel_time(200), 10);
+    assert_eq!(TravelTimeProfile::constant(7u32).travel_time(1_000), 7);
+}
+
+#[test]
+#[should_panic(expected = "the breakpoints must be in increasing order of time")]
+fn travel_time_profile_rejects_unordered_breakpoints() {
+    TravelTimeProfile::new(vec![(100u32, 30), (100, 10)]);
+}
@@ -0,0 +1,126 @@
+// The graphs derived from other graphs.
+use dijkstra::{dijkstra, CsrGraph, Graph, MergePolicy};
+
+#[test]
+fn reversed_graph_has_the_paths_backward() {
+    let mut graph: Graph<&str, u32, &str, &str> = Graph::new();
+    graph.add_edge_with_data("a", "b", 1, "ab");
+    graph.add_edge("b", "c", 2);
+    graph.set_node_data("a", "start");
+
+    let reversed = graph.reversed();
+    assert_eq!(reversed.edge_cost(&"b", &"a"), Some(1));
+    assert_eq!(reversed.edge_cost(&"a", &"b"), None);
+    assert_eq!(reversed.edge_data(&"b", &"a"), Some(&"ab"));
+    assert_eq!(reversed.node_data(&"a"), Some(&"start"));
+    let path = dijkstra(&reversed, &"c", &"a").unwrap();
+    assert_eq!(path.nodes, vec!["c", "b", "a"]);
+    assert_eq!(path.cost, 3);
+}
+
+#[test]
+fn reversed_csr_graph_has_the_paths_backward() {
+    let csr = CsrGraph::from_edges(vec![("a", "b", 1), ("b", "c", 2), ("a", "c", 4)]);
+
+    let reversed = csr.reversed();
+    assert_eq!(reversed.edge_count(), 3);
+    assert_eq!(dijkstra(&reversed, &"c", &"a").unwrap().cost, 3);
+    assert!(dijkstra(&reversed, &"a", &"c").is_err());
+}
+
+#[test]
+fn filter_keeps_the_edges_between_the_kept_nodes() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 1);
+    graph.add_edge("a", "c", 5);
+
+    let subgraph = graph.filter(|node| *node != "b");
+    assert_eq!(subgraph.node_count(), 2);
+    assert_eq!(subgraph.edge_count(), 1);
+    assert_eq!(dijkstra(&subgraph, &"a", &"c").unwrap().cost, 5);
+}
+
+#[test]
+fn filter_edges_keeps_all_the_nodes() {
+    let mut graph: Graph<&str> = Graph::new_undirected();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 7);
+
+    // The edge between b and c is only accepted in one direction.
+    let subgraph = graph.filter_edges(|source, _, cost| cost < 5 || *source == "b");
+    assert_eq!(subgraph.node_count(), 3);
+    assert_eq!(subgraph.edge_cost(&"a", &"b"), Some(1));
+    assert_eq!(subgraph.edge_cost(&"c", &"b"), None);
+    assert_eq!(subgraph.edge_cost(&"b", &"c"), None);
+}
+
+// Two graphs sharing the edge from a to b, with different costs.
+fn halves() -> (Graph<&'static str>, Graph<&'static str>) {
+    let mut first = Graph::new();
+    first.add_edge("a", "b", 4);
+    let mut second = Graph::new();
+    second.add_edge("a", "b", 2);
+    second.add_edge("b", "c", 1);
+    (first, second)
+}
+
+#[test]
+fn merge_follows_the_merge_policy() {
+    for &(policy, cost) in &[
+        (MergePolicy::Min, 2),
+        (MergePolicy::Max, 4),
+        (MergePolicy::Overwrite, 2),
+    ] {
+        let (mut first, second) = halves();
+        first.merge(&second, policy).unwrap();
+        assert_eq!(first.edge_cost(&"a", &"b"), Some(cost));
+        assert_eq!(first.edge_cost(&"b", &"c"), Some(1));
+    }
+}
+
+#[test]
+fn merge_reports_the_conflicts() {
+    let (mut first, second) = halves();
+
+    let conflict = first.merge(&second, MergePolicy::Error).unwrap_err();
+    assert_eq!((conflict.source, conflict.destination), ("a", "b"));
+    assert_eq!((conflict.existing, conflict.incoming), (4, 2));
+    // A failed merge leaves the graph untouched.
+    assert_eq!(first.edge_cost(&"a", &"b"), Some(4));
+    assert!(!first.contains_node(&"c"));
+}
+
+#[test]
+fn map_weights_keeps_the_nodes_and_the_data() {
+    let mut graph: Graph<&str, u32, &str> = Graph::new();
+    graph.add_edge("a", "b", 2);
+    graph.add_edge("b", "c", 3);
+    graph.add_node("d");
+    graph.set_node_data("a", "start");
+
+    let mapped = graph.map_weights(|_, destination, cost| {
+        u64::from(cost) * if *destination == "c" { 10 } else { 1 }
+    });
+    assert_eq!(mapped.node_count(), 4);
+    assert_eq!(mapped.edge_cost(&"b", &"c"), Some(30));
+    assert_eq!(mapped.node_data(&"a"), Some(&"start"));
+    assert_eq!(dijkstra(&mapped, &"a", &"c").unwrap().cost, 32);
+}
+
+#[test]
+fn map_nodes_keeps_the_cheapest_of_the_merged_edges() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a1", "b", 5);
+    graph.add_edge("a2", "b", 2);
+    graph.add_edge("b", "c", 1);
+
+    // Both a1 and a2 become the node a.
+    let mapped = graph.map_nodes(|node| node.chars().next().unwrap());
+    assert_eq!(mapped.node_count(), 3);
+    assert_eq!(mapped.edge_cost(&'a', &'b'), Some(2));
+    assert_eq!(
+        dijkstra(&mapped, &'a', &'c').unwrap().nodes,
+        vec!['a', 'b', 'c']
+    );
+}
@@ -0,0 +1,50 @@
+// The traversals of the graphs which ignore the costs of the edges.
+use dijkstra::{dfs, is_reachable, CsrGraph, Graph};
+
+#[test]
+fn dfs_goes_deep_first() {
+    // The edges of a CsrGraph come out in the order they were given.
+    let csr = CsrGraph::from_edges(vec![
+        ("a", "b", 1),
+        ("a", "c", 1),
+        ("b", "d", 1),
+        ("c", "e", 1),
+        ("d", "a", 1),
+        ("e", "b", 1),
+    ]);
+
+    let order: Vec<&str> = dfs(&csr, &"a").copied().collect();
+    assert_eq!(order, vec!["a", "b", "d", "c", "e"]);
+    let order: Vec<&str> = dfs(&csr, &"c").copied().collect();
+    assert_eq!(order, vec!["c", "e", "b", "d", "a"]);
+}
+
+#[test]
+fn dfs_visits_every_reachable_node_once() {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..20 {
+        graph.add_edge(node, (node * 3) % 20, 1);
+        graph.add_edge(node, (node + 2) % 20, 1);
+    }
+    graph.add_edge(21, 0, 1);
+
+    let mut visited: Vec<u32> = dfs(&graph, &0).copied().collect();
+    visited.sort_unstable();
+    // Going up by 2 and multiplying by 3 never leaves the even nodes.
+    assert_eq!(visited, (0..20).step_by(2).collect::<Vec<_>>());
+    assert_eq!(dfs(&graph, &30).count(), 0);
+}
+
+#[test]
+fn is_reachable_follows_the_directed_edges() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 1);
+    graph.add_node("d");
+
+    assert!(is_reachable(&graph, &"a", &"c"));
+    assert!(!is_reachable(&graph, &"c", &"a"));
+    assert!(is_reachable(&graph, &"d", &"d"));
+    assert!(!is_reachable(&graph, &"a", &"d"));
+    assert!(!is_reachable(&graph, &"z", &"z"));
+}
@@ -0,0 +1,44 @@
+// The trees of the shortest paths from a source to all the nodes.
+use dijkstra::{dijkstra, dijkstra_all, Graph, PathError};
+
+// A small road network, with a node which can't be reached.
+fn roads() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 4);
+    graph.add_edge("a", "c", 1);
+    graph.add_edge("c", "b", 2);
+    graph.add_edge("b", "d", 1);
+    graph.add_edge("c", "d", 6);
+    graph.add_node("e");
+    graph
+}
+
+#[test]
+fn dijkstra_all_agrees_with_dijkstra() {
+    let graph = roads();
+    let tree = dijkstra_all(&graph, &"a").unwrap();
+
+    assert_eq!(tree.source(), &"a");
+    assert_eq!(tree.len(), 4);
+    for destination in graph.nodes() {
+        let expected = dijkstra(&graph, &"a", destination);
+        assert_eq!(
+            tree.distance(destination),
+            expected.as_ref().ok().map(|path| path.cost)
+        );
+        match tree.path_to(destination) {
+            Ok(path) => assert_eq!(path.nodes, expected.unwrap().nodes),
+            Err(error) => assert_eq!(Err(error), expected),
+        }
+    }
+    assert_eq!(tree.predecessor(&"d"), Some(&"b"));
+    assert_eq!(tree.path_to(&"e"), Err(PathError::NoPath));
+}
+
+#[test]
+fn dijkstra_all_from_an_unknown_source() {
+    assert_eq!(
+        dijkstra_all(&roads(), &"z").unwrap_err(),
+        PathError::UnknownSource
+    );
+}
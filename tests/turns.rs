@@ -0,0 +1,94 @@
+// The searches forbidding or penalizing some of the turns of the paths.
+use dijkstra::{dijkstra, dijkstra_with_turns, Graph, PathError, Turns};
+
+// A crossing c entered from the west w, where the path may turn left
+// towards the north n, or go straight to the east e and around the block
+// through the north-east ne.
+fn crossing() -> Graph<&'static str> {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("w", "c", 1);
+    graph.add_edge("c", "n", 1);
+    graph.add_edge("c", "e", 1);
+    graph.add_edge("e", "ne", 1);
+    graph.add_edge("ne", "n", 1);
+    graph
+}
+
+#[test]
+fn free_turns_give_the_paths_of_dijkstra() {
+    let mut graph: Graph<u32> = Graph::new_undirected();
+    for node in 0..30 {
+        graph.add_edge(node, (node + 1) % 30, 1 + node % 4);
+        graph.add_edge(node, (node * 7 + 3) % 30, 5);
+    }
+    let turns = Turns::new();
+
+    for destination in 0..30 {
+        assert_eq!(
+            dijkstra_with_turns(&graph, &0, &destination, &turns).map(|path| path.cost),
+            dijkstra(&graph, &0, &destination).map(|path| path.cost)
+        );
+    }
+}
+
+#[test]
+fn forbidden_turn_goes_around_the_block() {
+    let graph = crossing();
+    let turns = Turns::new().forbid("w", "c", "n");
+    assert!(turns.forbids(&"w", &"c", &"n"));
+    assert!(!turns.forbids(&"n", &"c", &"w"));
+
+    let path = dijkstra_with_turns(&graph, &"w", &"n", &turns).unwrap();
+    assert_eq!(path.nodes, vec!["w", "c", "e", "ne", "n"]);
+    assert_eq!(path.cost, 4);
+    // The edge itself is still allowed.
+    assert_eq!(
+        dijkstra_with_turns(&graph, &"c", &"n", &turns)
+            .unwrap()
+            .cost,
+        1
+    );
+}
+
+#[test]
+fn penalties_add_to_the_cost_of_the_turns() {
+    let graph = crossing();
+
+    let turns = Turns::new().penalize("w", "c", "n", 1);
+    assert_eq!(turns.penalty(&"w", &"c", &"n"), 1);
+    let path = dijkstra_with_turns(&graph, &"w", &"n", &turns).unwrap();
+    assert_eq!(path.nodes, vec!["w", "c", "n"]);
+    assert_eq!(path.cost, 3);
+
+    // A later penalty replaces the earlier one.
+    let turns = turns.penalize("w", "c", "n", 5);
+    let path = dijkstra_with_turns(&graph, &"w", &"n", &turns).unwrap();
+    assert_eq!(path.nodes, vec!["w", "c", "e", "ne", "n"]);
+    assert_eq!(path.cost, 4);
+}
+
+#[test]
+fn path_may_go_through_a_node_twice() {
+    // The only way from s to t turns back at the dead end d.
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("s", "m", 1);
+    graph.add_edge("m", "d", 1);
+    graph.add_edge("d", "m", 1);
+    graph.add_edge("m", "t", 1);
+    let turns = Turns::new().forbid("s", "m", "t");
+
+    let path = dijkstra_with_turns(&graph, &"s", &"t", &turns).unwrap();
+    assert_eq!(path.nodes, vec!["s", "m", "d", "m", "t"]);
+    assert_eq!(path.cost, 4);
+
+    let turns = turns.forbid_u_turns();
+    assert!(turns.forbids(&"m", &"d", &"m"));
+    assert_eq!(
+        dijkstra_with_turns(&graph, &"s", &"t", &turns),
+        Err(PathError::NoPath)
+    );
+    assert_eq!(
+        dijkstra_with_turns(&graph, &"x", &"t", &turns),
+        Err(PathError::UnknownSource)
+    );
+}
@@ -0,0 +1,43 @@
+// The undirected graphs, whose edges are followed in both directions.
+use dijkstra::{dijkstra, Graph, GraphBuilder, GraphKind};
+
+#[test]
+fn undirected_edges_are_followed_backward() {
+    let mut graph: Graph<&str> = Graph::new_undirected();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "c", 2);
+
+    assert_eq!(graph.kind(), GraphKind::Undirected);
+    let path = dijkstra(&graph, &"c", &"a").unwrap();
+    assert_eq!(path.nodes, vec!["c", "b", "a"]);
+    assert_eq!(path.cost, 3);
+}
+
+#[test]
+fn updating_an_undirected_edge_updates_both_directions() {
+    let mut graph: Graph<&str> = Graph::new_undirected();
+    graph.add_edge("a", "b", 1);
+    assert_eq!(graph.add_edge("b", "a", 4), Some(1));
+
+    assert_eq!(graph.edge_cost(&"a", &"b"), Some(4));
+    assert_eq!(graph.edge_cost(&"b", &"a"), Some(4));
+}
+
+#[test]
+fn directed_graph_with_an_undirected_edge() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_undirected_edge("b", "c", 1);
+
+    assert_eq!(graph.kind(), GraphKind::Directed);
+    assert!(dijkstra(&graph, &"c", &"a").is_err());
+    assert_eq!(dijkstra(&graph, &"c", &"b").unwrap().cost, 1);
+}
+
+#[test]
+fn graph_builder_of_an_undirected_graph() {
+    let graph: Graph<&str> = GraphBuilder::new_undirected().edge("a", "b", 2).build();
+
+    assert_eq!(graph.kind(), GraphKind::Undirected);
+    assert_eq!(graph.edge_cost(&"b", &"a"), Some(2));
+}
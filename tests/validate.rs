@@ -0,0 +1,50 @@
+// The oddities reported by validate.
+use dijkstra::{validate, CsrGraph, Graph};
+
+#[test]
+fn consistent_graph_is_valid() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "a", 1);
+
+    assert!(graph.validate().is_valid());
+}
+
+#[test]
+fn validate_reports_the_loops_and_the_negative_edges() {
+    let mut graph: Graph<&str, i32> = Graph::new();
+    graph.add_edge("a", "a", 2);
+    graph.add_edge("a", "b", -1);
+    graph.add_edge("b", "c", 3);
+
+    let report = graph.validate();
+    assert!(!report.is_valid());
+    assert_eq!(report.self_loops, vec![("a", 2)]);
+    assert_eq!(report.negative_edges, vec![("a", "b", -1)]);
+    assert!(report.dangling_edges.is_empty());
+    assert!(report.zero_weight_cycles.is_empty());
+}
+
+#[test]
+fn validate_reports_the_cycles_of_zero_cost() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 0);
+    graph.add_edge("b", "c", 0);
+    graph.add_edge("c", "a", 0);
+    graph.add_edge("c", "d", 0);
+
+    let report = graph.validate();
+    assert_eq!(report.zero_weight_cycles.len(), 1);
+    let mut cycle = report.zero_weight_cycles[0].clone();
+    cycle.sort();
+    cycle.dedup();
+    assert_eq!(cycle, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn validate_reports_the_parallel_edges() {
+    let csr = CsrGraph::from_edges(vec![("a", "b", 1), ("a", "b", 2), ("b", "c", 1)]);
+
+    let report = validate(&csr);
+    assert_eq!(report.duplicate_edges, vec![("a", "b")]);
+}
@@ -0,0 +1,110 @@
+// The random walks, drawing their numbers from the generators of the caller.
+use dijkstra::{random_walk, weighted_random_walk, Graph, PathError};
+
+// A generator drawing the same numbers for the same seed.
+fn generator(seed: u64) -> impl FnMut() -> u64 {
+    let mut state = seed;
+    move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        state
+    }
+}
+
+#[test]
+fn random_walk_follows_the_edges() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "a", 1);
+    graph.add_edge("b", "c", 1);
+    graph.add_edge("c", "a", 1);
+
+    let walk = random_walk(&graph, &"a", 50, generator(7)).unwrap();
+    assert_eq!(walk.len(), 51);
+    assert_eq!(walk[0], "a");
+    for step in walk.windows(2) {
+        assert!(graph.edge_cost(&step[0], &step[1]).is_some());
+    }
+    assert_eq!(random_walk(&graph, &"a", 50, generator(7)).unwrap(), walk);
+}
+
+#[test]
+fn random_walk_stops_on_a_node_without_edges() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+
+    assert_eq!(
+        random_walk(&graph, &"a", 10, generator(0)).unwrap(),
+        vec!["a", "b"]
+    );
+    assert_eq!(
+        random_walk(&graph, &"c", 10, generator(0)),
+        Err(PathError::UnknownSource)
+    );
+}
+
+#[test]
+fn weighted_random_walk_never_follows_an_edge_of_zero_weight() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "a", 0);
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("b", "a", 1);
+
+    // Both the lowest and the highest numbers fall on the edges of weight
+    // one, whichever order the edges of "a" come out in.
+    for number in &[0, u64::MAX] {
+        let walk = weighted_random_walk(&graph, &"a", 4, || *number).unwrap();
+        assert_eq!(walk, vec!["a", "b", "a", "b", "a"]);
+    }
+    let walk = weighted_random_walk(&graph, &"a", 100, generator(3)).unwrap();
+    assert!(walk.windows(2).all(|step| step[0] != step[1]));
+}
+
+#[test]
+fn weighted_random_walk_follows_the_edges_of_zero_weight_uniformly() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 0);
+    graph.add_edge("a", "c", 0);
+
+    let walk = weighted_random_walk(&graph, &"a", 1, || 0).unwrap();
+    let other = weighted_random_walk(&graph, &"a", 1, || u64::MAX).unwrap();
+    assert_eq!(walk.len(), 2);
+    assert_eq!(other.len(), 2);
+    assert_ne!(walk, other);
+}
+
+#[test]
+fn weighted_random_walk_follows_the_edges_in_proportion_to_their_weights() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+    graph.add_edge("a", "c", 3);
+    graph.add_edge("b", "a", 1);
+    graph.add_edge("c", "a", 1);
+
+    let walk = weighted_random_walk(&graph, &"a", 4_000, generator(11)).unwrap();
+    let leaving: Vec<&str> = walk
+        .windows(2)
+        .filter(|step| step[0] == "a")
+        .map(|step| step[1])
+        .collect();
+    let to_c = leaving.iter().filter(|&&node| node == "c").count();
+    let share = to_c as f64 / leaving.len() as f64;
+    assert!(share > 0.7 && share < 0.8, "{}", share);
+}
+
+#[test]
+fn walk_without_steps_stays_on_the_source() {
+    let mut graph: Graph<&str> = Graph::new();
+    graph.add_edge("a", "b", 1);
+
+    assert_eq!(random_walk(&graph, &"a", 0, generator(1)), Ok(vec!["a"]));
+    assert_eq!(
+        weighted_random_walk(&graph, &"a", 0, generator(1)),
+        Ok(vec!["a"])
+    );
+    assert_eq!(
+        weighted_random_walk(&graph, &"c", 3, generator(1)),
+        Err(PathError::UnknownSource)
+    );
+}
@@ -0,0 +1,149 @@
+// The routes going through given stops, in order.
+use dijkstra::{
+    dijkstra, shortest_path_through, shortest_path_via, Graph, PathError, EXACT_WAYPOINTS,
+};
+
+// A ring of six towns, going one way only.
+fn ring() -> Graph<&'static str> {
+    let towns = ["a", "b", "c", "d", "e", "f"];
+    let mut graph: Graph<&str> = Graph::new();
+    for (position, town) in towns.iter().enumerate() {
+        graph.add_edge(*town, towns[(position + 1) % 6], 1 + position as u32);
+    }
+    graph.add_node("island");
+    graph
+}
+
+#[test]
+fn route_goes_through_the_stops_in_order() {
+    let graph = ring();
+
+    let path = shortest_path_via(&graph, &"a", &["e", "b"], &"c").unwrap();
+    assert_eq!(
+        path.nodes,
+        vec!["a", "b", "c", "d", "e", "f", "a", "b", "c"]
+    );
+    let legs = [("a", "e"), ("e", "b"), ("b", "c")];
+    let cost: u32 = legs
+        .iter()
+        .map(|(from, to)| dijkstra(&graph, from, to).unwrap().cost)
+        .sum();
+    assert_eq!(path.cost, cost);
+}
+
+#[test]
+fn route_without_stops_is_the_path_of_dijkstra() {
+    let graph = ring();
+
+    let path = shortest_path_via(&graph, &"b", &[], &"e").unwrap();
+    let expected = dijkstra(&graph, &"b", &"e").unwrap();
+    assert_eq!(path.nodes, expected.nodes);
+    assert_eq!(path.settled, expected.settled);
+}
+
+#[test]
+fn route_through_unknown_or_unreachable_stops() {
+    let graph = ring();
+
+    assert_eq!(
+        shortest_path_via(&graph, &"z", &["b"], &"c"),
+        Err(PathError::UnknownSource)
+    );
+    assert_eq!(
+        shortest_path_via(&graph, &"a", &["z"], &"c"),
+        Err(PathError::UnknownDestination)
+    );
+    assert_eq!(
+        shortest_path_via(&graph, &"a", &["island"], &"c"),
+        Err(PathError::NoPath)
+    );
+}
+
+// A grid of 5 by 5 nodes, whose edges cost more towards the bottom right.
+fn grid() -> Graph<(u32, u32)> {
+    let mut graph: Graph<(u32, u32)> = Graph::new_undirected();
+    for x in 0..5 {
+        for y in 0..5 {
+            if x + 1 < 5 {
+                graph.add_edge((x, y), (x + 1, y), 1 + x);
+            }
+            if y + 1 < 5 {
+                graph.add_edge((x, y), (x, y + 1), 1 + y);
+            }
+        }
+    }
+    graph
+}
+
+// Calls the closure with every order of the stops.
+fn permutations<F: FnMut(&[(u32, u32)])>(stops: &mut Vec<(u32, u32)>, start: usize, visit: &mut F) {
+    if start == stops.len() {
+        visit(stops);
+        return;
+    }
+    for position in start..stops.len() {
+        stops.swap(start, position);
+        permutations(stops, start + 1, visit);
+        stops.swap(start, position);
+    }
+}
+
+#[test]
+fn route_through_picks_the_cheapest_order() {
+    let graph = grid();
+    let mut waypoints = vec![(4, 4), (0, 4), (2, 1), (4, 0)];
+
+    let path = shortest_path_through(&graph, &(0, 0), &waypoints, &(2, 2)).unwrap();
+    let mut cheapest = u32::MAX;
+    permutations(&mut waypoints, 0, &mut |order| {
+        let cost = shortest_path_via(&graph, &(0, 0), order, &(2, 2))
+            .unwrap()
+            .cost;
+        cheapest = cheapest.min(cost);
+    });
+    assert_eq!(path.cost, cheapest);
+    for waypoint in &waypoints {
+        assert!(path.nodes.contains(waypoint));
+    }
+}
+
+#[test]
+fn route_through_many_waypoints_visits_them_all() {
+    let graph = grid();
+    let waypoints: Vec<(u32, u32)> = (0..5)
+        .flat_map(|x| vec![(x, 4 - x), (x, (x + 2) % 5), (4 - x, 0)])
+        .collect();
+    assert!(waypoints.len() > EXACT_WAYPOINTS);
+
+    let path = shortest_path_through(&graph, &(0, 0), &waypoints, &(4, 4)).unwrap();
+    assert_eq!(path.nodes.first(), Some(&(0, 0)));
+    assert_eq!(path.nodes.last(), Some(&(4, 4)));
+    for waypoint in &waypoints {
+        assert!(path.nodes.contains(waypoint));
+    }
+    let cost: u32 = path
+        .nodes
+        .windows(2)
+        .map(|pair| graph.edge_cost(&pair[0], &pair[1]).unwrap())
+        .sum();
+    assert_eq!(cost, path.cost);
+    // The route in the given order is a valid route, just not a short one.
+    assert!(
+        path.cost
+            <= shortest_path_via(&graph, &(0, 0), &waypoints, &(4, 4))
+                .unwrap()
+                .cost
+    );
+}
+
+#[test]
+fn route_through_unreachable_waypoints() {
+    let graph = ring();
+
+    assert_eq!(
+        shortest_path_through(&graph, &"a", &["c", "island"], &"b"),
+        Err(PathError::NoPath)
+    );
+    let path = shortest_path_through(&graph, &"a", &["c", "b"], &"d").unwrap();
+    assert_eq!(path.nodes, vec!["a", "b", "c", "d"]);
+}
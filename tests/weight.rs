@@ -0,0 +1,97 @@
+// The costs of the edges, which can be any Weight.
+use std::ops::Add;
+
+use dijkstra::{
+    dijkstra, dijkstra_with_policy, FloatWeight, Graph, InvalidWeight, OverflowPolicy, PathError,
+    Weight,
+};
+
+#[test]
+fn dijkstra_with_costs_wider_than_u32() {
+    let mut graph: Graph<&str, u64> = Graph::new();
+    graph.add_edge("a", "b", 1 << 40);
+    graph.add_edge("b", "c", 1 << 40);
+    graph.add_edge("a", "c", (1 << 41) + 1);
+
+    let path = dijkstra(&graph, &"a", &"c").unwrap();
+    assert_eq!(path.nodes, vec!["a", "b", "c"]);
+    assert_eq!(path.cost, 1 << 41);
+}
+
+// A cost made of a number of tolls, then of a distance, the paths with the
+// fewest tolls being the shortest ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Tolls(u32, u32);
+
+impl Add for Tolls {
+    type Output = Tolls;
+
+    fn add(self, other: Tolls) -> Tolls {
+        Tolls(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl Weight for Tolls {
+    fn zero() -> Self {
+        Tolls(0, 0)
+    }
+}
+
+#[test]
+fn dijkstra_with_a_weight_of_the_caller() {
+    let mut graph: Graph<&str, Tolls> = Graph::new();
+    graph.add_edge("a", "b", Tolls(1, 1));
+    graph.add_edge("a", "c", Tolls(0, 5));
+    graph.add_edge("c", "b", Tolls(0, 5));
+
+    let path = dijkstra(&graph, &"a", &"b").unwrap();
+    assert_eq!(path.nodes, vec!["a", "c", "b"]);
+    assert_eq!(path.cost, Tolls(0, 10));
+}
+
+#[test]
+fn float_weight_rejects_nan_and_negative_values() {
+    assert_eq!(FloatWeight::new(f64::NAN), Err(InvalidWeight::NaN));
+    assert_eq!(FloatWeight::new(-1.0f32), Err(InvalidWeight::Negative));
+    assert_eq!(FloatWeight::new(0.5).map(FloatWeight::get), Ok(0.5));
+    // Negative zero is a valid cost, equal to zero.
+    assert_eq!(FloatWeight::new(-0.0), Ok(FloatWeight::zero()));
+}
+
+#[test]
+fn dijkstra_with_floating_point_costs() {
+    let cost = |value: f64| FloatWeight::new(value).unwrap();
+    let mut graph: Graph<&str, FloatWeight> = Graph::new();
+    graph.add_edge("a", "b", cost(0.25));
+    graph.add_edge("b", "c", cost(0.5));
+    graph.add_edge("a", "c", cost(1.0));
+
+    let path = dijkstra(&graph, &"a", &"c").unwrap();
+    assert_eq!(path.nodes, vec!["a", "b", "c"]);
+    assert_eq!(path.cost.get(), 0.75);
+}
+
+// A path whose cost doesn't fit in an u8.
+fn overflowing() -> Graph<&'static str, u8> {
+    let mut graph = Graph::new();
+    graph.add_edge("a", "b", 200);
+    graph.add_edge("b", "c", 100);
+    graph
+}
+
+#[test]
+fn dijkstra_reports_overflowing_costs() {
+    let graph = overflowing();
+
+    assert_eq!(dijkstra(&graph, &"a", &"c"), Err(PathError::Overflow));
+    assert_eq!(dijkstra(&graph, &"a", &"b").unwrap().cost, 200);
+}
+
+#[test]
+fn saturating_policy_clamps_the_costs() {
+    let graph = overflowing();
+
+    let path = dijkstra_with_policy(&graph, &"a", &"c", OverflowPolicy::Saturating).unwrap();
+    assert_eq!(path.nodes, vec!["a", "b", "c"]);
+    assert_eq!(path.cost, u8::MAX);
+}
@@ -0,0 +1,47 @@
+// The search state kept across several queries.
+use dijkstra::{dijkstra, Graph, PathError, SearchWorkspace};
+
+// A ring of ten nodes, with a few chords.
+fn ring() -> Graph<u32> {
+    let mut graph: Graph<u32> = Graph::new();
+    for node in 0..10 {
+        graph.add_edge(node, (node + 1) % 10, 1);
+        graph.add_edge(node, (node + 4) % 10, 3);
+    }
+    graph
+}
+
+#[test]
+fn workspace_answers_like_dijkstra() {
+    let graph = ring();
+    let mut workspace = SearchWorkspace::new();
+
+    for source in 0..10 {
+        for destination in 0..10 {
+            assert_eq!(
+                workspace
+                    .dijkstra(&graph, &source, &destination)
+                    .unwrap()
+                    .cost,
+                dijkstra(&graph, &source, &destination).unwrap().cost
+            );
+        }
+    }
+}
+
+#[test]
+fn workspace_recovers_from_failed_queries() {
+    let mut graph = ring();
+    graph.add_node(10);
+    let mut workspace = SearchWorkspace::new();
+
+    assert_eq!(workspace.dijkstra(&graph, &0, &10), Err(PathError::NoPath));
+    assert_eq!(
+        workspace.dijkstra(&graph, &11, &0),
+        Err(PathError::UnknownSource)
+    );
+    // Nothing is left over from the searches above.
+    let path = workspace.dijkstra(&graph, &0, &2).unwrap();
+    assert_eq!(path.nodes, vec![0, 1, 2]);
+    assert_eq!(path.settled, dijkstra(&graph, &0, &2).unwrap().settled);
+}